@@ -0,0 +1,86 @@
+//! Benchmarks for z-MPC
+//! 
+//! Run with `cargo bench`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::collections::HashMap;
+use z_mpc::{CurveType, LaurentSeries, Participant, PedersenCommitment, ShareId, SharingParams, ZeroKnowledgeProof};
+use z_mpc::zkp::utils as zkp_utils;
+use z_mpc::registry::{key_fingerprint, ParticipantRegistry};
+
+/// Number of peers in the participant lookup benchmarks
+const REGISTRY_SIZE: u32 = 10_000;
+
+fn populated_registry() -> ParticipantRegistry {
+    let mut registry = ParticipantRegistry::new();
+    for id in 1..=REGISTRY_SIZE {
+        registry.insert(Participant {
+            id: id as ShareId,
+            address: format!("10.{}.{}.{}:7000", id >> 16, (id >> 8) & 0xff, id & 0xff).parse().unwrap(),
+            public_keys: HashMap::from([(CurveType::Secp256k1, id.to_be_bytes().repeat(8))]),
+            is_online: true,
+            last_heartbeat: 0,
+        }).unwrap();
+    }
+    registry
+}
+
+fn bench_participant_lookup(c: &mut Criterion) {
+    let registry = populated_registry();
+    let id = REGISTRY_SIZE / 2;
+    let address = registry.get(id).unwrap().address;
+    let fingerprint = key_fingerprint(&id.to_be_bytes().repeat(8));
+    
+    let mut group = c.benchmark_group("participant_lookup_10k");
+    group.bench_function("by_id", |b| b.iter(|| registry.get(black_box(id))));
+    group.bench_function("by_addr", |b| b.iter(|| registry.participant_by_addr(black_box(&address))));
+    group.bench_function("by_key", |b| b.iter(|| registry.participant_by_key(black_box(&fingerprint))));
+    group.finish();
+}
+
+fn bench_generate_shares(c: &mut Criterion) {
+    let params = SharingParams {
+        curve_type: CurveType::Secp256k1,
+        threshold: 20,
+        participants: 100,
+    };
+    let laurent = LaurentSeries::new(&params).unwrap();
+    
+    c.bench_function("generate_shares_20_of_100", |b| b.iter(|| laurent.generate_shares().unwrap()));
+}
+
+fn bench_batch_verify_proofs(c: &mut Criterion) {
+    let curve = z_mpc::curve::create_curve(CurveType::Secp256k1).unwrap();
+    let pedersen = PedersenCommitment::new(CurveType::Secp256k1).unwrap();
+    let proofs: Vec<ZeroKnowledgeProof> = (0..32)
+        .map(|_| {
+            let mut proof = ZeroKnowledgeProof::new(CurveType::Secp256k1);
+            proof.prove(&pedersen, &curve.random_scalar().unwrap(), &pedersen.generate_randomness()).unwrap();
+            proof
+        })
+        .collect();
+    
+    let mut group = c.benchmark_group("batch_verify_32_proofs");
+    group.bench_function("individual", |b| b.iter(|| zkp_utils::batch_verify_proofs(black_box(&proofs), &pedersen).unwrap()));
+    group.bench_function("combined", |b| b.iter(|| zkp_utils::batch_verify_proofs_fast(black_box(&proofs), &pedersen).unwrap()));
+    group.finish();
+}
+
+fn bench_pedersen_commit(c: &mut Criterion) {
+    let curve = z_mpc::curve::curve_backend(CurveType::Secp256k1).unwrap();
+    let pedersen = PedersenCommitment::new(CurveType::Secp256k1).unwrap();
+    let value = curve.random_scalar().unwrap();
+    let randomness = pedersen.generate_randomness();
+    let r = curve.scalar_from_bytes(&randomness).unwrap();
+    let generators = [pedersen.g.clone(), pedersen.h.clone()];
+    
+    let mut group = c.benchmark_group("pedersen_commit");
+    group.bench_function("generic", |b| b.iter(|| {
+        curve.msm(black_box(&generators), &[value.clone(), r.clone()]).unwrap().to_compressed_bytes().unwrap()
+    }));
+    group.bench_function("precomputed", |b| b.iter(|| pedersen.commit(black_box(&value), &randomness).unwrap()));
+    group.finish();
+}
+
+criterion_group!(benches, bench_participant_lookup, bench_generate_shares, bench_batch_verify_proofs, bench_pedersen_commit);
+criterion_main!(benches);
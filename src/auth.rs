@@ -0,0 +1,383 @@
+//! Pluggable peer authentication for network messages
+//! 
+//! Deployments authenticate peers differently, so the coordinator only sees
+//! the `AuthLayer` trait:
+//! - `SchnorrAuth`: every message is Schnorr-signed with the sender's key
+//! - `MtlsAuth`: the sender is identified by the client certificate of the
+//!   TLS connection the message arrived on
+//! 
+//! Both wrap outgoing messages into a `SignedEnvelope` and map a verified
+//! envelope back to the sender's `ShareId`. Every envelope carries a nonce
+//! that increases per sender, so a `ReplayGuard` can refuse replays.
+//! 
+//! Nodes sign with a generated `SchnorrAuth` identity unless configured
+//! otherwise.
+
+use crate::{Error, Result, CurveType, ShareId};
+use crate::curve::{curve_backend, enabled_curves, Scalar};
+use crate::network::{NetworkMessage, Participant};
+use crate::zkp::SchnorrSignature;
+use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Digest};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Domain separator for Schnorr-signed envelopes
+const ENVELOPE_DOMAIN: &[u8] = b"z-mpc-envelope";
+
+/// How far below a sender's highest nonce an unseen nonce is still accepted
+/// 
+/// Concurrent deliveries to one peer may arrive out of order.
+pub const REPLAY_WINDOW: u64 = 1024;
+
+/// Authentication scheme of an envelope
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuthScheme {
+    /// Schnorr signature over the payload
+    Schnorr,
+    /// Mutual TLS client certificate
+    MutualTls,
+}
+
+/// Network message together with the sender's credential
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedEnvelope {
+    pub sender_id: ShareId,
+    pub scheme: AuthScheme,
+    /// Per-sender counter, increasing with every envelope
+    #[serde(default)]
+    pub nonce: u64,
+    pub payload: Vec<u8>,
+    pub credential: Vec<u8>,
+}
+
+/// DER certificate a peer presented on its TLS connection
+/// 
+/// The TLS acceptor verifies the chain and inserts the certificate into the
+/// extensions of every request on the connection. Envelopes never carry it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerCertificate(pub Vec<u8>);
+
+impl SignedEnvelope {
+    /// Decode the wrapped network message
+    pub fn message(&self) -> Result<NetworkMessage> {
+        Ok(serde_json::from_slice(&self.payload)?)
+    }
+}
+
+/// Authentication of outgoing and incoming network messages
+pub trait AuthLayer: Send + Sync {
+    /// Scheme implemented by this layer
+    fn scheme(&self) -> AuthScheme;
+    
+    /// Wrap an outgoing message with this node's credential
+    fn sign_outgoing(&self, message: &NetworkMessage) -> Result<SignedEnvelope>;
+    
+    /// Authenticate an incoming envelope and return the sender
+    fn verify_incoming(&self, envelope: &SignedEnvelope) -> Result<ShareId>;
+    
+    /// Authenticate an envelope received over a connection
+    /// 
+    /// `peer_certificate` is the client certificate of the connection, if it
+    /// was made over mutual TLS.
+    fn verify_on_connection(
+        &self,
+        envelope: &SignedEnvelope,
+        _peer_certificate: Option<&PeerCertificate>,
+    ) -> Result<ShareId> {
+        self.verify_incoming(envelope)
+    }
+    
+    /// Key this node publishes in its `Participant` entry, if the scheme has one
+    fn published_key(&self) -> Option<(CurveType, Vec<u8>)> {
+        None
+    }
+    
+    /// Trust the published key of a newly registered participant
+    fn register_participant(&self, _participant: &Participant) {}
+    
+    /// Stop trusting a participant that left the network
+    fn deregister_participant(&self, _participant_id: ShareId) {}
+}
+
+/// Nonces handed out to a node's outgoing envelopes
+/// 
+/// Seeded from the wall clock in microseconds, so nonces keep increasing
+/// across restarts. Clones share the counter.
+#[derive(Debug, Clone)]
+struct NonceCounter(Arc<AtomicU64>);
+
+impl NonceCounter {
+    fn new() -> Self {
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_micros() as u64)
+            .unwrap_or_default();
+        Self(Arc::new(AtomicU64::new(seed)))
+    }
+    
+    fn next(&self) -> u64 {
+        self.0.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
+
+/// Nonces seen from one sender
+#[derive(Debug, Clone, Default)]
+struct SeenNonces {
+    highest: u64,
+    recent: BTreeSet<u64>,
+}
+
+/// Refuses envelopes whose nonce a sender already used
+/// 
+/// Nonces up to `REPLAY_WINDOW` below the highest one seen are accepted
+/// once; older ones are refused outright.
+#[derive(Debug, Clone, Default)]
+pub struct ReplayGuard {
+    senders: HashMap<ShareId, SeenNonces>,
+}
+
+impl ReplayGuard {
+    /// Create new replay guard
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Accept `nonce` from `sender_id` unless it was used before
+    /// 
+    /// Fails with `Error::ReplayedMessage` for a repeated or stale nonce.
+    pub fn check(&mut self, sender_id: ShareId, nonce: u64) -> Result<()> {
+        let seen = self.senders.entry(sender_id).or_default();
+        let stale = seen.highest > REPLAY_WINDOW && nonce <= seen.highest - REPLAY_WINDOW;
+        if stale || seen.recent.contains(&nonce) {
+            return Err(Error::ReplayedMessage(format!(
+                "Nonce {} from participant {} was already used", nonce, sender_id
+            )));
+        }
+        
+        seen.recent.insert(nonce);
+        seen.highest = seen.highest.max(nonce);
+        let floor = seen.highest.saturating_sub(REPLAY_WINDOW);
+        seen.recent = seen.recent.split_off(&(floor + 1));
+        Ok(())
+    }
+}
+
+/// Check that an envelope uses the expected scheme
+fn expect_scheme(envelope: &SignedEnvelope, scheme: AuthScheme) -> Result<()> {
+    if envelope.scheme != scheme {
+        return Err(Error::AuthError(format!(
+            "Expected {:?} envelope from {}, got {:?}", scheme, envelope.sender_id, envelope.scheme
+        )));
+    }
+    Ok(())
+}
+
+/// Schnorr-signed messages under per-node keys
+/// 
+/// Clones share the peer keys, so participants registered with a node are
+/// trusted by every copy of its layer.
+#[derive(Debug, Clone)]
+pub struct SchnorrAuth {
+    node_id: ShareId,
+    curve_type: CurveType,
+    secret_key: Scalar,
+    peer_keys: Arc<RwLock<HashMap<ShareId, Vec<u8>>>>,
+    nonces: NonceCounter,
+}
+
+impl SchnorrAuth {
+    /// Create new Schnorr authentication for a node
+    pub fn new(node_id: ShareId, secret_key: Scalar) -> Self {
+        Self {
+            node_id,
+            curve_type: secret_key.curve_type,
+            secret_key,
+            peer_keys: Arc::new(RwLock::new(HashMap::new())),
+            nonces: NonceCounter::new(),
+        }
+    }
+    
+    /// Create Schnorr authentication under a fresh key on the first enabled curve
+    pub fn generate(node_id: ShareId) -> Result<Self> {
+        let curve_type = enabled_curves().first().copied()
+            .ok_or_else(|| Error::InvalidCurve("No curve is compiled in".to_string()))?;
+        let secret_key = curve_backend(curve_type)?.random_scalar()?;
+        Ok(Self::new(node_id, secret_key))
+    }
+    
+    /// Compressed public key peers verify this node's envelopes with
+    pub fn public_key(&self) -> Result<Vec<u8>> {
+        let curve = curve_backend(self.curve_type)?;
+        curve.generator().mul(&self.secret_key)?.to_compressed_bytes()
+    }
+    
+    /// Register a peer's compressed public key
+    pub fn with_peer(self, peer_id: ShareId, public_key: Vec<u8>) -> Self {
+        self.peer_keys.write().unwrap_or_else(PoisonError::into_inner).insert(peer_id, public_key);
+        self
+    }
+    
+    /// Register the published keys of participants on this node's curve
+    pub fn with_participants<'a>(self, participants: impl IntoIterator<Item = &'a Participant>) -> Self {
+        for participant in participants {
+            self.register_participant(participant);
+        }
+        self
+    }
+    
+    /// Payload covered by the signature
+    fn signing_payload(sender_id: ShareId, nonce: u64, payload: &[u8]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(ENVELOPE_DOMAIN);
+        data.extend_from_slice(&sender_id.to_be_bytes());
+        data.extend_from_slice(&nonce.to_be_bytes());
+        data.extend_from_slice(payload);
+        data
+    }
+}
+
+impl AuthLayer for SchnorrAuth {
+    fn scheme(&self) -> AuthScheme {
+        AuthScheme::Schnorr
+    }
+    
+    fn sign_outgoing(&self, message: &NetworkMessage) -> Result<SignedEnvelope> {
+        let payload = serde_json::to_vec(message)?;
+        let nonce = self.nonces.next();
+        
+        let mut signature = SchnorrSignature::new(self.curve_type);
+        signature.sign(&Self::signing_payload(self.node_id, nonce, &payload), &self.secret_key)?;
+        
+        Ok(SignedEnvelope {
+            sender_id: self.node_id,
+            scheme: AuthScheme::Schnorr,
+            nonce,
+            payload,
+            credential: serde_json::to_vec(&signature)?,
+        })
+    }
+    
+    fn verify_incoming(&self, envelope: &SignedEnvelope) -> Result<ShareId> {
+        expect_scheme(envelope, AuthScheme::Schnorr)?;
+        
+        let public_key = self.peer_keys.read().unwrap_or_else(PoisonError::into_inner)
+            .get(&envelope.sender_id)
+            .cloned()
+            .ok_or_else(|| Error::AuthError(format!("Unknown peer {}", envelope.sender_id)))?;
+        
+        let signature: SchnorrSignature = serde_json::from_slice(&envelope.credential)
+            .map_err(|_| Error::AuthError("Malformed envelope signature".to_string()))?;
+        
+        if signature.curve_type != self.curve_type || signature.public_key != public_key {
+            return Err(Error::AuthError(format!("Envelope not signed by peer {}", envelope.sender_id)));
+        }
+        
+        if !signature.verify(&Self::signing_payload(envelope.sender_id, envelope.nonce, &envelope.payload))? {
+            return Err(Error::AuthError(format!("Invalid envelope signature from {}", envelope.sender_id)));
+        }
+        
+        Ok(envelope.sender_id)
+    }
+    
+    fn published_key(&self) -> Option<(CurveType, Vec<u8>)> {
+        self.public_key().ok().map(|public_key| (self.curve_type, public_key))
+    }
+    
+    fn register_participant(&self, participant: &Participant) {
+        if let Some(public_key) = participant.public_key(self.curve_type) {
+            self.peer_keys.write().unwrap_or_else(PoisonError::into_inner)
+                .insert(participant.id, public_key.to_vec());
+        }
+    }
+    
+    fn deregister_participant(&self, participant_id: ShareId) {
+        self.peer_keys.write().unwrap_or_else(PoisonError::into_inner).remove(&participant_id);
+    }
+}
+
+/// Peers identified by their mutual TLS client certificates
+/// 
+/// The TLS acceptor verifies the certificate chain and attaches the
+/// connection's certificate to its requests as a `PeerCertificate`. The
+/// envelope credential is ignored: certificates are public, so anyone could
+/// copy a peer's certificate into an envelope. Envelopes that did not arrive
+/// over such a connection are rejected.
+#[derive(Debug, Clone)]
+pub struct MtlsAuth {
+    node_id: ShareId,
+    certificate: Vec<u8>,
+    peer_fingerprints: HashMap<ShareId, Vec<u8>>,
+    nonces: NonceCounter,
+}
+
+impl MtlsAuth {
+    /// Create new mTLS authentication from this node's DER certificate
+    pub fn new(node_id: ShareId, certificate: Vec<u8>) -> Self {
+        Self {
+            node_id,
+            certificate,
+            peer_fingerprints: HashMap::new(),
+            nonces: NonceCounter::new(),
+        }
+    }
+    
+    /// This node's DER certificate, for the TLS client to present
+    pub fn certificate(&self) -> &[u8] {
+        &self.certificate
+    }
+    
+    /// Register a peer's DER certificate
+    pub fn with_peer(mut self, peer_id: ShareId, certificate: &[u8]) -> Self {
+        self.peer_fingerprints.insert(peer_id, Self::fingerprint(certificate));
+        self
+    }
+    
+    /// SHA-256 fingerprint of a DER certificate
+    pub fn fingerprint(certificate: &[u8]) -> Vec<u8> {
+        Sha256::digest(certificate).to_vec()
+    }
+}
+
+impl AuthLayer for MtlsAuth {
+    fn scheme(&self) -> AuthScheme {
+        AuthScheme::MutualTls
+    }
+    
+    fn sign_outgoing(&self, message: &NetworkMessage) -> Result<SignedEnvelope> {
+        Ok(SignedEnvelope {
+            sender_id: self.node_id,
+            scheme: AuthScheme::MutualTls,
+            nonce: self.nonces.next(),
+            payload: serde_json::to_vec(message)?,
+            credential: Vec::new(),
+        })
+    }
+    
+    fn verify_incoming(&self, envelope: &SignedEnvelope) -> Result<ShareId> {
+        self.verify_on_connection(envelope, None)
+    }
+    
+    fn verify_on_connection(
+        &self,
+        envelope: &SignedEnvelope,
+        peer_certificate: Option<&PeerCertificate>,
+    ) -> Result<ShareId> {
+        expect_scheme(envelope, AuthScheme::MutualTls)?;
+        
+        let PeerCertificate(certificate) = peer_certificate.ok_or_else(|| Error::AuthError(format!(
+            "Envelope from peer {} did not arrive over mutual TLS", envelope.sender_id
+        )))?;
+        let expected = self.peer_fingerprints.get(&envelope.sender_id)
+            .ok_or_else(|| Error::AuthError(format!("Unknown peer {}", envelope.sender_id)))?;
+        
+        if &Self::fingerprint(certificate) != expected {
+            return Err(Error::AuthError(format!(
+                "Certificate does not belong to peer {}", envelope.sender_id
+            )));
+        }
+        
+        Ok(envelope.sender_id)
+    }
+}
@@ -0,0 +1,175 @@
+//! Cross-curve benchmark comparison
+//! 
+//! Times the core flow on every supported curve and compares the result
+//! against stored baseline timings:
+//! - Phases: share, commit, prove, verify, reconstruct
+//! - Timings are mean microseconds per run, stored as JSON
+//! - A phase regresses when it is slower than its baseline by more than a
+//!   threshold percentage
+
+use crate::{Error, Result, CurveType};
+use crate::curve::{curve_backend, enabled_curves};
+use crate::laurent::LaurentSeries;
+use crate::pedersen::PedersenCommitment;
+use crate::types::SharingParams;
+use crate::zkp::utils as zkp_utils;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Instant;
+
+/// Default allowed slowdown in percent before a phase counts as regressed
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 20.0;
+
+/// Phases of the core flow, in execution order
+pub const PHASES: [&str; 5] = ["share", "commit", "prove", "verify", "reconstruct"];
+
+/// Mean microseconds per run, by curve and phase
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchTimings {
+    pub curves: BTreeMap<String, BTreeMap<String, f64>>,
+}
+
+/// Phase that got slower than its baseline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Regression {
+    pub curve: String,
+    pub phase: String,
+    pub baseline_us: f64,
+    pub current_us: f64,
+    /// Slowdown relative to the baseline in percent
+    pub change_percent: f64,
+}
+
+impl BenchTimings {
+    /// Time the core flow on all enabled curves
+    pub fn run(iterations: usize) -> Result<Self> {
+        let mut timings = Self::default();
+        for curve_type in enabled_curves() {
+            timings.curves.insert(curve_type.to_string(), measure_curve(curve_type, iterations)?);
+        }
+        Ok(timings)
+    }
+    
+    /// Load timings from a JSON file
+    pub fn load(path: &Path) -> Result<Self> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+    
+    /// Save timings to a JSON file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+    
+    /// Timing of each curve for a phase relative to the fastest curve
+    pub fn relative(&self, phase: &str) -> BTreeMap<String, f64> {
+        let timings: Vec<(&String, f64)> = self.curves.iter()
+            .filter_map(|(curve, phases)| phases.get(phase).map(|&us| (curve, us)))
+            .collect();
+        let fastest = timings.iter().map(|(_, us)| *us).fold(f64::INFINITY, f64::min);
+        if !(fastest > 0.0 && fastest.is_finite()) {
+            return BTreeMap::new();
+        }
+        
+        timings.into_iter()
+            .map(|(curve, us)| (curve.clone(), us / fastest))
+            .collect()
+    }
+}
+
+/// Phases that regressed beyond `threshold_percent` versus the baseline
+/// 
+/// Curves and phases missing from either side are not compared.
+pub fn compare(baseline: &BenchTimings, current: &BenchTimings, threshold_percent: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+    
+    for (curve, phases) in &current.curves {
+        let Some(baseline_phases) = baseline.curves.get(curve) else {
+            continue;
+        };
+        
+        for (phase, &current_us) in phases {
+            let Some(&baseline_us) = baseline_phases.get(phase) else {
+                continue;
+            };
+            if baseline_us <= 0.0 {
+                continue;
+            }
+            
+            let change_percent = (current_us - baseline_us) / baseline_us * 100.0;
+            if change_percent > threshold_percent {
+                regressions.push(Regression {
+                    curve: curve.clone(),
+                    phase: phase.clone(),
+                    baseline_us,
+                    current_us,
+                    change_percent,
+                });
+            }
+        }
+    }
+    
+    regressions
+}
+
+/// Time every phase of a 3-of-5 flow on one curve
+pub fn measure_curve(curve_type: CurveType, iterations: usize) -> Result<BTreeMap<String, f64>> {
+    if iterations == 0 {
+        return Err(Error::InvalidInput("At least one iteration is required".to_string()));
+    }
+    
+    let curve = curve_backend(curve_type)?;
+    let params = SharingParams {
+        curve_type,
+        threshold: 3,
+        participants: 5,
+    };
+    let pedersen = PedersenCommitment::new(curve_type)?;
+    let mut totals: BTreeMap<String, f64> = PHASES.iter().map(|phase| (phase.to_string(), 0.0)).collect();
+    let mut time = |phase: &str, start: Instant| {
+        *totals.get_mut(phase).expect("known phase") += start.elapsed().as_secs_f64() * 1e6;
+    };
+    
+    for _ in 0..iterations {
+        // Step 1: Split
+        let start = Instant::now();
+        let laurent = LaurentSeries::new(&params)?;
+        let shares = laurent.generate_shares()?;
+        time("share", start);
+        
+        // Step 2: Commit
+        let start = Instant::now();
+        let mut commitments = Vec::new();
+        for share in &shares {
+            let randomness = pedersen.generate_randomness();
+            let commitment = pedersen.commit(&curve.scalar_from_bytes(&share.value)?, &randomness)?;
+            commitments.push((commitment, randomness));
+        }
+        time("commit", start);
+        
+        // Step 3: Prove
+        let start = Instant::now();
+        let mut proofs = Vec::new();
+        for (share, (commitment, randomness)) in shares.iter().zip(&commitments) {
+            proofs.push(zkp_utils::prove_committed_share(&pedersen, share, commitment, randomness, curve_type)?);
+        }
+        time("prove", start);
+        
+        // Step 4: Verify
+        let start = Instant::now();
+        for (proof, (commitment, _)) in proofs.iter().zip(&commitments) {
+            proof.verify_against(&pedersen, commitment)?;
+        }
+        time("verify", start);
+        
+        // Step 5: Reconstruct
+        let start = Instant::now();
+        laurent.reconstruct_secret(&shares[..params.threshold])?;
+        time("reconstruct", start);
+    }
+    
+    Ok(totals.into_iter()
+        .map(|(phase, total)| (phase, total / iterations as f64))
+        .collect())
+}
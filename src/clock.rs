@@ -0,0 +1,74 @@
+//! Injectable time source
+//! 
+//! Everything time dependent in the network layer reads the time through
+//! `Clock`, so tests can drive heartbeat expiry with a `MockClock` instead
+//! of sleeping.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Source of wall-clock and monotonic time
+pub trait Clock: Send + Sync {
+    /// Seconds since the Unix epoch
+    fn now_unix(&self) -> u64;
+    
+    /// Monotonic instant
+    fn now_instant(&self) -> Instant;
+}
+
+/// Clock backed by the operating system
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_unix(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+    }
+    
+    fn now_instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Manually advanced clock for tests
+#[derive(Debug)]
+pub struct MockClock {
+    unix_start: u64,
+    instant_start: Instant,
+    elapsed: Mutex<Duration>,
+}
+
+impl MockClock {
+    /// Create new mock clock starting at a Unix timestamp
+    pub fn new(unix_start: u64) -> Self {
+        Self {
+            unix_start,
+            instant_start: Instant::now(),
+            elapsed: Mutex::new(Duration::ZERO),
+        }
+    }
+    
+    /// Move the clock forward
+    pub fn advance(&self, duration: Duration) {
+        let mut elapsed = self.elapsed.lock().unwrap_or_else(|e| e.into_inner());
+        *elapsed += duration;
+    }
+    
+    /// Time elapsed since creation
+    fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Clock for MockClock {
+    fn now_unix(&self) -> u64 {
+        self.unix_start + self.elapsed().as_secs()
+    }
+    
+    fn now_instant(&self) -> Instant {
+        self.instant_start + self.elapsed()
+    }
+}
@@ -1,10 +1,23 @@
 use crate::{Error, Result, CurveType};
-use crate::curve::{Curve, Scalar, Point};
+use crate::curve::{Curve, Scalar, Point, check_scalar_len, fixed_width};
+use crate::curve::xmd::expand_message_xmd;
 use curve25519_dalek::{EdwardsPoint, Scalar as Ed25519Scalar, constants::ED25519_BASEPOINT_POINT};
-use curve25519_dalek::edwards::CompressedEdwardsY;
-use rand::Rng;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsBasepointTable};
+use curve25519_dalek::traits::{BasepointTable, Identity, MultiscalarMul, VartimeMultiscalarMul};
+use crypto_bigint::{impl_modulus, Encoding, U256, U512};
+use crypto_bigint::modular::constant_mod::{Residue, ResidueParams};
+use rand_core::CryptoRngCore;
+
+/// Domain separation tag of `hash_to_point` (RFC 9380 suite edwards25519_XMD:SHA-512_ELL2_RO_)
+const HASH_TO_CURVE_DST: &[u8] = b"z-MPC-V01-CS01-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+
+impl_modulus!(FieldModulus, U256, "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed");
+
+/// Element of the base field GF(2^255 - 19)
+type FieldElement = Residue<FieldModulus, { U256::LIMBS }>;
 
 /// Edwards25519 curve implementation
+#[derive(Default)]
 pub struct Ed25519;
 
 impl Ed25519 {
@@ -15,84 +28,165 @@ impl Ed25519 {
     
     /// Add two scalars
     pub fn add_scalars(a: &Scalar, b: &Scalar) -> Result<Scalar> {
-        let a_scalar = Ed25519Scalar::from_bytes_mod_order(&a.value);
-        let b_scalar = Ed25519Scalar::from_bytes_mod_order(&b.value);
-        
-        let result = a_scalar + b_scalar;
-        Ok(Scalar::new(CurveType::Edwards25519, result.to_bytes().to_vec()))
+        let result = Self::scalar_to_dalek(a)? + Self::scalar_to_dalek(b)?;
+        Ok(Self::scalar_from_dalek(&result))
     }
     
     /// Multiply two scalars
     pub fn mul_scalars(a: &Scalar, b: &Scalar) -> Result<Scalar> {
-        let a_scalar = Ed25519Scalar::from_bytes_mod_order(&a.value);
-        let b_scalar = Ed25519Scalar::from_bytes_mod_order(&b.value);
-        
-        let result = a_scalar * b_scalar;
-        Ok(Scalar::new(CurveType::Edwards25519, result.to_bytes().to_vec()))
+        let result = Self::scalar_to_dalek(a)? * Self::scalar_to_dalek(b)?;
+        Ok(Self::scalar_from_dalek(&result))
+    }
+    
+    /// Negate scalar
+    pub fn neg_scalar(s: &Scalar) -> Result<Scalar> {
+        let result = -Self::scalar_to_dalek(s)?;
+        Ok(Self::scalar_from_dalek(&result))
     }
     
     /// Invert scalar
     pub fn invert_scalar(s: &Scalar) -> Result<Scalar> {
-        let scalar = Ed25519Scalar::from_bytes_mod_order(&s.value);
-        let result = scalar.invert();
-        Ok(Scalar::new(CurveType::Edwards25519, result.to_bytes().to_vec()))
+        let scalar = Self::scalar_to_dalek(s)?;
+        if scalar == Ed25519Scalar::ZERO {
+            return Err(Error::CurveError("Cannot invert zero scalar".to_string()));
+        }
+        
+        Ok(Self::scalar_from_dalek(&scalar.invert()))
+    }
+    
+    /// Decode a canonical little-endian scalar, padding short encodings
+    fn scalar_to_dalek(s: &Scalar) -> Result<Ed25519Scalar> {
+        let bytes: [u8; 32] = fixed_width(&s.value, Self.scalar_byte_len(), CurveType::Edwards25519)
+            .try_into()
+            .map_err(|_| Error::CurveError("Invalid scalar bytes".to_string()))?;
+        
+        Option::<Ed25519Scalar>::from(Ed25519Scalar::from_canonical_bytes(bytes))
+            .ok_or_else(|| Error::CurveError("Invalid scalar bytes".to_string()))
+    }
+    
+    /// Pad 1 to 32 little-endian bytes at the high end
+    fn pad_scalar_bytes(bytes: &[u8]) -> Result<[u8; 32]> {
+        check_scalar_len(bytes, Self.scalar_byte_len())?;
+        
+        let mut padded = [0u8; 32];
+        padded[..bytes.len()].copy_from_slice(bytes);
+        Ok(padded)
+    }
+    
+    /// Encode a scalar as 32 little-endian bytes
+    fn scalar_from_dalek(s: &Ed25519Scalar) -> Scalar {
+        Scalar::new(CurveType::Edwards25519, s.to_bytes().to_vec())
     }
     
     /// Convert point to compressed bytes
     pub fn point_to_compressed(p: &Point) -> Result<Vec<u8>> {
-        let compressed = CompressedEdwardsY::from_slice(&p.x)
-            .map_err(|_| Error::CurveError("Invalid point".to_string()))?;
-        Ok(compressed.as_bytes().to_vec())
+        let point = Self::edwards_from_point(p)?;
+        
+        Ok(point.compress().to_bytes().to_vec())
     }
     
     /// Create point from compressed bytes
+    /// 
+    /// Accepts the 32-byte compressed Edwards y-coordinate with the sign of
+    /// x in the top bit.
     pub fn point_from_compressed(bytes: &[u8]) -> Result<Point> {
-        let compressed = CompressedEdwardsY::from_slice(bytes)
-            .map_err(|_| Error::CurveError("Invalid compressed point".to_string()))?;
+        if bytes.len() != Self.scalar_byte_len() {
+            return Err(Error::CurveError(format!("Compressed point must be 32 bytes, got {}", bytes.len())));
+        }
         
-        let point = compressed.decompress()
-            .ok_or_else(|| Error::CurveError("Invalid compressed point".to_string()))?;
+        let point = Self::edwards_from_point(&Point::new(CurveType::Edwards25519, bytes.to_vec(), Vec::new()))?;
+        Ok(Self::point_from_edwards(&point))
+    }
+    
+    /// Decompress a point, rejecting encodings with no point on the curve
+    /// 
+    /// Ed25519 points keep their compressed encoding in `x`; `y` is unused.
+    pub fn edwards_from_point(p: &Point) -> Result<EdwardsPoint> {
+        let bytes = fixed_width(&p.x, Self.scalar_byte_len(), CurveType::Edwards25519);
+        let compressed = CompressedEdwardsY::from_slice(&bytes)
+            .map_err(|_| Error::CurveError("Invalid point coordinate length".to_string()))?;
         
-        Ok(Point::new(
-            CurveType::Edwards25519,
-            point.x.to_bytes().to_vec(),
-            point.y.to_bytes().to_vec(),
-        ))
+        compressed.decompress()
+            .ok_or_else(|| Error::CurveError("Point is not on the curve".to_string()))
+    }
+    
+    /// Store a point as its compressed encoding
+    fn point_from_edwards(point: &EdwardsPoint) -> Point {
+        Point::new(CurveType::Edwards25519, point.compress().to_bytes().to_vec(), Vec::new())
     }
     
     /// Add two points
     pub fn add_points(a: &Point, b: &Point) -> Result<Point> {
-        let a_point = Self::bytes_to_edwards_point(&a.x)?;
-        let b_point = Self::bytes_to_edwards_point(&b.x)?;
-        
-        let result = a_point + b_point;
-        Ok(Point::new(
-            CurveType::Edwards25519,
-            result.x.to_bytes().to_vec(),
-            result.y.to_bytes().to_vec(),
-        ))
+        let result = Self::edwards_from_point(a)? + Self::edwards_from_point(b)?;
+        Ok(Self::point_from_edwards(&result))
     }
     
     /// Multiply point by scalar
     pub fn mul_point_scalar(p: &Point, s: &Scalar) -> Result<Point> {
-        let point = Self::bytes_to_edwards_point(&p.x)?;
-        let scalar = Ed25519Scalar::from_bytes_mod_order(&s.value);
+        let result = Self::edwards_from_point(p)? * Self::scalar_to_dalek(s)?;
+        Ok(Self::point_from_edwards(&result))
+    }
+    
+    /// Fixed-base table for repeated multiplication of `p`
+    pub(crate) fn precompute_point(p: &Point) -> Result<EdwardsBasepointTable> {
+        Ok(EdwardsBasepointTable::create(&Self::edwards_from_point(p)?))
+    }
+    
+    /// Σ s_i * P_i over precomputed tables
+    pub(crate) fn mul_precomputed(terms: &[(&EdwardsBasepointTable, &Scalar)]) -> Result<Point> {
+        let mut result = EdwardsPoint::identity();
+        for (table, s) in terms {
+            result += *table * &Self::scalar_to_dalek(s)?;
+        }
         
-        let result = point * scalar;
-        Ok(Point::new(
-            CurveType::Edwards25519,
-            result.x.to_bytes().to_vec(),
-            result.y.to_bytes().to_vec(),
-        ))
-    }
-    
-    /// Convert bytes to Edwards point
-    fn bytes_to_edwards_point(bytes: &[u8]) -> Result<EdwardsPoint> {
-        let compressed = CompressedEdwardsY::from_slice(bytes)
-            .map_err(|_| Error::CurveError("Invalid point bytes".to_string()))?;
+        Ok(Self::point_from_edwards(&result))
+    }
+    
+    /// Identity point
+    pub fn identity_point() -> Result<Point> {
+        Ok(Self::point_from_edwards(&EdwardsPoint::identity()))
+    }
+    
+    /// Whether a point is the identity
+    pub fn is_identity_point(p: &Point) -> Result<bool> {
+        let point = Self::edwards_from_point(p)?;
         
-        compressed.decompress()
-            .ok_or_else(|| Error::CurveError("Invalid point".to_string()))
+        Ok(point == EdwardsPoint::identity())
+    }
+    
+    /// Negate point
+    pub fn neg_point(p: &Point) -> Result<Point> {
+        let result = -Self::edwards_from_point(p)?;
+        Ok(Self::point_from_edwards(&result))
+    }
+    
+    /// Multi-scalar multiplication Σ s_i * P_i
+    pub fn multi_scalar_mul(points: &[Point], scalars: &[Scalar]) -> Result<Point> {
+        let points = points.iter().map(Self::edwards_from_point).collect::<Result<Vec<_>>>()?;
+        let scalars = scalars.iter().map(Self::scalar_to_dalek).collect::<Result<Vec<_>>>()?;
+        
+        Ok(Self::point_from_edwards(&EdwardsPoint::multiscalar_mul(&scalars, &points)))
+    }
+    
+    /// Multi-scalar multiplication for public inputs, Pippenger for large batches
+    pub fn multi_scalar_mul_vartime(points: &[Point], scalars: &[Scalar]) -> Result<Point> {
+        let points = points.iter().map(Self::edwards_from_point).collect::<Result<Vec<_>>>()?;
+        let scalars = scalars.iter().map(Self::scalar_to_dalek).collect::<Result<Vec<_>>>()?;
+        
+        Ok(Self::point_from_edwards(&EdwardsPoint::vartime_multiscalar_mul(&scalars, &points)))
+    }
+    
+    /// Hash to the prime-order subgroup with the RFC 9380 random-oracle encoding
+    /// 
+    /// Two field elements from expand_message_xmd with SHA-512 go through
+    /// Elligator 2 onto curve25519 and the rational map to edwards25519; their
+    /// sum is multiplied by the cofactor.
+    pub fn hash_to_point_with_dst(msg: &[u8], dst: &[u8]) -> Result<Point> {
+        let uniform = expand_message_xmd(msg, dst, 96)?;
+        let q0 = map_to_edwards(fe_from_be_bytes(&uniform[..48]))?;
+        let q1 = map_to_edwards(fe_from_be_bytes(&uniform[48..]))?;
+        
+        Ok(Self::point_from_edwards(&(q0 + q1).mul_by_cofactor()))
     }
 }
 
@@ -101,35 +195,43 @@ impl Curve for Ed25519 {
         CurveType::Edwards25519
     }
     
-    fn random_scalar(&self) -> Result<Scalar> {
-        let mut rng = rand::thread_rng();
-        let scalar = Ed25519Scalar::random(&mut rng);
-        Ok(Scalar::new(CurveType::Edwards25519, scalar.to_bytes().to_vec()))
+    fn random_scalar_with_rng(&self, rng: &mut dyn CryptoRngCore) -> Result<Scalar> {
+        let scalar = Ed25519Scalar::random(rng);
+        Ok(Self::scalar_from_dalek(&scalar))
     }
     
     fn scalar_from_bytes(&self, bytes: &[u8]) -> Result<Scalar> {
-        let scalar = Ed25519Scalar::from_bytes_mod_order(bytes);
-        Ok(Scalar::new(CurveType::Edwards25519, scalar.to_bytes().to_vec()))
+        let padded = Self::pad_scalar_bytes(bytes)?;
+        let scalar = Option::<Ed25519Scalar>::from(Ed25519Scalar::from_canonical_bytes(padded))
+            .ok_or_else(|| Error::InvalidInput("Scalar is not below the Ed25519 group order".to_string()))?;
+        Ok(Self::scalar_from_dalek(&scalar))
+    }
+    
+    fn scalar_from_bytes_mod_order(&self, bytes: &[u8]) -> Result<Scalar> {
+        let scalar = Ed25519Scalar::from_bytes_mod_order(Self::pad_scalar_bytes(bytes)?);
+        Ok(Self::scalar_from_dalek(&scalar))
+    }
+    
+    fn scalar_byte_len(&self) -> usize {
+        32
     }
     
     fn scalar_from_u64(&self, value: u64) -> Result<Scalar> {
-        let scalar = Ed25519Scalar::from(value);
-        Ok(Scalar::new(CurveType::Edwards25519, scalar.to_bytes().to_vec()))
+        Ok(Self::scalar_from_dalek(&Ed25519Scalar::from(value)))
     }
     
     fn generator(&self) -> Point {
-        let generator = ED25519_BASEPOINT_POINT;
-        Point::new(
-            CurveType::Edwards25519,
-            generator.x.to_bytes().to_vec(),
-            generator.y.to_bytes().to_vec(),
-        )
+        Self::point_from_edwards(&ED25519_BASEPOINT_POINT)
     }
     
     fn point_from_bytes(&self, bytes: &[u8]) -> Result<Point> {
         Self::point_from_compressed(bytes)
     }
     
+    fn hash_to_point(&self, msg: &[u8]) -> Result<Point> {
+        Self::hash_to_point_with_dst(msg, HASH_TO_CURVE_DST)
+    }
+    
     fn order(&self) -> Scalar {
         // Ed25519 order (2^252 + 27742317777372353535851937790883648493)
         let order_bytes = [
@@ -151,4 +253,77 @@ impl Curve for Ed25519 {
         ];
         Scalar::new(CurveType::Edwards25519, modulus_bytes.to_vec())
     }
-} 
\ No newline at end of file
+} 
+/// Field element from a small integer
+fn fe(value: u64) -> FieldElement {
+    FieldElement::new(&U256::from_u64(value))
+}
+
+/// Reduce a big-endian integer of at most 64 bytes modulo p
+fn fe_from_be_bytes(bytes: &[u8]) -> FieldElement {
+    let mut wide = [0u8; 64];
+    wide[64 - bytes.len()..].copy_from_slice(bytes);
+    let (high, low) = U512::from_be_slice(&wide).split();
+    
+    FieldElement::new(&U256::const_rem_wide((low, high), &FieldModulus::MODULUS).0)
+}
+
+/// Low bit of the canonical representative, the sgn0 of RFC 9380
+fn sgn0(x: &FieldElement) -> u8 {
+    x.retrieve().to_le_bytes()[0] & 1
+}
+
+/// x / y for non-zero y
+fn fe_div(x: &FieldElement, y: &FieldElement) -> FieldElement {
+    x * y.invert().0
+}
+
+/// Whether x is a square, by Euler's criterion x^((p-1)/2) ∈ {0, 1}
+fn is_square(x: &FieldElement) -> bool {
+    let legendre = x.pow(&U256::from_be_hex("3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff6"));
+    legendre == FieldElement::ZERO || legendre == FieldElement::ONE
+}
+
+/// Square root of a square with the requested sgn0
+/// 
+/// As p ≡ 5 (mod 8), the root is x^((p+3)/8), times √-1 = 2^((p-1)/4) when
+/// that only squares to -x.
+fn sqrt_with_sign(x: &FieldElement, sign: u8) -> FieldElement {
+    let mut root = x.pow(&U256::from_be_hex("0ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe"));
+    if root.square() != *x {
+        root *= fe(2).pow(&U256::from_be_hex("1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffb"));
+    }
+    
+    if sgn0(&root) == sign { root } else { -root }
+}
+
+/// Elligator 2 onto curve25519 followed by the rational map to edwards25519
+fn map_to_edwards(u: FieldElement) -> Result<EdwardsPoint> {
+    let j = fe(486662);
+    let curve25519_rhs = |x: &FieldElement| x * (x * (x + j) + FieldElement::ONE);
+    
+    // Step 1: Elligator 2 with Z = 2 gives the Montgomery point (s, t)
+    let denominator = FieldElement::ONE + fe(2) * u.square();
+    let x1 = if denominator == FieldElement::ZERO { -j } else { fe_div(&-j, &denominator) };
+    let gx1 = curve25519_rhs(&x1);
+    let (s, t) = if is_square(&gx1) {
+        (x1, sqrt_with_sign(&gx1, 1))
+    } else {
+        let x2 = -x1 - j;
+        (x2, sqrt_with_sign(&curve25519_rhs(&x2), 0))
+    };
+    
+    // Step 2: (x, y) = (√-486664·s/t, (s-1)/(s+1)), the identity when undefined
+    let s_plus_one = s + FieldElement::ONE;
+    if t == FieldElement::ZERO || s_plus_one == FieldElement::ZERO {
+        return Ok(EdwardsPoint::identity());
+    }
+    let x = fe_div(&(sqrt_with_sign(&-fe(486664), 0) * s), &t);
+    let y = fe_div(&(s - FieldElement::ONE), &s_plus_one);
+    
+    // Step 3: Compressed encoding is y with the sign of x in the top bit
+    let mut encoding = y.retrieve().to_le_bytes();
+    encoding[31] |= sgn0(&x) << 7;
+    CompressedEdwardsY(encoding).decompress()
+        .ok_or_else(|| Error::CurveError("Hash-to-curve produced an invalid point".to_string()))
+}
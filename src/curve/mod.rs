@@ -4,13 +4,72 @@
 //! - secp256k1 (Bitcoin/Ethereum)
 //! - P-256 (NIST)
 //! - Edwards25519
+//! - Ristretto255
+//! 
+//! Each backend sits behind a cargo feature of the same name (`secp256k1`,
+//! `p256`, `ed25519`, `ristretto255`). Artifacts of a disabled curve fail with
+//! `Error::InvalidCurve` instead of reaching a backend.
 
+#[cfg(feature = "secp256k1")]
 mod secp256k1;
+#[cfg(feature = "p256")]
 mod p256;
+#[cfg(feature = "ed25519")]
 mod ed25519;
+#[cfg(feature = "ristretto255")]
+mod ristretto255;
+#[cfg(any(feature = "ed25519", feature = "ristretto255"))]
+mod xmd;
+mod precomputed;
+
+pub use precomputed::PrecomputedPoint;
 
 use crate::{Error, Result, CurveType};
+use rand_core::CryptoRngCore;
 use serde::{Deserialize, Serialize};
+#[cfg(any(test, feature = "op-counters"))]
+use std::cell::Cell;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Call a backend function for `$curve_type`, failing for disabled curves
+macro_rules! dispatch {
+    ($curve_type:expr, $function:ident($($arg:expr),*)) => {
+        match $curve_type {
+            #[cfg(feature = "secp256k1")]
+            CurveType::Secp256k1 => secp256k1::Secp256k1::$function($($arg),*),
+            #[cfg(feature = "p256")]
+            CurveType::P256 => p256::P256::$function($($arg),*),
+            #[cfg(feature = "ed25519")]
+            CurveType::Edwards25519 => ed25519::Ed25519::$function($($arg),*),
+            #[cfg(feature = "ristretto255")]
+            CurveType::Ristretto255 => ristretto255::Ristretto255::$function($($arg),*),
+            #[allow(unreachable_patterns)]
+            curve_type => Err(disabled_curve(curve_type)),
+        }
+    };
+}
+
+#[cfg(any(test, feature = "op-counters"))]
+thread_local! {
+    static SCALAR_MULTIPLICATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Number of point-scalar multiplications performed on this thread
+/// 
+/// Lets tests and benchmarks compare the dominant cost of code paths.
+/// Only built with the `op-counters` feature.
+#[cfg(any(test, feature = "op-counters"))]
+pub fn scalar_mul_count() -> u64 {
+    SCALAR_MULTIPLICATIONS.with(|count| count.get())
+}
+
+/// Record one point-scalar multiplication for `scalar_mul_count`
+#[inline]
+pub(crate) fn count_scalar_mul() {
+    #[cfg(any(test, feature = "op-counters"))]
+    SCALAR_MULTIPLICATIONS.with(|count| count.set(count.get() + 1));
+}
 
 /// Common trait for elliptic curve operations
 pub trait Curve: Send + Sync {
@@ -18,11 +77,32 @@ pub trait Curve: Send + Sync {
     fn curve_type(&self) -> CurveType;
     
     /// Generate a random scalar
-    fn random_scalar(&self) -> Result<Scalar>;
+    fn random_scalar(&self) -> Result<Scalar> {
+        self.random_scalar_with_rng(&mut rand::thread_rng())
+    }
+    
+    /// Generate a random scalar from `rng`
+    /// 
+    /// For seeded test vectors or entropy from an HSM-backed DRBG.
+    fn random_scalar_with_rng(&self, rng: &mut dyn CryptoRngCore) -> Result<Scalar>;
     
-    /// Create scalar from bytes
+    /// Create scalar from 1 to 32 bytes in the curve's byte order
+    /// 
+    /// Shorter inputs are zero-padded; values not below the group order are
+    /// rejected with `Error::InvalidInput`.
     fn scalar_from_bytes(&self, bytes: &[u8]) -> Result<Scalar>;
     
+    /// Create scalar from 1 to 32 bytes, reducing modulo the group order
+    /// 
+    /// For hash outputs and other uniformly random input; user-supplied values
+    /// should go through `scalar_from_bytes`.
+    fn scalar_from_bytes_mod_order(&self, bytes: &[u8]) -> Result<Scalar>;
+    
+    /// Length in bytes of an encoded scalar
+    /// 
+    /// Field elements are encoded with the same width on every supported curve.
+    fn scalar_byte_len(&self) -> usize;
+    
     /// Create scalar from integer
     fn scalar_from_u64(&self, value: u64) -> Result<Scalar>;
     
@@ -32,6 +112,37 @@ pub trait Curve: Send + Sync {
     /// Create point from bytes
     fn point_from_bytes(&self, bytes: &[u8]) -> Result<Point>;
     
+    /// Hash a message to a point with no known discrete log relative to the generator
+    /// 
+    /// Implements the RFC 9380 random-oracle suite of the curve (simplified SWU
+    /// for secp256k1 and P-256, Elligator 2 for Ed25519) under a z-MPC domain
+    /// separation tag, so the output is stable across runs and releases.
+    fn hash_to_point(&self, msg: &[u8]) -> Result<Point>;
+    
+    /// Multi-scalar multiplication Σ scalars[i]·points[i] on this curve
+    /// 
+    /// Uses the backend's constant-time combined algorithm, so secret scalars
+    /// are fine. Empty input gives the identity.
+    fn msm(&self, points: &[Point], scalars: &[Scalar]) -> Result<Point> {
+        if points.is_empty() && scalars.is_empty() {
+            return Point::identity(self.curve_type());
+        }
+        check_msm_curve(self.curve_type(), points)?;
+        Point::multi_scalar_mul(points, scalars)
+    }
+    
+    /// Multi-scalar multiplication for public points and scalars only
+    /// 
+    /// Backends may switch to a faster variable-time algorithm (Pippenger on
+    /// Ed25519), so never pass secrets. Empty input gives the identity.
+    fn msm_vartime(&self, points: &[Point], scalars: &[Scalar]) -> Result<Point> {
+        if points.is_empty() && scalars.is_empty() {
+            return Point::identity(self.curve_type());
+        }
+        check_msm_curve(self.curve_type(), points)?;
+        Point::multi_scalar_mul_vartime(points, scalars)
+    }
+    
     /// Get curve order
     fn order(&self) -> Scalar;
     
@@ -40,7 +151,11 @@ pub trait Curve: Send + Sync {
 }
 
 /// Scalar value on elliptic curve
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// 
+/// Equality and hashing use the canonical encoding, so equal values compare
+/// equal however they were encoded. `==` is variable-time; compare secrets
+/// with `ct_eq`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Scalar {
     pub curve_type: CurveType,
     pub value: Vec<u8>,
@@ -57,17 +172,65 @@ impl Scalar {
         &self.value
     }
     
+    /// Take the encoding out of the scalar
+    /// 
+    /// The caller becomes responsible for wiping it.
+    pub fn into_bytes(mut self) -> Vec<u8> {
+        std::mem::take(&mut self.value)
+    }
+    
+    /// Canonical fixed-width encoding, reduced by the backend where possible
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let Ok(curve) = curve_backend(self.curve_type) else {
+            return self.value.clone();
+        };
+        let width = curve.scalar_byte_len();
+        let bytes = fixed_width(&self.value, width, self.curve_type);
+        
+        if bytes.len() == width {
+            if let Ok(scalar) = curve.scalar_from_bytes(&bytes) {
+                return scalar.into_bytes();
+            }
+        }
+        bytes
+    }
+    
+    /// Whether this is the zero scalar, in constant time
+    pub fn is_zero(&self) -> bool {
+        let bytes = self.canonical_bytes();
+        bool::from(bytes.as_slice().ct_eq(&vec![0u8; bytes.len()]))
+    }
+    
+    /// Constant-time equality on the canonical encoding
+    /// 
+    /// Use instead of `==` for secrets, challenges and proof responses. Only
+    /// the curve types, which are public, are compared in variable time.
+    pub fn ct_eq(&self, other: &Scalar) -> bool {
+        self.curve_type == other.curve_type
+            && bool::from(self.canonical_bytes().as_slice().ct_eq(other.canonical_bytes().as_slice()))
+    }
+    
     /// Add two scalars
     pub fn add(&self, other: &Scalar) -> Result<Scalar> {
         if self.curve_type != other.curve_type {
             return Err(Error::CurveError("Cannot add scalars from different curves".to_string()));
         }
         
-        match self.curve_type {
-            CurveType::Secp256k1 => secp256k1::Secp256k1::add_scalars(self, other),
-            CurveType::P256 => p256::P256::add_scalars(self, other),
-            CurveType::Edwards25519 => ed25519::Ed25519::add_scalars(self, other),
+        dispatch!(self.curve_type, add_scalars(self, other))
+    }
+    
+    /// Subtract two scalars modulo the group order
+    pub fn sub(&self, other: &Scalar) -> Result<Scalar> {
+        if self.curve_type != other.curve_type {
+            return Err(Error::CurveError("Cannot subtract scalars from different curves".to_string()));
         }
+        
+        self.add(&other.neg()?)
+    }
+    
+    /// Additive inverse modulo the group order
+    pub fn neg(&self) -> Result<Scalar> {
+        dispatch!(self.curve_type, neg_scalar(self))
     }
     
     /// Multiply two scalars
@@ -76,25 +239,49 @@ impl Scalar {
             return Err(Error::CurveError("Cannot multiply scalars from different curves".to_string()));
         }
         
-        match self.curve_type {
-            CurveType::Secp256k1 => secp256k1::Secp256k1::mul_scalars(self, other),
-            CurveType::P256 => p256::P256::mul_scalars(self, other),
-            CurveType::Edwards25519 => ed25519::Ed25519::mul_scalars(self, other),
-        }
+        dispatch!(self.curve_type, mul_scalars(self, other))
     }
     
     /// Invert scalar
     pub fn invert(&self) -> Result<Scalar> {
-        match self.curve_type {
-            CurveType::Secp256k1 => secp256k1::Secp256k1::invert_scalar(self),
-            CurveType::P256 => p256::P256::invert_scalar(self),
-            CurveType::Edwards25519 => ed25519::Ed25519::invert_scalar(self),
-        }
+        dispatch!(self.curve_type, invert_scalar(self))
+    }
+}
+
+impl PartialEq for Scalar {
+    fn eq(&self, other: &Self) -> bool {
+        self.curve_type == other.curve_type && self.canonical_bytes() == other.canonical_bytes()
+    }
+}
+
+impl Eq for Scalar {}
+
+impl Zeroize for Scalar {
+    fn zeroize(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl Drop for Scalar {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Scalar {}
+
+impl std::hash::Hash for Scalar {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.curve_type.hash(state);
+        self.canonical_bytes().hash(state);
     }
 }
 
 /// Point on elliptic curve
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// 
+/// Equality and hashing use the canonical encoding, so equal points compare
+/// equal however their coordinates were encoded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Point {
     pub curve_type: CurveType,
     pub x: Vec<u8>,
@@ -109,20 +296,78 @@ impl Point {
     
     /// Get point as compressed bytes
     pub fn to_compressed_bytes(&self) -> Result<Vec<u8>> {
-        match self.curve_type {
-            CurveType::Secp256k1 => secp256k1::Secp256k1::point_to_compressed(self),
-            CurveType::P256 => p256::P256::point_to_compressed(self),
-            CurveType::Edwards25519 => ed25519::Ed25519::point_to_compressed(self),
-        }
+        dispatch!(self.curve_type, point_to_compressed(self))
+    }
+    
+    /// Canonical encoding: compressed, or fixed-width coordinates if the
+    /// backend cannot encode the point
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        // Without a backend the coordinates are only stripped of padding
+        let width = curve_backend(self.curve_type).map_or(0, |curve| curve.scalar_byte_len());
+        let normalized = Point::new(
+            self.curve_type,
+            fixed_width(&self.x, width, self.curve_type),
+            fixed_width(&self.y, width, self.curve_type),
+        );
+        
+        normalized.to_compressed_bytes().unwrap_or_else(|_| {
+            let mut bytes = normalized.x;
+            bytes.extend_from_slice(&normalized.y);
+            bytes
+        })
+    }
+    
+    /// Constant-time equality on the canonical encoding
+    pub fn ct_eq(&self, other: &Point) -> bool {
+        self.curve_type == other.curve_type
+            && bool::from(self.canonical_bytes().as_slice().ct_eq(other.canonical_bytes().as_slice()))
     }
     
     /// Create point from compressed bytes
     pub fn from_compressed_bytes(curve_type: CurveType, bytes: &[u8]) -> Result<Point> {
-        match curve_type {
-            CurveType::Secp256k1 => secp256k1::Secp256k1::point_from_compressed(bytes),
-            CurveType::P256 => p256::P256::point_from_compressed(bytes),
-            CurveType::Edwards25519 => ed25519::Ed25519::point_from_compressed(bytes),
+        dispatch!(curve_type, point_from_compressed(bytes))
+    }
+    
+    /// Identity (neutral) point of a curve
+    /// 
+    /// On the Weierstrass curves, which have no affine identity, it is
+    /// represented with empty coordinates.
+    pub fn identity(curve_type: CurveType) -> Result<Point> {
+        dispatch!(curve_type, identity_point())
+    }
+    
+    /// Whether this is the identity point
+    /// 
+    /// Malformed points and points of disabled curves are never the identity.
+    pub fn is_identity(&self) -> bool {
+        let result: Result<bool> = dispatch!(self.curve_type, is_identity_point(self));
+        result.unwrap_or(false)
+    }
+    
+    /// Whether the point lies in the prime-order subgroup
+    /// 
+    /// Only Edwards25519 has a cofactor; points of the other curves always
+    /// qualify. Malformed Edwards25519 points never do.
+    pub fn is_torsion_free(&self) -> bool {
+        match self.curve_type {
+            #[cfg(feature = "ed25519")]
+            CurveType::Edwards25519 => ed25519::Ed25519::edwards_from_point(self).is_ok_and(|point| point.is_torsion_free()),
+            _ => true,
+        }
+    }
+    
+    /// Negate point
+    pub fn neg(&self) -> Result<Point> {
+        dispatch!(self.curve_type, neg_point(self))
+    }
+    
+    /// Subtract two points
+    pub fn sub(&self, other: &Point) -> Result<Point> {
+        if self.curve_type != other.curve_type {
+            return Err(Error::CurveError("Cannot subtract points from different curves".to_string()));
         }
+        
+        self.add(&other.neg()?)
     }
     
     /// Add two points
@@ -131,11 +376,7 @@ impl Point {
             return Err(Error::CurveError("Cannot add points from different curves".to_string()));
         }
         
-        match self.curve_type {
-            CurveType::Secp256k1 => secp256k1::Secp256k1::add_points(self, other),
-            CurveType::P256 => p256::P256::add_points(self, other),
-            CurveType::Edwards25519 => ed25519::Ed25519::add_points(self, other),
-        }
+        dispatch!(self.curve_type, add_points(self, other))
     }
     
     /// Multiply point by scalar
@@ -144,24 +385,668 @@ impl Point {
             return Err(Error::CurveError("Cannot multiply point by scalar from different curve".to_string()));
         }
         
-        match self.curve_type {
-            CurveType::Secp256k1 => secp256k1::Secp256k1::mul_point_scalar(self, scalar),
-            CurveType::P256 => p256::P256::mul_point_scalar(self, scalar),
-            CurveType::Edwards25519 => ed25519::Ed25519::mul_point_scalar(self, scalar),
+        count_scalar_mul();
+        dispatch!(self.curve_type, mul_point_scalar(self, scalar))
+    }
+    
+    /// Multi-scalar multiplication Σ s_i * P_i
+    /// 
+    /// Uses the backend's combined algorithm instead of chaining `mul` and
+    /// `add`, and counts as one multiplication for `scalar_mul_count`. All
+    /// points and scalars must be on the same curve.
+    pub fn multi_scalar_mul(points: &[Point], scalars: &[Scalar]) -> Result<Point> {
+        let curve_type = Self::msm_curve_type(points, scalars)?;
+        
+        count_scalar_mul();
+        dispatch!(curve_type, multi_scalar_mul(points, scalars))
+    }
+    
+    /// Multi-scalar multiplication Σ s_i * P_i in variable time
+    /// 
+    /// Same as `multi_scalar_mul`, but may leak the scalars through timing;
+    /// only for public inputs such as verification equations.
+    pub fn multi_scalar_mul_vartime(points: &[Point], scalars: &[Scalar]) -> Result<Point> {
+        let curve_type = Self::msm_curve_type(points, scalars)?;
+        
+        count_scalar_mul();
+        dispatch!(curve_type, multi_scalar_mul_vartime(points, scalars))
+    }
+    
+    /// Curve shared by a non-empty, equally long list of points and scalars
+    fn msm_curve_type(points: &[Point], scalars: &[Scalar]) -> Result<CurveType> {
+        if points.len() != scalars.len() {
+            return Err(Error::InvalidInput(format!(
+                "Got {} points but {} scalars", points.len(), scalars.len()
+            )));
+        }
+        let curve_type = points.first()
+            .ok_or_else(|| Error::InvalidInput("Multi-scalar multiplication needs at least one point".to_string()))?
+            .curve_type;
+        if points.iter().any(|p| p.curve_type != curve_type) || scalars.iter().any(|s| s.curve_type != curve_type) {
+            return Err(Error::CurveError("Cannot combine points and scalars from different curves".to_string()));
+        }
+        
+        Ok(curve_type)
+    }
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.curve_type == other.curve_type && self.canonical_bytes() == other.canonical_bytes()
+    }
+}
+
+impl Eq for Point {}
+
+impl std::hash::Hash for Point {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.curve_type.hash(state);
+        self.canonical_bytes().hash(state);
+    }
+}
+
+/// Reject points from another curve than the one running a multi-scalar multiplication
+fn check_msm_curve(curve_type: CurveType, points: &[Point]) -> Result<()> {
+    match points.iter().find(|point| point.curve_type != curve_type) {
+        Some(point) => Err(Error::CurveError(format!(
+            "Cannot multiply a {} point on {}", point.curve_type, curve_type
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Reject scalar encodings that are empty or longer than `width` bytes
+pub(crate) fn check_scalar_len(bytes: &[u8], width: usize) -> Result<()> {
+    if bytes.is_empty() || bytes.len() > width {
+        return Err(Error::InvalidInput(format!("Scalar must be 1 to {} bytes, got {}", width, bytes.len())));
+    }
+    Ok(())
+}
+
+/// Whether the curve encodes scalars little-endian
+/// 
+/// The curve25519 groups do; the Weierstrass curves are big-endian.
+pub(crate) fn is_little_endian(curve_type: CurveType) -> bool {
+    matches!(curve_type, CurveType::Edwards25519 | CurveType::Ristretto255)
+}
+
+/// Strip redundant zero padding and pad to `width` in the curve's byte order
+/// 
+/// Values with significant bytes beyond `width` are returned unpadded.
+pub(crate) fn fixed_width(bytes: &[u8], width: usize, curve_type: CurveType) -> Vec<u8> {
+    let mut bytes = bytes.to_vec();
+    
+    if is_little_endian(curve_type) {
+        while bytes.len() > width && bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+        if bytes.len() < width {
+            bytes.resize(width, 0);
         }
+    } else {
+        let padding = bytes.iter().take_while(|&&byte| byte == 0).count();
+        let strip = padding.min(bytes.len().saturating_sub(width));
+        bytes.drain(..strip);
+        if bytes.len() < width {
+            let mut padded = vec![0u8; width - bytes.len()];
+            padded.extend_from_slice(&bytes);
+            bytes = padded;
+        }
+    }
+    
+    bytes
+}
+
+#[cfg(feature = "secp256k1")]
+static SECP256K1_BACKEND: secp256k1::Secp256k1 = secp256k1::Secp256k1;
+#[cfg(feature = "p256")]
+static P256_BACKEND: p256::P256 = p256::P256;
+#[cfg(feature = "ed25519")]
+static ED25519_BACKEND: ed25519::Ed25519 = ed25519::Ed25519;
+#[cfg(feature = "ristretto255")]
+static RISTRETTO255_BACKEND: ristretto255::Ristretto255 = ristretto255::Ristretto255;
+
+/// Shared backend for a curve type
+/// 
+/// Backends are stateless, so one static instance per curve serves every
+/// caller and nothing is allocated per operation. Fails with
+/// `Error::InvalidCurve` if the curve's feature is disabled.
+pub fn curve_backend(curve_type: CurveType) -> Result<&'static dyn Curve> {
+    match curve_type {
+        #[cfg(feature = "secp256k1")]
+        CurveType::Secp256k1 => Ok(&SECP256K1_BACKEND),
+        #[cfg(feature = "p256")]
+        CurveType::P256 => Ok(&P256_BACKEND),
+        #[cfg(feature = "ed25519")]
+        CurveType::Edwards25519 => Ok(&ED25519_BACKEND),
+        #[cfg(feature = "ristretto255")]
+        CurveType::Ristretto255 => Ok(&RISTRETTO255_BACKEND),
+        #[allow(unreachable_patterns)]
+        curve_type => Err(disabled_curve(curve_type)),
     }
 }
 
 /// Create curve instance by type
-pub fn create_curve(curve_type: CurveType) -> Box<dyn Curve> {
+/// 
+/// Kept for callers that want an owned handle; prefer `curve_backend`.
+/// Fails with `Error::InvalidCurve` if the curve's feature is disabled.
+pub fn create_curve(curve_type: CurveType) -> Result<Box<dyn Curve>> {
     match curve_type {
-        CurveType::Secp256k1 => Box::new(secp256k1::Secp256k1::new()),
-        CurveType::P256 => Box::new(p256::P256::new()),
-        CurveType::Edwards25519 => Box::new(ed25519::Ed25519::new()),
+        #[cfg(feature = "secp256k1")]
+        CurveType::Secp256k1 => Ok(Box::new(secp256k1::Secp256k1::new())),
+        #[cfg(feature = "p256")]
+        CurveType::P256 => Ok(Box::new(p256::P256::new())),
+        #[cfg(feature = "ed25519")]
+        CurveType::Edwards25519 => Ok(Box::new(ed25519::Ed25519::new())),
+        #[cfg(feature = "ristretto255")]
+        CurveType::Ristretto255 => Ok(Box::new(ristretto255::Ristretto255::new())),
+        #[allow(unreachable_patterns)]
+        curve_type => Err(disabled_curve(curve_type)),
     }
 }
 
+/// Whether the curve's backend is compiled in
+pub fn is_curve_enabled(curve_type: CurveType) -> bool {
+    curve_backend(curve_type).is_ok()
+}
+
+/// Curves whose backends are compiled in
+pub fn enabled_curves() -> Vec<CurveType> {
+    [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519, CurveType::Ristretto255]
+        .into_iter()
+        .filter(|&curve_type| is_curve_enabled(curve_type))
+        .collect()
+}
+
+/// Error for a curve whose feature is disabled
+fn disabled_curve(curve_type: CurveType) -> Error {
+    Error::InvalidCurve(format!(
+        "{} support is not compiled in; enable the \"{}\" feature", curve_type, curve_type
+    ))
+}
+
 // Re-export specific curve implementations
+#[cfg(feature = "secp256k1")]
 pub use secp256k1::Secp256k1;
+#[cfg(feature = "p256")]
 pub use p256::P256;
-pub use ed25519::Ed25519; 
\ No newline at end of file
+#[cfg(feature = "ed25519")]
+pub use ed25519::Ed25519;
+#[cfg(feature = "ristretto255")]
+pub use ristretto255::Ristretto255;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn check_sub_and_neg(curve_type: CurveType) {
+        let curve = create_curve(curve_type).unwrap();
+        let a = curve.random_scalar().unwrap();
+        let b = curve.random_scalar().unwrap();
+        let zero = curve.scalar_from_u64(0).unwrap();
+        let one = curve.scalar_from_u64(1).unwrap();
+        
+        assert_eq!(a.sub(&b).unwrap(), a.add(&b.neg().unwrap()).unwrap());
+        assert_eq!(a.sub(&a).unwrap(), zero);
+        assert_eq!(a.add(&a.neg().unwrap()).unwrap(), zero);
+        assert_eq!(a.neg().unwrap().neg().unwrap(), a);
+        assert_eq!(zero.neg().unwrap(), zero);
+        assert_eq!(zero.sub(&b).unwrap(), b.neg().unwrap());
+        
+        // 0 - 1 wraps around to order - 1
+        assert_eq!(zero.sub(&one).unwrap().add(&one).unwrap(), zero);
+    }
+    
+    #[test]
+    fn test_secp256k1_scalar_sub_and_neg() {
+        check_sub_and_neg(CurveType::Secp256k1);
+    }
+    
+    #[test]
+    fn test_p256_scalar_sub_and_neg() {
+        check_sub_and_neg(CurveType::P256);
+    }
+    
+    #[test]
+    fn test_ed25519_scalar_sub_and_neg() {
+        check_sub_and_neg(CurveType::Edwards25519);
+    }
+    
+    #[test]
+    fn test_ristretto255_scalar_sub_and_neg() {
+        check_sub_and_neg(CurveType::Ristretto255);
+    }
+    
+    fn check_point_neg_and_sub(curve_type: CurveType) {
+        let curve = create_curve(curve_type).unwrap();
+        let p = curve.generator().mul(&curve.random_scalar().unwrap()).unwrap();
+        let q = curve.generator().mul(&curve.random_scalar().unwrap()).unwrap();
+        let identity = Point::identity(curve_type).unwrap();
+        
+        assert!(identity.is_identity());
+        assert!(p.add(&p.neg().unwrap()).unwrap().is_identity());
+        assert!(p.sub(&p).unwrap().is_identity());
+        assert!(identity.neg().unwrap().is_identity());
+        assert_eq!(p.neg().unwrap().neg().unwrap(), p);
+        assert_eq!(p.sub(&q).unwrap().add(&q).unwrap(), p);
+        assert_eq!(p.add(&identity).unwrap(), p);
+        assert_eq!(identity.add(&p).unwrap(), p);
+        
+        // Identity survives compression
+        let bytes = identity.to_compressed_bytes().unwrap();
+        assert!(Point::from_compressed_bytes(curve_type, &bytes).unwrap().is_identity());
+    }
+    
+    #[test]
+    fn test_secp256k1_point_neg_and_sub() {
+        check_point_neg_and_sub(CurveType::Secp256k1);
+    }
+    
+    #[test]
+    fn test_p256_point_neg_and_sub() {
+        check_point_neg_and_sub(CurveType::P256);
+    }
+    
+    #[test]
+    fn test_ed25519_point_neg_and_sub() {
+        check_point_neg_and_sub(CurveType::Edwards25519);
+    }
+    
+    #[test]
+    fn test_ristretto255_point_neg_and_sub() {
+        check_point_neg_and_sub(CurveType::Ristretto255);
+    }
+    
+    fn check_identity_arithmetic(curve_type: CurveType) {
+        let curve = create_curve(curve_type).unwrap();
+        let identity = Point::identity(curve_type).unwrap();
+        let scalar = curve.random_scalar().unwrap();
+        
+        assert!(identity.mul(&scalar).unwrap().is_identity());
+        assert!(curve.generator().mul(&curve.scalar_from_u64(0).unwrap()).unwrap().is_identity());
+        assert!(identity.add(&identity).unwrap().is_identity());
+        assert!(!curve.generator().is_identity());
+    }
+    
+    #[test]
+    fn test_secp256k1_identity_arithmetic() {
+        check_identity_arithmetic(CurveType::Secp256k1);
+    }
+    
+    #[test]
+    fn test_p256_identity_arithmetic() {
+        check_identity_arithmetic(CurveType::P256);
+    }
+    
+    #[test]
+    fn test_ed25519_identity_arithmetic() {
+        check_identity_arithmetic(CurveType::Edwards25519);
+    }
+    
+    #[test]
+    fn test_ristretto255_identity_arithmetic() {
+        check_identity_arithmetic(CurveType::Ristretto255);
+    }
+    
+    fn check_point_encoding_round_trip(curve_type: CurveType) {
+        let curve = create_curve(curve_type).unwrap();
+        let g = curve.generator();
+        
+        // G decodes back to G, and 2G agrees between addition and scalar multiplication
+        let encoded = g.to_compressed_bytes().unwrap();
+        assert_eq!(Point::from_compressed_bytes(curve_type, &encoded).unwrap(), g);
+        assert_eq!(g.add(&g).unwrap(), g.mul(&curve.scalar_from_u64(2).unwrap()).unwrap());
+        
+        for _ in 0..16 {
+            let p = g.mul(&curve.random_scalar().unwrap()).unwrap();
+            let bytes = p.to_compressed_bytes().unwrap();
+            let decoded = Point::from_compressed_bytes(curve_type, &bytes).unwrap();
+            assert_eq!(decoded, p);
+            assert_eq!(decoded.to_compressed_bytes().unwrap(), bytes);
+            assert_eq!(decoded.add(&g).unwrap(), p.add(&g).unwrap());
+        }
+    }
+    
+    #[test]
+    fn test_secp256k1_point_encoding_round_trip() {
+        check_point_encoding_round_trip(CurveType::Secp256k1);
+        
+        let g = create_curve(CurveType::Secp256k1).unwrap().generator();
+        assert_eq!(
+            hex::encode(g.to_compressed_bytes().unwrap()),
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        );
+    }
+    
+    #[test]
+    fn test_p256_point_encoding_round_trip() {
+        check_point_encoding_round_trip(CurveType::P256);
+        
+        let g = create_curve(CurveType::P256).unwrap().generator();
+        assert_eq!(
+            hex::encode(g.to_compressed_bytes().unwrap()),
+            "036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        );
+    }
+    
+    #[test]
+    fn test_ed25519_point_encoding_round_trip() {
+        check_point_encoding_round_trip(CurveType::Edwards25519);
+        
+        let curve = create_curve(CurveType::Edwards25519).unwrap();
+        let eight_g = curve.generator().mul(&curve.scalar_from_u64(8).unwrap()).unwrap();
+        assert_eq!(
+            hex::encode(eight_g.to_compressed_bytes().unwrap()),
+            "b4b937fca95b2f1e93e41e62fc3c78818ff38a66096fad6e7973e5c90006d321"
+        );
+    }
+    
+    #[test]
+    fn test_ed25519_rejects_invalid_encodings() {
+        // About half of all y-coordinates have no point on the curve
+        let mut rejected = 0;
+        for y in 0u8..32 {
+            let mut bytes = [0u8; 32];
+            bytes[0] = y;
+            match Point::from_compressed_bytes(CurveType::Edwards25519, &bytes) {
+                Ok(point) => assert_eq!(point.to_compressed_bytes().unwrap(), bytes),
+                Err(Error::CurveError(_)) => rejected += 1,
+                Err(e) => panic!("Unexpected error {:?}", e),
+            }
+        }
+        assert!(rejected > 0);
+        
+        for len in [0, 31, 33] {
+            assert!(matches!(Point::from_compressed_bytes(CurveType::Edwards25519, &vec![1u8; len]), Err(Error::CurveError(_))));
+        }
+    }
+    
+    #[test]
+    fn test_ristretto255_point_encoding_round_trip() {
+        check_point_encoding_round_trip(CurveType::Ristretto255);
+        
+        // Multiples of the generator from RFC 9496, Appendix A.1
+        let curve = create_curve(CurveType::Ristretto255).unwrap();
+        let g = curve.generator();
+        assert_eq!(
+            hex::encode(g.to_compressed_bytes().unwrap()),
+            "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76"
+        );
+        assert_eq!(
+            hex::encode(g.mul(&curve.scalar_from_u64(2).unwrap()).unwrap().to_compressed_bytes().unwrap()),
+            "6a493210f7499cd17fecb510ae0cea23a110e8d5b901f8acadd3095c73a3b919"
+        );
+    }
+    
+    #[test]
+    fn test_ristretto255_rejects_invalid_encodings() {
+        // Non-canonical field element, negative field element, and non-square x^2 (RFC 9496, Appendix A.2)
+        for encoding in [
+            "00ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "26948d35ca62e643e26a83177332e6b6afeb9d08e4268b650f1f5bbd8d81d371",
+        ] {
+            let bytes = hex::decode(encoding).unwrap();
+            assert!(matches!(Point::from_compressed_bytes(CurveType::Ristretto255, &bytes), Err(Error::CurveError(_))), "{}", encoding);
+        }
+        
+        for len in [0, 31, 33] {
+            assert!(matches!(Point::from_compressed_bytes(CurveType::Ristretto255, &vec![0u8; len]), Err(Error::CurveError(_))));
+        }
+    }
+    
+    #[test]
+    fn test_weierstrass_identity_encodings() {
+        for curve_type in [CurveType::Secp256k1, CurveType::P256] {
+            // SEC1 encodes the identity as a single zero byte
+            let identity = Point::identity(curve_type).unwrap();
+            assert_eq!(identity.to_compressed_bytes().unwrap(), vec![0u8]);
+            assert_eq!(Point::from_compressed_bytes(curve_type, &[0u8]).unwrap(), identity);
+            
+            // Zero-filled coordinates are the identity too
+            let width = curve_backend(curve_type).unwrap().scalar_byte_len();
+            let zeros = Point::new(curve_type, vec![0u8; width], vec![0u8; width]);
+            assert!(zeros.is_identity());
+            
+            // Uncompressed encodings are not accepted as compressed input
+            let uncompressed = [&[0x04][..], &[0u8; 64][..]].concat();
+            assert!(Point::from_compressed_bytes(curve_type, &uncompressed).is_err());
+        }
+    }
+    
+    #[test]
+    fn test_scalar_from_bytes_validates_length() {
+        for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519, CurveType::Ristretto255] {
+            let curve = create_curve(curve_type).unwrap();
+            
+            for len in [0, 33, 64] {
+                assert!(matches!(curve.scalar_from_bytes(&vec![1u8; len]), Err(Error::InvalidInput(_))));
+                assert!(matches!(curve.scalar_from_bytes_mod_order(&vec![1u8; len]), Err(Error::InvalidInput(_))));
+            }
+            
+            // Shorter inputs are padded in the curve's byte order
+            for len in [16, 31] {
+                let mut bytes = vec![0u8; len];
+                if is_little_endian(curve_type) {
+                    bytes[0] = 5;
+                } else {
+                    bytes[len - 1] = 5;
+                }
+                assert_eq!(curve.scalar_from_bytes(&bytes).unwrap(), curve.scalar_from_u64(5).unwrap());
+            }
+        }
+    }
+    
+    /// Encode `order + delta` in the curve's byte order
+    fn order_plus(curve: &dyn Curve, delta: i8) -> Vec<u8> {
+        // No curve order ends in 0x00 or 0xff, so no carry is needed
+        let mut bytes = curve.order().into_bytes();
+        bytes[31] = bytes[31].wrapping_add_signed(delta);
+        if is_little_endian(curve.curve_type()) {
+            bytes.reverse();
+        }
+        bytes
+    }
+    
+    #[test]
+    fn test_scalar_from_bytes_rejects_values_above_order() {
+        for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519, CurveType::Ristretto255] {
+            let curve = create_curve(curve_type).unwrap();
+            let zero = curve.scalar_from_u64(0).unwrap();
+            let one = curve.scalar_from_u64(1).unwrap();
+            
+            let below = curve.scalar_from_bytes(&order_plus(curve.as_ref(), -1)).unwrap();
+            assert_eq!(below.add(&one).unwrap(), zero, "curve {}", curve_type);
+            for delta in [0, 1] {
+                assert!(matches!(curve.scalar_from_bytes(&order_plus(curve.as_ref(), delta)), Err(Error::InvalidInput(_))));
+            }
+            
+            // The mod-order variant reduces instead
+            assert_eq!(curve.scalar_from_bytes_mod_order(&order_plus(curve.as_ref(), -1)).unwrap(), below);
+            assert_eq!(curve.scalar_from_bytes_mod_order(&order_plus(curve.as_ref(), 0)).unwrap(), zero);
+            assert_eq!(curve.scalar_from_bytes_mod_order(&order_plus(curve.as_ref(), 1)).unwrap(), one);
+            assert_eq!(curve.scalar_from_bytes_mod_order(&[0xff; 32]).unwrap().value.len(), 32);
+        }
+    }
+    
+    #[test]
+    fn test_ct_eq_agrees_with_eq() {
+        for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519, CurveType::Ristretto255] {
+            let curve = create_curve(curve_type).unwrap();
+            for _ in 0..16 {
+                let a = curve.random_scalar().unwrap();
+                let b = curve.random_scalar().unwrap();
+                assert_eq!(a.ct_eq(&b), a == b);
+                assert!(a.ct_eq(&a.clone()));
+                
+                let p = curve.generator().mul(&a).unwrap();
+                let q = curve.generator().mul(&b).unwrap();
+                assert_eq!(p.ct_eq(&q), p == q);
+                assert!(p.ct_eq(&Point::from_compressed_bytes(curve_type, &p.to_compressed_bytes().unwrap()).unwrap()));
+            }
+            
+            // Encodings of the same value compare equal, other curves never do
+            let five = curve.scalar_from_u64(5).unwrap();
+            let short = if is_little_endian(curve_type) { vec![5u8] } else { vec![0u8, 5] };
+            assert!(five.ct_eq(&Scalar::new(curve_type, short)));
+            let other = if curve_type == CurveType::P256 { CurveType::Secp256k1 } else { CurveType::P256 };
+            let foreign = create_curve(other).unwrap();
+            assert!(!five.ct_eq(&foreign.scalar_from_u64(5).unwrap()));
+            assert!(!curve.generator().ct_eq(&foreign.generator()));
+        }
+    }
+    
+    #[test]
+    fn test_multi_scalar_mul_matches_naive_loop() {
+        for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519, CurveType::Ristretto255] {
+            let curve = create_curve(curve_type).unwrap();
+            
+            for len in [1, 2, 3, 8] {
+                let points: Vec<Point> = (0..len)
+                    .map(|_| curve.generator().mul(&curve.random_scalar().unwrap()).unwrap())
+                    .collect();
+                let scalars: Vec<Scalar> = (0..len).map(|_| curve.random_scalar().unwrap()).collect();
+                
+                let mut expected = Point::identity(curve_type).unwrap();
+                for (point, scalar) in points.iter().zip(&scalars) {
+                    expected = expected.add(&point.mul(scalar).unwrap()).unwrap();
+                }
+                assert_eq!(Point::multi_scalar_mul(&points, &scalars).unwrap(), expected, "curve {} len {}", curve_type, len);
+            }
+            
+            // Terms that cancel give the identity
+            let g = curve.generator();
+            let one = curve.scalar_from_u64(1).unwrap();
+            assert!(Point::multi_scalar_mul(&[g.clone(), g.clone()], &[one.clone(), one.neg().unwrap()]).unwrap().is_identity());
+            
+            assert!(matches!(Point::multi_scalar_mul(std::slice::from_ref(&g), &[]), Err(Error::InvalidInput(_))));
+            assert!(matches!(Point::multi_scalar_mul(&[], &[]), Err(Error::InvalidInput(_))));
+            let other = if curve_type == CurveType::P256 { CurveType::Secp256k1 } else { CurveType::P256 };
+            let foreign = create_curve(other).unwrap();
+            assert!(matches!(
+                Point::multi_scalar_mul(&[g.clone(), foreign.generator()], &[one.clone(), one.clone()]),
+                Err(Error::CurveError(_))
+            ));
+            assert!(matches!(
+                Point::multi_scalar_mul(&[g], &[foreign.scalar_from_u64(1).unwrap()]),
+                Err(Error::CurveError(_))
+            ));
+        }
+    }
+    
+    #[test]
+    #[cfg(all(feature = "secp256k1", feature = "p256", feature = "ed25519"))]
+    fn test_hash_to_point_matches_rfc9380_vectors() {
+        // Suites secp256k1_XMD:SHA-256_SSWU_RO_ and P256_XMD:SHA-256_SSWU_RO_, msg "abc"
+        let k1 = secp256k1::Secp256k1::hash_to_point_with_dst(b"abc", b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_").unwrap();
+        assert_eq!(hex::encode(&k1.x), "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b");
+        assert_eq!(hex::encode(&k1.y), "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6");
+        
+        let p256 = p256::P256::hash_to_point_with_dst(b"abc", b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_").unwrap();
+        assert_eq!(hex::encode(&p256.x), "0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f");
+        assert_eq!(hex::encode(&p256.y), "5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e");
+        
+        // Suite edwards25519_XMD:SHA-512_ELL2_RO_: y little-endian, x odd for "abc"
+        let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+        let empty = ed25519::Ed25519::hash_to_point_with_dst(b"", dst).unwrap();
+        let mut y = hex::decode("09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21").unwrap();
+        y.reverse();
+        assert_eq!(empty.x, y);
+        let abc = ed25519::Ed25519::hash_to_point_with_dst(b"abc", dst).unwrap();
+        let mut y = hex::decode("1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531").unwrap();
+        y.reverse();
+        y[31] |= 0x80;
+        assert_eq!(abc.x, y);
+    }
+    
+    #[test]
+    fn test_hash_to_point_known_answers() {
+        let cases = [
+            (CurveType::Secp256k1, "0317f97c2df09066bdf561ac87a8b4d8c1abcf6c61ca2ec8f6f081bd21d0d733af"),
+            (CurveType::P256, "02d21b9d37e4b0d630327e1e93bb945f7a6b956fab065b828d2477fa8e0443217d"),
+            (CurveType::Edwards25519, "afaf2319edd8085a0ed50d62ee5e5fc6f70906014a7743ac1cfc1b491c25a7a6"),
+            (CurveType::Ristretto255, "86cf767ae1459f35f41b95b55b0ef02012f0a72b762e7d7e5d09c5d741bcc174"),
+        ];
+        for (curve_type, expected) in cases {
+            let curve = create_curve(curve_type).unwrap();
+            let point = curve.hash_to_point(b"z-MPC").unwrap();
+            let encoded = point.to_compressed_bytes().unwrap();
+            assert_eq!(hex::encode(&encoded), expected, "curve {}", curve_type);
+            
+            // The output decodes back to itself and depends on the message
+            assert_eq!(curve.point_from_bytes(&encoded).unwrap(), point);
+            assert!(!point.is_identity());
+            assert_ne!(curve.hash_to_point(b"z-MPC ").unwrap(), point);
+        }
+    }
+    
+    #[test]
+    fn test_curve_msm_matches_naive_fold() {
+        for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519, CurveType::Ristretto255] {
+            let curve = create_curve(curve_type).unwrap();
+            
+            for len in [1, 2, 17, 100] {
+                let points: Vec<Point> = (0..len)
+                    .map(|_| curve.generator().mul(&curve.random_scalar().unwrap()).unwrap())
+                    .collect();
+                let scalars: Vec<Scalar> = (0..len).map(|_| curve.random_scalar().unwrap()).collect();
+                
+                let expected = points.iter().zip(&scalars)
+                    .fold(Point::identity(curve_type).unwrap(), |sum, (point, scalar)| sum.add(&point.mul(scalar).unwrap()).unwrap());
+                assert_eq!(curve.msm(&points, &scalars).unwrap(), expected, "curve {} len {}", curve_type, len);
+                assert_eq!(curve.msm_vartime(&points, &scalars).unwrap(), expected, "curve {} len {}", curve_type, len);
+            }
+            
+            assert!(curve.msm(&[], &[]).unwrap().is_identity());
+            assert!(curve.msm_vartime(&[], &[]).unwrap().is_identity());
+            let other = if curve_type == CurveType::P256 { CurveType::Secp256k1 } else { CurveType::P256 };
+            let foreign = create_curve(other).unwrap();
+            assert!(matches!(
+                curve.msm(&[foreign.generator()], &[foreign.scalar_from_u64(1).unwrap()]),
+                Err(Error::CurveError(_))
+            ));
+        }
+    }
+    
+    #[test]
+    fn test_precomputed_point_matches_point_mul() {
+        for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519, CurveType::Ristretto255] {
+            let curve = create_curve(curve_type).unwrap();
+            let base = curve.generator().mul(&curve.random_scalar().unwrap()).unwrap();
+            let table = PrecomputedPoint::new(&base).unwrap();
+            assert_eq!(table.point(), &base);
+            
+            let minus_one = curve.scalar_from_u64(1).unwrap().neg().unwrap();
+            let edge_cases = [curve.scalar_from_u64(0).unwrap(), curve.scalar_from_u64(1).unwrap(), minus_one];
+            let random = (0..1000).map(|_| curve.random_scalar().unwrap());
+            for scalar in edge_cases.into_iter().chain(random) {
+                assert_eq!(table.mul(&scalar).unwrap(), base.mul(&scalar).unwrap(), "curve {}", curve_type);
+            }
+            
+            let other = PrecomputedPoint::new(&curve.generator()).unwrap();
+            let (a, b) = (curve.random_scalar().unwrap(), curve.random_scalar().unwrap());
+            let expected = base.mul(&a).unwrap().add(&curve.generator().mul(&b).unwrap()).unwrap();
+            assert_eq!(table.mul_add(&a, &other, &b).unwrap(), expected, "curve {}", curve_type);
+            
+            let foreign_type = if curve_type == CurveType::P256 { CurveType::Secp256k1 } else { CurveType::P256 };
+            let foreign = create_curve(foreign_type).unwrap();
+            assert!(matches!(table.mul(&foreign.scalar_from_u64(1).unwrap()), Err(Error::CurveError(_))));
+            let foreign_table = PrecomputedPoint::new(&foreign.generator()).unwrap();
+            assert!(matches!(table.mul_add(&a, &foreign_table, &b), Err(Error::CurveError(_))));
+        }
+    }
+    
+    #[test]
+    fn test_scalar_sub_rejects_mixed_curves() {
+        let a = create_curve(CurveType::Secp256k1).unwrap().scalar_from_u64(1).unwrap();
+        let b = create_curve(CurveType::P256).unwrap().scalar_from_u64(1).unwrap();
+        assert!(a.sub(&b).is_err());
+    }
+    
+    #[test]
+    fn test_point_sub_rejects_mixed_curves() {
+        let p = create_curve(CurveType::Secp256k1).unwrap().generator();
+        let q = create_curve(CurveType::P256).unwrap().generator();
+        assert!(p.sub(&q).is_err());
+    }
+}
@@ -1,11 +1,21 @@
 use crate::{Error, Result, CurveType};
-use crate::curve::{Curve, Scalar, Point};
-use p256::{Secp256r1 as P256Curve, Scalar as P256Scalar, ProjectivePoint, AffinePoint};
-use p256::elliptic_curve::group::Group;
-use p256::elliptic_curve::sec1::ToEncodedPoint;
-use rand::Rng;
+use crate::curve::{Curve, Scalar, Point, check_scalar_len, fixed_width};
+use crate::curve::precomputed::WindowTable;
+use p256::{Scalar as P256Scalar, ProjectivePoint, AffinePoint};
+use p256::elliptic_curve::bigint::U256;
+use p256::elliptic_curve::ff::{Field, PrimeField};
+use p256::elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest};
+use p256::elliptic_curve::ops::{LinearCombination, Reduce};
+use p256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use p256::{EncodedPoint, FieldBytes, NistP256};
+use rand_core::CryptoRngCore;
+use sha2::Sha256;
+
+/// Domain separation tag of `hash_to_point` (RFC 9380 suite P256_XMD:SHA-256_SSWU_RO_)
+const HASH_TO_CURVE_DST: &[u8] = b"z-MPC-V01-CS01-with-P256_XMD:SHA-256_SSWU_RO_";
 
 /// P-256 curve implementation
+#[derive(Default)]
 pub struct P256;
 
 impl P256 {
@@ -16,107 +26,183 @@ impl P256 {
     
     /// Add two scalars
     pub fn add_scalars(a: &Scalar, b: &Scalar) -> Result<Scalar> {
-        let a_scalar = P256Scalar::from_bytes_be(&a.value.into())
-            .map_err(|_| Error::CurveError("Invalid scalar bytes".to_string()))?;
-        let b_scalar = P256Scalar::from_bytes_be(&b.value.into())
-            .map_err(|_| Error::CurveError("Invalid scalar bytes".to_string()))?;
-        
-        let result = a_scalar + b_scalar;
-        Ok(Scalar::new(CurveType::P256, result.to_bytes_be().to_vec()))
+        let result = Self::scalar_to_p256(a)? + Self::scalar_to_p256(b)?;
+        Ok(Self::scalar_from_p256(&result))
     }
     
     /// Multiply two scalars
     pub fn mul_scalars(a: &Scalar, b: &Scalar) -> Result<Scalar> {
-        let a_scalar = P256Scalar::from_bytes_be(&a.value.into())
-            .map_err(|_| Error::CurveError("Invalid scalar bytes".to_string()))?;
-        let b_scalar = P256Scalar::from_bytes_be(&b.value.into())
-            .map_err(|_| Error::CurveError("Invalid scalar bytes".to_string()))?;
-        
-        let result = a_scalar * b_scalar;
-        Ok(Scalar::new(CurveType::P256, result.to_bytes_be().to_vec()))
+        let result = Self::scalar_to_p256(a)? * Self::scalar_to_p256(b)?;
+        Ok(Self::scalar_from_p256(&result))
+    }
+    
+    /// Negate scalar
+    pub fn neg_scalar(s: &Scalar) -> Result<Scalar> {
+        let result = -Self::scalar_to_p256(s)?;
+        Ok(Self::scalar_from_p256(&result))
     }
     
     /// Invert scalar
     pub fn invert_scalar(s: &Scalar) -> Result<Scalar> {
-        let scalar = P256Scalar::from_bytes_be(&s.value.into())
-            .map_err(|_| Error::CurveError("Invalid scalar bytes".to_string()))?;
+        let result = Option::<P256Scalar>::from(Self::scalar_to_p256(s)?.invert())
+            .ok_or_else(|| Error::CurveError("Cannot invert zero scalar".to_string()))?;
+        Ok(Self::scalar_from_p256(&result))
+    }
+    
+    /// Decode a canonical big-endian scalar, left-padding short encodings
+    fn scalar_to_p256(s: &Scalar) -> Result<P256Scalar> {
+        let width = Self.scalar_byte_len();
+        let bytes = fixed_width(&s.value, width, CurveType::P256);
+        if bytes.len() != width {
+            return Err(Error::CurveError("Invalid scalar bytes".to_string()));
+        }
         
-        let result = scalar.invert().unwrap_or(P256Scalar::ZERO);
-        Ok(Scalar::new(CurveType::P256, result.to_bytes_be().to_vec()))
+        Option::<P256Scalar>::from(P256Scalar::from_repr(*FieldBytes::from_slice(&bytes)))
+            .ok_or_else(|| Error::CurveError("Invalid scalar bytes".to_string()))
+    }
+    
+    /// Encode a scalar as 32 big-endian bytes
+    fn scalar_from_p256(s: &P256Scalar) -> Scalar {
+        Scalar::new(CurveType::P256, s.to_repr().to_vec())
     }
     
     /// Convert point to compressed bytes
     pub fn point_to_compressed(p: &Point) -> Result<Vec<u8>> {
-        let affine = AffinePoint::from_compressed_bytes(&p.x)
-            .map_err(|_| Error::CurveError("Invalid point".to_string()))?;
+        let affine = Self::affine_from_point(p)?;
         
         Ok(affine.to_encoded_point(true).as_bytes().to_vec())
     }
     
     /// Create point from compressed bytes
+    /// 
+    /// Accepts the 33-byte SEC1 compressed form, or the single 0x00 byte
+    /// that SEC1 uses for the identity.
     pub fn point_from_compressed(bytes: &[u8]) -> Result<Point> {
-        let affine = AffinePoint::from_compressed_bytes(bytes)
+        let encoded = EncodedPoint::from_bytes(bytes)
             .map_err(|_| Error::CurveError("Invalid compressed point".to_string()))?;
+        if !encoded.is_compressed() && !encoded.is_identity() {
+            return Err(Error::CurveError("Invalid compressed point".to_string()));
+        }
         
-        let encoded = affine.to_encoded_point(false);
-        let coords = encoded.coordinates();
+        // Decompression fails for x-coordinates with no point on the curve
+        let affine = Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+            .ok_or_else(|| Error::CurveError("Point is not on the curve".to_string()))?;
         
-        match coords {
-            p256::elliptic_curve::sec1::Coordinates::Uncompressed { x, y } => {
-                Ok(Point::new(
-                    CurveType::P256,
-                    x.to_vec(),
-                    y.to_vec(),
-                ))
-            }
-            _ => Err(Error::CurveError("Expected uncompressed coordinates".to_string())),
+        Ok(Self::point_from_affine(&affine))
+    }
+    
+    /// Decode a point's affine coordinates, rejecting points off the curve
+    /// 
+    /// Points built with `Point::new` skip decompression, so every operation
+    /// re-checks the curve equation here before touching the coordinates.
+    pub fn affine_from_point(p: &Point) -> Result<AffinePoint> {
+        // Empty or all-zero coordinates encode the identity; (0, 0) is not on the curve
+        if p.x.iter().chain(&p.y).all(|&byte| byte == 0) {
+            return Ok(AffinePoint::IDENTITY);
+        }
+        
+        let width = Self.scalar_byte_len();
+        let x = fixed_width(&p.x, width, CurveType::P256);
+        let y = fixed_width(&p.y, width, CurveType::P256);
+        if x.len() != width || y.len() != width {
+            return Err(Error::CurveError("Invalid point coordinate length".to_string()));
+        }
+        
+        let encoded = EncodedPoint::from_affine_coordinates(FieldBytes::from_slice(&x), FieldBytes::from_slice(&y), false);
+        Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+            .ok_or_else(|| Error::CurveError("Point is not on the curve".to_string()))
+    }
+    
+    /// Encode an affine point, mapping the identity to empty coordinates
+    fn point_from_affine(affine: &AffinePoint) -> Point {
+        let encoded = affine.to_encoded_point(false);
+        match (encoded.x(), encoded.y()) {
+            (Some(x), Some(y)) => Point::new(CurveType::P256, x.to_vec(), y.to_vec()),
+            _ => Point::new(CurveType::P256, Vec::new(), Vec::new()),
         }
     }
     
+    /// Identity point
+    pub fn identity_point() -> Result<Point> {
+        Ok(Self::point_from_affine(&AffinePoint::IDENTITY))
+    }
+    
+    /// Whether a point is the identity, in any of its encodings
+    pub fn is_identity_point(p: &Point) -> Result<bool> {
+        let affine = Self::affine_from_point(p)?;
+        
+        Ok(affine == AffinePoint::IDENTITY)
+    }
+    
+    /// Negate point
+    pub fn neg_point(p: &Point) -> Result<Point> {
+        let point = Self::affine_from_point(p)?;
+        
+        Ok(Self::point_from_affine(&-point))
+    }
+    
     /// Add two points
     pub fn add_points(a: &Point, b: &Point) -> Result<Point> {
-        let a_affine = AffinePoint::from_compressed_bytes(&a.x)
-            .map_err(|_| Error::CurveError("Invalid point A".to_string()))?;
-        let b_affine = AffinePoint::from_compressed_bytes(&b.x)
-            .map_err(|_| Error::CurveError("Invalid point B".to_string()))?;
+        let a_affine = Self::affine_from_point(a)?;
+        let b_affine = Self::affine_from_point(b)?;
         
-        let result = (a_affine + b_affine).to_affine();
-        let encoded = result.to_encoded_point(false);
-        let coords = encoded.coordinates();
-        
-        match coords {
-            p256::elliptic_curve::sec1::Coordinates::Uncompressed { x, y } => {
-                Ok(Point::new(
-                    CurveType::P256,
-                    x.to_vec(),
-                    y.to_vec(),
-                ))
-            }
-            _ => Err(Error::CurveError("Expected uncompressed coordinates".to_string())),
-        }
+        let result = (ProjectivePoint::from(a_affine) + b_affine).to_affine();
+        Ok(Self::point_from_affine(&result))
     }
     
     /// Multiply point by scalar
     pub fn mul_point_scalar(p: &Point, s: &Scalar) -> Result<Point> {
-        let point = AffinePoint::from_compressed_bytes(&p.x)
-            .map_err(|_| Error::CurveError("Invalid point".to_string()))?;
-        let scalar = P256Scalar::from_bytes_be(&s.value.into())
-            .map_err(|_| Error::CurveError("Invalid scalar".to_string()))?;
+        let point = Self::affine_from_point(p)?;
+        let scalar = Self::scalar_to_p256(s)?;
         
-        let result = (point * scalar).to_affine();
-        let encoded = result.to_encoded_point(false);
-        let coords = encoded.coordinates();
+        let result = (ProjectivePoint::from(point) * scalar).to_affine();
+        Ok(Self::point_from_affine(&result))
+    }
+    
+    /// Fixed-base table for repeated multiplication of `p`
+    pub(crate) fn precompute_point(p: &Point) -> Result<WindowTable<ProjectivePoint>> {
+        Ok(WindowTable::new(ProjectivePoint::from(Self::affine_from_point(p)?)))
+    }
+    
+    /// Σ s_i * P_i over precomputed tables
+    pub(crate) fn mul_precomputed(terms: &[(&WindowTable<ProjectivePoint>, &Scalar)]) -> Result<Point> {
+        let mut result = ProjectivePoint::IDENTITY;
+        for (table, s) in terms {
+            result += table.mul(&Self::scalar_to_p256(s)?.to_repr().into());
+        }
+        
+        Ok(Self::point_from_affine(&result.to_affine()))
+    }
+    
+    /// Multi-scalar multiplication Σ s_i * P_i
+    /// 
+    /// p256 only combines two terms at a time, so terms are folded in pairs
+    /// with Shamir's trick.
+    pub fn multi_scalar_mul(points: &[Point], scalars: &[Scalar]) -> Result<Point> {
+        let terms = points.iter().zip(scalars)
+            .map(|(p, s)| Ok((ProjectivePoint::from(Self::affine_from_point(p)?), Self::scalar_to_p256(s)?)))
+            .collect::<Result<Vec<_>>>()?;
         
-        match coords {
-            p256::elliptic_curve::sec1::Coordinates::Uncompressed { x, y } => {
-                Ok(Point::new(
-                    CurveType::P256,
-                    x.to_vec(),
-                    y.to_vec(),
-                ))
-            }
-            _ => Err(Error::CurveError("Expected uncompressed coordinates".to_string())),
+        let mut result = ProjectivePoint::IDENTITY;
+        for pair in terms.chunks(2) {
+            result += match pair {
+                [(x, k), (y, l)] => ProjectivePoint::lincomb(x, k, y, l),
+                _ => pair[0].0 * pair[0].1,
+            };
         }
+        Ok(Self::point_from_affine(&result.to_affine()))
+    }
+    
+    /// Multi-scalar multiplication for public inputs; the constant-time one is as fast
+    pub fn multi_scalar_mul_vartime(points: &[Point], scalars: &[Scalar]) -> Result<Point> {
+        Self::multi_scalar_mul(points, scalars)
+    }
+    
+    /// Hash to the curve with the RFC 9380 simplified SWU random-oracle encoding
+    pub fn hash_to_point_with_dst(msg: &[u8], dst: &[u8]) -> Result<Point> {
+        let point = NistP256::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[msg], &[dst])
+            .map_err(|_| Error::InvalidInput(format!("Invalid hash-to-curve domain separation tag of {} bytes", dst.len())))?;
+        Ok(Self::point_from_affine(&point.to_affine()))
     }
 }
 
@@ -125,40 +211,49 @@ impl Curve for P256 {
         CurveType::P256
     }
     
-    fn random_scalar(&self) -> Result<Scalar> {
-        let mut rng = rand::thread_rng();
-        let scalar = P256Scalar::random(&mut rng);
-        Ok(Scalar::new(CurveType::P256, scalar.to_bytes_be().to_vec()))
+    fn random_scalar_with_rng(&self, rng: &mut dyn CryptoRngCore) -> Result<Scalar> {
+        let scalar = P256Scalar::random(rng.as_rngcore());
+        Ok(Self::scalar_from_p256(&scalar))
     }
     
     fn scalar_from_bytes(&self, bytes: &[u8]) -> Result<Scalar> {
-        let scalar = P256Scalar::from_bytes_be(bytes.into())
-            .map_err(|_| Error::CurveError("Invalid scalar bytes".to_string()))?;
-        Ok(Scalar::new(CurveType::P256, scalar.to_bytes_be().to_vec()))
+        check_scalar_len(bytes, self.scalar_byte_len())?;
+        
+        // Shorter inputs are left-padded, which keeps them below the order
+        let padded = fixed_width(bytes, self.scalar_byte_len(), CurveType::P256);
+        let scalar = Option::<P256Scalar>::from(P256Scalar::from_repr(*FieldBytes::from_slice(&padded)))
+            .ok_or_else(|| Error::InvalidInput("Scalar is not below the P-256 group order".to_string()))?;
+        Ok(Self::scalar_from_p256(&scalar))
+    }
+    
+    fn scalar_from_bytes_mod_order(&self, bytes: &[u8]) -> Result<Scalar> {
+        check_scalar_len(bytes, self.scalar_byte_len())?;
+        
+        let padded = fixed_width(bytes, self.scalar_byte_len(), CurveType::P256);
+        let scalar = <P256Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(&padded));
+        Ok(Self::scalar_from_p256(&scalar))
+    }
+    
+    fn scalar_byte_len(&self) -> usize {
+        32
     }
     
     fn scalar_from_u64(&self, value: u64) -> Result<Scalar> {
-        let scalar = P256Scalar::from(value);
-        Ok(Scalar::new(CurveType::P256, scalar.to_bytes_be().to_vec()))
+        Ok(Self::scalar_from_p256(&P256Scalar::from(value)))
     }
     
     fn generator(&self) -> Point {
-        let generator = ProjectivePoint::generator().to_affine();
-        let encoded = generator.to_encoded_point(false);
-        let coords = encoded.coordinates();
-        
-        match coords {
-            p256::elliptic_curve::sec1::Coordinates::Uncompressed { x, y } => {
-                Point::new(CurveType::P256, x.to_vec(), y.to_vec())
-            }
-            _ => unreachable!("Generator should have uncompressed coordinates"),
-        }
+        Self::point_from_affine(&AffinePoint::GENERATOR)
     }
     
     fn point_from_bytes(&self, bytes: &[u8]) -> Result<Point> {
         Self::point_from_compressed(bytes)
     }
     
+    fn hash_to_point(&self, msg: &[u8]) -> Result<Point> {
+        Self::hash_to_point_with_dst(msg, HASH_TO_CURVE_DST)
+    }
+    
     fn order(&self) -> Scalar {
         // P-256 order
         let order_bytes = [
@@ -0,0 +1,174 @@
+//! Fixed-base tables for points multiplied many times
+//! 
+//! A table holds j * 16^i * P for every 4-bit window i and digit j, so a
+//! multiplication is one table lookup and addition per window with no
+//! doublings. Lookups scan the whole row, keeping the scalar secret.
+
+use crate::{Error, Result, CurveType};
+use crate::curve::{count_scalar_mul, Point, Scalar};
+#[cfg(any(feature = "secp256k1", feature = "p256"))]
+use std::ops::Add;
+use std::fmt;
+use std::sync::Arc;
+#[cfg(any(feature = "secp256k1", feature = "p256"))]
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+/// Windowed table over a backend's native point type
+/// 
+/// Covers 256-bit scalars given as 32 big-endian bytes.
+#[cfg(any(feature = "secp256k1", feature = "p256"))]
+pub(crate) struct WindowTable<G> {
+    windows: Vec<[G; 16]>,
+}
+
+#[cfg(any(feature = "secp256k1", feature = "p256"))]
+impl<G: Copy + Default + ConditionallySelectable + Add<Output = G>> WindowTable<G> {
+    /// Number of 4-bit windows in a 256-bit scalar
+    const WINDOWS: usize = 64;
+    
+    /// Build the table for `base`
+    pub(crate) fn new(base: G) -> Self {
+        let mut windows = Vec::with_capacity(Self::WINDOWS);
+        let mut window_base = base;
+        for _ in 0..Self::WINDOWS {
+            // row[j] = j * 16^i * base; the identity is G::default()
+            let mut row = [G::default(); 16];
+            for j in 1..16 {
+                row[j] = row[j - 1] + window_base;
+            }
+            window_base = row[15] + window_base;
+            windows.push(row);
+        }
+        
+        Self { windows }
+    }
+    
+    /// Multiply the base by a 32-byte big-endian scalar
+    pub(crate) fn mul(&self, scalar: &[u8; 32]) -> G {
+        let mut result = G::default();
+        for (i, row) in self.windows.iter().enumerate() {
+            let byte = scalar[31 - i / 2];
+            let digit = if i % 2 == 0 { byte & 0x0f } else { byte >> 4 };
+            
+            let mut entry = G::default();
+            for (j, point) in row.iter().enumerate() {
+                entry.conditional_assign(point, (j as u8).ct_eq(&digit));
+            }
+            result = result + entry;
+        }
+        
+        result
+    }
+}
+
+/// Backend table behind a `PrecomputedPoint`
+enum Table {
+    #[cfg(feature = "secp256k1")]
+    Secp256k1(WindowTable<k256::ProjectivePoint>),
+    #[cfg(feature = "p256")]
+    P256(WindowTable<p256::ProjectivePoint>),
+    #[cfg(feature = "ed25519")]
+    Edwards25519(Box<curve25519_dalek::edwards::EdwardsBasepointTable>),
+    #[cfg(feature = "ristretto255")]
+    Ristretto255(Box<curve25519_dalek::ristretto::RistrettoBasepointTable>),
+}
+
+/// Point with a fixed-base table for repeated multiplication
+/// 
+/// Building the table costs about as much as a few dozen multiplications,
+/// so it pays off for generators such as Pedersen's g and h. Clones share
+/// the table.
+#[derive(Clone)]
+pub struct PrecomputedPoint {
+    point: Point,
+    table: Arc<Table>,
+}
+
+impl PrecomputedPoint {
+    /// Build the table for `point`
+    pub fn new(point: &Point) -> Result<Self> {
+        let table = match point.curve_type {
+            #[cfg(feature = "secp256k1")]
+            CurveType::Secp256k1 => Table::Secp256k1(super::secp256k1::Secp256k1::precompute_point(point)?),
+            #[cfg(feature = "p256")]
+            CurveType::P256 => Table::P256(super::p256::P256::precompute_point(point)?),
+            #[cfg(feature = "ed25519")]
+            CurveType::Edwards25519 => Table::Edwards25519(Box::new(super::ed25519::Ed25519::precompute_point(point)?)),
+            #[cfg(feature = "ristretto255")]
+            CurveType::Ristretto255 => Table::Ristretto255(Box::new(super::ristretto255::Ristretto255::precompute_point(point)?)),
+            #[allow(unreachable_patterns)]
+            curve_type => return Err(super::disabled_curve(curve_type)),
+        };
+        
+        Ok(Self {
+            point: point.clone(),
+            table: Arc::new(table),
+        })
+    }
+    
+    /// The point the table was built from
+    pub fn point(&self) -> &Point {
+        &self.point
+    }
+    
+    /// Multiply by a scalar; same result as `Point::mul`
+    pub fn mul(&self, scalar: &Scalar) -> Result<Point> {
+        self.check_curve(scalar)?;
+        
+        count_scalar_mul();
+        match &*self.table {
+            #[cfg(feature = "secp256k1")]
+            Table::Secp256k1(table) => super::secp256k1::Secp256k1::mul_precomputed(&[(table, scalar)]),
+            #[cfg(feature = "p256")]
+            Table::P256(table) => super::p256::P256::mul_precomputed(&[(table, scalar)]),
+            #[cfg(feature = "ed25519")]
+            Table::Edwards25519(table) => super::ed25519::Ed25519::mul_precomputed(&[(table, scalar)]),
+            #[cfg(feature = "ristretto255")]
+            Table::Ristretto255(table) => super::ristretto255::Ristretto255::mul_precomputed(&[(table, scalar)]),
+        }
+    }
+    
+    /// Compute a * self + b * other with a single point encoding
+    /// 
+    /// Counts as one multiplication for `scalar_mul_count`, like an MSM.
+    pub fn mul_add(&self, a: &Scalar, other: &PrecomputedPoint, b: &Scalar) -> Result<Point> {
+        self.check_curve(a)?;
+        other.check_curve(b)?;
+        
+        count_scalar_mul();
+        match (&*self.table, &*other.table) {
+            #[cfg(feature = "secp256k1")]
+            (Table::Secp256k1(table), Table::Secp256k1(other_table)) => {
+                super::secp256k1::Secp256k1::mul_precomputed(&[(table, a), (other_table, b)])
+            }
+            #[cfg(feature = "p256")]
+            (Table::P256(table), Table::P256(other_table)) => {
+                super::p256::P256::mul_precomputed(&[(table, a), (other_table, b)])
+            }
+            #[cfg(feature = "ed25519")]
+            (Table::Edwards25519(table), Table::Edwards25519(other_table)) => {
+                super::ed25519::Ed25519::mul_precomputed(&[(table, a), (other_table, b)])
+            }
+            #[cfg(feature = "ristretto255")]
+            (Table::Ristretto255(table), Table::Ristretto255(other_table)) => {
+                super::ristretto255::Ristretto255::mul_precomputed(&[(table, a), (other_table, b)])
+            }
+            #[allow(unreachable_patterns)]
+            _ => Err(Error::CurveError("Cannot combine precomputed points from different curves".to_string())),
+        }
+    }
+    
+    fn check_curve(&self, scalar: &Scalar) -> Result<()> {
+        if self.point.curve_type != scalar.curve_type {
+            return Err(Error::CurveError("Cannot multiply point by scalar from different curve".to_string()));
+        }
+        
+        Ok(())
+    }
+}
+
+impl fmt::Debug for PrecomputedPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrecomputedPoint").field("point", &self.point).finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{Error, Result, CurveType};
+use crate::curve::{Curve, Scalar, Point, check_scalar_len, fixed_width};
+use crate::curve::xmd::expand_message_xmd;
+use curve25519_dalek::{RistrettoPoint, Scalar as RistrettoScalar, constants::RISTRETTO_BASEPOINT_POINT};
+use curve25519_dalek::ristretto::{CompressedRistretto, RistrettoBasepointTable};
+use curve25519_dalek::traits::{Identity, MultiscalarMul, VartimeMultiscalarMul};
+use rand_core::CryptoRngCore;
+
+/// Domain separation tag of `hash_to_point` (RFC 9380 suite ristretto255_XMD:SHA-512_R255MAP_RO_)
+const HASH_TO_CURVE_DST: &[u8] = b"z-MPC-V01-CS01-with-ristretto255_XMD:SHA-512_R255MAP_RO_";
+
+/// Ristretto255 group implementation
+/// 
+/// The prime-order group built over edwards25519 (RFC 9496). Scalars are the
+/// Ed25519 scalars; points have a single canonical 32-byte encoding, so no
+/// cofactor handling is needed.
+#[derive(Default)]
+pub struct Ristretto255;
+
+impl Ristretto255 {
+    /// Create new Ristretto255 instance
+    pub fn new() -> Self {
+        Self
+    }
+    
+    /// Add two scalars
+    pub fn add_scalars(a: &Scalar, b: &Scalar) -> Result<Scalar> {
+        let result = Self::scalar_to_dalek(a)? + Self::scalar_to_dalek(b)?;
+        Ok(Self::scalar_from_dalek(&result))
+    }
+    
+    /// Multiply two scalars
+    pub fn mul_scalars(a: &Scalar, b: &Scalar) -> Result<Scalar> {
+        let result = Self::scalar_to_dalek(a)? * Self::scalar_to_dalek(b)?;
+        Ok(Self::scalar_from_dalek(&result))
+    }
+    
+    /// Negate scalar
+    pub fn neg_scalar(s: &Scalar) -> Result<Scalar> {
+        let result = -Self::scalar_to_dalek(s)?;
+        Ok(Self::scalar_from_dalek(&result))
+    }
+    
+    /// Invert scalar
+    pub fn invert_scalar(s: &Scalar) -> Result<Scalar> {
+        let scalar = Self::scalar_to_dalek(s)?;
+        if scalar == RistrettoScalar::ZERO {
+            return Err(Error::CurveError("Cannot invert zero scalar".to_string()));
+        }
+        
+        Ok(Self::scalar_from_dalek(&scalar.invert()))
+    }
+    
+    /// Decode a canonical little-endian scalar, padding short encodings
+    fn scalar_to_dalek(s: &Scalar) -> Result<RistrettoScalar> {
+        let bytes: [u8; 32] = fixed_width(&s.value, Self.scalar_byte_len(), CurveType::Ristretto255)
+            .try_into()
+            .map_err(|_| Error::CurveError("Invalid scalar bytes".to_string()))?;
+        
+        Option::<RistrettoScalar>::from(RistrettoScalar::from_canonical_bytes(bytes))
+            .ok_or_else(|| Error::CurveError("Invalid scalar bytes".to_string()))
+    }
+    
+    /// Pad 1 to 32 little-endian bytes at the high end
+    fn pad_scalar_bytes(bytes: &[u8]) -> Result<[u8; 32]> {
+        check_scalar_len(bytes, Self.scalar_byte_len())?;
+        
+        let mut padded = [0u8; 32];
+        padded[..bytes.len()].copy_from_slice(bytes);
+        Ok(padded)
+    }
+    
+    /// Encode a scalar as 32 little-endian bytes
+    fn scalar_from_dalek(s: &RistrettoScalar) -> Scalar {
+        Scalar::new(CurveType::Ristretto255, s.to_bytes().to_vec())
+    }
+    
+    /// Convert point to compressed bytes
+    pub fn point_to_compressed(p: &Point) -> Result<Vec<u8>> {
+        let point = Self::ristretto_from_point(p)?;
+        
+        Ok(point.compress().to_bytes().to_vec())
+    }
+    
+    /// Create point from compressed bytes
+    /// 
+    /// Accepts only the canonical 32-byte Ristretto encoding.
+    pub fn point_from_compressed(bytes: &[u8]) -> Result<Point> {
+        if bytes.len() != Self.scalar_byte_len() {
+            return Err(Error::CurveError(format!("Compressed point must be 32 bytes, got {}", bytes.len())));
+        }
+        
+        let point = Self::ristretto_from_point(&Point::new(CurveType::Ristretto255, bytes.to_vec(), Vec::new()))?;
+        Ok(Self::point_from_ristretto(&point))
+    }
+    
+    /// Decompress a point, rejecting non-canonical encodings
+    /// 
+    /// Ristretto255 points keep their compressed encoding in `x`; `y` is unused.
+    pub fn ristretto_from_point(p: &Point) -> Result<RistrettoPoint> {
+        let bytes = fixed_width(&p.x, Self.scalar_byte_len(), CurveType::Ristretto255);
+        let compressed = CompressedRistretto::from_slice(&bytes)
+            .map_err(|_| Error::CurveError("Invalid point coordinate length".to_string()))?;
+        
+        compressed.decompress()
+            .ok_or_else(|| Error::CurveError("Point is not a valid Ristretto encoding".to_string()))
+    }
+    
+    /// Store a point as its compressed encoding
+    fn point_from_ristretto(point: &RistrettoPoint) -> Point {
+        Point::new(CurveType::Ristretto255, point.compress().to_bytes().to_vec(), Vec::new())
+    }
+    
+    /// Add two points
+    pub fn add_points(a: &Point, b: &Point) -> Result<Point> {
+        let result = Self::ristretto_from_point(a)? + Self::ristretto_from_point(b)?;
+        Ok(Self::point_from_ristretto(&result))
+    }
+    
+    /// Multiply point by scalar
+    pub fn mul_point_scalar(p: &Point, s: &Scalar) -> Result<Point> {
+        let result = Self::ristretto_from_point(p)? * Self::scalar_to_dalek(s)?;
+        Ok(Self::point_from_ristretto(&result))
+    }
+    
+    /// Fixed-base table for repeated multiplication of `p`
+    pub(crate) fn precompute_point(p: &Point) -> Result<RistrettoBasepointTable> {
+        Ok(RistrettoBasepointTable::create(&Self::ristretto_from_point(p)?))
+    }
+    
+    /// Σ s_i * P_i over precomputed tables
+    pub(crate) fn mul_precomputed(terms: &[(&RistrettoBasepointTable, &Scalar)]) -> Result<Point> {
+        let mut result = RistrettoPoint::identity();
+        for (table, s) in terms {
+            result += *table * &Self::scalar_to_dalek(s)?;
+        }
+        
+        Ok(Self::point_from_ristretto(&result))
+    }
+    
+    /// Identity point
+    pub fn identity_point() -> Result<Point> {
+        Ok(Self::point_from_ristretto(&RistrettoPoint::identity()))
+    }
+    
+    /// Whether a point is the identity
+    pub fn is_identity_point(p: &Point) -> Result<bool> {
+        let point = Self::ristretto_from_point(p)?;
+        
+        Ok(point == RistrettoPoint::identity())
+    }
+    
+    /// Negate point
+    pub fn neg_point(p: &Point) -> Result<Point> {
+        let result = -Self::ristretto_from_point(p)?;
+        Ok(Self::point_from_ristretto(&result))
+    }
+    
+    /// Multi-scalar multiplication Σ s_i * P_i
+    pub fn multi_scalar_mul(points: &[Point], scalars: &[Scalar]) -> Result<Point> {
+        let points = points.iter().map(Self::ristretto_from_point).collect::<Result<Vec<_>>>()?;
+        let scalars = scalars.iter().map(Self::scalar_to_dalek).collect::<Result<Vec<_>>>()?;
+        
+        Ok(Self::point_from_ristretto(&RistrettoPoint::multiscalar_mul(&scalars, &points)))
+    }
+    
+    /// Multi-scalar multiplication for public inputs, Pippenger for large batches
+    pub fn multi_scalar_mul_vartime(points: &[Point], scalars: &[Scalar]) -> Result<Point> {
+        let points = points.iter().map(Self::ristretto_from_point).collect::<Result<Vec<_>>>()?;
+        let scalars = scalars.iter().map(Self::scalar_to_dalek).collect::<Result<Vec<_>>>()?;
+        
+        Ok(Self::point_from_ristretto(&RistrettoPoint::vartime_multiscalar_mul(&scalars, &points)))
+    }
+    
+    /// Hash to the group with the RFC 9380 random-oracle encoding
+    /// 
+    /// 64 bytes from expand_message_xmd with SHA-512 go through the
+    /// one-way map of RFC 9496.
+    pub fn hash_to_point_with_dst(msg: &[u8], dst: &[u8]) -> Result<Point> {
+        let uniform: [u8; 64] = expand_message_xmd(msg, dst, 64)?
+            .try_into()
+            .map_err(|_| Error::CurveError("Invalid uniform bytes".to_string()))?;
+        
+        Ok(Self::point_from_ristretto(&RistrettoPoint::from_uniform_bytes(&uniform)))
+    }
+}
+
+impl Curve for Ristretto255 {
+    fn curve_type(&self) -> CurveType {
+        CurveType::Ristretto255
+    }
+    
+    fn random_scalar_with_rng(&self, rng: &mut dyn CryptoRngCore) -> Result<Scalar> {
+        let scalar = RistrettoScalar::random(rng);
+        Ok(Self::scalar_from_dalek(&scalar))
+    }
+    
+    fn scalar_from_bytes(&self, bytes: &[u8]) -> Result<Scalar> {
+        let padded = Self::pad_scalar_bytes(bytes)?;
+        let scalar = Option::<RistrettoScalar>::from(RistrettoScalar::from_canonical_bytes(padded))
+            .ok_or_else(|| Error::InvalidInput("Scalar is not below the Ristretto255 group order".to_string()))?;
+        Ok(Self::scalar_from_dalek(&scalar))
+    }
+    
+    fn scalar_from_bytes_mod_order(&self, bytes: &[u8]) -> Result<Scalar> {
+        let scalar = RistrettoScalar::from_bytes_mod_order(Self::pad_scalar_bytes(bytes)?);
+        Ok(Self::scalar_from_dalek(&scalar))
+    }
+    
+    fn scalar_byte_len(&self) -> usize {
+        32
+    }
+    
+    fn scalar_from_u64(&self, value: u64) -> Result<Scalar> {
+        Ok(Self::scalar_from_dalek(&RistrettoScalar::from(value)))
+    }
+    
+    fn generator(&self) -> Point {
+        Self::point_from_ristretto(&RISTRETTO_BASEPOINT_POINT)
+    }
+    
+    fn point_from_bytes(&self, bytes: &[u8]) -> Result<Point> {
+        Self::point_from_compressed(bytes)
+    }
+    
+    fn hash_to_point(&self, msg: &[u8]) -> Result<Point> {
+        Self::hash_to_point_with_dst(msg, HASH_TO_CURVE_DST)
+    }
+    
+    fn order(&self) -> Scalar {
+        // Ristretto255 order, that of the edwards25519 prime-order subgroup
+        let order_bytes = [
+            0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x14, 0xde, 0xf9, 0xde, 0xa2, 0xf7, 0x9c, 0xd6,
+            0x58, 0x12, 0x63, 0x1a, 0x5c, 0xf5, 0xd3, 0xed
+        ];
+        Scalar::new(CurveType::Ristretto255, order_bytes.to_vec())
+    }
+    
+    fn field_modulus(&self) -> Scalar {
+        // Field modulus of the underlying curve (2^255 - 19)
+        let modulus_bytes = [
+            0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xed
+        ];
+        Scalar::new(CurveType::Ristretto255, modulus_bytes.to_vec())
+    }
+}
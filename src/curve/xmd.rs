@@ -0,0 +1,39 @@
+//! expand_message_xmd of RFC 9380 with SHA-512
+//! 
+//! Shared by the hash-to-curve encodings of edwards25519 and ristretto255.
+
+use crate::{Error, Result};
+use sha2::{Digest, Sha512};
+
+/// Expand `msg` to `len` uniform bytes under the domain separation tag `dst`
+pub(crate) fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Result<Vec<u8>> {
+    let blocks = len.div_ceil(64);
+    if dst.len() > 255 || blocks > 255 || len > u16::MAX as usize {
+        return Err(Error::InvalidInput(format!(
+            "Cannot expand {} bytes under a {}-byte domain separation tag", len, dst.len()
+        )));
+    }
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+    
+    // Step 1: b_0 = H(Z_pad || msg || I2OSP(len, 2) || 0 || DST')
+    let b_0 = Sha512::new()
+        .chain_update([0u8; 128])
+        .chain_update(msg)
+        .chain_update((len as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(&dst_prime)
+        .finalize();
+    
+    // Step 2: b_i = H((b_0 xor b_{i-1}) || i || DST'), with b_1 = H(b_0 || 1 || DST')
+    let mut uniform = Vec::with_capacity(blocks * 64);
+    let mut b_i = Sha512::new().chain_update(b_0).chain_update([1u8]).chain_update(&dst_prime).finalize();
+    uniform.extend_from_slice(&b_i);
+    for i in 2..=blocks {
+        let mixed: Vec<u8> = b_0.iter().zip(&b_i).map(|(x, y)| x ^ y).collect();
+        b_i = Sha512::new().chain_update(mixed).chain_update([i as u8]).chain_update(&dst_prime).finalize();
+        uniform.extend_from_slice(&b_i);
+    }
+    
+    uniform.truncate(len);
+    Ok(uniform)
+}
@@ -0,0 +1,263 @@
+//! Dealer-free distributed key generation
+//! 
+//! Every participant deals its own Laurent series: it publishes the series'
+//! Feldman commitments G·a_j, G·b_k and sends each peer its sub-share, which
+//! the peer checks against them. A node's final
+//! share is the sum of the sub-shares it received, which is a share of the
+//! series whose coefficients are the sums of all contributions. The joint
+//! secret is the sum of the residues b_{-1}, so the group public key is the
+//! sum of the committed G·b_{-1}; no node ever learns the secret itself.
+//! 
+//! Before finishing, participants echo a digest of the commitment set they
+//! received to each other, so a contributor that sent different commitments
+//! to different peers fails the run instead of splitting the group key.
+
+use crate::{Error, Result, CurveType, SessionId, ShareId};
+use crate::curve::{Point, curve_backend};
+use crate::laurent::{CoefficientCommitments, Share, verify_share_against_commitments};
+use sha2::{Sha256, Digest};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{Mutex, Notify};
+
+/// Domain separator for commitment set digests
+const COMMITMENT_SET_DOMAIN: &[u8] = b"z-mpc-dkg-commitment-set";
+
+/// A node's result of a distributed key generation
+#[derive(Debug, Clone)]
+pub struct DkgOutput {
+    /// This node's share of the joint secret
+    pub share: Share,
+    /// G·secret for the joint secret
+    pub group_public_key: Point,
+    /// Commitments to the joint series, which every final share matches
+    pub coefficient_commitments: CoefficientCommitments,
+}
+
+/// Contributions a node received in one key generation
+#[derive(Debug, Clone, Default)]
+pub struct DkgRound {
+    /// Coefficient commitments by contributor
+    pub commitments: HashMap<ShareId, CoefficientCommitments>,
+    /// Sub-shares addressed to this node by contributor
+    pub shares: HashMap<ShareId, Share>,
+    /// (complainant, accused) pairs
+    pub complaints: Vec<(ShareId, ShareId)>,
+    /// Commitment set digests by participant, this node's own included
+    pub echoes: HashMap<ShareId, Vec<u8>>,
+}
+
+impl DkgRound {
+    /// Number of `contributors` that sent both commitments and a sub-share
+    pub fn received(&self, contributors: &[ShareId]) -> usize {
+        contributors.iter()
+            .filter(|id| self.commitments.contains_key(id) && self.shares.contains_key(id))
+            .count()
+    }
+    
+    /// Whether every one of `contributors` sent commitments and a sub-share
+    pub fn is_complete(&self, contributors: &[ShareId]) -> bool {
+        self.received(contributors) == contributors.len()
+    }
+    
+    /// Whether every one of `contributors` echoed its commitment set
+    pub fn has_echoes(&self, contributors: &[ShareId]) -> bool {
+        contributors.iter().all(|id| self.echoes.contains_key(id))
+    }
+    
+    /// Digest of the commitments received from every contributor
+    /// 
+    /// Hashes each contributor's id and dealing id in id order, so nodes
+    /// that received the same commitments get the same digest.
+    pub fn commitment_digest(&self, curve_type: CurveType) -> Result<Vec<u8>> {
+        let mut contributors: Vec<&ShareId> = self.commitments.keys().collect();
+        contributors.sort();
+        
+        let mut hasher = Sha256::new();
+        hasher.update(COMMITMENT_SET_DOMAIN);
+        hasher.update(curve_type.to_string().as_bytes());
+        for id in contributors {
+            hasher.update(id.to_be_bytes());
+            hasher.update(self.commitments[id].dealing_id(curve_type)?);
+        }
+        Ok(hasher.finalize().to_vec())
+    }
+    
+    /// Participants whose echoed digest differs from `digest`, by id
+    pub fn mismatched_echoes(&self, digest: &[u8]) -> Vec<ShareId> {
+        let mut mismatched: Vec<ShareId> = self.echoes.iter()
+            .filter(|(_, echo)| echo.as_slice() != digest)
+            .map(|(id, _)| *id)
+            .collect();
+        mismatched.sort();
+        mismatched
+    }
+    
+    /// Contributors whose sub-share does not match their commitments, by id
+    pub fn invalid_contributors(&self, curve_type: CurveType) -> Vec<ShareId> {
+        let mut invalid: Vec<ShareId> = self.commitments.iter()
+            .filter(|(id, commitments)| {
+                self.shares.get(id).is_some_and(|share| {
+                    !matches!(verify_share_against_commitments(share, commitments, curve_type), Ok(true))
+                })
+            })
+            .map(|(id, _)| *id)
+            .collect();
+        invalid.sort();
+        invalid
+    }
+    
+    /// Sum the verified contributions into `node_id`'s final share
+    pub fn finish(&self, node_id: ShareId, curve_type: CurveType) -> Result<DkgOutput> {
+        let curve = curve_backend(curve_type)?;
+        let mut contributors: Vec<ShareId> = self.commitments.keys().copied().collect();
+        contributors.sort();
+        
+        // Step 1: Add up the sub-shares and the commitments they match
+        let mut value = curve.scalar_from_u64(0)?;
+        let mut joint: Option<CoefficientCommitments> = None;
+        for contributor in contributors {
+            let commitments = &self.commitments[&contributor];
+            let share = self.shares.get(&contributor)
+                .ok_or_else(|| Error::InvalidInput(format!("No sub-share from participant {}", contributor)))?;
+            if share.id != node_id {
+                return Err(Error::InvalidInput(format!(
+                    "Participant {} sent the sub-share of participant {}", contributor, share.id
+                )));
+            }
+            if !verify_share_against_commitments(share, commitments, curve_type)? {
+                return Err(Error::CommitmentError(format!(
+                    "Sub-share of participant {} does not match its commitments", contributor
+                )));
+            }
+            
+            value = value.add(&curve.scalar_from_bytes(&share.value)?)?;
+            joint = Some(match joint {
+                Some(joint) => joint.add(commitments)?,
+                None => commitments.clone(),
+            });
+        }
+        
+        // Step 2: The joint residue commitment is the group public key
+        let joint = joint.ok_or_else(|| Error::InvalidInput("No key generation contributions".to_string()))?;
+        let group_public_key = joint.b_commitments.first()
+            .cloned()
+            .ok_or_else(|| Error::InvalidInput("Joint commitments have no residue".to_string()))?;
+        let share = Share::new(node_id, value.as_bytes().to_vec()).with_dealing_id(joint.dealing_id(curve_type)?);
+        
+        Ok(DkgOutput {
+            share,
+            group_public_key,
+            coefficient_commitments: joint,
+        })
+    }
+}
+
+/// A node's key generation rounds by session id
+/// 
+/// Shared between the node and its HTTP handlers; waiters are woken on
+/// every recorded message.
+#[derive(Clone, Default)]
+pub struct DkgRounds {
+    rounds: Arc<Mutex<HashMap<SessionId, DkgRound>>>,
+    updated: Arc<Notify>,
+}
+
+impl DkgRounds {
+    /// Record a contributor's coefficient commitments
+    /// 
+    /// A contributor cannot replace commitments it already sent.
+    pub async fn record_commitments(&self, session_id: SessionId, sender_id: ShareId, commitments: CoefficientCommitments) -> Result<()> {
+        self.update(session_id, |round| {
+            match round.commitments.get(&sender_id) {
+                Some(existing) if *existing != commitments => Err(Error::InvalidInput(format!(
+                    "Participant {} already sent different commitments", sender_id
+                ))),
+                _ => {
+                    round.commitments.insert(sender_id, commitments);
+                    Ok(())
+                }
+            }
+        }).await
+    }
+    
+    /// Record the sub-share a contributor sent this node
+    /// 
+    /// A contributor cannot replace a sub-share it already sent.
+    pub async fn record_share(&self, session_id: SessionId, sender_id: ShareId, share: Share) -> Result<()> {
+        self.update(session_id, |round| {
+            match round.shares.get(&sender_id) {
+                Some(existing) if *existing != share => Err(Error::InvalidInput(format!(
+                    "Participant {} already sent a different sub-share", sender_id
+                ))),
+                _ => {
+                    round.shares.insert(sender_id, share);
+                    Ok(())
+                }
+            }
+        }).await
+    }
+    
+    /// Record the commitment set digest `sender_id` echoed
+    /// 
+    /// A participant cannot replace a digest it already echoed.
+    pub async fn record_echo(&self, session_id: SessionId, sender_id: ShareId, digest: Vec<u8>) -> Result<()> {
+        self.update(session_id, |round| {
+            match round.echoes.get(&sender_id) {
+                Some(existing) if *existing != digest => Err(Error::InvalidInput(format!(
+                    "Participant {} already echoed a different commitment set", sender_id
+                ))),
+                _ => {
+                    round.echoes.insert(sender_id, digest);
+                    Ok(())
+                }
+            }
+        }).await
+    }
+    
+    /// Record a complaint of `sender_id` against `accused`
+    pub async fn record_complaint(&self, session_id: SessionId, sender_id: ShareId, accused: ShareId) -> Result<()> {
+        self.update(session_id, |round| {
+            if !round.complaints.contains(&(sender_id, accused)) {
+                round.complaints.push((sender_id, accused));
+            }
+            Ok(())
+        }).await
+    }
+    
+    /// Snapshot of a session's round
+    pub async fn round(&self, session_id: SessionId) -> DkgRound {
+        self.rounds.lock().await.get(&session_id).cloned().unwrap_or_default()
+    }
+    
+    /// Wait until `ready` holds for a session's round or `deadline` passes
+    /// 
+    /// Returns the round as it stands at that point.
+    pub async fn wait_until(
+        &self,
+        session_id: SessionId,
+        deadline: tokio::time::Instant,
+        ready: impl Fn(&DkgRound) -> bool,
+    ) -> DkgRound {
+        loop {
+            // Register before checking, so an update in between is not missed
+            let notified = self.updated.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            
+            let round = self.round(session_id).await;
+            if ready(&round) {
+                return round;
+            }
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                return self.round(session_id).await;
+            }
+        }
+    }
+    
+    async fn update(&self, session_id: SessionId, f: impl FnOnce(&mut DkgRound) -> Result<()>) -> Result<()> {
+        let result = f(self.rounds.lock().await.entry(session_id).or_default());
+        self.updated.notify_waiters();
+        result
+    }
+}
@@ -1,3 +1,5 @@
+use crate::ShareId;
+use crate::types::ProtocolPhase;
 use thiserror::Error;
 
 /// Custom error types for z-MPC
@@ -21,12 +23,53 @@ pub enum Error {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
+    #[error("Unknown session: {0}")]
+    UnknownSession(String),
+
     #[error("Insufficient shares: required {required}, got {got}")]
     InsufficientShares { required: usize, got: usize },
 
     #[error("Invalid curve type: {0}")]
     InvalidCurve(String),
 
+    #[error("Signing policy denied request: {0}")]
+    PolicyDenied(String),
+
+    #[error("Authentication failed: {0}")]
+    AuthError(String),
+
+    #[error("Self-test failed: {0}")]
+    SelfTestFailed(String),
+
+    #[error("Public key mismatch: {0}")]
+    KeyMismatch(String),
+
+    #[error("Shares from different dealings: {0}")]
+    DealingMismatch(String),
+
+    #[error("Message channel full: {0}")]
+    ChannelFull(String),
+
+    #[error("Performance regression: {0}")]
+    PerformanceRegression(String),
+
+    #[error("Port conflict: {0}")]
+    PortConflict(String),
+
+    #[error("Replayed message: {0}")]
+    ReplayedMessage(String),
+
+    #[error("Protocol out of order: expected {expected}, got {got}")]
+    ProtocolState { expected: ProtocolPhase, got: ProtocolPhase },
+
+    #[error("Network error with participant {participant_id}: {message}")]
+    NetworkError {
+        participant_id: ShareId,
+        /// HTTP status of a rejected request; `None` if no response arrived
+        status: Option<u16>,
+        message: String,
+    },
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
@@ -0,0 +1,46 @@
+//! Canonicalizing hex parser for user input
+//! 
+//! Accepts hex the way other tools print it:
+//! - Optional `0x`/`0X` prefix
+//! - Upper, lower or mixed case digits
+//! - Surrounding and embedded whitespace, e.g. from wrapped lines
+//! 
+//! Errors name the offending input and say exactly what is wrong.
+
+use crate::{Error, Result};
+
+/// Decode hex user input, naming it `field` in errors
+pub fn parse_hex(input: &str, field: &str) -> Result<Vec<u8>> {
+    let trimmed = input.trim_start();
+    let offset = input.len() - trimmed.len();
+    let (digits_start, body) = match trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")) {
+        Some(body) => (offset + 2, body),
+        None => (offset, trimmed),
+    };
+    
+    let mut digits = Vec::with_capacity(body.len());
+    for (position, c) in body.char_indices() {
+        if c.is_whitespace() {
+            continue;
+        }
+        match c.to_digit(16) {
+            Some(digit) => digits.push(digit as u8),
+            None => {
+                return Err(Error::InvalidInput(format!(
+                    "Invalid hex {}: unexpected character {:?} at position {}", field, c, digits_start + position
+                )));
+            }
+        }
+    }
+    
+    if digits.is_empty() {
+        return Err(Error::InvalidInput(format!("Invalid hex {}: no hex digits", field)));
+    }
+    if digits.len() % 2 != 0 {
+        return Err(Error::InvalidInput(format!(
+            "Invalid hex {}: odd number of digits ({})", field, digits.len()
+        )));
+    }
+    
+    Ok(digits.chunks(2).map(|pair| pair[0] << 4 | pair[1]).collect())
+}
@@ -0,0 +1,168 @@
+//! Ceremony invitations
+//! 
+//! An organizer signs an `Invitation` carrying everything a participant
+//! needs to join a ceremony: sharing parameters, roster, session id and
+//! bootstrap endpoints. Participants verify it against the organizer key
+//! they pinned out of band, register with the organizer and pre-populate
+//! their coordinator session, so the organizer's share distribution is
+//! matched to the invitation's ceremony automatically.
+
+use crate::{Error, Result, SessionId, ShareId};
+use crate::curve::{Point, Scalar};
+use crate::network::{NetworkCoordinator, NetworkMessage, Participant};
+use crate::types::SharingParams;
+use crate::zkp::SchnorrSignature;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+
+/// Domain separator for organizer signatures
+const INVITATION_DOMAIN: &[u8] = b"z-mpc-invitation";
+
+/// Participant listed in an invitation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RosterEntry {
+    pub id: ShareId,
+    pub address: SocketAddr,
+    /// Compressed public key on the ceremony curve
+    pub public_key: Vec<u8>,
+}
+
+/// Signed invitation to a ceremony
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invitation {
+    pub params: SharingParams,
+    pub roster: Vec<RosterEntry>,
+    pub session_id: SessionId,
+    pub organizer_id: ShareId,
+    pub bootstrap_endpoints: Vec<SocketAddr>,
+    pub expires_at: u64,
+    pub organizer_signature: SchnorrSignature,
+}
+
+/// Fields covered by the organizer signature
+#[derive(Serialize)]
+struct InvitationPayload<'a> {
+    params: &'a SharingParams,
+    roster: &'a [RosterEntry],
+    session_id: SessionId,
+    organizer_id: ShareId,
+    bootstrap_endpoints: &'a [SocketAddr],
+    expires_at: u64,
+}
+
+impl Invitation {
+    /// Create and sign an invitation
+    pub fn create(
+        params: SharingParams,
+        roster: Vec<RosterEntry>,
+        session_id: SessionId,
+        organizer_id: ShareId,
+        bootstrap_endpoints: Vec<SocketAddr>,
+        expires_at: u64,
+        organizer_key: &Scalar,
+    ) -> Result<Self> {
+        if organizer_key.curve_type != params.curve_type {
+            return Err(Error::InvalidInput("Organizer key is not on the ceremony curve".to_string()));
+        }
+        
+        if roster.len() != params.participants {
+            return Err(Error::InvalidInput(format!(
+                "Roster lists {} participants, parameters require {}", roster.len(), params.participants
+            )));
+        }
+        
+        let mut invitation = Self {
+            params,
+            roster,
+            session_id,
+            organizer_id,
+            bootstrap_endpoints,
+            expires_at,
+            organizer_signature: SchnorrSignature::new(organizer_key.curve_type),
+        };
+        
+        let payload = invitation.signing_payload()?;
+        invitation.organizer_signature.sign(&payload, organizer_key)?;
+        
+        Ok(invitation)
+    }
+    
+    /// Verify the invitation against the pinned organizer key at time `now`
+    pub fn verify(&self, organizer_public_key: &Point, now: u64) -> Result<()> {
+        if now >= self.expires_at {
+            return Err(Error::InvalidInput(format!(
+                "Invitation for session {} expired at {}", self.session_id, self.expires_at
+            )));
+        }
+        
+        let payload = self.signing_payload()?;
+        if !self.organizer_signature.verify_for(&payload, organizer_public_key)? {
+            return Err(Error::AuthError(format!(
+                "Invalid organizer signature on invitation for session {}", self.session_id
+            )));
+        }
+        
+        Ok(())
+    }
+    
+    /// Roster entry of a participant
+    pub fn roster_entry(&self, id: ShareId) -> Option<&RosterEntry> {
+        self.roster.iter().find(|entry| entry.id == id)
+    }
+    
+    /// Verify the invitation and join its ceremony with `coordinator`
+    /// 
+    /// Registers the roster with the node, pins the organizer as the only
+    /// accepted dealer of the session and announces this node to the organizer.
+    pub async fn join(&self, coordinator: &mut NetworkCoordinator, organizer_public_key: &Point) -> Result<()> {
+        self.verify(organizer_public_key, coordinator.node.clock.now_unix())?;
+        
+        let node_id = coordinator.node.id;
+        let own_entry = self.roster_entry(node_id)
+            .ok_or_else(|| Error::InvalidInput(format!("Participant {} is not on the roster", node_id)))?
+            .clone();
+        
+        // Step 1: Register the other participants
+        for entry in self.roster.iter().filter(|entry| entry.id != node_id) {
+            coordinator.node.add_participant(Participant {
+                id: entry.id,
+                address: entry.address,
+                public_keys: HashMap::from([(self.params.curve_type, entry.public_key.clone())]),
+                is_online: true,
+                last_heartbeat: 0,
+            }).await?;
+        }
+        
+        // Step 2: Pre-populate the session and pin its dealer
+        coordinator.join_protocol(self.session_id, self.params.clone()).await?;
+        coordinator.node.pin_dealer(self.session_id, self.organizer_id).await?;
+        
+        // Step 3: Announce ourselves to the organizer
+        let registration = NetworkMessage::Join {
+            sender_id: node_id,
+            session_id: self.session_id,
+            address: own_entry.address,
+            public_key: own_entry.public_key,
+        };
+        coordinator.node.send_to(self.organizer_id, registration).await?;
+        
+        Ok(())
+    }
+    
+    /// Domain-separated bytes signed by the organizer
+    fn signing_payload(&self) -> Result<Vec<u8>> {
+        let payload = InvitationPayload {
+            params: &self.params,
+            roster: &self.roster,
+            session_id: self.session_id,
+            organizer_id: self.organizer_id,
+            bootstrap_endpoints: &self.bootstrap_endpoints,
+            expires_at: self.expires_at,
+        };
+        
+        let mut data = INVITATION_DOMAIN.to_vec();
+        data.extend_from_slice(&serde_json::to_vec(&payload)?);
+        Ok(data)
+    }
+}
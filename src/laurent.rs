@@ -4,41 +4,296 @@
 //! - f(z) = A(z) + B(z) where sk = b_{-1}
 //! - Share structure: f_i(z) = A(z) + B_i(z)
 //! - B(z) = Σb_{-k}, sk = Σb_{-1,i}
+//! - A(z) and B(z) share `threshold` coefficients between them
 //! - Linear combine & residue extraction
 
-use crate::{Error, Result, CurveType, ShareId, Threshold, ParticipantCount};
-use crate::curve::{Curve, Scalar, Point, create_curve};
-use crate::types::{LaurentCoefficients, SharingParams, ReconstructionResult};
+use crate::{Error, Result, CurveType, ShareId, Threshold, ParticipantCount, Commitment};
+use crate::curve::{Curve, Scalar, Point, curve_backend};
+use crate::types::{IdScheme, LaurentCoefficients, SharingParams, ReconstructionResult, ReconstructionProgress};
+use crate::zkp::SchnorrSignature;
 use serde::{Deserialize, Serialize};
-use rand::Rng;
+use sha2::{Sha256, Digest};
+use rand::{CryptoRng, Rng, RngCore};
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Domain separation tag for hashed evaluation points
+const EVAL_POINT_DOMAIN: &[u8] = b"z-mpc-eval-point";
+
+/// Domain separation tag for polynomial commitments
+const POLYNOMIAL_COMMITMENT_DOMAIN: &[u8] = b"z-mpc-polynomial-commitment";
+
+/// Convert a share id into its Laurent evaluation point
+/// 
+/// All share generation and verification goes through this function so that
+/// id 0 and degenerate (zero) evaluation points are rejected consistently.
+pub fn eval_point_for_id(id: ShareId, scheme: IdScheme, curve_type: CurveType) -> Result<Scalar> {
+    if id == 0 {
+        return Err(Error::InvalidInput("Share id 0 is not a valid evaluation point".to_string()));
+    }
+    
+    let curve = curve_backend(curve_type)?;
+    let z = match scheme {
+        IdScheme::Direct => curve.scalar_from_u64(id as u64)?,
+        IdScheme::Offset(offset) => {
+            let shifted = (id as u64).checked_add(offset)
+                .ok_or_else(|| Error::InvalidInput(format!("Share id {} overflows with offset {}", id, offset)))?;
+            curve.scalar_from_u64(shifted)?
+        }
+        IdScheme::Hashed => {
+            let mut hasher = Sha256::new();
+            hasher.update(EVAL_POINT_DOMAIN);
+            hasher.update(curve_type.to_string().as_bytes());
+            hasher.update(id.to_be_bytes());
+            curve.scalar_from_bytes_mod_order(&hasher.finalize())?
+        }
+    };
+    
+    if z.is_zero() {
+        return Err(Error::InvalidInput(format!("Share id {} maps to a zero evaluation point", id)));
+    }
+    
+    Ok(z)
+}
+
+/// Number of A(z) and B(z) coefficients for a threshold
+/// 
+/// f(z) has exactly `threshold` unknown coefficients, so any `threshold`
+/// shares determine it. B(z), which carries the residue b_{-1}, gets the
+/// extra coefficient when the threshold is odd.
+pub fn coefficient_counts(threshold: Threshold) -> (usize, usize) {
+    (threshold / 2, threshold - threshold / 2)
+}
+
+/// Weights w_i with b_{-1} = Σ w_i·f(z_i) over distinct evaluation points
+/// 
+/// With q B(z) coefficients, P(z) = z^q·f(z) is a polynomial of degree below
+/// the point count, and b_{-1} is its z^{q-1} coefficient. Interpolating P
+/// through (z_i, z_i^q·f(z_i)) gives
+/// w_i = z_i^q·[z^{q-1}]Π_{j≠i}(z - z_j) / Π_{j≠i}(z_i - z_j).
+fn residue_weights(points: &[Scalar], b_count: usize, curve: &dyn Curve) -> Result<Vec<Scalar>> {
+    let zero = curve.scalar_from_u64(0)?;
+    let one = curve.scalar_from_u64(1)?;
+    
+    points.iter().enumerate().map(|(i, z_i)| {
+        // Step 1: Expand Π_{j≠i}(z - z_j), lowest degree first
+        let mut numerator = vec![one.clone()];
+        let mut denominator = one.clone();
+        for z_j in points.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, z_j)| z_j) {
+            let mut next = vec![zero.clone(); numerator.len() + 1];
+            for (k, coeff) in numerator.iter().enumerate() {
+                next[k + 1] = next[k + 1].add(coeff)?;
+                next[k] = next[k].sub(&coeff.mul(z_j)?)?;
+            }
+            numerator = next;
+            denominator = denominator.mul(&z_i.sub(z_j)?)?;
+        }
+        
+        // Step 2: Scale the z^{q-1} coefficient by z_i^q / denominator
+        let z_i_q = positive_power_scalar(z_i, b_count as u64, curve)?;
+        let coeff = numerator.get(b_count - 1).cloned().unwrap_or_else(|| zero.clone());
+        z_i_q.mul(&coeff)?.mul(&denominator.invert()?)
+    }).collect()
+}
+
+/// Compute `base^exponent` by square-and-multiply
+fn positive_power_scalar(base: &Scalar, exponent: u64, curve: &dyn Curve) -> Result<Scalar> {
+    let mut result = curve.scalar_from_u64(1)?;
+    for bit in (0..u64::BITS - exponent.leading_zeros()).rev() {
+        result = result.mul(&result)?;
+        if exponent >> bit & 1 == 1 {
+            result = result.mul(base)?;
+        }
+    }
+    
+    Ok(result)
+}
+
+/// Lagrange coefficient λ_target for interpolating at zero
+/// 
+/// For interoperating with Shamir-based systems (e.g. FROST): share `i` is
+/// the polynomial evaluated at x = i, and Σ λ_i·f(i) over `participants`
+/// recovers f(0).
+pub fn lagrange_coefficient(participants: &[ShareId], target: ShareId, curve_type: CurveType) -> Result<Scalar> {
+    lagrange_coefficient_at(participants, target, 0, IdScheme::Direct, curve_type)
+}
+
+/// Lagrange coefficient λ_target(x) = Π_{j≠target} (x - x_j) / (x_target - x_j)
+/// 
+/// Share ids map to x_j through `eval_point_for_id`, so shares dealt under
+/// any `IdScheme` interpolate at their actual evaluation points.
+pub fn lagrange_coefficient_at(
+    participants: &[ShareId],
+    target: ShareId,
+    x: u64,
+    id_scheme: IdScheme,
+    curve_type: CurveType,
+) -> Result<Scalar> {
+    let mut seen = std::collections::HashSet::new();
+    for &id in participants {
+        if !seen.insert(id) {
+            return Err(Error::InvalidInput(format!("Duplicate participant {}", id)));
+        }
+    }
+    if !seen.contains(&target) {
+        return Err(Error::InvalidInput(format!("Participant {} is not in the set", target)));
+    }
+    
+    let curve = curve_backend(curve_type)?;
+    let x = curve.scalar_from_u64(x)?;
+    let x_target = eval_point_for_id(target, id_scheme, curve_type)?;
+    
+    let mut numerator = curve.scalar_from_u64(1)?;
+    let mut denominator = curve.scalar_from_u64(1)?;
+    for &id in participants.iter().filter(|&&id| id != target) {
+        let x_j = eval_point_for_id(id, id_scheme, curve_type)?;
+        numerator = numerator.mul(&x.sub(&x_j)?)?;
+        denominator = denominator.mul(&x_target.sub(&x_j)?)?;
+    }
+    
+    numerator.mul(&denominator.invert()?)
+}
+
+/// Weight w_target with b_{-1} = Σ w_i·s_i over the shares of `participants`
+/// 
+/// The Laurent counterpart of `lagrange_coefficient`: the weights are public,
+/// so a shareholder can scale its own share without revealing it. Any
+/// `threshold` or more distinct participants work.
+pub fn residue_coefficient(
+    participants: &[ShareId],
+    target: ShareId,
+    threshold: Threshold,
+    id_scheme: IdScheme,
+    curve_type: CurveType,
+) -> Result<Scalar> {
+    let mut seen = std::collections::HashSet::new();
+    for &id in participants {
+        if !seen.insert(id) {
+            return Err(Error::InvalidInput(format!("Duplicate participant {}", id)));
+        }
+    }
+    let position = participants.iter().position(|&id| id == target)
+        .ok_or_else(|| Error::InvalidInput(format!("Participant {} is not in the set", target)))?;
+    if threshold == 0 || participants.len() < threshold {
+        return Err(Error::InsufficientShares { required: threshold, got: participants.len() });
+    }
+    
+    let curve = curve_backend(curve_type)?;
+    let points = participants.iter()
+        .map(|&id| eval_point_for_id(id, id_scheme, curve_type))
+        .collect::<Result<Vec<_>>>()?;
+    let (_, b_count) = coefficient_counts(threshold);
+    Ok(residue_weights(&points, b_count, curve)?.swap_remove(position))
+}
 
 /// Laurent Series for secret sharing
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// 
+/// Holds the secret coefficients, so it deliberately does not implement
+/// `Serialize`. Publish `public_form()`; export the coefficients only through
+/// `expose_secret_serialize()`.
+#[derive(Debug, Clone)]
 pub struct LaurentSeries {
     pub curve_type: CurveType,
     pub a_coeffs: Vec<Scalar>,  // A(z) coefficients
     pub b_coeffs: Vec<Scalar>,  // B(z) coefficients
     pub threshold: Threshold,
     pub participants: ParticipantCount,
+    pub id_scheme: IdScheme,
+}
+
+/// Serializable public form of a Laurent series: parameters and coefficient
+/// commitments, never the coefficients
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublicLaurentSeries {
+    pub curve_type: CurveType,
+    pub threshold: Threshold,
+    pub participants: ParticipantCount,
+    #[serde(default)]
+    pub id_scheme: IdScheme,
+    /// G·a_k for the A(z) coefficients
+    pub a_commitments: Vec<Point>,
+    /// G·b_{-k} for the B(z) coefficients
+    pub b_commitments: Vec<Point>,
+}
+
+/// Feldman commitments to the coefficients of one dealing
+/// 
+/// Published by the dealer alongside the shares, so every receiver can check
+/// its own share with `verify_share_against_commitments` without learning
+/// the coefficients.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoefficientCommitments {
+    /// G·a_k for the A(z) coefficients
+    pub a_commitments: Vec<Point>,
+    /// G·b_{-k} for the B(z) coefficients
+    pub b_commitments: Vec<Point>,
+    #[serde(default)]
+    pub id_scheme: IdScheme,
+}
+
+impl CoefficientCommitments {
+    /// Commitments to the coefficient-wise sum of two series
+    /// 
+    /// Shares of the summed series are the sums of the two series' shares.
+    pub fn add(&self, other: &CoefficientCommitments) -> Result<Self> {
+        if self.a_commitments.len() != other.a_commitments.len()
+            || self.b_commitments.len() != other.b_commitments.len()
+            || self.id_scheme != other.id_scheme
+        {
+            return Err(Error::InvalidInput("Cannot add commitments of differently shaped series".to_string()));
+        }
+        
+        let sum = |lhs: &[Point], rhs: &[Point]| {
+            lhs.iter().zip(rhs).map(|(a, b)| a.add(b)).collect::<Result<Vec<_>>>()
+        };
+        Ok(Self {
+            a_commitments: sum(&self.a_commitments, &other.a_commitments)?,
+            b_commitments: sum(&self.b_commitments, &other.b_commitments)?,
+            id_scheme: self.id_scheme,
+        })
+    }
+    
+    /// Dealing id carried by shares of the committed series
+    pub fn dealing_id(&self, curve_type: CurveType) -> Result<Vec<u8>> {
+        hash_coefficient_commitments(curve_type, &self.a_commitments, &self.b_commitments)
+    }
+}
+
+/// Wire format of `expose_secret_serialize`, including the coefficients
+#[derive(Serialize, Deserialize)]
+struct ExposedLaurentSeries {
+    curve_type: CurveType,
+    a_coeffs: Vec<Scalar>,
+    b_coeffs: Vec<Scalar>,
+    threshold: Threshold,
+    participants: ParticipantCount,
+    #[serde(default)]
+    id_scheme: IdScheme,
 }
 
 impl LaurentSeries {
     /// Create new Laurent series for secret sharing
     pub fn new(params: &SharingParams) -> Result<Self> {
-        let curve = create_curve(params.curve_type);
+        Self::new_with_rng(params, &mut rand::thread_rng())
+    }
+    
+    /// Create new Laurent series with coefficients drawn from `rng`
+    pub fn new_with_rng<R: RngCore + CryptoRng>(params: &SharingParams, rng: &mut R) -> Result<Self> {
+        let curve = curve_backend(params.curve_type)?;
         
         // Generate random coefficients for A(z) and B(z)
+        let (a_count, b_count) = coefficient_counts(params.threshold);
         let mut a_coeffs = Vec::new();
         let mut b_coeffs = Vec::new();
         
-        // A(z) has coefficients from a_0 to a_{threshold-1}
-        for _ in 0..params.threshold {
-            a_coeffs.push(curve.random_scalar()?);
+        // A(z) has coefficients from a_0 to a_{a_count-1}
+        for _ in 0..a_count {
+            a_coeffs.push(curve.random_scalar_with_rng(rng)?);
         }
         
-        // B(z) has coefficients from b_{-threshold} to b_{-1}
-        for _ in 0..params.threshold {
-            b_coeffs.push(curve.random_scalar()?);
+        // B(z) has coefficients from b_{-1} to b_{-b_count}
+        for _ in 0..b_count {
+            b_coeffs.push(curve.random_scalar_with_rng(rng)?);
         }
         
         Ok(Self {
@@ -47,112 +302,447 @@ impl LaurentSeries {
             b_coeffs,
             threshold: params.threshold,
             participants: params.participants,
+            id_scheme: IdScheme::default(),
         })
     }
     
+    /// Create Laurent series from externally sampled coefficients
+    /// 
+    /// For polynomials generated elsewhere (e.g. in an HSM) where z-MPC only
+    /// evaluates, commits and proves. Requires the `coefficient_counts` of the
+    /// threshold for A(z) and B(z), all canonical scalars on the sharing
+    /// curve, and a non-zero residue coefficient b_{-1}.
+    pub fn from_coefficients(a_coeffs: Vec<Scalar>, b_coeffs: Vec<Scalar>, params: &SharingParams) -> Result<Self> {
+        utils::validate_params(params)?;
+        
+        // Step 1: Coefficient counts
+        let (a_count, b_count) = coefficient_counts(params.threshold);
+        if a_coeffs.len() != a_count || b_coeffs.len() != b_count {
+            return Err(Error::InvalidInput(format!(
+                "Expected {} A(z) and {} B(z) coefficients, got {} and {}",
+                a_count, b_count, a_coeffs.len(), b_coeffs.len()
+            )));
+        }
+        
+        // Step 2: Every coefficient is a canonical scalar on the sharing curve
+        let curve = curve_backend(params.curve_type)?;
+        for (name, coeff) in a_coeffs.iter().map(|c| ("A(z)", c)).chain(b_coeffs.iter().map(|c| ("B(z)", c))) {
+            if coeff.curve_type != params.curve_type {
+                return Err(Error::InvalidCurve(format!(
+                    "{} coefficient is on {}, expected {}", name, coeff.curve_type, params.curve_type
+                )));
+            }
+            if coeff.value.len() != curve.scalar_byte_len() || curve.scalar_from_bytes(&coeff.value)?.value != coeff.value {
+                return Err(Error::InvalidInput(format!("{} coefficient is not a canonical scalar", name)));
+            }
+        }
+        
+        // Step 3: The residue b_{-1} carries the secret
+        if b_coeffs[0].is_zero() {
+            return Err(Error::InvalidInput("Residue coefficient b_{-1} must be non-zero".to_string()));
+        }
+        
+        Ok(Self {
+            curve_type: params.curve_type,
+            a_coeffs,
+            b_coeffs,
+            threshold: params.threshold,
+            participants: params.participants,
+            id_scheme: IdScheme::default(),
+        })
+    }
+    
+    /// Create Laurent series that shares an existing secret
+    /// 
+    /// Sets the residue b_{-1} to `secret`, so `get_secret_key` returns it
+    /// exactly; every other coefficient is random. The secret must be a
+    /// non-zero canonical scalar on the sharing curve.
+    pub fn from_secret(secret: &Scalar, params: &SharingParams) -> Result<Self> {
+        let random = Self::new(params)?;
+        let mut b_coeffs = random.b_coeffs;
+        b_coeffs[0] = secret.clone();
+        
+        Self::from_coefficients(random.a_coeffs, b_coeffs, params)
+    }
+    
+    /// Create Laurent series from serialized coefficients
+    pub fn from_laurent_coefficients(coefficients: &LaurentCoefficients, params: &SharingParams) -> Result<Self> {
+        let to_scalars = |coeffs: &[Vec<u8>]| {
+            coeffs.iter()
+                .map(|bytes| Scalar::new(params.curve_type, bytes.clone()))
+                .collect::<Vec<_>>()
+        };
+        
+        Self::from_coefficients(to_scalars(&coefficients.a_coeffs), to_scalars(&coefficients.b_coeffs), params)
+    }
+    
+    /// Use a different id to evaluation point mapping
+    pub fn with_id_scheme(mut self, id_scheme: IdScheme) -> Self {
+        self.id_scheme = id_scheme;
+        self
+    }
+    
     /// Generate shares for participants
     pub fn generate_shares(&self) -> Result<Vec<Share>> {
-        let curve = create_curve(self.curve_type);
+        let curve = curve_backend(self.curve_type)?;
+        let dealing_id = self.dealing_id()?;
         let mut shares = Vec::new();
         
         for i in 1..=self.participants {
-            let share = self.generate_share_for_participant(i as ShareId, &curve)?;
+            let share = self.generate_share_for_participant(i as ShareId, curve)?
+                .with_dealing_id(dealing_id.clone());
             shares.push(share);
         }
         
         Ok(shares)
     }
     
+    /// Re-randomize existing shares without changing the secret
+    /// 
+    /// Adds the shares of a fresh series with residue b_{-1} = 0, so the
+    /// refreshed shares reconstruct the same secret but cannot be combined
+    /// with shares from before the refresh. Every old share must belong to
+    /// this dealing. Returns the refreshed shares with the zero series'
+    /// coefficient commitments: added to this dealing's commitments, they
+    /// verify the refreshed shares, whose dealing id is that of the sum.
+    /// Commitments and proofs of the old shares are dropped.
+    pub fn refresh_shares(&self, old_shares: &[Share]) -> Result<(Vec<Share>, CoefficientCommitments)> {
+        let commitments = self.coefficient_commitments()?;
+        let old_dealing_id = commitments.dealing_id(self.curve_type)?;
+        if let Some(other) = old_shares.iter().find(|share| share.dealing_id.as_ref() != Some(&old_dealing_id)) {
+            return Err(Error::DealingMismatch(format!("Share {} does not come from this dealing", other.id)));
+        }
+        
+        // Step 1: Zero-secret series with the same parameters
+        let curve = curve_backend(self.curve_type)?;
+        let params = SharingParams {
+            curve_type: self.curve_type,
+            threshold: self.threshold,
+            participants: self.participants,
+        };
+        let mut zero = Self::new(&params)?.with_id_scheme(self.id_scheme);
+        zero.b_coeffs[0] = curve.scalar_from_u64(0)?;
+        let zero_commitments = zero.coefficient_commitments()?;
+        let dealing_id = commitments.add(&zero_commitments)?.dealing_id(self.curve_type)?;
+        
+        // Step 2: Add its evaluation to every old share
+        let shares = old_shares.iter()
+            .map(|share| {
+                let delta = zero.generate_share_for_participant(share.id, curve)?;
+                let value = curve.scalar_from_bytes(&share.value)?
+                    .add(&curve.scalar_from_bytes(&delta.value)?)?;
+                Ok(Share::new(share.id, value.as_bytes().to_vec()).with_dealing_id(dealing_id.clone()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        
+        Ok((shares, zero_commitments))
+    }
+    
+    /// Identifier of this dealing: its polynomial commitment
+    /// 
+    /// Reveals nothing about the coefficients yet differs between independent
+    /// dealings.
+    pub fn dealing_id(&self) -> Result<Vec<u8>> {
+        self.polynomial_commitment()
+    }
+    
+    /// Feldman commitments G·a_k to the A(z) and G·b_{-k} to the B(z)
+    /// coefficients, for publishing with the shares
+    pub fn coefficient_commitments(&self) -> Result<CoefficientCommitments> {
+        let generator = curve_backend(self.curve_type)?.generator();
+        let commit = |coeffs: &[Scalar]| {
+            coeffs.iter().map(|coeff| generator.mul(coeff)).collect::<Result<Vec<_>>>()
+        };
+        
+        Ok(CoefficientCommitments {
+            a_commitments: commit(&self.a_coeffs)?,
+            b_commitments: commit(&self.b_coeffs)?,
+            id_scheme: self.id_scheme,
+        })
+    }
+    
+    /// Feldman commitments to the coefficients, for publishing with the shares
+    /// 
+    /// Same as `coefficient_commitments`.
+    pub fn commit_coefficients(&self) -> Result<CoefficientCommitments> {
+        self.coefficient_commitments()
+    }
+    
+    /// Feldman commitments to every coefficient, A(z) first then B(z)
+    /// 
+    /// The `coefficient_commitments` in one list:
+    /// G·a_0, …, G·a_{p-1}, G·b_{-1}, …, G·b_{-q}.
+    pub fn commitments(&self) -> Result<Vec<Point>> {
+        let CoefficientCommitments { mut a_commitments, b_commitments, .. } = self.coefficient_commitments()?;
+        a_commitments.extend(b_commitments);
+        Ok(a_commitments)
+    }
+    
+    /// Single commitment to the whole polynomial
+    /// 
+    /// Hash of the ordered coefficient commitments. A dealer publishes it once;
+    /// verifiers check the coefficient commitments against it and every share
+    /// against those with `verify_share_against_polynomial_commitment`.
+    pub fn polynomial_commitment(&self) -> Result<Commitment> {
+        self.coefficient_commitments()?.dealing_id(self.curve_type)
+    }
+    
+    /// Verify a share against published coefficient commitments
+    /// 
+    /// Checks that the coefficient commitments hash to `polynomial_commitment`
+    /// and that G·f(z_i) = Σ z_i^k·(G·a_k) + Σ z_i^{-k}·(G·b_{-k}).
+    pub fn verify_share_against_polynomial_commitment(
+        share: &Share,
+        a_commitments: &[Point],
+        b_commitments: &[Point],
+        polynomial_commitment: &[u8],
+        curve_type: CurveType,
+        id_scheme: IdScheme,
+    ) -> Result<bool> {
+        // Step 1: Coefficient commitments match the polynomial commitment
+        if hash_coefficient_commitments(curve_type, a_commitments, b_commitments)? != polynomial_commitment {
+            return Ok(false);
+        }
+        
+        Self::verify_share_evaluation(share, a_commitments, b_commitments, curve_type, id_scheme)
+    }
+    
+    /// Verify a share against the Feldman commitments of `commitments`
+    /// 
+    /// The list holds the A(z) commitments followed by the B(z) ones and is
+    /// split by `coefficient_counts` of its length, so it holds exactly
+    /// `threshold` points. Shares are mapped to evaluation points with the
+    /// default id scheme; see the free `verify_share_against_commitments`.
+    pub fn verify_share_against_commitments(share: &Share, commitments: &[Point]) -> Result<bool> {
+        let curve_type = commitments.first()
+            .ok_or_else(|| Error::InvalidInput("No coefficient commitments".to_string()))?
+            .curve_type;
+        let (a_count, _) = coefficient_counts(commitments.len());
+        let (a_commitments, b_commitments) = commitments.split_at(a_count);
+        let commitments = CoefficientCommitments {
+            a_commitments: a_commitments.to_vec(),
+            b_commitments: b_commitments.to_vec(),
+            id_scheme: IdScheme::default(),
+        };
+        
+        verify_share_against_commitments(share, &commitments, curve_type)
+    }
+    
+    /// Check G·f(z_i) against the evaluation of the coefficient commitments
+    fn verify_share_evaluation(
+        share: &Share,
+        a_commitments: &[Point],
+        b_commitments: &[Point],
+        curve_type: CurveType,
+        id_scheme: IdScheme,
+    ) -> Result<bool> {
+        // Step 1: Evaluate the committed polynomial at the share's point
+        let curve = curve_backend(curve_type)?;
+        let z = eval_point_for_id(share.id, id_scheme, curve_type)?;
+        let z_inv = z.invert()?;
+        
+        let mut powers = Vec::with_capacity(a_commitments.len() + b_commitments.len());
+        let mut power = curve.scalar_from_u64(1)?;
+        for _ in a_commitments {
+            let next = power.mul(&z)?;
+            powers.push(power);
+            power = next;
+        }
+        
+        let mut power = z_inv.clone();
+        for _ in b_commitments {
+            let next = power.mul(&z_inv)?;
+            powers.push(power);
+            power = next;
+        }
+        
+        // Step 2: Compare against the share
+        if powers.is_empty() {
+            return Ok(false);
+        }
+        let commitments: Vec<Point> = a_commitments.iter().chain(b_commitments).cloned().collect();
+        let expected = curve.msm_vartime(&commitments, &powers)?;
+        let actual = curve.generator().mul(&curve.scalar_from_bytes(&share.value)?)?;
+        
+        Ok(actual.to_compressed_bytes()? == expected.to_compressed_bytes()?)
+    }
+    
     /// Generate share for specific participant
-    fn generate_share_for_participant(&self, id: ShareId, curve: &Box<dyn Curve>) -> Result<Share> {
-        let z = curve.scalar_from_u64(id as u64)?;
+    /// 
+    /// Powers of z and z^{-1} are built incrementally, one multiplication per
+    /// coefficient and a single inversion per share.
+    fn generate_share_for_participant(&self, id: ShareId, curve: &dyn Curve) -> Result<Share> {
+        let z = eval_point_for_id(id, self.id_scheme, self.curve_type)?;
+        let z_inv = z.invert()?;
         let mut share_value = curve.scalar_from_u64(0)?; // Start with zero
         
         // Compute A(z) = Σ a_k * z^k
-        for (k, a_k) in self.a_coeffs.iter().enumerate() {
-            let z_k = self.power_scalar(&z, k as u64, curve)?;
-            let term = a_k.mul(&z_k)?;
-            share_value = share_value.add(&term)?;
+        let mut z_k = curve.scalar_from_u64(1)?;
+        for a_k in &self.a_coeffs {
+            share_value = share_value.add(&a_k.mul(&z_k)?)?;
+            z_k = z_k.mul(&z)?;
         }
         
         // Compute B_i(z) = Σ b_{-k} * z^{-k} for this participant
-        for (k, b_neg_k) in self.b_coeffs.iter().enumerate() {
-            let z_neg_k = self.power_scalar(&z, -(k as i64 + 1), curve)?;
-            let term = b_neg_k.mul(&z_neg_k)?;
-            share_value = share_value.add(&term)?;
+        let mut z_neg_k = z_inv.clone();
+        for b_neg_k in &self.b_coeffs {
+            share_value = share_value.add(&b_neg_k.mul(&z_neg_k)?)?;
+            z_neg_k = z_neg_k.mul(&z_inv)?;
         }
         
-        Ok(Share {
-            id,
-            value: share_value.as_bytes().to_vec(),
-            commitment: None,
-            proof: None,
-        })
+        Ok(Share::new(id, share_value.as_bytes().to_vec()))
     }
     
-    /// Compute scalar power (including negative powers)
-    fn power_scalar(&self, base: &Scalar, exponent: i64, curve: &Box<dyn Curve>) -> Result<Scalar> {
-        if exponent >= 0 {
-            self.positive_power_scalar(base, exponent as u64, curve)
+    /// Extract secret from shares using residue extraction
+    /// 
+    /// Shares must come from distinct participants, so a duplicated share
+    /// cannot stand in for a missing one; an n-of-n sharing needs every share.
+    /// Shares of different dealings are rejected. The result does not depend
+    /// on the order of `shares`; debug builds and the `paranoid-checks`
+    /// feature verify this on every call.
+    pub fn reconstruct_secret(&self, shares: &[Share]) -> Result<ReconstructionResult> {
+        if cfg!(any(debug_assertions, feature = "paranoid-checks")) {
+            self.reconstruct_secret_checked(shares)
         } else {
-            let positive_power = self.positive_power_scalar(base, (-exponent) as u64, curve)?;
-            positive_power.invert()
+            self.reconstruct_from(shares.iter().collect())
         }
     }
     
-    /// Compute positive power of scalar
-    fn positive_power_scalar(&self, base: &Scalar, exponent: u64, curve: &Box<dyn Curve>) -> Result<Scalar> {
-        if exponent == 0 {
-            return curve.scalar_from_u64(1);
-        }
+    /// Reconstruct from two random orderings of `shares` and compare
+    /// 
+    /// Fails with `Error::Internal` if the results differ.
+    pub fn reconstruct_secret_checked(&self, shares: &[Share]) -> Result<ReconstructionResult> {
+        use rand::seq::SliceRandom;
         
-        let mut result = base.clone();
-        for _ in 1..exponent {
-            result = result.mul(base)?;
-        }
+        let mut rng = rand::thread_rng();
+        let mut first: Vec<&Share> = shares.iter().collect();
+        let mut second = first.clone();
+        first.shuffle(&mut rng);
+        second.shuffle(&mut rng);
+        
+        let result = self.reconstruct_from(first)?;
+        let mut check = self.reconstruct_from(second)?;
+        let consistent = bool::from(result.secret.ct_eq(&check.secret)) && result.participants_used == check.participants_used;
+        check.secret.zeroize();
         
+        if !consistent {
+            return Err(Error::Internal("Reconstruction depends on share order".to_string()));
+        }
         Ok(result)
     }
     
-    /// Extract secret from shares using residue extraction
-    pub fn reconstruct_secret(&self, shares: &[Share]) -> Result<ReconstructionResult> {
-        if shares.len() < self.threshold {
+    /// Reconstruct from the shares of exactly the participants in `ids`
+    /// 
+    /// `shares` may hold more shares in any order; only those whose id is
+    /// listed take part. Fails with `Error::InvalidInput` on duplicate ids or
+    /// an id without a share, and with `Error::InsufficientShares` if fewer
+    /// than `threshold` ids are given.
+    pub fn reconstruct_with_ids(&self, shares: &[Share], ids: &[ShareId]) -> Result<ReconstructionResult> {
+        let mut quorum = std::collections::HashSet::new();
+        for &id in ids {
+            if !quorum.insert(id) {
+                return Err(Error::InvalidInput(format!("Duplicate participant {} in quorum", id)));
+            }
+        }
+        if quorum.len() < self.threshold {
             return Err(Error::InsufficientShares {
                 required: self.threshold,
-                got: shares.len(),
+                got: quorum.len(),
             });
         }
+        if let Some(missing) = ids.iter().find(|&&id| !shares.iter().any(|share| share.id == id)) {
+            return Err(Error::InvalidInput(format!("No share for participant {}", missing)));
+        }
         
-        let curve = create_curve(self.curve_type);
-        let mut secret = curve.scalar_from_u64(0)?;
-        let mut participants_used = Vec::new();
+        self.reconstruct_from(shares.iter().filter(|share| quorum.contains(&share.id)).collect())
+    }
+    
+    /// Reconstruct from the lowest `threshold` share ids
+    fn reconstruct_from(&self, shares: Vec<&Share>) -> Result<ReconstructionResult> {
+        let params = SharingParams {
+            curve_type: self.curve_type,
+            threshold: self.threshold,
+            participants: self.participants,
+        };
+        reconstruct_residue(&params, self.id_scheme, shares)
+    }
+    
+    /// Reconstruct the secret and immediately sign a message with it
+    /// 
+    /// The reconstructed secret is wiped before returning and never leaves
+    /// this function, so callers only ever see the signature.
+    pub fn reconstruct_and_sign(&self, shares: &[Share], message: &[u8]) -> Result<SchnorrSignature> {
+        let mut result = self.reconstruct_secret(shares)?;
+        let mut secret = Scalar::new(self.curve_type, std::mem::take(&mut result.secret));
         
-        // Linear combination of shares to extract b_{-1} (the secret)
-        for share in shares.iter().take(self.threshold) {
-            let share_scalar = curve.scalar_from_bytes(&share.value)?;
-            secret = secret.add(&share_scalar)?;
-            participants_used.push(share.id);
-        }
+        let mut signature = SchnorrSignature::new(self.curve_type);
+        let signed = signature.sign(message, &secret);
+        secret.value.zeroize();
+        signed?;
         
-        // The secret is the sum of b_{-1,i} values
-        Ok(ReconstructionResult {
-            secret: secret.as_bytes().to_vec(),
-            valid: true,
-            participants_used,
-        })
+        Ok(signature)
     }
     
-    /// Get the secret key (b_{-1})
-    pub fn get_secret_key(&self) -> Result<Scalar> {
-        let curve = create_curve(self.curve_type);
-        let mut secret = curve.scalar_from_u64(0)?;
+    /// Reconstruct the group public key G·secret without exposing the secret
+    /// 
+    /// Dry run for checking that a set of shares recovers the expected key
+    /// before performing a real, secret-exposing reconstruction.
+    pub fn reconstruct_public_key(&self, shares: &[Share]) -> Result<Point> {
+        let mut result = self.reconstruct_secret(shares)?;
+        let mut secret = Scalar::new(self.curve_type, std::mem::take(&mut result.secret));
         
-        // Sum all b_{-1} coefficients
-        for b_coeff in &self.b_coeffs {
-            secret = secret.add(b_coeff)?;
-        }
+        let curve = curve_backend(self.curve_type)?;
+        let public_key = curve.generator().mul(&secret);
+        secret.value.zeroize();
         
-        Ok(secret)
+        public_key
+    }
+    
+    /// Get the secret key (b_{-1})
+    pub fn get_secret_key(&self) -> Result<Scalar> {
+        self.b_coeffs.first()
+            .cloned()
+            .ok_or_else(|| Error::InvalidInput("Laurent series has no residue coefficient".to_string()))
+    }
+    
+    /// Public form, safe to serialize and publish
+    pub fn public_form(&self) -> Result<PublicLaurentSeries> {
+        let CoefficientCommitments { a_commitments, b_commitments, .. } = self.coefficient_commitments()?;
+        Ok(PublicLaurentSeries {
+            curve_type: self.curve_type,
+            threshold: self.threshold,
+            participants: self.participants,
+            id_scheme: self.id_scheme,
+            a_commitments,
+            b_commitments,
+        })
+    }
+    
+    /// Serialize the series including its secret coefficients as JSON
+    /// 
+    /// Anyone holding the output can recompute the secret and every share.
+    pub fn expose_secret_serialize(&self) -> Result<String> {
+        Ok(serde_json::to_string(&ExposedLaurentSeries {
+            curve_type: self.curve_type,
+            a_coeffs: self.a_coeffs.clone(),
+            b_coeffs: self.b_coeffs.clone(),
+            threshold: self.threshold,
+            participants: self.participants,
+            id_scheme: self.id_scheme,
+        })?)
+    }
+    
+    /// Restore a series written by `expose_secret_serialize`
+    pub fn expose_secret_deserialize(json: &str) -> Result<Self> {
+        let exposed: ExposedLaurentSeries = serde_json::from_str(json)?;
+        Ok(Self {
+            curve_type: exposed.curve_type,
+            a_coeffs: exposed.a_coeffs,
+            b_coeffs: exposed.b_coeffs,
+            threshold: exposed.threshold,
+            participants: exposed.participants,
+            id_scheme: exposed.id_scheme,
+        })
     }
     
     /// Get coefficients for verification
@@ -165,20 +755,259 @@ impl LaurentSeries {
     
     /// Verify share consistency
     pub fn verify_share(&self, share: &Share) -> Result<bool> {
-        let curve = create_curve(self.curve_type);
-        let expected_share = self.generate_share_for_participant(share.id, &curve)?;
+        let curve = curve_backend(self.curve_type)?;
+        let expected_share = self.generate_share_for_participant(share.id, curve)?;
         
-        Ok(share.value == expected_share.value)
+        Ok(bool::from(share.value.ct_eq(&expected_share.value)))
+    }
+}
+
+impl Zeroize for LaurentSeries {
+    fn zeroize(&mut self) {
+        self.a_coeffs.iter_mut().for_each(Zeroize::zeroize);
+        self.b_coeffs.iter_mut().for_each(Zeroize::zeroize);
+    }
+}
+
+// Every coefficient is a `Scalar`, which wipes itself on drop
+impl ZeroizeOnDrop for LaurentSeries {}
+
+/// Verify a share against a dealer's published coefficient commitments
+/// 
+/// Checks G·f(z_i) = Σ z_i^k·(G·a_k) + Σ z_i^{-k}·(G·b_{-k}) at the share's
+/// evaluation point. A share tagged with a dealing id must also belong to
+/// the committed dealing. Fails if the commitments do not have the shape of
+/// a sharing on `curve_type`.
+pub fn verify_share_against_commitments(
+    share: &Share,
+    commitments: &CoefficientCommitments,
+    curve_type: CurveType,
+) -> Result<bool> {
+    let (a_commitments, b_commitments) = (&commitments.a_commitments, &commitments.b_commitments);
+    
+    // Step 1: Shape and curve of the commitments
+    let threshold = a_commitments.len() + b_commitments.len();
+    if threshold == 0 || coefficient_counts(threshold) != (a_commitments.len(), b_commitments.len()) {
+        return Err(Error::InvalidInput(format!(
+            "{} A(z) and {} B(z) commitments do not form a sharing",
+            a_commitments.len(), b_commitments.len()
+        )));
+    }
+    if let Some(point) = a_commitments.iter().chain(b_commitments).find(|point| point.curve_type != curve_type) {
+        return Err(Error::InvalidCurve(format!(
+            "Coefficient commitment on {}, expected {}", point.curve_type, curve_type
+        )));
+    }
+    
+    // Step 2: The share belongs to the committed dealing
+    if let Some(dealing_id) = &share.dealing_id {
+        if hash_coefficient_commitments(curve_type, a_commitments, b_commitments)? != *dealing_id {
+            return Ok(false);
+        }
+    }
+    
+    LaurentSeries::verify_share_evaluation(share, a_commitments, b_commitments, curve_type, commitments.id_scheme)
+}
+
+/// Hash ordered coefficient commitments into a polynomial commitment
+fn hash_coefficient_commitments(curve_type: CurveType, a_commitments: &[Point], b_commitments: &[Point]) -> Result<Commitment> {
+    let mut hasher = Sha256::new();
+    hasher.update(POLYNOMIAL_COMMITMENT_DOMAIN);
+    hasher.update(curve_type.to_string().as_bytes());
+    hasher.update((a_commitments.len() as u32).to_be_bytes());
+    hasher.update((b_commitments.len() as u32).to_be_bytes());
+    for commitment in a_commitments.iter().chain(b_commitments) {
+        if commitment.curve_type != curve_type {
+            return Err(Error::InvalidCurve(format!(
+                "Coefficient commitment on {}, expected {}", commitment.curve_type, curve_type
+            )));
+        }
+        hasher.update(commitment.to_compressed_bytes()?);
+    }
+    
+    Ok(hasher.finalize().to_vec())
+}
+
+/// Residue b_{-1} from the lowest `params.threshold` share ids
+/// 
+/// Needs only the public parameters, so reconstruction never builds a
+/// series of its own.
+fn reconstruct_residue(params: &SharingParams, id_scheme: IdScheme, mut shares: Vec<&Share>) -> Result<ReconstructionResult> {
+    if let Some(first) = shares.first() {
+        if let Some(other) = shares.iter().find(|share| share.dealing_id != first.dealing_id) {
+            return Err(Error::DealingMismatch(format!(
+                "Share {} and share {} come from different dealings", first.id, other.id
+            )));
+        }
+    }
+    
+    let mut ids = std::collections::HashSet::new();
+    for share in &shares {
+        if !ids.insert(share.id) {
+            return Err(Error::InvalidInput(format!("Duplicate share {}", share.id)));
+        }
+    }
+    
+    if shares.len() < params.threshold {
+        return Err(Error::InsufficientShares {
+            required: params.threshold,
+            got: shares.len(),
+        });
+    }
+    
+    // Pick the subset by id, never by position
+    shares.sort_by_key(|share| share.id);
+    
+    let curve = curve_backend(params.curve_type)?;
+    let subset = &shares[..params.threshold];
+    let points = subset.iter()
+        .map(|share| eval_point_for_id(share.id, id_scheme, params.curve_type))
+        .collect::<Result<Vec<_>>>()?;
+    let (_, b_count) = coefficient_counts(params.threshold);
+    let weights = residue_weights(&points, b_count, curve)?;
+    
+    // Linear combination of shares to extract the residue b_{-1} (the secret);
+    // intermediate scalars wipe themselves on drop
+    let mut secret = curve.scalar_from_u64(0)?;
+    let mut participants_used = Vec::new();
+    for (share, weight) in subset.iter().zip(&weights) {
+        let share_scalar = curve.scalar_from_bytes(&share.value)?;
+        secret = secret.add(&weight.mul(&share_scalar)?)?;
+        participants_used.push(share.id);
+    }
+    
+    Ok(ReconstructionResult {
+        secret: secret.into_bytes(),
+        valid: true,
+        participants_used,
+        rejected_participants: Vec::new(),
+    })
+}
+
+/// Callback reporting reconstruction progress
+pub type ProgressCallback<'a> = Box<dyn FnMut(ReconstructionProgress) + Send + 'a>;
+
+/// Reconstruction from shares that arrive one at a time
+/// 
+/// Every share is verified on arrival (same dealing, unique id, valid
+/// scalar) and the optional progress callback fires once per share.
+pub struct Reconstructor<'a> {
+    params: SharingParams,
+    id_scheme: IdScheme,
+    commitments: Option<CoefficientCommitments>,
+    shares: Vec<Share>,
+    collected: usize,
+    on_progress: Option<ProgressCallback<'a>>,
+}
+
+impl<'a> Reconstructor<'a> {
+    /// Create reconstructor for a sharing
+    pub fn new(params: &SharingParams) -> Self {
+        Self {
+            params: params.clone(),
+            id_scheme: IdScheme::Direct,
+            commitments: None,
+            shares: Vec::new(),
+            collected: 0,
+            on_progress: None,
+        }
+    }
+    
+    /// Set the id scheme the shares were dealt with
+    pub fn with_id_scheme(mut self, id_scheme: IdScheme) -> Self {
+        self.id_scheme = id_scheme;
+        self
+    }
+    
+    /// Only accept shares of the dealing behind `commitments`
+    /// 
+    /// The id scheme is taken from the commitments.
+    pub fn with_commitments(mut self, commitments: CoefficientCommitments) -> Self {
+        self.id_scheme = commitments.id_scheme;
+        self.commitments = Some(commitments);
+        self
+    }
+    
+    /// Report progress after each collected and verified share
+    pub fn with_progress(mut self, on_progress: impl FnMut(ReconstructionProgress) + Send + 'a) -> Self {
+        self.on_progress = Some(Box::new(on_progress));
+        self
+    }
+    
+    /// Collect and verify a share
+    /// 
+    /// Rejected shares count as collected but not verified; the callback
+    /// fires either way before the error is returned.
+    pub fn add_share(&mut self, share: Share) -> Result<ReconstructionProgress> {
+        self.collected += 1;
+        let verified = self.verify(&share);
+        if verified.is_ok() {
+            self.shares.push(share);
+        }
+        
+        let progress = self.progress();
+        if let Some(on_progress) = self.on_progress.as_mut() {
+            on_progress(progress);
+        }
+        verified.map(|_| progress)
+    }
+    
+    /// Current progress
+    pub fn progress(&self) -> ReconstructionProgress {
+        ReconstructionProgress {
+            collected: self.collected,
+            verified: self.shares.len(),
+            threshold: self.params.threshold,
+        }
+    }
+    
+    /// Whether enough verified shares were collected
+    pub fn is_ready(&self) -> bool {
+        self.shares.len() >= self.params.threshold
+    }
+    
+    /// Reconstruct the secret from the verified shares
+    pub fn finish(self) -> Result<ReconstructionResult> {
+        reconstruct_residue(&self.params, self.id_scheme, self.shares.iter().collect())
+    }
+    
+    /// Check a share against the ones already verified
+    fn verify(&self, share: &Share) -> Result<()> {
+        if let Some(first) = self.shares.first() {
+            if share.dealing_id != first.dealing_id {
+                return Err(Error::DealingMismatch(format!(
+                    "Share {} and share {} come from different dealings", first.id, share.id
+                )));
+            }
+        }
+        if self.shares.iter().any(|other| other.id == share.id) {
+            return Err(Error::InvalidInput(format!("Duplicate share {}", share.id)));
+        }
+        
+        curve_backend(self.params.curve_type)?.scalar_from_bytes(&share.value)?;
+        if let Some(commitments) = &self.commitments {
+            if !verify_share_against_commitments(share, commitments, self.params.curve_type)? {
+                return Err(Error::CommitmentError(format!(
+                    "Share {} does not match the coefficient commitments", share.id
+                )));
+            }
+        }
+        Ok(())
     }
 }
 
 /// Share with additional metadata
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Share {
     pub id: ShareId,
     pub value: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub commitment: Option<Vec<u8>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub proof: Option<Vec<u8>>,
+    /// Identifier of the dealing this share belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dealing_id: Option<Vec<u8>>,
 }
 
 impl Share {
@@ -189,9 +1018,16 @@ impl Share {
             value,
             commitment: None,
             proof: None,
+            dealing_id: None,
         }
     }
     
+    /// Tag share with the dealing it belongs to
+    pub fn with_dealing_id(mut self, dealing_id: Vec<u8>) -> Self {
+        self.dealing_id = Some(dealing_id);
+        self
+    }
+    
     /// Add commitment to share
     pub fn with_commitment(mut self, commitment: Vec<u8>) -> Self {
         self.commitment = Some(commitment);
@@ -206,11 +1042,25 @@ impl Share {
     
     /// Get share value as scalar
     pub fn as_scalar(&self, curve_type: CurveType) -> Result<Scalar> {
-        let curve = create_curve(curve_type);
+        let curve = curve_backend(curve_type)?;
         curve.scalar_from_bytes(&self.value)
     }
 }
 
+impl Zeroize for Share {
+    fn zeroize(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl Drop for Share {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Share {}
+
 /// Utility functions for Laurent series operations
 pub mod utils {
     use super::*;
@@ -229,6 +1079,8 @@ pub mod utils {
     }
     
     /// Validate sharing parameters
+    /// 
+    /// `threshold == participants` (n-of-n) is allowed.
     pub fn validate_params(params: &SharingParams) -> Result<()> {
         if params.threshold < 2 {
             return Err(Error::InvalidInput("Threshold must be at least 2".to_string()));
@@ -240,4 +1092,23 @@ pub mod utils {
         
         Ok(())
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_positive_power_matches_naive_product() {
+        for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+            let curve = curve_backend(curve_type).unwrap();
+            let base = curve.random_scalar().unwrap();
+            
+            let mut naive = curve.scalar_from_u64(1).unwrap();
+            for exponent in 0..=32 {
+                assert_eq!(positive_power_scalar(&base, exponent, curve).unwrap(), naive, "{} exponent {}", curve_type, exponent);
+                naive = naive.mul(&base).unwrap();
+            }
+        }
+    }
+}
@@ -7,35 +7,61 @@
 //! - Aggregator-free secure reconstruction
 //! - Lagrange-free structure reducing computational complexity
 //! - Pedersen Commitment + ZK Proof based validity verification
-//! - Multi-curve support (secp256k1, P-256, Edwards25519)
+//! - Multi-curve support (secp256k1, P-256, Edwards25519, Ristretto255)
 //! - CLI and WebAssembly ready Rust engine
 //! - Distributed network communication
+//! - Dealer-free distributed key generation
 
 pub mod curve;
 pub mod laurent;
+pub mod dkg;
 pub mod pedersen;
 pub mod zkp;
 pub mod error;
 pub mod types;
 pub mod network;
+pub mod policy;
+pub mod qr;
+pub mod auth;
+pub mod selftest;
+pub mod clock;
+pub mod invite;
+pub mod transport;
+pub mod traffic;
+pub mod registry;
+pub mod vectors;
+pub mod bench;
+pub mod hexinput;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use error::{Error, Result};
 pub use types::*;
 
 // Re-export main components for easy access
 pub use curve::{Curve, Scalar, Point};
-pub use laurent::{LaurentSeries, Share};
-pub use pedersen::PedersenCommitment;
+pub use laurent::{CoefficientCommitments, LaurentSeries, Share};
+pub use dkg::DkgOutput;
+pub use pedersen::{PedersenCommitment, PedersenParameters};
 pub use zkp::ZeroKnowledgeProof;
-pub use network::{NetworkNode, NetworkCoordinator, NetworkMessage, Participant};
+pub use network::{BroadcastReport, NetworkNode, NetworkCoordinator, NetworkMessage, Participant, Session};
 
 /// Initialize the z-MPC library
+/// 
+/// Installs a tracing subscriber unless one is already set, so it is safe
+/// to call more than once.
 pub fn init() -> Result<()> {
-    tracing_subscriber::fmt::init();
+    let _ = tracing_subscriber::fmt().try_init();
     tracing::info!("z-MPC library initialized");
     Ok(())
 }
 
+/// Initialize the z-MPC library and verify the curve backends
+pub fn init_with_selftest() -> Result<()> {
+    init()?;
+    selftest::known_answer_tests()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
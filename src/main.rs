@@ -1,22 +1,22 @@
 //! z-MPC CLI Engine
 //! 
 //! Command-line interface for z-MPC operations:
-//! - share --curve [k1|r1|ed25519]
+//! - share --curve [k1|r1|ed25519|ristretto255]
 //! - commit --value <b_{-1,i}>
 //! - verify --commitment <C_i>
 //! - combine → Σb_{-1,i} calculation
+//! - prove-membership → one-of-many proof over a commitment bundle
 //! - network --start distributed protocol
 
 use clap::{Parser, Subcommand};
 use z_mpc::{
-    init, Error, Result, CurveType, SharingParams, LaurentSeries, 
+    init, Error, Result, CurveType, SessionId, SharingParams, LaurentSeries, 
     PedersenCommitment, ZeroKnowledgeProof, laurent::Share,
     pedersen::utils as pedersen_utils, zkp::utils as zkp_utils,
     laurent::utils as laurent_utils, network::utils as network_utils,
-    NetworkCoordinator
+    NetworkCoordinator, NetworkNode, network::NodeConfig, zkp::{MembershipProof, ProofFailure}, qr,
+    invite::{Invitation, RosterEntry}, transport::TransportConfig, vectors, bench, hexinput::parse_hex
 };
-use std::str::FromStr;
-use serde_json;
 
 #[derive(Parser)]
 #[command(name = "z-mpc")]
@@ -35,7 +35,7 @@ struct Cli {
 enum Commands {
     /// Generate secret shares using Laurent series
     Share {
-        /// Curve type (k1, r1, ed25519)
+        /// Curve type (k1, r1, ed25519, ristretto255)
         #[arg(short, long, value_enum)]
         curve: CurveTypeArg,
         
@@ -50,11 +50,23 @@ enum Commands {
         /// Output file for shares
         #[arg(short, long)]
         output: Option<String>,
+        
+        /// Directory for per-participant QR codes
+        #[arg(long)]
+        qr_dir: Option<String>,
+        
+        /// Directory for one share file per participant plus a public bundle
+        #[arg(long)]
+        split_output: Option<String>,
+        
+        /// Existing secret to split (hex string); random when omitted
+        #[arg(long)]
+        secret: Option<String>,
     },
     
     /// Create Pedersen commitment for a value
     Commit {
-        /// Value to commit (hex string)
+        /// Value to commit (hex string, optional 0x prefix)
         #[arg(short, long)]
         value: String,
         
@@ -99,6 +111,10 @@ enum Commands {
         /// Output file for reconstructed secret
         #[arg(short, long)]
         output: Option<String>,
+        
+        /// Sign this message with the reconstructed key instead of outputting the secret
+        #[arg(long)]
+        sign: Option<String>,
     },
     
     /// Generate zero-knowledge proof for a commitment
@@ -129,6 +145,37 @@ enum Commands {
         /// Curve type
         #[arg(short, long, value_enum)]
         curve: CurveTypeArg,
+        
+        /// Print a step-by-step verification trace
+        #[arg(long)]
+        explain: bool,
+    },
+    
+    /// Prove that you can open one commitment of a public bundle, without revealing which
+    ProveMembership {
+        /// Public bundle (JSON) with a `commitments` list
+        #[arg(long)]
+        commitments: String,
+        
+        /// Position of your commitment in the list
+        #[arg(short, long)]
+        index: usize,
+        
+        /// Committed value (hex string)
+        #[arg(short, long)]
+        value: String,
+        
+        /// Randomness used (hex string)
+        #[arg(short, long)]
+        randomness: String,
+        
+        /// Curve type
+        #[arg(short, long, value_enum)]
+        curve: CurveTypeArg,
+        
+        /// Output file for proof
+        #[arg(short, long)]
+        output: Option<String>,
     },
     
     /// Sign a message using Schnorr signature
@@ -161,6 +208,27 @@ enum Commands {
         message: String,
     },
     
+    /// Import a committed share from QR codes
+    ImportShare {
+        /// Scanned QR text payloads (base45 or base64), one per code
+        #[arg(long, num_args = 1..)]
+        qr_text: Vec<String>,
+        
+        /// QR code images (requires the qr-image feature)
+        #[arg(long, num_args = 1..)]
+        qr_image: Vec<String>,
+        
+        /// Output file for the committed share
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    
+    /// Create or join ceremony invitations
+    Invite {
+        #[command(subcommand)]
+        action: InviteAction,
+    },
+    
     /// Start distributed network protocol
     Network {
         /// Number of participants
@@ -171,13 +239,98 @@ enum Commands {
         #[arg(short, long, default_value = "3")]
         threshold: usize,
         
-        /// Curve type
-        #[arg(short, long, value_enum)]
-        curve: CurveTypeArg,
+        /// Curve types, one ceremony is run per curve
+        #[arg(short, long, value_enum, num_args = 1.., required = true)]
+        curve: Vec<CurveTypeArg>,
         
         /// Port base for network nodes
-        #[arg(short, long, default_value = "8000")]
+        #[arg(long, default_value = "8000")]
         port_base: u16,
+        
+        /// Proxy URL for peer traffic (defaults to HTTPS_PROXY)
+        #[arg(long)]
+        proxy: Option<String>,
+        
+        /// Peers reached without the proxy (*, ip or ip:port)
+        #[arg(long, num_args = 1..)]
+        no_proxy: Vec<String>,
+        
+        /// PEM file with additional trusted root certificates
+        #[arg(long, num_args = 1..)]
+        root_cert: Vec<std::path::PathBuf>,
+        
+        /// Maximum idle pooled connections per peer
+        #[arg(long, default_value = "8")]
+        pool_max_idle: usize,
+        
+        /// TCP keep-alive interval in seconds (0 disables)
+        #[arg(long, default_value = "60")]
+        keep_alive: u64,
+        
+        /// Time allowed for a request to a peer in seconds
+        #[arg(long, default_value = "30")]
+        request_timeout: u64,
+        
+        /// Keep the HTTP servers running until Ctrl-C
+        #[arg(long)]
+        serve: bool,
+        
+        /// Reconstruct each secret on a quorum of nodes over HTTP
+        #[arg(long)]
+        reconstruct: bool,
+        
+        /// Node config file (JSON), e.g. with the threshold-signing policy
+        #[arg(long)]
+        config: Option<String>,
+    },
+    
+    /// Run a complete local 3-of-5 ceremony with safe defaults
+    ///
+    /// Share files are encrypted under a key derived from the passphrase in
+    /// the Z_MPC_PASSPHRASE environment variable.
+    Quickstart {
+        /// Curve type
+        #[arg(short, long, value_enum, default_value = "k1")]
+        curve: CurveTypeArg,
+        
+        /// Directory for the ceremony artifacts
+        #[arg(short, long, default_value = "./demo")]
+        dir: String,
+    },
+    
+    /// Compare core-flow timings on all curves against a baseline
+    BenchCompare {
+        /// Baseline timings file (JSON)
+        #[arg(short, long)]
+        baseline: String,
+        
+        /// Allowed slowdown per phase in percent
+        #[arg(short, long, default_value_t = bench::DEFAULT_REGRESSION_THRESHOLD)]
+        threshold: f64,
+        
+        /// Runs per curve
+        #[arg(short, long, default_value = "10")]
+        iterations: usize,
+        
+        /// Write the current timings to this file
+        #[arg(short, long)]
+        save: Option<String>,
+    },
+    
+    /// Generate or check deterministic test vectors
+    #[command(hide = true)]
+    GenVectors {
+        /// Seed (hex string)
+        #[arg(long)]
+        seed: Option<String>,
+        
+        /// Directory holding one vector file per curve
+        #[arg(short, long, default_value = "tests/vectors")]
+        dir: String,
+        
+        /// Check the vectors in the directory instead of writing them
+        #[arg(long)]
+        check: bool,
     },
     
     /// Run integration tests
@@ -192,11 +345,68 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum InviteAction {
+    /// Create a signed ceremony invitation
+    Create {
+        /// Curve type
+        #[arg(short, long, value_enum)]
+        curve: CurveTypeArg,
+        
+        /// Threshold for secret sharing
+        #[arg(short, long)]
+        threshold: usize,
+        
+        /// Session id of the ceremony (32 hex digits, random by default)
+        #[arg(short, long)]
+        session: Option<SessionId>,
+        
+        /// Roster file (JSON list of {id, address, public_key})
+        #[arg(short, long)]
+        roster: String,
+        
+        /// Participant id of the organizer
+        #[arg(long)]
+        organizer_id: u32,
+        
+        /// Organizer private key (hex string)
+        #[arg(long)]
+        organizer_key: String,
+        
+        /// Bootstrap endpoints of the ceremony
+        #[arg(short, long, num_args = 1..)]
+        bootstrap: Vec<std::net::SocketAddr>,
+        
+        /// Validity of the invitation in seconds
+        #[arg(long, default_value = "86400")]
+        ttl: u64,
+        
+        /// Output file for the invitation
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    
+    /// Verify an invitation and join its ceremony
+    Join {
+        /// Invitation file (JSON)
+        invite: String,
+        
+        /// Keystore file (JSON with id and hex secret_key)
+        #[arg(short, long)]
+        keystore: String,
+        
+        /// Pinned organizer public key (compressed hex)
+        #[arg(long)]
+        organizer_key: String,
+    },
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 enum CurveTypeArg {
     K1,
     R1,
     Ed25519,
+    Ristretto255,
 }
 
 impl From<CurveTypeArg> for CurveType {
@@ -205,6 +415,7 @@ impl From<CurveTypeArg> for CurveType {
             CurveTypeArg::K1 => CurveType::Secp256k1,
             CurveTypeArg::R1 => CurveType::P256,
             CurveTypeArg::Ed25519 => CurveType::Edwards25519,
+            CurveTypeArg::Ristretto255 => CurveType::Ristretto255,
         }
     }
 }
@@ -221,8 +432,8 @@ async fn main() -> Result<()> {
     }
     
     match cli.command {
-        Commands::Share { curve, threshold, participants, output } => {
-            handle_share(curve.into(), threshold, participants, output)?;
+        Commands::Share { curve, threshold, participants, output, qr_dir, split_output, secret } => {
+            handle_share(curve.into(), threshold, participants, output, qr_dir, split_output, secret)?;
         }
         Commands::Commit { value, curve, output } => {
             handle_commit(value, curve.into(), output)?;
@@ -230,14 +441,17 @@ async fn main() -> Result<()> {
         Commands::Verify { commitment, value, randomness, curve } => {
             handle_verify(commitment, value, randomness, curve.into())?;
         }
-        Commands::Combine { input, curve, output } => {
-            handle_combine(input, curve.into(), output)?;
+        Commands::Combine { input, curve, output, sign } => {
+            handle_combine(input, curve.into(), output, sign)?;
         }
         Commands::Prove { value, randomness, curve, output } => {
             handle_prove(value, randomness, curve.into(), output)?;
         }
-        Commands::VerifyProof { proof, curve } => {
-            handle_verify_proof(proof, curve.into())?;
+        Commands::VerifyProof { proof, curve, explain } => {
+            handle_verify_proof(proof, curve.into(), explain)?;
+        }
+        Commands::ProveMembership { commitments, index, value, randomness, curve, output } => {
+            handle_prove_membership(commitments, index, value, randomness, curve.into(), output)?;
         }
         Commands::Sign { message, private_key, curve, output } => {
             handle_sign(message, private_key, curve.into(), output)?;
@@ -245,8 +459,38 @@ async fn main() -> Result<()> {
         Commands::VerifySignature { signature, message } => {
             handle_verify_signature(signature, message)?;
         }
-        Commands::Network { participants, threshold, curve, port_base } => {
-            handle_network(participants, threshold, curve.into(), port_base).await?;
+        Commands::ImportShare { qr_text, qr_image, output } => {
+            handle_import_share(qr_text, qr_image, output)?;
+        }
+        Commands::Invite { action } => {
+            handle_invite(action).await?;
+        }
+        Commands::Network {
+            participants, threshold, curve, port_base, proxy, no_proxy, root_cert, pool_max_idle, keep_alive,
+            request_timeout, serve, reconstruct, config,
+        } => {
+            let curve_types = curve.into_iter().map(CurveType::from).collect();
+            let env = TransportConfig::from_env();
+            let transport = TransportConfig {
+                proxy: proxy.or(env.proxy),
+                no_proxy: if no_proxy.is_empty() { env.no_proxy } else { no_proxy },
+                root_certificates: root_cert,
+                pool_max_idle_per_host: pool_max_idle,
+                keep_alive: (keep_alive > 0).then(|| std::time::Duration::from_secs(keep_alive)),
+                request_timeout: std::time::Duration::from_secs(request_timeout),
+            };
+            let mut config = config.map(|path| NodeConfig::from_file(&path)).transpose()?.unwrap_or_default();
+            config.transport = Some(transport);
+            handle_network(participants, threshold, curve_types, port_base, config, serve, reconstruct).await?;
+        }
+        Commands::Quickstart { curve, dir } => {
+            handle_quickstart(curve.into(), dir)?;
+        }
+        Commands::BenchCompare { baseline, threshold, iterations, save } => {
+            handle_bench_compare(baseline, threshold, iterations, save)?;
+        }
+        Commands::GenVectors { seed, dir, check } => {
+            handle_gen_vectors(seed, dir, check)?;
         }
         Commands::Test { test, verbose } => {
             handle_test(test, verbose).await?;
@@ -256,7 +500,15 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-fn handle_share(curve_type: CurveType, threshold: usize, participants: usize, output: Option<String>) -> Result<()> {
+fn handle_share(
+    curve_type: CurveType,
+    threshold: usize,
+    participants: usize,
+    output: Option<String>,
+    qr_dir: Option<String>,
+    split_output: Option<String>,
+    secret: Option<String>,
+) -> Result<()> {
     println!("Generating shares for curve: {}", curve_type);
     println!("Threshold: {}, Participants: {}", threshold, participants);
     
@@ -268,8 +520,15 @@ fn handle_share(curve_type: CurveType, threshold: usize, participants: usize, ou
     };
     laurent_utils::validate_params(&params)?;
     
-    // Create Laurent series
-    let laurent = LaurentSeries::new(&params)?;
+    // Create Laurent series, around the given secret if there is one
+    let laurent = match secret {
+        Some(secret) => {
+            let secret_bytes = parse_hex(&secret, "secret")?;
+            let secret = z_mpc::curve::curve_backend(curve_type)?.scalar_from_bytes(&secret_bytes)?;
+            LaurentSeries::from_secret(&secret, &params)?
+        }
+        None => LaurentSeries::new(&params)?,
+    };
     
     // Generate shares
     let shares = laurent.generate_shares()?;
@@ -279,10 +538,79 @@ fn handle_share(curve_type: CurveType, threshold: usize, participants: usize, ou
         println!("  Share {}: {}", share.id, hex::encode(&share.value));
     }
     
-    // Create commitments for all shares
-    let committed_shares = pedersen_utils::commit_all_shares(&shares, curve_type)?;
+    // Commit to every share and attach a proof of its opening
+    let curve = z_mpc::curve::curve_backend(curve_type)?;
+    let pedersen = PedersenCommitment::new(curve_type)?;
+    let mut rng = rand::thread_rng();
+    let mut committed_shares = Vec::new();
+    for share in &shares {
+        let randomness = pedersen.generate_randomness();
+        let context = format!("share/{}", share.id);
+        let (commitment, proof) = pedersen.commit_and_prove(
+            &curve.scalar_from_bytes(&share.value)?,
+            &randomness,
+            context.as_bytes(),
+            &mut rng,
+        )?;
+        let mut share = share.clone();
+        share.proof = Some(serde_json::to_vec(&proof)?);
+        committed_shares.push(z_mpc::pedersen::CommittedShare::new(share, commitment, randomness));
+    }
+    pedersen_utils::ensure_distinct_randomness(&committed_shares)?;
+    
+    println!("Created commitments and proofs for all shares");
+    
+    // Write one set of QR codes per participant
+    if let Some(dir) = &qr_dir {
+        std::fs::create_dir_all(dir)?;
+        for committed_share in &committed_shares {
+            let payloads = qr::to_qr_payloads(committed_share, curve_type)?;
+            let total = payloads.len();
+            for (index, payload) in payloads.iter().enumerate() {
+                let path = std::path::Path::new(dir).join(format!(
+                    "participant_{}_part_{}_of_{}.svg", committed_share.share.id, index + 1, total
+                ));
+                std::fs::write(&path, qr::render_svg(payload)?)?;
+            }
+        }
+        println!("QR codes saved to {}", dir);
+    }
     
-    println!("Created commitments for all shares");
+    // Write each participant only their own share, plus the public bundle
+    if let Some(dir) = &split_output {
+        std::fs::create_dir_all(dir)?;
+        for committed_share in &committed_shares {
+            let share_data = serde_json::json!({
+                "curve_type": curve_type.to_string(),
+                "threshold": threshold,
+                "participants": participants,
+                "committed_share": committed_share,
+            });
+            let path = std::path::Path::new(dir).join(format!("share_{}.json", committed_share.share.id));
+            std::fs::write(&path, serde_json::to_string_pretty(&share_data)?)?;
+        }
+        
+        let commitments: Vec<_> = committed_shares.iter()
+            .map(|committed_share| serde_json::json!({
+                "id": committed_share.share.id,
+                "commitment": hex::encode(&committed_share.commitment),
+            }))
+            .collect();
+        let group_public_key = laurent.reconstruct_public_key(&shares)?.to_compressed_bytes()?;
+        let public_data = serde_json::json!({
+            "curve_type": curve_type.to_string(),
+            "threshold": threshold,
+            "participants": participants,
+            "commitments": commitments,
+            "group_public_key": hex::encode(group_public_key),
+        });
+        std::fs::write(std::path::Path::new(dir).join("public.json"), serde_json::to_string_pretty(&public_data)?)?;
+        
+        println!("Per-participant shares and public bundle saved to {}", dir);
+        if output.is_none() {
+            return Ok(());
+        }
+    }
     
     // Output to file or stdout
     let output_data = serde_json::json!({
@@ -294,7 +622,7 @@ fn handle_share(curve_type: CurveType, threshold: usize, participants: usize, ou
     });
     
     if let Some(output_file) = output {
-        std::fs::write(output_file, serde_json::to_string_pretty(&output_data)?)?;
+        std::fs::write(&output_file, serde_json::to_string_pretty(&output_data)?)?;
         println!("Shares saved to {}", output_file);
     } else {
         println!("{}", serde_json::to_string_pretty(&output_data)?);
@@ -303,14 +631,58 @@ fn handle_share(curve_type: CurveType, threshold: usize, participants: usize, ou
     Ok(())
 }
 
+fn handle_import_share(qr_text: Vec<String>, qr_image: Vec<String>, output: Option<String>) -> Result<()> {
+    let payloads = qr_text;
+    
+    // Scan images into text payloads
+    #[cfg(feature = "qr-image")]
+    let payloads = {
+        let mut payloads = payloads;
+        for path in &qr_image {
+            payloads.extend(qr::scan_image(path)?);
+        }
+        payloads
+    };
+    
+    #[cfg(not(feature = "qr-image"))]
+    if !qr_image.is_empty() {
+        return Err(Error::InvalidInput("Built without QR image support; use --qr-text".to_string()));
+    }
+    
+    let (curve_type, committed_share) = qr::from_qr_payloads(&payloads)?;
+    
+    // Check the share against its commitment before accepting it
+    let is_valid = committed_share.verify(curve_type)?;
+    
+    println!("Imported share {} for curve {}", committed_share.share.id, curve_type);
+    println!("Commitment verification: {}", if is_valid { "✅ VALID" } else { "❌ INVALID" });
+    
+    if !is_valid {
+        return Err(Error::CommitmentError("Imported share does not match its commitment".to_string()));
+    }
+    
+    let output_data = serde_json::json!({
+        "curve_type": curve_type.to_string(),
+        "committed_share": committed_share,
+    });
+    
+    if let Some(output_file) = output {
+        std::fs::write(&output_file, serde_json::to_string_pretty(&output_data)?)?;
+        println!("Share saved to {}", output_file);
+    } else {
+        println!("{}", serde_json::to_string_pretty(&output_data)?);
+    }
+    
+    Ok(())
+}
+
 fn handle_commit(value: String, curve_type: CurveType, output: Option<String>) -> Result<()> {
     println!("Creating commitment for curve: {}", curve_type);
     
     // Parse value
-    let value_bytes = hex::decode(&value)
-        .map_err(|_| Error::InvalidInput("Invalid hex value".to_string()))?;
+    let value_bytes = parse_hex(&value, "value")?;
     
-    let curve = z_mpc::curve::create_curve(curve_type);
+    let curve = z_mpc::curve::curve_backend(curve_type)?;
     let value_scalar = curve.scalar_from_bytes(&value_bytes)?;
     
     // Create Pedersen commitment
@@ -330,7 +702,7 @@ fn handle_commit(value: String, curve_type: CurveType, output: Option<String>) -
     });
     
     if let Some(output_file) = output {
-        std::fs::write(output_file, serde_json::to_string_pretty(&output_data)?)?;
+        std::fs::write(&output_file, serde_json::to_string_pretty(&output_data)?)?;
         println!("Commitment saved to {}", output_file);
     } else {
         println!("{}", serde_json::to_string_pretty(&output_data)?);
@@ -343,19 +715,17 @@ fn handle_verify(commitment: String, value: String, randomness: String, curve_ty
     println!("Verifying commitment for curve: {}", curve_type);
     
     // Parse inputs
-    let commitment_bytes = hex::decode(&commitment)
-        .map_err(|_| Error::InvalidInput("Invalid hex commitment".to_string()))?;
-    let value_bytes = hex::decode(&value)
-        .map_err(|_| Error::InvalidInput("Invalid hex value".to_string()))?;
-    let randomness_bytes = hex::decode(&randomness)
-        .map_err(|_| Error::InvalidInput("Invalid hex randomness".to_string()))?;
-    
-    let curve = z_mpc::curve::create_curve(curve_type);
+    let commitment_bytes = parse_hex(&commitment, "commitment")?;
+    let value_bytes = parse_hex(&value, "value")?;
+    let randomness_bytes = parse_hex(&randomness, "randomness")?;
+    
+    let curve = z_mpc::curve::curve_backend(curve_type)?;
     let value_scalar = curve.scalar_from_bytes(&value_bytes)?;
     
-    // Verify commitment
+    // Verify the opening of the commitment
     let pedersen = PedersenCommitment::new(curve_type)?;
-    let is_valid = pedersen.verify(&commitment_bytes, &value_scalar, &randomness_bytes)?;
+    let opening = z_mpc::pedersen::CommitmentOpening::new(&value_scalar, randomness_bytes);
+    let is_valid = pedersen.open(&commitment_bytes, &opening, &value_scalar)?;
     
     if is_valid {
         println!("✅ Commitment verification successful");
@@ -367,7 +737,7 @@ fn handle_verify(commitment: String, value: String, randomness: String, curve_ty
     Ok(())
 }
 
-fn handle_combine(input: String, curve_type: CurveType, output: Option<String>) -> Result<()> {
+fn handle_combine(input: String, curve_type: CurveType, output: Option<String>, sign: Option<String>) -> Result<()> {
     println!("Combining shares for curve: {}", curve_type);
     
     // Read input file
@@ -396,6 +766,29 @@ fn handle_combine(input: String, curve_type: CurveType, output: Option<String>)
     
     let laurent = LaurentSeries::new(&params)?;
     
+    if let Some(message) = sign {
+        // Reconstruct and sign in one step so the secret is never printed or written
+        let signature = laurent.reconstruct_and_sign(&shares, message.as_bytes())?;
+        
+        println!("Message signed with reconstructed key");
+        println!("Public key: {}", hex::encode(&signature.public_key));
+        
+        let output_data = serde_json::json!({
+            "curve_type": curve_type.to_string(),
+            "message": message,
+            "signature": signature,
+        });
+        
+        if let Some(output_file) = output {
+            std::fs::write(&output_file, serde_json::to_string_pretty(&output_data)?)?;
+            println!("Signature saved to {}", output_file);
+        } else {
+            println!("{}", serde_json::to_string_pretty(&output_data)?);
+        }
+        
+        return Ok(());
+    }
+    
     // Reconstruct secret
     let result = laurent.reconstruct_secret(&shares)?;
     
@@ -411,7 +804,7 @@ fn handle_combine(input: String, curve_type: CurveType, output: Option<String>)
     });
     
     if let Some(output_file) = output {
-        std::fs::write(output_file, serde_json::to_string_pretty(&output_data)?)?;
+        std::fs::write(&output_file, serde_json::to_string_pretty(&output_data)?)?;
         println!("Reconstruction result saved to {}", output_file);
     } else {
         println!("{}", serde_json::to_string_pretty(&output_data)?);
@@ -424,12 +817,10 @@ fn handle_prove(value: String, randomness: String, curve_type: CurveType, output
     println!("Generating zero-knowledge proof for curve: {}", curve_type);
     
     // Parse inputs
-    let value_bytes = hex::decode(&value)
-        .map_err(|_| Error::InvalidInput("Invalid hex value".to_string()))?;
-    let randomness_bytes = hex::decode(&randomness)
-        .map_err(|_| Error::InvalidInput("Invalid hex randomness".to_string()))?;
+    let value_bytes = parse_hex(&value, "value")?;
+    let randomness_bytes = parse_hex(&randomness, "randomness")?;
     
-    let curve = z_mpc::curve::create_curve(curve_type);
+    let curve = z_mpc::curve::curve_backend(curve_type)?;
     let value_scalar = curve.scalar_from_bytes(&value_bytes)?;
     
     // Create share for proof
@@ -438,8 +829,9 @@ fn handle_prove(value: String, randomness: String, curve_type: CurveType, output
     // Create Pedersen commitment
     let pedersen = PedersenCommitment::new(curve_type)?;
     
-    // Generate proof
-    let proof = zkp_utils::prove_committed_share(&pedersen, &share, &randomness_bytes, curve_type)?;
+    // Generate proof over the commitment to the value
+    let commitment = pedersen.commit(&value_scalar, &randomness_bytes)?;
+    let proof = zkp_utils::prove_committed_share(&pedersen, &share, &commitment, &randomness_bytes, curve_type)?;
     
     println!("Proof generated successfully");
     
@@ -450,7 +842,7 @@ fn handle_prove(value: String, randomness: String, curve_type: CurveType, output
     });
     
     if let Some(output_file) = output {
-        std::fs::write(output_file, serde_json::to_string_pretty(&output_data)?)?;
+        std::fs::write(&output_file, serde_json::to_string_pretty(&output_data)?)?;
         println!("Proof saved to {}", output_file);
     } else {
         println!("{}", serde_json::to_string_pretty(&output_data)?);
@@ -459,7 +851,7 @@ fn handle_prove(value: String, randomness: String, curve_type: CurveType, output
     Ok(())
 }
 
-fn handle_verify_proof(proof: String, curve_type: CurveType) -> Result<()> {
+fn handle_verify_proof(proof: String, curve_type: CurveType, explain: bool) -> Result<()> {
     println!("Verifying zero-knowledge proof for curve: {}", curve_type);
     
     // Read proof file
@@ -469,6 +861,27 @@ fn handle_verify_proof(proof: String, curve_type: CurveType) -> Result<()> {
     // Create Pedersen commitment for verification
     let pedersen = PedersenCommitment::new(curve_type)?;
     
+    if explain {
+        let trace = proof.explain(&pedersen)?;
+        println!("{}", serde_json::to_string_pretty(&trace)?);
+        
+        match &trace.failure {
+            None => println!("✅ Verification equation holds"),
+            Some(ProofFailure::MalformedCommitment(e)) => println!("❌ Commitment is not a valid point: {}", e),
+            Some(ProofFailure::MalformedAnnouncement(e)) => println!("❌ Announcement is not a valid point: {}", e),
+            Some(ProofFailure::MalformedResponse(e)) => println!("❌ Response is malformed: {}", e),
+            Some(ProofFailure::DegenerateStatement(e)) => println!("❌ Degenerate statement: {}", e),
+            Some(ProofFailure::ChallengeMismatch) => println!(
+                "❌ Stored challenge {} does not match transcript challenge {}",
+                trace.stored_challenge, trace.recomputed_challenge
+            ),
+            Some(ProofFailure::EquationMismatch) => println!(
+                "❌ Verification equation diverges: lhs {} != rhs {}",
+                trace.lhs.as_deref().unwrap_or("-"), trace.rhs.as_deref().unwrap_or("-")
+            ),
+        }
+    }
+    
     // Verify proof
     let is_valid = zkp_utils::verify_committed_share_proof(&proof, &pedersen)?;
     
@@ -482,14 +895,62 @@ fn handle_verify_proof(proof: String, curve_type: CurveType) -> Result<()> {
     Ok(())
 }
 
+fn handle_prove_membership(
+    commitments: String,
+    index: usize,
+    value: String,
+    randomness: String,
+    curve_type: CurveType,
+    output: Option<String>,
+) -> Result<()> {
+    println!("Generating membership proof for curve: {}", curve_type);
+    
+    // Read the commitment set; entries are hex strings or objects with a `commitment`
+    let bundle: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&commitments)?)?;
+    let entries = bundle["commitments"].as_array()
+        .ok_or_else(|| Error::InvalidInput("Bundle has no commitments list".to_string()))?;
+    let commitments = entries.iter()
+        .map(|entry| entry.as_str().or_else(|| entry["commitment"].as_str())
+            .ok_or_else(|| Error::InvalidInput("Commitment entry is not hex".to_string()))
+            .and_then(|commitment| parse_hex(commitment, "commitment")))
+        .collect::<Result<Vec<_>>>()?;
+    
+    // Parse the opening
+    let value_bytes = parse_hex(&value, "value")?;
+    let randomness_bytes = parse_hex(&randomness, "randomness")?;
+    let curve = z_mpc::curve::curve_backend(curve_type)?;
+    let value_scalar = curve.scalar_from_bytes(&value_bytes)?;
+    let opening = z_mpc::pedersen::CommitmentOpening::new(&value_scalar, randomness_bytes);
+    
+    let pedersen = PedersenCommitment::new(curve_type)?;
+    let proof = MembershipProof::prove(&pedersen, index, &value_scalar, &opening, &commitments)?;
+    
+    println!("Proof covers {} commitments", commitments.len());
+    
+    // Output to file or stdout
+    let output_data = serde_json::json!({
+        "curve_type": curve_type.to_string(),
+        "commitments": commitments.iter().map(hex::encode).collect::<Vec<_>>(),
+        "proof": proof,
+    });
+    
+    if let Some(output_file) = output {
+        std::fs::write(&output_file, serde_json::to_string_pretty(&output_data)?)?;
+        println!("Proof saved to {}", output_file);
+    } else {
+        println!("{}", serde_json::to_string_pretty(&output_data)?);
+    }
+    
+    Ok(())
+}
+
 fn handle_sign(message: String, private_key: String, curve_type: CurveType, output: Option<String>) -> Result<()> {
     println!("Signing message for curve: {}", curve_type);
     
     // Parse private key
-    let private_key_bytes = hex::decode(&private_key)
-        .map_err(|_| Error::InvalidInput("Invalid hex private key".to_string()))?;
+    let private_key_bytes = parse_hex(&private_key, "private key")?;
     
-    let curve = z_mpc::curve::create_curve(curve_type);
+    let curve = z_mpc::curve::curve_backend(curve_type)?;
     let private_key_scalar = curve.scalar_from_bytes(&private_key_bytes)?;
     
     // Sign message
@@ -505,7 +966,7 @@ fn handle_sign(message: String, private_key: String, curve_type: CurveType, outp
     });
     
     if let Some(output_file) = output {
-        std::fs::write(output_file, serde_json::to_string_pretty(&output_data)?)?;
+        std::fs::write(&output_file, serde_json::to_string_pretty(&output_data)?)?;
         println!("Signature saved to {}", output_file);
     } else {
         println!("{}", serde_json::to_string_pretty(&output_data)?);
@@ -535,30 +996,381 @@ fn handle_verify_signature(signature: String, message: String) -> Result<()> {
     Ok(())
 }
 
-async fn handle_network(participants: usize, threshold: usize, curve_type: CurveType, port_base: u16) -> Result<()> {
+async fn handle_invite(action: InviteAction) -> Result<()> {
+    match action {
+        InviteAction::Create {
+            curve, threshold, session, roster, organizer_id, organizer_key, bootstrap, ttl, output,
+        } => {
+            let curve_type: CurveType = curve.into();
+            let roster: Vec<RosterEntry> = serde_json::from_str(&std::fs::read_to_string(&roster)?)?;
+            
+            let params = SharingParams {
+                curve_type,
+                threshold,
+                participants: roster.len(),
+            };
+            
+            let organizer_key_bytes = parse_hex(&organizer_key, "organizer key")?;
+            let organizer_key = z_mpc::curve::curve_backend(curve_type)?.scalar_from_bytes(&organizer_key_bytes)?;
+            
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            
+            let invitation = Invitation::create(
+                params, roster, session.unwrap_or_else(SessionId::random), organizer_id, bootstrap, now + ttl, &organizer_key,
+            )?;
+            
+            println!("✅ Invitation created for session {}", invitation.session_id);
+            
+            let invitation_json = serde_json::to_string_pretty(&invitation)?;
+            if let Some(output_file) = output {
+                std::fs::write(&output_file, invitation_json)?;
+                println!("Invitation saved to {}", output_file);
+            } else {
+                println!("{}", invitation_json);
+            }
+        }
+        InviteAction::Join { invite, keystore, organizer_key } => {
+            let invitation: Invitation = serde_json::from_str(&std::fs::read_to_string(&invite)?)?;
+            let curve_type = invitation.params.curve_type;
+            let curve = z_mpc::curve::curve_backend(curve_type)?;
+            
+            let organizer_key_bytes = parse_hex(&organizer_key, "organizer key")?;
+            let organizer_public_key = curve.point_from_bytes(&organizer_key_bytes)?;
+            
+            // Load our identity and check it against the roster
+            let keystore: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&keystore)?)?;
+            let node_id = keystore["id"].as_u64()
+                .and_then(|id| u32::try_from(id).ok())
+                .ok_or_else(|| Error::InvalidInput("Keystore has no participant id".to_string()))?;
+            let secret_key_bytes = keystore["secret_key"].as_str()
+                .ok_or_else(|| Error::InvalidInput("Keystore has no hex secret_key".to_string()))
+                .and_then(|key| parse_hex(key, "keystore secret_key"))?;
+            let secret_key = curve.scalar_from_bytes(&secret_key_bytes)?;
+            
+            let entry = invitation.roster_entry(node_id)
+                .ok_or_else(|| Error::InvalidInput(format!("Participant {} is not on the roster", node_id)))?
+                .clone();
+            if curve.generator().mul(&secret_key)?.to_compressed_bytes()? != entry.public_key {
+                return Err(Error::KeyMismatch(format!(
+                    "Keystore key does not match roster entry of participant {}", node_id
+                )));
+            }
+            
+            // The roster key doubles as our identity towards the other participants
+            let node = NetworkNode::new(node_id, entry.address).with_identity_key(secret_key);
+            let mut coordinator = NetworkCoordinator::new(node);
+            invitation.join(&mut coordinator, &organizer_public_key).await?;
+            
+            println!("✅ Joined session {} as participant {}", invitation.session_id, node_id);
+            println!("Waiting for shares from organizer {} on {}", invitation.organizer_id, entry.address);
+            
+            coordinator.start_http_server().await?;
+        }
+    }
+    
+    Ok(())
+}
+
+async fn handle_network(
+    participants: usize,
+    threshold: usize,
+    curve_types: Vec<CurveType>,
+    port_base: u16,
+    config: NodeConfig,
+    serve: bool,
+    reconstruct: bool,
+) -> Result<()> {
+    let curve_names: Vec<String> = curve_types.iter().map(|c| c.to_string()).collect();
     println!("🌐 Starting distributed network protocol");
-    println!("Participants: {}, Threshold: {}, Curve: {}", participants, threshold, curve_type);
+    println!("Participants: {}, Threshold: {}, Curves: {}", participants, threshold, curve_names.join(", "));
     println!("Port base: {}", port_base);
     
+    let transport = config.transport.clone().unwrap_or_else(TransportConfig::from_env);
+    let root_certificates = transport.load_root_certificates()?;
+    if let Some(proxy) = &transport.proxy {
+        println!("Proxy: {} ({} no-proxy entries)", proxy, transport.no_proxy.len());
+    }
+    if !root_certificates.is_empty() {
+        println!("Trusting {} additional root certificate(s)", root_certificates.len());
+    }
+    if config.signing_policy.is_some() {
+        println!("Threshold signing policy loaded");
+    }
+    
     // Create test network
-    let mut coordinators = network_utils::create_test_network(participants, curve_type).await?;
+    let mut coordinators = network_utils::create_test_network_on(port_base, participants, &curve_types).await?;
+    for coordinator in coordinators.iter_mut() {
+        coordinator.node = coordinator.node.clone().with_config(&config)?;
+    }
     println!("✅ Network created with {} nodes", coordinators.len());
     
-    // Run distributed protocol
-    network_utils::run_distributed_protocol(&mut coordinators, threshold).await?;
-    println!("✅ Distributed protocol completed successfully");
+    // Servers must be up before shares are dealt over HTTP
+    let (shutdown, shutdown_signal) = tokio::sync::watch::channel(false);
+    let servers = if serve || reconstruct {
+        let handles = network_utils::serve_all_until(&coordinators, shutdown_signal).await?;
+        println!("✅ HTTP servers listening on ports {}-{}", port_base as usize + 1, port_base as usize + participants);
+        handles
+    } else {
+        Vec::new()
+    };
+    
+    // Run one session per curve
+    for curve_type in &curve_types {
+        let session_id = network_utils::run_distributed_protocol(&mut coordinators, *curve_type, threshold).await?;
+        println!(
+            "✅ Distributed protocol completed for {} session {} ({})",
+            curve_type, session_id, coordinators[0].phase(session_id).await
+        );
+        
+        if reconstruct {
+            let results = network_utils::reconstruct_over_network(&coordinators, session_id).await?;
+            let secret = &results[0].secret;
+            if results.iter().any(|result| result.secret != *secret) {
+                return Err(Error::Internal(format!("Quorum of session {} disagrees on the secret", session_id)));
+            }
+            println!("🔓 Session {} reconstructed by {} nodes: {}", session_id, results.len(), hex::encode(secret));
+            println!("   Phase: {}", coordinators[0].phase(session_id).await);
+        }
+    }
     
     // Show network status
     for (i, coordinator) in coordinators.iter().enumerate() {
-        println!("   Node {}: {} - Protocol initialized", 
+        println!("   Node {}: {} - {} session(s) initialized", 
                  i + 1, 
-                 coordinator.node.address);
+                 coordinator.node.address,
+                 coordinator.laurent_series.len());
+    }
+    
+    if serve {
+        println!("Serving until Ctrl-C");
+        tokio::signal::ctrl_c().await?;
+        println!("Shutting down");
+    }
+    let _ = shutdown.send(true);
+    for server in servers {
+        server.await.map_err(|e| Error::Internal(format!("HTTP server task failed: {}", e)))??;
     }
     
     println!("🎉 Network protocol execution completed");
     Ok(())
 }
 
+/// Message signed by the quickstart ceremony
+const QUICKSTART_MESSAGE: &str = "z-mpc quickstart";
+
+/// Environment variable holding the passphrase for quickstart share files
+const PASSPHRASE_VAR: &str = "Z_MPC_PASSPHRASE";
+
+fn handle_quickstart(curve_type: CurveType, dir: String) -> Result<()> {
+    use chacha20poly1305::{aead::{Aead, KeyInit}, ChaCha20Poly1305, Key, Nonce};
+    use rand::{seq::SliceRandom, RngCore};
+    
+    let passphrase = std::env::var(PASSPHRASE_VAR)
+        .ok()
+        .filter(|passphrase| !passphrase.is_empty())
+        .ok_or_else(|| Error::InvalidInput(format!("Set {} to the passphrase for the share files", PASSPHRASE_VAR)))?;
+    
+    let (threshold, participants) = (3, 5);
+    println!("🚀 z-MPC quickstart: {}-of-{} ceremony on {}", threshold, participants, curve_type);
+    
+    let dir = std::path::Path::new(&dir);
+    std::fs::create_dir_all(dir)?;
+    
+    // Step 1: Split a fresh key
+    let params = SharingParams {
+        curve_type,
+        threshold,
+        participants,
+    };
+    laurent_utils::validate_params(&params)?;
+    let laurent = LaurentSeries::new(&params)?;
+    let shares = laurent.generate_shares()?;
+    let group_public_key = laurent.reconstruct_public_key(&shares)?;
+    println!("✅ Split key into {} shares", shares.len());
+    
+    // Step 2: Commit to every share and prove knowledge of each opening
+    let curve = z_mpc::curve::curve_backend(curve_type)?;
+    let pedersen = PedersenCommitment::new(curve_type)?;
+    let mut committed_shares = Vec::new();
+    for share in &shares {
+        let randomness = pedersen.generate_randomness();
+        let value = curve.scalar_from_bytes(&share.value)?;
+        let commitment = pedersen.commit(&value, &randomness)?;
+        let proof = zkp_utils::prove_committed_share(&pedersen, share, &commitment, &randomness, curve_type)?;
+        
+        if !pedersen.verify(&commitment, &value, &randomness)? || !proof.verify(&pedersen)? {
+            return Err(Error::ZKProofError(format!("Share {} failed verification", share.id)));
+        }
+        
+        std::fs::write(dir.join(format!("proof_{}.json", share.id)), serde_json::to_string_pretty(&proof)?)?;
+        committed_shares.push(z_mpc::pedersen::CommittedShare::new(share.clone(), commitment, randomness));
+    }
+    pedersen_utils::ensure_distinct_randomness(&committed_shares)?;
+    println!("✅ Committed, proved and verified all shares");
+    
+    // Step 3: Encrypt each participant's share under a passphrase-derived key
+    let mut salt = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut salt);
+    let mut key = zeroize::Zeroizing::new([0u8; 32]);
+    argon2::Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), &salt, key.as_mut())
+        .map_err(|e| Error::Internal(format!("Key derivation failed: {}", e)))?;
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(key.as_ref()));
+    
+    for committed_share in &committed_shares {
+        let mut nonce = [0u8; 12];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        
+        let ciphertext = cipher.encrypt(Nonce::from_slice(&nonce), serde_json::to_vec(committed_share)?.as_slice())
+            .map_err(|_| Error::Internal("Share encryption failed".to_string()))?;
+        
+        let id = committed_share.share.id;
+        let encrypted = serde_json::json!({
+            "id": id,
+            "curve_type": curve_type.to_string(),
+            "cipher": "chacha20poly1305",
+            "kdf": "argon2id",
+            "salt": hex::encode(salt),
+            "nonce": hex::encode(nonce),
+            "ciphertext": hex::encode(ciphertext),
+        });
+        std::fs::write(dir.join(format!("share_{}.enc.json", id)), serde_json::to_string_pretty(&encrypted)?)?;
+    }
+    println!("✅ Wrote encrypted share files");
+    
+    // Step 4: Reconstruct from a random subset and sign with it
+    let mut subset: Vec<Share> = shares.choose_multiple(&mut rand::thread_rng(), threshold).cloned().collect();
+    subset.sort_by_key(|share| share.id);
+    let subset_ids: Vec<_> = subset.iter().map(|share| share.id).collect();
+    
+    if laurent.reconstruct_public_key(&subset)? != group_public_key {
+        return Err(Error::InvalidInput(format!("Shares {:?} reconstruct a different key", subset_ids)));
+    }
+    let signature = laurent.reconstruct_and_sign(&subset, QUICKSTART_MESSAGE.as_bytes())?;
+    if !signature.verify_for(QUICKSTART_MESSAGE.as_bytes(), &group_public_key)? {
+        return Err(Error::ZKProofError("Quickstart signature does not verify".to_string()));
+    }
+    let signature_data = serde_json::json!({
+        "curve_type": curve_type.to_string(),
+        "message": QUICKSTART_MESSAGE,
+        "signature": signature,
+    });
+    std::fs::write(dir.join("signature.json"), serde_json::to_string_pretty(&signature_data)?)?;
+    println!("✅ Shares {:?} reconstructed the group key and signed", subset_ids);
+    
+    // Step 5: Public bundle
+    let group_public_key = hex::encode(group_public_key.to_compressed_bytes()?);
+    let commitments: Vec<_> = committed_shares.iter()
+        .map(|committed_share| serde_json::json!({
+            "id": committed_share.share.id,
+            "commitment": hex::encode(&committed_share.commitment),
+        }))
+        .collect();
+    let public_data = serde_json::json!({
+        "curve_type": curve_type.to_string(),
+        "threshold": threshold,
+        "participants": participants,
+        "pedersen": pedersen,
+        "commitments": commitments,
+        "group_public_key": group_public_key,
+    });
+    std::fs::write(dir.join("public.json"), serde_json::to_string_pretty(&public_data)?)?;
+    
+    let curve_arg = match curve_type {
+        CurveType::Secp256k1 => "k1",
+        CurveType::P256 => "r1",
+        CurveType::Edwards25519 => "ed25519",
+        CurveType::Ristretto255 => "ristretto255",
+    };
+    println!();
+    println!("🎉 Ceremony complete");
+    println!("Group public key: {}", group_public_key);
+    println!("Artifacts:        {}", dir.display());
+    println!();
+    println!("Next steps:");
+    println!("  z-mpc verify-proof --proof {} --curve {}", dir.join("proof_1.json").display(), curve_arg);
+    println!("  z-mpc verify-signature --signature {} --message \"{}\"", dir.join("signature.json").display(), QUICKSTART_MESSAGE);
+    println!("  Hand share_<id>.enc.json and share_<id>.key to each participant over separate channels");
+    
+    Ok(())
+}
+
+fn handle_bench_compare(baseline: String, threshold: f64, iterations: usize, save: Option<String>) -> Result<()> {
+    let baseline = bench::BenchTimings::load(std::path::Path::new(&baseline))?;
+    
+    println!("⏱️  Timing core flow ({} runs per curve)", iterations);
+    let current = bench::BenchTimings::run(iterations)?;
+    
+    // Per-phase timings and speed relative to the fastest curve
+    for phase in bench::PHASES {
+        println!("{}:", phase);
+        let relative = current.relative(phase);
+        for (curve, phases) in &current.curves {
+            let current_us = phases.get(phase).copied().unwrap_or_default();
+            let baseline_us = baseline.curves.get(curve).and_then(|phases| phases.get(phase));
+            println!(
+                "  {:<10} {:>12.1} µs  {:>5.2}x  (baseline {})",
+                curve,
+                current_us,
+                relative.get(curve).copied().unwrap_or(1.0),
+                baseline_us.map_or("n/a".to_string(), |us| format!("{:.1} µs", us)),
+            );
+        }
+    }
+    
+    if let Some(path) = save {
+        current.save(std::path::Path::new(&path))?;
+        println!("Current timings saved to {}", path);
+    }
+    
+    let regressions = bench::compare(&baseline, &current, threshold);
+    if regressions.is_empty() {
+        println!("✅ No phase regressed beyond {}%", threshold);
+        return Ok(());
+    }
+    
+    for regression in &regressions {
+        println!(
+            "❌ {} {}: {:.1} µs → {:.1} µs (+{:.1}%)",
+            regression.curve, regression.phase, regression.baseline_us, regression.current_us, regression.change_percent
+        );
+    }
+    Err(Error::PerformanceRegression(format!(
+        "{} phase(s) regressed beyond {}%", regressions.len(), threshold
+    )))
+}
+
+fn handle_gen_vectors(seed: Option<String>, dir: String, check: bool) -> Result<()> {
+    let seed = match seed {
+        Some(seed) => parse_hex(&seed, "seed")?,
+        None => vectors::DEFAULT_SEED.to_vec(),
+    };
+    let dir = std::path::Path::new(&dir);
+    
+    for curve_type in z_mpc::curve::enabled_curves() {
+        let path = dir.join(format!("{}.json", curve_type));
+        
+        if check {
+            let vector: vectors::TestVector = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+            if vector.seed != hex::encode(&seed) {
+                return Err(Error::InvalidInput(format!("{} was generated from a different seed", path.display())));
+            }
+            vectors::check(&vector)?;
+            println!("✅ {} matches", path.display());
+        } else {
+            std::fs::create_dir_all(dir)?;
+            let vector = vectors::generate(curve_type, &seed, vectors::DEFAULT_THRESHOLD, vectors::DEFAULT_PARTICIPANTS)?;
+            std::fs::write(&path, serde_json::to_string_pretty(&vector)? + "\n")?;
+            println!("📝 Wrote {}", path.display());
+        }
+    }
+    
+    Ok(())
+}
+
 async fn handle_test(test: String, verbose: bool) -> Result<()> {
     println!("🧪 Running z-MPC tests");
     
@@ -601,11 +1413,29 @@ async fn handle_test(test: String, verbose: bool) -> Result<()> {
 }
 
 async fn run_all_tests(verbose: bool) -> Result<()> {
+    if verbose {
+        println!("Running complete flow test...");
+    }
     run_flow_test().await?;
+    if verbose {
+        println!("Running network protocol test...");
+    }
     run_network_test().await?;
+    if verbose {
+        println!("Running multi-curve test...");
+    }
     run_curves_test().await?;
+    if verbose {
+        println!("Running error handling test...");
+    }
     run_errors_test().await?;
+    if verbose {
+        println!("Running performance test...");
+    }
     run_performance_test().await?;
+    if verbose {
+        println!("Running security test...");
+    }
     run_security_test().await?;
     Ok(())
 }
@@ -646,14 +1476,17 @@ async fn run_flow_test() -> Result<()> {
     assert!(all_valid);
     println!("✅ All commitments verified");
     
-    // 5. Generate zero-knowledge proofs
+    // 5. Generate zero-knowledge proofs over commitments under the proof parameters
+    let curve = z_mpc::curve::curve_backend(curve_type)?;
     let pedersen = PedersenCommitment::new(curve_type)?;
     let mut proofs = Vec::new();
     
     for (share, committed_share) in shares.iter().zip(committed_shares.iter()) {
+        let commitment = pedersen.commit(&curve.scalar_from_bytes(&share.value)?, &committed_share.randomness)?;
         let proof = zkp_utils::prove_committed_share(
             &pedersen,
             share,
+            &commitment,
             &committed_share.randomness,
             curve_type,
         )?;
@@ -698,18 +1531,18 @@ async fn run_network_test() -> Result<()> {
     println!("🌐 Testing distributed network protocol with {} participants", num_participants);
     
     // 1. Create test network
-    let mut coordinators = network_utils::create_test_network(num_participants, curve_type).await?;
+    let mut coordinators = network_utils::create_test_network(num_participants, &[curve_type]).await?;
     assert_eq!(coordinators.len(), num_participants);
     println!("✅ Test network created with {} nodes", coordinators.len());
     
     // 2. Run distributed protocol
-    network_utils::run_distributed_protocol(&mut coordinators, threshold).await?;
+    let session_id = network_utils::run_distributed_protocol(&mut coordinators, curve_type, threshold).await?;
     println!("✅ Distributed protocol executed successfully");
     
     // 3. Verify all coordinators are initialized
     for (i, coordinator) in coordinators.iter().enumerate() {
-        assert!(coordinator.laurent_series.is_some());
-        assert!(coordinator.pedersen.is_some());
+        assert!(coordinator.laurent_series.contains_key(&session_id));
+        assert!(coordinator.pedersen.contains_key(&session_id));
         println!("   Node {}: Protocol initialized", i + 1);
     }
     
@@ -721,7 +1554,7 @@ async fn run_curves_test() -> Result<()> {
     // Initialize library
     init()?;
     
-    let curves = [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519];
+    let curves = [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519, CurveType::Ristretto255];
     let threshold = 3;
     let participants = 5;
     
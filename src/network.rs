@@ -5,48 +5,66 @@
 //! - Commitment exchange
 //! - Proof verification
 //! - Secret reconstruction
+//! - Threshold signing, checked against the node's signing policy
+//! 
+//! A node is not tied to a curve: every ceremony runs in its own named
+//! session, and the session fixes the curve its messages are handled on.
 
-use crate::{Error, Result, CurveType, ShareId};
-use crate::laurent::{Share, LaurentSeries};
-use crate::pedersen::{PedersenCommitment, CommittedShare};
-use crate::zkp::ZeroKnowledgeProof;
+use crate::{Error, Result, CurveType, SessionId, ShareId};
+use crate::laurent::{CoefficientCommitments, Share, LaurentSeries, Reconstructor};
+use crate::pedersen::{PedersenCommitment, CommittedShare, CommitmentOpening};
+use crate::types::{ProtocolPhase, ReconstructionProgress, SharingParams};
+use crate::registry::ParticipantRegistry;
+use crate::auth::{AuthLayer, PeerCertificate, ReplayGuard, SchnorrAuth, SignedEnvelope};
+use crate::zkp::{PartialSignature, SchnorrSignature, SigningCommitment, SigningNonces, ZeroKnowledgeProof};
+use crate::policy::{Approval, PolicyConfig, SigningContext, SigningPolicy};
+use crate::dkg::{DkgOutput, DkgRounds};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::net::SocketAddr;
-use tokio::sync::{mpsc, RwLock};
+use futures::stream::{FuturesUnordered, StreamExt};
+use tokio::sync::{mpsc, Mutex, RwLock};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use axum::{
     routing::{post, get},
-    http::StatusCode,
+    http::{Method, StatusCode},
+    response::{IntoResponse, Response},
+    middleware::{self, Next},
     Json, Router,
-    extract::State,
+    extract::{Request, State},
 };
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::Duration;
+use crate::clock::{Clock, SystemClock};
+use crate::transport::TransportConfig;
+use crate::traffic::{SessionTraffic, TrafficLog};
 
 /// Network message types for z-MPC
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NetworkMessage {
-    /// Share distribution message
-    ShareDistribution {
+    /// One participant's own share of a dealing, with its opening proof
+    ShareDelivery {
         sender_id: ShareId,
-        shares: Vec<Share>,
-        commitments: Vec<CommittedShare>,
-        proofs: Vec<ZeroKnowledgeProof>,
-        curve_type: CurveType,
+        session_id: SessionId,
+        committed_share: Box<CommittedShare>,
+        proof: Box<ZeroKnowledgeProof>,
+        /// Feldman commitments the share is checked against
+        coefficient_commitments: CoefficientCommitments,
     },
     
     /// Commitment verification request
     CommitmentVerification {
         sender_id: ShareId,
+        session_id: SessionId,
         commitment: Vec<u8>,
         value: Vec<u8>,
-        randomness: Vec<u8>,
-        curve_type: CurveType,
+        opening: CommitmentOpening,
     },
     
     /// Commitment verification response
     CommitmentVerificationResponse {
         sender_id: ShareId,
+        session_id: SessionId,
         commitment: Vec<u8>,
         is_valid: bool,
     },
@@ -54,12 +72,14 @@ pub enum NetworkMessage {
     /// Proof verification request
     ProofVerification {
         sender_id: ShareId,
+        session_id: SessionId,
         proof: ZeroKnowledgeProof,
     },
     
     /// Proof verification response
     ProofVerificationResponse {
         sender_id: ShareId,
+        session_id: SessionId,
         proof_commitment: Vec<u8>,
         is_valid: bool,
     },
@@ -67,18 +87,106 @@ pub enum NetworkMessage {
     /// Secret reconstruction request
     SecretReconstruction {
         sender_id: ShareId,
+        session_id: SessionId,
         shares: Vec<Share>,
-        curve_type: CurveType,
+    },
+    
+    /// A participant's own share, sent to the rest of a reconstruction quorum
+    ShareContribution {
+        sender_id: ShareId,
+        session_id: SessionId,
+        share: Share,
+    },
+    
+    /// Request for a participant's own share of a session
+    ShareRequest {
+        sender_id: ShareId,
+        session_id: SessionId,
+    },
+    
+    /// Share request response
+    ShareResponse {
+        sender_id: ShareId,
+        session_id: SessionId,
+        share: Share,
+    },
+    
+    /// A key generation contributor's coefficient commitments
+    DkgCommitment {
+        sender_id: ShareId,
+        session_id: SessionId,
+        coefficient_commitments: CoefficientCommitments,
+    },
+    
+    /// A key generation contributor's sub-share for the recipient
+    DkgShare {
+        sender_id: ShareId,
+        session_id: SessionId,
+        share: Share,
+    },
+    
+    /// Complaint that a contributor's sub-share does not match its commitments
+    DkgComplaint {
+        sender_id: ShareId,
+        session_id: SessionId,
+        accused: ShareId,
+        reason: String,
+    },
+    
+    /// Digest of the key generation commitments the sender received
+    DkgEcho {
+        sender_id: ShareId,
+        session_id: SessionId,
+        digest: Vec<u8>,
+    },
+    
+    /// Request to commit to nonces for signing `message` with the session key
+    SigningRequest {
+        sender_id: ShareId,
+        session_id: SessionId,
+        message: Vec<u8>,
+        approvals: Vec<Approval>,
+    },
+    
+    /// A signer's nonce commitment for a signing request
+    SigningCommitmentResponse {
+        sender_id: ShareId,
+        session_id: SessionId,
+        commitment: SigningCommitment,
+    },
+    
+    /// Request for a partial signature over every signer's nonce commitment
+    PartialSignatureRequest {
+        sender_id: ShareId,
+        session_id: SessionId,
+        message: Vec<u8>,
+        commitments: Vec<SigningCommitment>,
+    },
+    
+    /// A signer's partial signature
+    PartialSignatureResponse {
+        sender_id: ShareId,
+        session_id: SessionId,
+        partial: PartialSignature,
     },
     
     /// Secret reconstruction response
     SecretReconstructionResponse {
         sender_id: ShareId,
+        session_id: SessionId,
         secret: Vec<u8>,
         participants_used: Vec<ShareId>,
         is_valid: bool,
     },
     
+    /// Registration of an invited participant with the organizer
+    Join {
+        sender_id: ShareId,
+        session_id: SessionId,
+        address: SocketAddr,
+        public_key: Vec<u8>,
+    },
+    
     /// Heartbeat message
     Heartbeat {
         sender_id: ShareId,
@@ -92,97 +200,976 @@ pub enum NetworkMessage {
     },
 }
 
+impl NetworkMessage {
+    /// Get the participant that sent the message
+    pub fn sender_id(&self) -> ShareId {
+        match self {
+            NetworkMessage::ShareDelivery { sender_id, .. }
+            | NetworkMessage::CommitmentVerification { sender_id, .. }
+            | NetworkMessage::CommitmentVerificationResponse { sender_id, .. }
+            | NetworkMessage::ProofVerification { sender_id, .. }
+            | NetworkMessage::ProofVerificationResponse { sender_id, .. }
+            | NetworkMessage::SecretReconstruction { sender_id, .. }
+            | NetworkMessage::ShareContribution { sender_id, .. }
+            | NetworkMessage::ShareRequest { sender_id, .. }
+            | NetworkMessage::ShareResponse { sender_id, .. }
+            | NetworkMessage::DkgCommitment { sender_id, .. }
+            | NetworkMessage::DkgShare { sender_id, .. }
+            | NetworkMessage::DkgComplaint { sender_id, .. }
+            | NetworkMessage::DkgEcho { sender_id, .. }
+            | NetworkMessage::SigningRequest { sender_id, .. }
+            | NetworkMessage::SigningCommitmentResponse { sender_id, .. }
+            | NetworkMessage::PartialSignatureRequest { sender_id, .. }
+            | NetworkMessage::PartialSignatureResponse { sender_id, .. }
+            | NetworkMessage::SecretReconstructionResponse { sender_id, .. }
+            | NetworkMessage::Join { sender_id, .. }
+            | NetworkMessage::Heartbeat { sender_id, .. }
+            | NetworkMessage::Error { sender_id, .. } => *sender_id,
+        }
+    }
+    
+    /// Get the session the message belongs to, if any
+    /// 
+    /// Heartbeats and errors concern the node rather than a session.
+    pub fn session_id(&self) -> Option<SessionId> {
+        match self {
+            NetworkMessage::ShareDelivery { session_id, .. }
+            | NetworkMessage::CommitmentVerification { session_id, .. }
+            | NetworkMessage::CommitmentVerificationResponse { session_id, .. }
+            | NetworkMessage::ProofVerification { session_id, .. }
+            | NetworkMessage::ProofVerificationResponse { session_id, .. }
+            | NetworkMessage::SecretReconstruction { session_id, .. }
+            | NetworkMessage::ShareContribution { session_id, .. }
+            | NetworkMessage::ShareRequest { session_id, .. }
+            | NetworkMessage::ShareResponse { session_id, .. }
+            | NetworkMessage::DkgCommitment { session_id, .. }
+            | NetworkMessage::DkgShare { session_id, .. }
+            | NetworkMessage::DkgComplaint { session_id, .. }
+            | NetworkMessage::DkgEcho { session_id, .. }
+            | NetworkMessage::SigningRequest { session_id, .. }
+            | NetworkMessage::SigningCommitmentResponse { session_id, .. }
+            | NetworkMessage::PartialSignatureRequest { session_id, .. }
+            | NetworkMessage::PartialSignatureResponse { session_id, .. }
+            | NetworkMessage::SecretReconstructionResponse { session_id, .. }
+            | NetworkMessage::Join { session_id, .. } => Some(*session_id),
+            _ => None,
+        }
+    }
+    
+    /// Get the name of the message variant
+    pub fn kind(&self) -> &'static str {
+        match self {
+            NetworkMessage::ShareDelivery { .. } => "ShareDelivery",
+            NetworkMessage::CommitmentVerification { .. } => "CommitmentVerification",
+            NetworkMessage::CommitmentVerificationResponse { .. } => "CommitmentVerificationResponse",
+            NetworkMessage::ProofVerification { .. } => "ProofVerification",
+            NetworkMessage::ProofVerificationResponse { .. } => "ProofVerificationResponse",
+            NetworkMessage::SecretReconstruction { .. } => "SecretReconstruction",
+            NetworkMessage::ShareContribution { .. } => "ShareContribution",
+            NetworkMessage::ShareRequest { .. } => "ShareRequest",
+            NetworkMessage::ShareResponse { .. } => "ShareResponse",
+            NetworkMessage::DkgCommitment { .. } => "DkgCommitment",
+            NetworkMessage::DkgShare { .. } => "DkgShare",
+            NetworkMessage::DkgComplaint { .. } => "DkgComplaint",
+            NetworkMessage::DkgEcho { .. } => "DkgEcho",
+            NetworkMessage::SigningRequest { .. } => "SigningRequest",
+            NetworkMessage::SigningCommitmentResponse { .. } => "SigningCommitmentResponse",
+            NetworkMessage::PartialSignatureRequest { .. } => "PartialSignatureRequest",
+            NetworkMessage::PartialSignatureResponse { .. } => "PartialSignatureResponse",
+            NetworkMessage::SecretReconstructionResponse { .. } => "SecretReconstructionResponse",
+            NetworkMessage::Join { .. } => "Join",
+            NetworkMessage::Heartbeat { .. } => "Heartbeat",
+            NetworkMessage::Error { .. } => "Error",
+        }
+    }
+    
+    /// HTTP endpoint that accepts the message, if it is sent on its own
+    /// 
+    /// Responses travel back in the HTTP response body instead.
+    pub fn endpoint(&self) -> Option<&'static str> {
+        match self {
+            NetworkMessage::ShareDelivery { .. } => Some("/shares"),
+            NetworkMessage::CommitmentVerification { .. } => Some("/commitment"),
+            NetworkMessage::ProofVerification { .. } => Some("/proof"),
+            NetworkMessage::SecretReconstruction { .. } => Some("/reconstruct"),
+            NetworkMessage::ShareContribution { .. } => Some("/contribute"),
+            NetworkMessage::ShareRequest { .. } => Some("/share-request"),
+            NetworkMessage::DkgCommitment { .. }
+            | NetworkMessage::DkgShare { .. }
+            | NetworkMessage::DkgComplaint { .. }
+            | NetworkMessage::DkgEcho { .. } => Some("/dkg"),
+            NetworkMessage::SigningRequest { .. } | NetworkMessage::PartialSignatureRequest { .. } => Some("/sign"),
+            NetworkMessage::Heartbeat { .. } => Some("/heartbeat"),
+            _ => None,
+        }
+    }
+}
+
+/// Per-peer outcome of a broadcast
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BroadcastReport {
+    pub delivered: Vec<ShareId>,
+    pub failed: Vec<(ShareId, String)>,
+    /// Registered peers skipped because they are marked offline
+    pub offline: Vec<ShareId>,
+}
+
+impl BroadcastReport {
+    /// Check if every online participant received the message
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+    
+    /// Peers that did not receive the message, offline ones included
+    pub fn unreachable(&self) -> Vec<ShareId> {
+        let mut unreachable: Vec<ShareId> = self.failed.iter()
+            .map(|(id, _)| *id)
+            .chain(self.offline.iter().copied())
+            .collect();
+        unreachable.sort();
+        unreachable
+    }
+}
+
+/// Background tasks started by `NetworkNode::start_heartbeat`
+pub struct HeartbeatTasks {
+    pub sender: tokio::task::JoinHandle<()>,
+    pub monitor: tokio::task::JoinHandle<()>,
+}
+
+impl HeartbeatTasks {
+    /// Stop sending heartbeats and sweeping for silent peers
+    pub fn abort(&self) {
+        self.sender.abort();
+        self.monitor.abort();
+    }
+}
+
 /// Network participant information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Participant {
     pub id: ShareId,
     pub address: SocketAddr,
-    pub curve_type: CurveType,
+    pub public_keys: HashMap<CurveType, Vec<u8>>,
     pub is_online: bool,
+    /// Unix time this node last heard from the participant, on its own clock
     pub last_heartbeat: u64,
 }
 
+impl Participant {
+    /// Get the participant's compressed public key on a curve
+    pub fn public_key(&self, curve_type: CurveType) -> Option<&[u8]> {
+        self.public_keys.get(&curve_type).map(Vec::as_slice)
+    }
+}
+
+/// Ceremony a node takes part in
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub id: SessionId,
+    pub params: SharingParams,
+    pub shares: Vec<Share>,
+    /// Only participant allowed to distribute shares, if pinned
+    pub dealer: Option<ShareId>,
+    /// Coefficient commitments of the accepted dealing
+    pub coefficient_commitments: Option<CoefficientCommitments>,
+    pub phase: ProtocolPhase,
+}
+
+impl Session {
+    /// Create new session
+    pub fn new(id: SessionId, params: SharingParams) -> Self {
+        Self {
+            id,
+            params,
+            shares: Vec::new(),
+            dealer: None,
+            coefficient_commitments: None,
+            phase: ProtocolPhase::Initialized,
+        }
+    }
+    
+    /// Curve used by this session
+    pub fn curve_type(&self) -> CurveType {
+        self.params.curve_type
+    }
+    
+    /// Fail with `Error::ProtocolState` unless the session is in `allowed`
+    pub fn require_phase(&self, allowed: &[ProtocolPhase]) -> Result<()> {
+        if allowed.contains(&self.phase) {
+            Ok(())
+        } else {
+            Err(Error::ProtocolState { expected: allowed[0], got: self.phase })
+        }
+    }
+}
+
+/// Phases in which a session accepts a dealing
+/// 
+/// A verified dealing is final, so a session past `SharesDistributed` takes
+/// no new one.
+const DEALING_PHASES: &[ProtocolPhase] = &[ProtocolPhase::Initialized, ProtocolPhase::SharesDistributed];
+
+/// Phases in which a session's shares may be used to reconstruct
+/// 
+/// `Reconstructing` is left out so a session reconstructs once at a time.
+const RECONSTRUCTION_PHASES: &[ProtocolPhase] = &[ProtocolPhase::Verified, ProtocolPhase::Complete, ProtocolPhase::Failed];
+
+/// Phases in which a session holds a verified dealing
+/// 
+/// Shares are still served to peers while a reconstruction is running.
+const VERIFIED_PHASES: &[ProtocolPhase] = &[
+    ProtocolPhase::Verified,
+    ProtocolPhase::Reconstructing,
+    ProtocolPhase::Complete,
+    ProtocolPhase::Failed,
+];
+
+/// Sessions hosted by a node, keyed by session id
+pub type SessionMap = Arc<RwLock<HashMap<SessionId, Session>>>;
+
+/// Verified shares addressed to a node, keyed by session and dealer id
+pub type ReceivedShares = Arc<RwLock<HashMap<(SessionId, ShareId), CommittedShare>>>;
+
+/// Default capacity of a node's incoming message channel
+pub const DEFAULT_CHANNEL_CAPACITY: usize = 1000;
+
+/// What to do with a new message when the incoming channel is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum OverflowPolicy {
+    /// Wait until there is room
+    #[default]
+    Block,
+    /// Discard the oldest queued message to make room
+    DropOldest,
+    /// Refuse the new message with `Error::ChannelFull`
+    RejectNew,
+}
+
+/// Node settings read from a JSON config file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NodeConfig {
+    /// Outbound transport settings, if set; taken from the command line, not the file
+    #[serde(skip)]
+    pub transport: Option<TransportConfig>,
+    /// Policy every threshold-signing request must pass
+    #[serde(default)]
+    pub signing_policy: Option<PolicyConfig>,
+}
+
+impl NodeConfig {
+    /// Load node configuration from a JSON file
+    pub fn from_file(path: &str) -> Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+}
+
+/// Nonces of requests awaiting their partial signature, keyed by session and message
+type PendingNonces = Arc<Mutex<HashMap<(SessionId, Vec<u8>), SigningNonces>>>;
+
+/// Threshold-signing state of a node
+/// 
+/// Holds the policy signing requests are checked against and the nonces of
+/// requests that wait for their partial signature.
+#[derive(Clone, Default)]
+pub struct ThresholdSigner {
+    policy: Option<Arc<dyn SigningPolicy>>,
+    nonces: PendingNonces,
+}
+
+impl ThresholdSigner {
+    /// Round 1: check a request against the policy, then commit to fresh nonces
+    /// 
+    /// Nonces are only drawn for allowed requests. Fails with
+    /// `Error::PolicyDenied` for a denied request, or for any request when
+    /// no policy is configured.
+    pub async fn commit(
+        &self,
+        sessions: &SessionMap,
+        node_id: ShareId,
+        requester: ShareId,
+        session_id: SessionId,
+        message: &[u8],
+        context: &SigningContext,
+    ) -> Result<SigningCommitment> {
+        let share = own_share(sessions, node_id, session_id).await?;
+        let policy = self.policy.as_ref()
+            .ok_or_else(|| Error::PolicyDenied("No signing policy configured".to_string()))?;
+        crate::policy::enforce(policy.as_ref(), message, requester, context)?;
+        
+        let (nonces, commitment) = crate::zkp::utils::commit_signing_nonces(&share, context.curve_type, &mut rand::thread_rng())?;
+        self.nonces.lock().await.insert((session_id, message.to_vec()), nonces);
+        Ok(commitment)
+    }
+    
+    /// Round 2: sign `message` with the nonces committed to in round 1
+    /// 
+    /// The nonces are used up, so every signature needs a new round 1.
+    pub async fn sign(
+        &self,
+        sessions: &SessionMap,
+        node_id: ShareId,
+        session_id: SessionId,
+        message: &[u8],
+        commitments: &[SigningCommitment],
+    ) -> Result<PartialSignature> {
+        let share = own_share(sessions, node_id, session_id).await?;
+        let (params, coefficient_commitments) = verified_dealing(sessions, session_id).await?;
+        let nonces = self.nonces.lock().await.remove(&(session_id, message.to_vec()))
+            .ok_or_else(|| Error::InvalidInput(format!("No signing request for this message in session {}", session_id)))?;
+        
+        crate::zkp::utils::partial_sign(
+            &share,
+            message,
+            nonces,
+            commitments,
+            &group_public_key(&coefficient_commitments)?,
+            &params,
+            coefficient_commitments.id_scheme,
+        )
+    }
+}
+
 /// Network node for z-MPC
 #[derive(Clone)]
 pub struct NetworkNode {
     pub id: ShareId,
     pub address: SocketAddr,
-    pub curve_type: CurveType,
-    pub participants: Arc<RwLock<HashMap<ShareId, Participant>>>,
+    pub participants: Arc<RwLock<ParticipantRegistry>>,
+    pub sessions: SessionMap,
+    pub received_shares: ReceivedShares,
+    pub dkg: DkgRounds,
+    pub clock: Arc<dyn Clock>,
+    pub transport: TransportConfig,
+    pub traffic: Arc<RwLock<TrafficLog>>,
     pub message_sender: mpsc::Sender<NetworkMessage>,
-    pub message_receiver: mpsc::Receiver<NetworkMessage>,
+    pub message_receiver: Arc<Mutex<mpsc::Receiver<NetworkMessage>>>,
+    pub overflow_policy: OverflowPolicy,
+    /// Signs outgoing and authenticates incoming messages
+    pub auth: Option<Arc<dyn AuthLayer>>,
+    /// Exchange unsigned messages when no authentication layer is configured
+    pub insecure_no_auth: bool,
+    pub signer: ThresholdSigner,
+    replay_guard: Arc<Mutex<ReplayGuard>>,
+    dropped_messages: Arc<AtomicU64>,
 }
 
 impl NetworkNode {
     /// Create new network node
-    pub fn new(id: ShareId, address: SocketAddr, curve_type: CurveType) -> Self {
-        let (message_sender, message_receiver) = mpsc::channel(1000);
+    /// 
+    /// The node authenticates peers with a freshly generated Schnorr
+    /// identity; use `with_identity_key` to load a long-term key instead.
+    /// Peers learn the key from the node's `participant` entry.
+    pub fn new(id: ShareId, address: SocketAddr) -> Self {
+        let (message_sender, message_receiver) = mpsc::channel(DEFAULT_CHANNEL_CAPACITY);
+        
+        // Without an identity the node refuses unsigned traffic rather than accepting it
+        let auth = match SchnorrAuth::generate(id) {
+            Ok(auth) => Some(Arc::new(auth) as Arc<dyn AuthLayer>),
+            Err(e) => {
+                tracing::error!("Failed to generate an identity key for node {}: {}", id, e);
+                None
+            }
+        };
         
         Self {
             id,
             address,
-            curve_type,
-            participants: Arc::new(RwLock::new(HashMap::new())),
+            participants: Arc::new(RwLock::new(ParticipantRegistry::new())),
+            sessions: Arc::new(RwLock::new(HashMap::new())),
+            received_shares: Arc::new(RwLock::new(HashMap::new())),
+            dkg: DkgRounds::default(),
+            clock: Arc::new(SystemClock),
+            transport: TransportConfig::from_env(),
+            traffic: Arc::new(RwLock::new(TrafficLog::default())),
             message_sender,
-            message_receiver,
+            message_receiver: Arc::new(Mutex::new(message_receiver)),
+            overflow_policy: OverflowPolicy::default(),
+            auth,
+            insecure_no_auth: false,
+            signer: ThresholdSigner::default(),
+            replay_guard: Arc::new(Mutex::new(ReplayGuard::new())),
+            dropped_messages: Arc::new(AtomicU64::new(0)),
+        }
+    }
+    
+    /// Use an incoming message channel of `capacity` with an overflow policy
+    /// 
+    /// A zero capacity is rejected, since the channel could never hold a message.
+    pub fn with_message_channel(mut self, capacity: usize, policy: OverflowPolicy) -> Result<Self> {
+        if capacity == 0 {
+            return Err(Error::InvalidInput("Message channel capacity must be at least 1".to_string()));
+        }
+        
+        let (message_sender, message_receiver) = mpsc::channel(capacity);
+        self.message_sender = message_sender;
+        self.message_receiver = Arc::new(Mutex::new(message_receiver));
+        self.overflow_policy = policy;
+        Ok(self)
+    }
+    
+    /// Queue an incoming message according to the overflow policy
+    pub async fn enqueue(&self, message: NetworkMessage) -> Result<()> {
+        let closed = || Error::Internal("Message channel closed".to_string());
+        
+        match self.overflow_policy {
+            OverflowPolicy::Block => self.message_sender.send(message).await.map_err(|_| closed()),
+            OverflowPolicy::RejectNew => match self.message_sender.try_send(message) {
+                Ok(()) => Ok(()),
+                Err(mpsc::error::TrySendError::Full(message)) => {
+                    self.dropped_messages.fetch_add(1, Ordering::Relaxed);
+                    Err(Error::ChannelFull(format!(
+                        "Rejected {} from participant {}", message.kind(), message.sender_id()
+                    )))
+                }
+                Err(mpsc::error::TrySendError::Closed(_)) => Err(closed()),
+            },
+            OverflowPolicy::DropOldest => {
+                let mut message = message;
+                loop {
+                    match self.message_sender.try_send(message) {
+                        Ok(()) => return Ok(()),
+                        Err(mpsc::error::TrySendError::Full(rejected)) => {
+                            if self.message_receiver.lock().await.try_recv().is_ok() {
+                                self.dropped_messages.fetch_add(1, Ordering::Relaxed);
+                            }
+                            message = rejected;
+                        }
+                        Err(mpsc::error::TrySendError::Closed(_)) => return Err(closed()),
+                    }
+                }
+            }
+        }
+    }
+    
+    /// Wait for the next queued message
+    pub async fn next_message(&self) -> Option<NetworkMessage> {
+        self.message_receiver.lock().await.recv().await
+    }
+    
+    /// Number of messages dropped or rejected because the channel was full
+    pub fn dropped_messages(&self) -> u64 {
+        self.dropped_messages.load(Ordering::Relaxed)
+    }
+    
+    /// Use a different time source
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+    
+    /// Use different outbound transport settings
+    pub fn with_transport(mut self, transport: TransportConfig) -> Self {
+        self.transport = transport;
+        self
+    }
+    
+    /// Authenticate peers with a different layer
+    /// 
+    /// Peers registered before the switch must be registered again.
+    pub fn with_auth(mut self, auth: Arc<dyn AuthLayer>) -> Self {
+        self.auth = Some(auth);
+        self.insecure_no_auth = false;
+        self
+    }
+    
+    /// Sign with a long-term Schnorr key instead of the generated one
+    pub fn with_identity_key(self, secret_key: crate::curve::Scalar) -> Self {
+        let auth = SchnorrAuth::new(self.id, secret_key);
+        
+        // Keep trusting the peers registered so far
+        if let Ok(participants) = self.participants.try_read() {
+            for participant in participants.values() {
+                auth.register_participant(participant);
+            }
+        }
+        self.with_auth(Arc::new(auth))
+    }
+    
+    /// Exchange unsigned messages with peers
+    /// 
+    /// Anyone able to reach the node can then impersonate participants;
+    /// only meant for tests and local experiments.
+    pub fn with_insecure_no_auth(mut self) -> Self {
+        self.auth = None;
+        self.insecure_no_auth = true;
+        self
+    }
+    
+    /// Entry peers register for this node, including its published key
+    pub fn participant(&self) -> Participant {
+        Participant {
+            id: self.id,
+            address: self.address,
+            public_keys: self.auth.as_ref().and_then(|auth| auth.published_key()).into_iter().collect(),
+            is_online: true,
+            last_heartbeat: self.clock.now_unix(),
+        }
+    }
+    
+    /// Check threshold-signing requests against `policy`
+    /// 
+    /// Without a policy the node refuses to sign.
+    pub fn with_signing_policy(mut self, policy: Arc<dyn SigningPolicy>) -> Self {
+        self.signer.policy = Some(policy);
+        self
+    }
+    
+    /// Apply the settings of a node config
+    pub fn with_config(self, config: &NodeConfig) -> Result<Self> {
+        let node = match &config.transport {
+            Some(transport) => self.with_transport(transport.clone()),
+            None => self,
+        };
+        Ok(match &config.signing_policy {
+            Some(policy) => node.with_signing_policy(Arc::from(policy.build()?)),
+            None => node,
+        })
+    }
+    
+    /// Wrap an outgoing message with this node's credential
+    pub fn seal(&self, message: &NetworkMessage) -> Result<SignedEnvelope> {
+        let auth = self.auth.as_ref()
+            .ok_or_else(|| Error::AuthError("No authentication layer configured".to_string()))?;
+        auth.sign_outgoing(message)
+    }
+    
+    /// Authenticate an incoming envelope and unwrap its message
+    /// 
+    /// Fails with `Error::AuthError` for a bad credential or a sender
+    /// speaking for someone else, and with `Error::ReplayedMessage` for a
+    /// nonce the sender already used.
+    pub async fn open_envelope(&self, envelope: &SignedEnvelope) -> Result<NetworkMessage> {
+        let auth = self.auth.as_ref()
+            .ok_or_else(|| Error::AuthError("No authentication layer configured".to_string()))?;
+        open_envelope(auth.as_ref(), &self.replay_guard, envelope, None).await
+    }
+    
+    /// Authenticate and process an incoming envelope
+    pub async fn receive_envelope(&self, envelope: &SignedEnvelope) -> Result<()> {
+        let message = self.open_envelope(envelope).await?;
+        self.handle_message(message).await
+    }
+    
+    /// Mark participants without a heartbeat within `timeout` as offline
+    pub async fn expire_stale_participants(&self, timeout: Duration) -> Vec<ShareId> {
+        let now = self.clock.now_unix();
+        let mut participants = self.participants.write().await;
+        let mut expired: Vec<ShareId> = participants.values()
+            .filter(|participant| {
+                participant.is_online && now.saturating_sub(participant.last_heartbeat) > timeout.as_secs()
+            })
+            .map(|participant| participant.id)
+            .collect();
+        
+        for id in &expired {
+            // Liveness is not indexed, so the update cannot collide
+            let _ = participants.update(*id, |participant| participant.is_online = false);
+        }
+        
+        expired.sort();
+        expired
+    }
+    
+    /// Run `expire_stale_participants` every `interval` in the background
+    /// 
+    /// Peers marked offline are skipped by `broadcast` until their next
+    /// heartbeat. The sweep runs until the returned handle is aborted.
+    pub fn spawn_liveness_monitor(&self, timeout: Duration, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let node = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let expired = node.expire_stale_participants(timeout).await;
+                if !expired.is_empty() {
+                    tracing::warn!("Participants {:?} missed their heartbeats and are now offline", expired);
+                }
+            }
+        })
+    }
+    
+    /// Send a heartbeat to every registered participant
+    /// 
+    /// Unlike `broadcast`, offline peers are included, so a peer that comes
+    /// back hears from this node again.
+    pub async fn send_heartbeats(&self) -> Result<BroadcastReport> {
+        let heartbeat = NetworkMessage::Heartbeat {
+            sender_id: self.id,
+            timestamp: self.clock.now_unix(),
+        };
+        let peers: Vec<(ShareId, SocketAddr, NetworkMessage)> = self.participants.read().await.values()
+            .map(|participant| (participant.id, participant.address, heartbeat.clone()))
+            .collect();
+        
+        self.deliver_all(peers).await
+    }
+    
+    /// Send heartbeats every `interval` and mark peers silent for `timeout` offline
+    /// 
+    /// Both run in the background until the returned tasks are aborted.
+    pub fn start_heartbeat(&self, interval: Duration, timeout: Duration) -> HeartbeatTasks {
+        let node = self.clone();
+        let sender = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                match node.send_heartbeats().await {
+                    Ok(report) if !report.is_complete() => {
+                        tracing::debug!("Heartbeat of {} did not reach {:?}", node.id, report.unreachable());
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("Failed to send heartbeats: {}", e),
+                }
+            }
+        });
+        
+        HeartbeatTasks {
+            sender,
+            monitor: self.spawn_liveness_monitor(timeout, interval),
         }
     }
     
+    /// Participants currently considered online, by id
+    pub async fn online_participants(&self) -> Vec<Participant> {
+        let mut online: Vec<Participant> = self.participants.read().await.values()
+            .filter(|participant| participant.is_online)
+            .cloned()
+            .collect();
+        online.sort_by_key(|participant| participant.id);
+        online
+    }
+    
     /// Add participant to network
+    /// 
+    /// The participant's published key authenticates its messages from now on.
     pub async fn add_participant(&self, participant: Participant) -> Result<()> {
         let mut participants = self.participants.write().await;
-        participants.insert(participant.id, participant);
+        participants.insert(participant.clone())?;
+        if let Some(auth) = &self.auth {
+            auth.register_participant(&participant);
+        }
         Ok(())
     }
     
     /// Remove participant from network
     pub async fn remove_participant(&self, participant_id: ShareId) -> Result<()> {
         let mut participants = self.participants.write().await;
-        participants.remove(&participant_id);
+        participants.remove(participant_id);
+        if let Some(auth) = &self.auth {
+            auth.deregister_participant(participant_id);
+        }
         Ok(())
     }
     
-    /// Send message to all participants
-    pub async fn broadcast(&self, message: NetworkMessage) -> Result<()> {
-        let participants = self.participants.read().await;
-        for participant in participants.values() {
-            if participant.is_online {
-                // In a real implementation, this would send over the network
-                tracing::info!("Broadcasting to participant {}: {:?}", participant.id, message);
+    /// Join a ceremony session
+    pub async fn join_session(&self, session_id: SessionId, params: SharingParams) -> Result<()> {
+        let mut sessions = self.sessions.write().await;
+        if let Some(existing) = sessions.get(&session_id) {
+            if existing.curve_type() != params.curve_type {
+                return Err(Error::InvalidInput(format!(
+                    "Session {} already runs on {}", session_id, existing.curve_type()
+                )));
             }
+            return Ok(());
         }
+        
+        sessions.insert(session_id, Session::new(session_id, params));
         Ok(())
     }
     
-    /// Send message to specific participant
-    pub async fn send_to(&self, participant_id: ShareId, message: NetworkMessage) -> Result<()> {
-        let participants = self.participants.read().await;
-        if let Some(participant) = participants.get(&participant_id) {
-            if participant.is_online {
-                // In a real implementation, this would send over the network
-                tracing::info!("Sending to participant {}: {:?}", participant_id, message);
+    /// Get the curve of a session this node takes part in
+    pub async fn session_curve(&self, session_id: SessionId) -> Result<CurveType> {
+        session_curve(&self.sessions, session_id).await
+    }
+    
+    /// Phase of a session, `Idle` if the node does not take part in it
+    pub async fn phase(&self, session_id: SessionId) -> ProtocolPhase {
+        self.sessions.read().await.get(&session_id)
+            .map_or(ProtocolPhase::Idle, |session| session.phase)
+    }
+    
+    /// Only accept share distributions for a session from `dealer`
+    pub async fn pin_dealer(&self, session_id: SessionId, dealer: ShareId) -> Result<()> {
+        let mut sessions = self.sessions.write().await;
+        let session = sessions.get_mut(&session_id)
+            .ok_or_else(|| Error::UnknownSession(session_id.to_string()))?;
+        session.dealer = Some(dealer);
+        Ok(())
+    }
+    
+    /// Get the shares this node received in a session
+    pub async fn session_shares(&self, session_id: SessionId) -> Result<Vec<Share>> {
+        let sessions = self.sessions.read().await;
+        sessions.get(&session_id)
+            .map(|session| session.shares.clone())
+            .ok_or_else(|| Error::UnknownSession(session_id.to_string()))
+    }
+    
+    /// The verified share a dealer sent this node in a session, without opening it
+    pub async fn my_share(&self, session_id: SessionId, dealer_id: ShareId) -> Option<CommittedShare> {
+        self.received_shares.read().await.get(&(session_id, dealer_id)).cloned()
+    }
+    
+    /// Reconstruct a session's secret from shares
+    pub async fn reconstruct(&self, session_id: SessionId, shares: &[Share]) -> Result<crate::types::ReconstructionResult> {
+        self.reconstruct_with_progress(session_id, shares, |_| {}).await
+    }
+    
+    /// Reconstruct a session's secret from the shares this node holds
+    /// 
+    /// Those are its own share plus any contributed by peers.
+    pub async fn reconstruct_session(&self, session_id: SessionId) -> Result<crate::types::ReconstructionResult> {
+        let shares = self.session_shares(session_id).await?;
+        self.reconstruct(session_id, &shares).await
+    }
+    
+    /// Reconstruct a session's secret, reporting progress after every share
+    pub async fn reconstruct_with_progress(
+        &self,
+        session_id: SessionId,
+        shares: &[Share],
+        on_progress: impl FnMut(ReconstructionProgress) + Send,
+    ) -> Result<crate::types::ReconstructionResult> {
+        reconstruct_in_session(&self.sessions, session_id, shares, on_progress).await
+    }
+    
+    /// Send message to all online participants
+    /// 
+    /// Delivery is attempted to every peer concurrently; peers that could
+    /// not be reached are listed in the report rather than failing the call,
+    /// as are the offline peers that were skipped.
+    pub async fn broadcast(&self, message: NetworkMessage) -> Result<BroadcastReport> {
+        let peers: Vec<ShareId> = self.participants.read().await.values()
+            .map(|participant| participant.id)
+            .collect();
+        
+        self.send_each(peers.into_iter().map(|id| (id, message.clone())).collect()).await
+    }
+    
+    /// Send every participant its own message
+    /// 
+    /// Like `broadcast`, deliveries run concurrently and failures are listed
+    /// in the report. Messages for offline participants are skipped and
+    /// listed as offline; messages for unknown participants are dropped.
+    pub async fn send_each(&self, messages: Vec<(ShareId, NetworkMessage)>) -> Result<BroadcastReport> {
+        let mut peers = Vec::new();
+        let mut offline = Vec::new();
+        {
+            let participants = self.participants.read().await;
+            for (id, message) in messages {
+                match participants.get(id) {
+                    Some(participant) if participant.is_online => peers.push((id, participant.address, message)),
+                    Some(_) => offline.push(id),
+                    None => {}
+                }
+            }
+        }
+        
+        let mut report = self.deliver_all(peers).await?;
+        offline.sort();
+        offline.dedup();
+        report.offline = offline;
+        Ok(report)
+    }
+    
+    /// Deliver every message to its peer concurrently, reporting failures
+    async fn deliver_all(&self, peers: Vec<(ShareId, SocketAddr, NetworkMessage)>) -> Result<BroadcastReport> {
+        let client = self.transport.build_client()?;
+        {
+            let mut traffic = self.traffic.write().await;
+            for (id, _, message) in &peers {
+                traffic.record_sent(*id, message, self.clock.now_unix());
             }
         }
+        
+        let deliveries = peers.iter().map(|(id, address, message)| {
+            let client = &client;
+            async move { (*id, self.deliver(client, *id, *address, message).await) }
+        });
+        
+        let mut report = BroadcastReport::default();
+        for (id, outcome) in futures::future::join_all(deliveries).await {
+            match outcome {
+                Ok(()) => report.delivered.push(id),
+                Err(e) => {
+                    tracing::warn!("Broadcast to participant {} failed: {}", id, e);
+                    report.failed.push((id, e.to_string()));
+                }
+            }
+        }
+        Ok(report)
+    }
+    
+    /// Send message to specific participant
+    /// 
+    /// Fails with `Error::NetworkError` if the participant cannot be reached
+    /// within the transport's request timeout or rejects the message. Offline or unknown participants are skipped.
+    pub async fn send_to(&self, participant_id: ShareId, message: NetworkMessage) -> Result<()> {
+        let address = match self.participants.read().await.get(participant_id) {
+            Some(participant) if participant.is_online => participant.address,
+            _ => return Ok(()),
+        };
+        
+        self.traffic.write().await.record_sent(participant_id, &message, self.clock.now_unix());
+        let client = self.transport.build_client()?;
+        self.deliver(&client, participant_id, address, &message).await
+    }
+    
+    /// Send a request to a participant and wait for its response message
+    /// 
+    /// Fails with `Error::NetworkError` like `send_to`, and also if the
+    /// participant is offline or unknown or its response does not parse.
+    pub async fn request(&self, participant_id: ShareId, message: NetworkMessage) -> Result<NetworkMessage> {
+        let unavailable = |message: String| Error::NetworkError { participant_id, status: None, message };
+        let address = match self.participants.read().await.get(participant_id) {
+            Some(participant) if participant.is_online => participant.address,
+            _ => return Err(unavailable("participant is offline or unknown".to_string())),
+        };
+        let endpoint = message.endpoint()
+            .ok_or_else(|| Error::InvalidInput(format!("{} has no endpoint", message.kind())))?;
+        
+        self.traffic.write().await.record_sent(participant_id, &message, self.clock.now_unix());
+        let client = self.transport.build_client()?;
+        let response = self.post(&client, participant_id, address, endpoint, &message).await?;
+        let reply: NetworkMessage = response.json().await
+            .map_err(|e| unavailable(format!("unreadable response to {}: {}", message.kind(), e)))?;
+        
+        self.traffic.write().await.record_received(participant_id, &reply, self.clock.now_unix());
+        Ok(reply)
+    }
+    
+    /// POST a message as JSON to its endpoint on a peer
+    async fn deliver(
+        &self,
+        client: &reqwest::Client,
+        participant_id: ShareId,
+        address: SocketAddr,
+        message: &NetworkMessage,
+    ) -> Result<()> {
+        let Some(endpoint) = message.endpoint() else {
+            tracing::debug!("{} to participant {} has no endpoint; not sent", message.kind(), participant_id);
+            return Ok(());
+        };
+        
+        self.post(client, participant_id, address, endpoint, message).await?;
         Ok(())
     }
     
+    /// POST a message to `endpoint` on a peer, failing unless it is accepted
+    async fn post(
+        &self,
+        client: &reqwest::Client,
+        participant_id: ShareId,
+        address: SocketAddr,
+        endpoint: &str,
+        message: &NetworkMessage,
+    ) -> Result<reqwest::Response> {
+        let route = self.transport.proxy_for(&address).unwrap_or("direct");
+        tracing::info!("Sending {} to participant {} via {}", message.kind(), participant_id, route);
+        
+        let request = client.post(format!("http://{}{}", address, endpoint));
+        let request = if self.insecure_no_auth {
+            request.json(message)
+        } else {
+            request.json(&self.seal(message)?)
+        };
+        let response = request
+            .send()
+            .await
+            .map_err(|e| Error::NetworkError {
+                participant_id,
+                status: None,
+                message: if e.is_timeout() {
+                    format!("no response from {} within {:?}", address, self.transport.request_timeout)
+                } else {
+                    format!("{} unreachable: {}", address, e)
+                },
+            })?;
+        
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(Error::NetworkError {
+                participant_id,
+                status: Some(status.as_u16()),
+                message: format!("{} rejected with {}: {}", message.kind(), status, body),
+            });
+        }
+        Ok(response)
+    }
+    
     /// Process incoming message
-    pub async fn process_message(&mut self, message: NetworkMessage) -> Result<()> {
+    /// 
+    /// Peer messages must come through `receive_envelope`; unsigned ones are
+    /// only accepted by nodes built `with_insecure_no_auth`.
+    pub async fn process_message(&self, message: NetworkMessage) -> Result<()> {
+        if !self.insecure_no_auth {
+            return Err(Error::AuthError(format!(
+                "Unsigned {} from participant {} rejected", message.kind(), message.sender_id()
+            )));
+        }
+        self.handle_message(message).await
+    }
+    
+    /// Handle an incoming message that needs no further authentication
+    async fn handle_message(&self, message: NetworkMessage) -> Result<()> {
+        let sender_id = message.sender_id();
+        let session_id = message.session_id();
+        self.traffic.write().await.record_received(sender_id, &message, self.clock.now_unix());
+        
+        let result = self.dispatch_message(message).await;
+        
+        if let Some(session_id) = session_id {
+            let outcome = result.as_ref().map(|_| ()).map_err(|e| e.to_string());
+            self.traffic.write().await.record_outcome(session_id, sender_id, outcome, self.clock.now_unix());
+        }
+        result
+    }
+    
+    /// Traffic exchanged with peers in a session
+    pub async fn session_traffic(&self, session_id: SessionId) -> Option<SessionTraffic> {
+        self.traffic.read().await.session(session_id).cloned()
+    }
+    
+    /// Route an incoming message to its handler
+    async fn dispatch_message(&self, message: NetworkMessage) -> Result<()> {
         match message {
-            NetworkMessage::ShareDistribution { sender_id, shares, commitments, proofs, curve_type } => {
-                self.handle_share_distribution(sender_id, shares, commitments, proofs, curve_type).await?;
+            message @ NetworkMessage::ShareDelivery { .. } => {
+                self.handle_share_delivery(message).await?;
+            }
+            NetworkMessage::CommitmentVerification { sender_id, session_id, commitment, value, opening } => {
+                self.handle_commitment_verification(sender_id, session_id, commitment, value, opening).await?;
+            }
+            NetworkMessage::ProofVerification { sender_id, session_id, proof } => {
+                self.handle_proof_verification(sender_id, session_id, proof).await?;
+            }
+            NetworkMessage::SecretReconstruction { sender_id, session_id, shares } => {
+                self.handle_secret_reconstruction(sender_id, session_id, shares).await?;
+            }
+            NetworkMessage::ShareContribution { sender_id, session_id, share } => {
+                accept_contribution(&self.sessions, sender_id, session_id, share).await?;
             }
-            NetworkMessage::CommitmentVerification { sender_id, commitment, value, randomness, curve_type } => {
-                self.handle_commitment_verification(sender_id, commitment, value, randomness, curve_type).await?;
+            NetworkMessage::ShareRequest { sender_id, session_id } => {
+                self.handle_share_request(sender_id, session_id).await?;
             }
-            NetworkMessage::ProofVerification { sender_id, proof } => {
-                self.handle_proof_verification(sender_id, proof).await?;
+            message @ (NetworkMessage::DkgCommitment { .. }
+            | NetworkMessage::DkgShare { .. }
+            | NetworkMessage::DkgComplaint { .. }
+            | NetworkMessage::DkgEcho { .. }) => {
+                accept_dkg_message(self.id, &self.participants, &self.dkg, message).await?;
             }
-            NetworkMessage::SecretReconstruction { sender_id, shares, curve_type } => {
-                self.handle_secret_reconstruction(sender_id, shares, curve_type).await?;
+            message @ (NetworkMessage::SigningRequest { .. } | NetworkMessage::PartialSignatureRequest { .. }) => {
+                self.handle_signing_request(message).await?;
             }
-            NetworkMessage::Heartbeat { sender_id, timestamp } => {
-                self.handle_heartbeat(sender_id, timestamp).await?;
+            NetworkMessage::Join { sender_id, session_id, address, public_key } => {
+                self.handle_join(sender_id, session_id, address, public_key).await?;
+            }
+            NetworkMessage::Heartbeat { sender_id, .. } => {
+                self.handle_heartbeat(sender_id).await?;
             }
             _ => {
                 tracing::warn!("Unhandled message type: {:?}", message);
@@ -191,33 +1178,16 @@ impl NetworkNode {
         Ok(())
     }
     
-    /// Handle share distribution
-    async fn handle_share_distribution(
-        &self,
-        sender_id: ShareId,
-        shares: Vec<Share>,
-        commitments: Vec<CommittedShare>,
-        proofs: Vec<ZeroKnowledgeProof>,
-        curve_type: CurveType,
-    ) -> Result<()> {
-        tracing::info!("Received share distribution from participant {}", sender_id);
+    /// Handle the delivery of this node's share
+    async fn handle_share_delivery(&self, message: NetworkMessage) -> Result<()> {
+        tracing::info!(
+            "Received {} from participant {} in session {}",
+            message.kind(), message.sender_id(), message.session_id().map(|session_id| session_id.to_string()).unwrap_or_default()
+        );
         
-        // Verify commitments
-        let pedersen = PedersenCommitment::new(curve_type)?;
-        for committed_share in &commitments {
-            if !committed_share.verify(curve_type)? {
-                return Err(Error::CommitmentError("Invalid commitment".to_string()));
-            }
-        }
+        accept_dealing(self.id, &self.sessions, &self.received_shares, message).await?;
         
-        // Verify proofs
-        for proof in &proofs {
-            if !proof.verify(&pedersen)? {
-                return Err(Error::ZKProofError("Invalid proof".to_string()));
-            }
-        }
-        
-        tracing::info!("Share distribution verified successfully");
+        tracing::info!("Share delivery verified successfully");
         Ok(())
     }
     
@@ -225,21 +1195,19 @@ impl NetworkNode {
     async fn handle_commitment_verification(
         &self,
         sender_id: ShareId,
+        session_id: SessionId,
         commitment: Vec<u8>,
         value: Vec<u8>,
-        randomness: Vec<u8>,
-        curve_type: CurveType,
+        opening: CommitmentOpening,
     ) -> Result<()> {
-        tracing::info!("Verifying commitment from participant {}", sender_id);
-        
-        let pedersen = PedersenCommitment::new(curve_type)?;
-        let curve = crate::curve::create_curve(curve_type);
-        let value_scalar = curve.scalar_from_bytes(&value)?;
+        tracing::info!("Verifying commitment from participant {} in session {}", sender_id, session_id);
         
-        let is_valid = pedersen.verify(&commitment, &value_scalar, &randomness)?;
+        let curve_type = session_curve(&self.sessions, session_id).await?;
+        let is_valid = verify_commitment_internal(&commitment, &value, &opening, curve_type).await?;
         
         let response = NetworkMessage::CommitmentVerificationResponse {
             sender_id: self.id,
+            session_id,
             commitment,
             is_valid,
         };
@@ -252,15 +1220,17 @@ impl NetworkNode {
     async fn handle_proof_verification(
         &self,
         sender_id: ShareId,
+        session_id: SessionId,
         proof: ZeroKnowledgeProof,
     ) -> Result<()> {
-        tracing::info!("Verifying proof from participant {}", sender_id);
+        tracing::info!("Verifying proof from participant {} in session {}", sender_id, session_id);
         
-        let pedersen = PedersenCommitment::new(proof.curve_type)?;
-        let is_valid = proof.verify(&pedersen)?;
+        let curve_type = session_curve(&self.sessions, session_id).await?;
+        let is_valid = verify_proof_internal(&proof, curve_type).await?;
         
         let response = NetworkMessage::ProofVerificationResponse {
             sender_id: self.id,
+            session_id,
             proof_commitment: proof.commitment,
             is_valid,
         };
@@ -273,22 +1243,16 @@ impl NetworkNode {
     async fn handle_secret_reconstruction(
         &self,
         sender_id: ShareId,
+        session_id: SessionId,
         shares: Vec<Share>,
-        curve_type: CurveType,
     ) -> Result<()> {
-        tracing::info!("Reconstructing secret from participant {}", sender_id);
-        
-        let params = crate::types::SharingParams {
-            curve_type,
-            threshold: shares.len(),
-            participants: shares.len(),
-        };
+        tracing::info!("Reconstructing secret from participant {} in session {}", sender_id, session_id);
         
-        let laurent = LaurentSeries::new(&params)?;
-        let result = laurent.reconstruct_secret(&shares)?;
+        let result = self.reconstruct(session_id, &shares).await?;
         
         let response = NetworkMessage::SecretReconstructionResponse {
             sender_id: self.id,
+            session_id,
             secret: result.secret,
             participants_used: result.participants_used,
             is_valid: result.valid,
@@ -298,13 +1262,64 @@ impl NetworkNode {
         Ok(())
     }
     
+    /// Handle a request for this node's share
+    async fn handle_share_request(&self, sender_id: ShareId, session_id: SessionId) -> Result<()> {
+        tracing::info!("Participant {} requested the share of session {}", sender_id, session_id);
+        
+        let response = share_response(self.id, &self.participants, &self.sessions, sender_id, session_id).await?;
+        self.send_to(sender_id, response).await?;
+        Ok(())
+    }
+    
+    /// Handle a threshold-signing request of either round
+    async fn handle_signing_request(&self, message: NetworkMessage) -> Result<()> {
+        let sender_id = message.sender_id();
+        tracing::info!("Participant {} sent a {}", sender_id, message.kind());
+        
+        let response = signing_response(
+            self.id, &self.participants, &self.sessions, &self.signer, self.clock.now_unix(), message,
+        ).await?;
+        self.send_to(sender_id, response).await?;
+        Ok(())
+    }
+    
+    /// Handle registration of an invited participant
+    async fn handle_join(
+        &self,
+        sender_id: ShareId,
+        session_id: SessionId,
+        address: SocketAddr,
+        public_key: Vec<u8>,
+    ) -> Result<()> {
+        tracing::info!("Participant {} joined session {}", sender_id, session_id);
+        
+        let curve_type = self.session_curve(session_id).await?;
+        let mut participants = self.participants.write().await;
+        let mut participant = participants.get(sender_id).cloned().unwrap_or_else(|| Participant {
+            id: sender_id,
+            address,
+            public_keys: HashMap::new(),
+            is_online: true,
+            last_heartbeat: 0,
+        });
+        participant.address = address;
+        participant.is_online = true;
+        participant.public_keys.insert(curve_type, public_key);
+        participants.insert(participant)?;
+        Ok(())
+    }
+    
     /// Handle heartbeat
-    async fn handle_heartbeat(&self, sender_id: ShareId, timestamp: u64) -> Result<()> {
+    /// 
+    /// The heartbeat is stamped with this node's clock, since the sender's
+    /// timestamp comes from a clock that may be skewed.
+    async fn handle_heartbeat(&self, sender_id: ShareId) -> Result<()> {
+        let now = self.clock.now_unix();
         let mut participants = self.participants.write().await;
-        if let Some(participant) = participants.get_mut(&sender_id) {
-            participant.last_heartbeat = timestamp;
+        participants.update(sender_id, |participant| {
+            participant.last_heartbeat = now;
             participant.is_online = true;
-        }
+        })?;
         Ok(())
     }
 }
@@ -313,8 +1328,8 @@ impl NetworkNode {
 #[derive(Clone)]
 pub struct NetworkCoordinator {
     pub node: NetworkNode,
-    pub laurent_series: Option<LaurentSeries>,
-    pub pedersen: Option<PedersenCommitment>,
+    pub laurent_series: HashMap<SessionId, LaurentSeries>,
+    pub pedersen: HashMap<SessionId, PedersenCommitment>,
 }
 
 impl NetworkCoordinator {
@@ -322,59 +1337,455 @@ impl NetworkCoordinator {
     pub fn new(node: NetworkNode) -> Self {
         Self {
             node,
-            laurent_series: None,
-            pedersen: None,
+            laurent_series: HashMap::new(),
+            pedersen: HashMap::new(),
+        }
+    }
+    
+    /// Authenticate peer messages with the given layer
+    pub fn with_auth(mut self, auth: Arc<dyn AuthLayer>) -> Self {
+        self.node = self.node.with_auth(auth);
+        self
+    }
+    
+    /// Exchange unsigned messages with peers; only meant for tests
+    pub fn with_insecure_no_auth(mut self) -> Self {
+        self.node = self.node.with_insecure_no_auth();
+        self
+    }
+    
+    /// Wrap an outgoing message with this node's credential
+    pub fn seal(&self, message: &NetworkMessage) -> Result<SignedEnvelope> {
+        self.node.seal(message)
+    }
+    
+    /// Authenticate and process an incoming envelope
+    pub async fn receive_envelope(&self, envelope: &SignedEnvelope) -> Result<()> {
+        self.node.receive_envelope(envelope).await
+    }
+    
+    /// Initialize z-MPC protocol under a fresh random session id and return the id
+    /// 
+    /// Every message of the session carries the id, so concurrent sessions
+    /// on the same nodes keep their shares and commitments apart. Other
+    /// participants enter the session with `join_protocol`.
+    pub async fn initialize_protocol(&mut self, params: SharingParams) -> Result<SessionId> {
+        let session_id = SessionId::random();
+        self.join_protocol(session_id, params).await?;
+        Ok(session_id)
+    }
+    
+    /// Take part in a session another participant initialized
+    pub async fn join_protocol(&mut self, session_id: SessionId, params: SharingParams) -> Result<()> {
+        self.node.join_session(session_id, params.clone()).await?;
+        self.laurent_series.insert(session_id, LaurentSeries::new(&params)?);
+        self.pedersen.insert(session_id, PedersenCommitment::new(params.curve_type)?);
+        
+        tracing::info!("z-MPC session {} initialized for curve: {}", session_id, params.curve_type);
+        Ok(())
+    }
+    
+    /// Traffic exchanged with peers in a session
+    pub async fn session_traffic(&self, session_id: SessionId) -> Option<SessionTraffic> {
+        self.node.session_traffic(session_id).await
+    }
+    
+    /// Phase of a session, `Idle` if it was never initialized here
+    pub async fn phase(&self, session_id: SessionId) -> ProtocolPhase {
+        self.node.phase(session_id).await
+    }
+    
+    /// Distribute shares of a session to all participants
+    /// 
+    /// Each participant receives only its own share. The dealer keeps its
+    /// own share, so it can join a reconstruction quorum. Fails with
+    /// `Error::ProtocolState` before `initialize_protocol` or once the
+    /// session holds a verified dealing.
+    pub async fn distribute_shares(&self, session_id: SessionId) -> Result<BroadcastReport> {
+        {
+            let mut sessions = self.node.sessions.write().await;
+            let session = sessions.get_mut(&session_id).ok_or(Error::ProtocolState {
+                expected: ProtocolPhase::Initialized,
+                got: ProtocolPhase::Idle,
+            })?;
+            session.require_phase(DEALING_PHASES)?;
+            if session.phase == ProtocolPhase::Initialized {
+                session.phase = ProtocolPhase::SharesDistributed;
+            }
         }
+        
+        let mut deliveries = Vec::new();
+        for (id, delivery) in self.deal_share_deliveries(session_id)? {
+            if id == self.node.id {
+                accept_dealing(self.node.id, &self.node.sessions, &self.node.received_shares, delivery).await?;
+            } else {
+                deliveries.push((id, delivery));
+            }
+        }
+        
+        self.node.send_each(deliveries).await
     }
     
-    /// Initialize z-MPC protocol
-    pub async fn initialize_protocol(&mut self, params: crate::types::SharingParams) -> Result<()> {
-        self.laurent_series = Some(LaurentSeries::new(&params)?);
-        self.pedersen = Some(PedersenCommitment::new(params.curve_type)?);
+    /// Split a session's dealing into one `ShareDelivery` per participant
+    pub fn deal_share_deliveries(&self, session_id: SessionId) -> Result<Vec<(ShareId, NetworkMessage)>> {
+        let (commitments, proofs, coefficient_commitments) = self.deal_shares(session_id)?;
         
-        tracing::info!("z-MPC protocol initialized for curve: {}", params.curve_type);
+        Ok(commitments.into_iter().zip(proofs).map(|(committed_share, proof)| {
+            let delivery = NetworkMessage::ShareDelivery {
+                sender_id: self.node.id,
+                session_id,
+                committed_share: Box::new(committed_share.clone()),
+                proof: Box::new(proof),
+                coefficient_commitments: coefficient_commitments.clone(),
+            };
+            (committed_share.share.id, delivery)
+        }).collect())
+    }
+    
+    /// Send this node's share of a session to the other members of a quorum
+    pub async fn contribute_share(&self, session_id: SessionId, quorum: &[ShareId]) -> Result<()> {
+        let share = own_share(&self.node.sessions, self.node.id, session_id).await?;
+        
+        for &peer in quorum.iter().filter(|&&peer| peer != self.node.id) {
+            let message = NetworkMessage::ShareContribution {
+                sender_id: self.node.id,
+                session_id,
+                share: share.clone(),
+            };
+            self.node.send_to(peer, message).await?;
+        }
         Ok(())
     }
     
-    /// Distribute shares to all participants
-    pub async fn distribute_shares(&self) -> Result<()> {
-        let laurent = self.laurent_series.as_ref()
-            .ok_or_else(|| Error::InvalidInput("Protocol not initialized".to_string()))?;
+    /// Collect shares of a session from the online participants and reconstruct
+    /// 
+    /// Every online peer is asked for its own share, and responses are
+    /// awaited until all peers answered or the transport's request timeout
+    /// passed. Shares that do not match the session's coefficient commitments
+    /// are skipped and their senders listed in `rejected_participants`. Fails
+    /// with `Error::InsufficientShares` if fewer than `threshold` shares,
+    /// this node's own included, pass.
+    pub async fn request_reconstruction(&self, session_id: SessionId) -> Result<crate::types::ReconstructionResult> {
+        let (params, coefficient_commitments) = verified_dealing(&self.node.sessions, session_id).await?;
+        set_phase(&self.node.sessions, session_id, ProtocolPhase::Reconstructing).await;
+        
+        let result = self.collect_and_reconstruct(session_id, &params, &coefficient_commitments).await;
+        let phase = if result.is_ok() { ProtocolPhase::Complete } else { ProtocolPhase::Failed };
+        set_phase(&self.node.sessions, session_id, phase).await;
+        result
+    }
+    
+    /// Gather verified shares from the online peers and reconstruct
+    async fn collect_and_reconstruct(
+        &self,
+        session_id: SessionId,
+        params: &SharingParams,
+        coefficient_commitments: &CoefficientCommitments,
+    ) -> Result<crate::types::ReconstructionResult> {
+        // Step 1: Ask every online peer for its share
+        let request = NetworkMessage::ShareRequest {
+            sender_id: self.node.id,
+            session_id,
+        };
+        let mut pending: FuturesUnordered<_> = self.node.online_participants().await
+            .into_iter()
+            .map(|participant| {
+                let request = request.clone();
+                async move { (participant.id, self.node.request(participant.id, request).await) }
+            })
+            .collect();
+        
+        // Step 2: Keep the shares that lie on the dealt series until the deadline
+        let mut shares: Vec<Share> = own_share(&self.node.sessions, self.node.id, session_id).await
+            .into_iter()
+            .collect();
+        let mut rejected_participants = Vec::new();
+        let deadline = tokio::time::Instant::now() + self.node.transport.request_timeout;
+        while let Ok(Some((peer, response))) = tokio::time::timeout_at(deadline, pending.next()).await {
+            let share = match response {
+                Ok(NetworkMessage::ShareResponse { share, .. }) => share,
+                Ok(other) => {
+                    tracing::warn!("Participant {} answered the share request with {}", peer, other.kind());
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!("No share from participant {}: {}", peer, e);
+                    continue;
+                }
+            };
+            
+            let verified = share.id == peer && matches!(
+                crate::laurent::verify_share_against_commitments(&share, coefficient_commitments, params.curve_type),
+                Ok(true)
+            );
+            if verified {
+                shares.push(share);
+            } else {
+                tracing::warn!("Share of participant {} does not match the dealing of session {}", peer, session_id);
+                rejected_participants.push(peer);
+            }
+        }
+        
+        // Step 3: Reconstruct from the verified shares
+        if shares.len() < params.threshold {
+            return Err(Error::InsufficientShares { required: params.threshold, got: shares.len() });
+        }
+        let mut reconstructor = Reconstructor::new(params).with_id_scheme(coefficient_commitments.id_scheme);
+        for share in shares {
+            reconstructor.add_share(share)?;
+        }
+        let mut result = reconstructor.finish()?;
+        rejected_participants.sort();
+        result.rejected_participants = rejected_participants;
+        Ok(result)
+    }
+    
+    /// Generate a joint secret with the other participants, without a dealer
+    /// 
+    /// Every participant `1..=participants` deals a series of its own in the
+    /// session and sends each peer its sub-share along with the series'
+    /// coefficient commitments. Once all contributions arrived, sub-shares are
+    /// checked against their commitments: a mismatch is broadcast as a
+    /// `DkgComplaint` and fails the run, as does any complaint received.
+    /// Participants then echo a digest of the commitments they received; a
+    /// digest that differs from this node's fails the run with
+    /// `Error::CommitmentError`. Fails with `Error::InsufficientShares` if
+    /// contributions or echoes are still missing when the transport's
+    /// request timeout passes. The final share is
+    /// kept in the session, so the joint secret can be reconstructed like a
+    /// dealt one.
+    pub async fn run_dkg(&mut self, session_id: SessionId, params: SharingParams) -> Result<DkgOutput> {
+        let node_id = self.node.id;
+        if node_id == 0 || node_id as usize > params.participants {
+            return Err(Error::InvalidInput(format!(
+                "Participant {} is not one of the {} key generation participants", node_id, params.participants
+            )));
+        }
+        let curve_type = params.curve_type;
+        self.join_protocol(session_id, params.clone()).await?;
+        
+        // Step 1: Deal this node's contribution
+        let (shares, coefficient_commitments) = {
+            let laurent = &self.laurent_series[&session_id];
+            (laurent.generate_shares()?, laurent.coefficient_commitments()?)
+        };
+        let dkg = &self.node.dkg;
+        dkg.record_commitments(session_id, node_id, coefficient_commitments.clone()).await?;
+        
+        let mut messages = Vec::new();
+        for share in shares {
+            if share.id == node_id {
+                dkg.record_share(session_id, node_id, share).await?;
+                continue;
+            }
+            messages.push((share.id, NetworkMessage::DkgCommitment {
+                sender_id: node_id,
+                session_id,
+                coefficient_commitments: coefficient_commitments.clone(),
+            }));
+            messages.push((share.id, NetworkMessage::DkgShare {
+                sender_id: node_id,
+                session_id,
+                share,
+            }));
+        }
+        let report = self.node.send_each(messages).await?;
+        if !report.is_complete() {
+            tracing::warn!("Key generation contribution of {} not delivered to {:?}", node_id, report.failed);
+        }
+        
+        // Step 2: Wait for every participant's contribution
+        let contributors: Vec<ShareId> = (1..=params.participants as ShareId).collect();
+        let deadline = tokio::time::Instant::now() + self.node.transport.request_timeout;
+        let round = dkg.wait_until(session_id, deadline, |round| round.is_complete(&contributors)).await;
+        if !round.is_complete(&contributors) {
+            return Err(Error::InsufficientShares {
+                required: contributors.len(),
+                got: round.received(&contributors),
+            });
+        }
+        
+        // Step 3: Complain about sub-shares that do not match their commitments
+        let invalid = round.invalid_contributors(curve_type);
+        for &accused in &invalid {
+            let complaint = NetworkMessage::DkgComplaint {
+                sender_id: node_id,
+                session_id,
+                accused,
+                reason: "Sub-share does not match the coefficient commitments".to_string(),
+            };
+            dkg.record_complaint(session_id, node_id, accused).await?;
+            self.node.broadcast(complaint).await?;
+        }
+        if !invalid.is_empty() {
+            return Err(Error::CommitmentError(format!(
+                "Sub-shares of participants {:?} do not match their commitments", invalid
+            )));
+        }
+        let complaints = dkg.round(session_id).await.complaints;
+        if !complaints.is_empty() {
+            return Err(Error::CommitmentError(format!("Key generation complaints raised: {:?}", complaints)));
+        }
+        
+        // Step 4: Echo the commitment set and compare it with every participant's
+        let digest = round.commitment_digest(curve_type)?;
+        dkg.record_echo(session_id, node_id, digest.clone()).await?;
+        self.node.broadcast(NetworkMessage::DkgEcho {
+            sender_id: node_id,
+            session_id,
+            digest: digest.clone(),
+        }).await?;
+        
+        let deadline = tokio::time::Instant::now() + self.node.transport.request_timeout;
+        let echoed = dkg.wait_until(session_id, deadline, |round| {
+            round.has_echoes(&contributors) || !round.complaints.is_empty()
+        }).await;
+        if !echoed.complaints.is_empty() {
+            return Err(Error::CommitmentError(format!("Key generation complaints raised: {:?}", echoed.complaints)));
+        }
+        if !echoed.has_echoes(&contributors) {
+            return Err(Error::InsufficientShares {
+                required: contributors.len(),
+                got: contributors.iter().filter(|id| echoed.echoes.contains_key(id)).count(),
+            });
+        }
+        let mismatched = echoed.mismatched_echoes(&digest);
+        if !mismatched.is_empty() {
+            return Err(Error::CommitmentError(format!(
+                "Participants {:?} received different key generation commitments", mismatched
+            )));
+        }
+        
+        // Step 5: Keep the summed share as this node's share of the session
+        let output = round.finish(node_id, curve_type)?;
+        if let Some(session) = self.node.sessions.write().await.get_mut(&session_id) {
+            session.shares = vec![output.share.clone()];
+            session.coefficient_commitments = Some(output.coefficient_commitments.clone());
+            session.phase = ProtocolPhase::Verified;
+        }
+        
+        tracing::info!("Key generation of session {} finished on participant {}", session_id, node_id);
+        Ok(output)
+    }
+    
+    /// Sign `message` with a session's shared key together with `signers`
+    /// 
+    /// `signers` must include this node and hold at least `threshold`
+    /// participants. Each signer checks the request against its own signing
+    /// policy before it commits to nonces, so a single refusal fails the
+    /// signature. `approvals` are passed on for approval quorums. Returns a
+    /// signature under the session's group public key.
+    pub async fn threshold_sign(
+        &self,
+        session_id: SessionId,
+        message: &[u8],
+        signers: &[ShareId],
+        approvals: Vec<Approval>,
+    ) -> Result<SchnorrSignature> {
+        if !signers.contains(&self.node.id) {
+            return Err(Error::InvalidInput(format!("Participant {} is not among the signers", self.node.id)));
+        }
+        let curve_type = self.node.session_curve(session_id).await?;
+        let node = &self.node;
+        
+        // Round 1: Collect every signer's nonce commitment
+        let request = NetworkMessage::SigningRequest {
+            sender_id: node.id,
+            session_id,
+            message: message.to_vec(),
+            approvals: approvals.clone(),
+        };
+        let context = SigningContext::new(curve_type, node.clock.now_unix()).with_approvals(approvals);
+        let mut commitments = Vec::new();
+        for &signer in signers {
+            if signer == node.id {
+                commitments.push(node.signer.commit(&node.sessions, node.id, node.id, session_id, message, &context).await?);
+                continue;
+            }
+            match node.request(signer, request.clone()).await? {
+                NetworkMessage::SigningCommitmentResponse { commitment, .. } if commitment.id == signer => {
+                    commitments.push(commitment);
+                }
+                other => return Err(Error::InvalidInput(format!(
+                    "Participant {} answered the signing request with {}", signer, other.kind()
+                ))),
+            }
+        }
+        
+        // Round 2: Collect the partial signatures over all commitments
+        let request = NetworkMessage::PartialSignatureRequest {
+            sender_id: node.id,
+            session_id,
+            message: message.to_vec(),
+            commitments: commitments.clone(),
+        };
+        let mut partials = Vec::new();
+        for &signer in signers {
+            if signer == node.id {
+                partials.push(node.signer.sign(&node.sessions, node.id, session_id, message, &commitments).await?);
+                continue;
+            }
+            match node.request(signer, request.clone()).await? {
+                NetworkMessage::PartialSignatureResponse { partial, .. } if partial.id == signer => partials.push(partial),
+                other => return Err(Error::InvalidInput(format!(
+                    "Participant {} answered the partial signature request with {}", signer, other.kind()
+                ))),
+            }
+        }
+        
+        // Step 3: Aggregate under the session's group public key
+        let (_, coefficient_commitments) = verified_dealing(&node.sessions, session_id).await?;
+        crate::zkp::utils::aggregate_partial_signatures(
+            message,
+            &commitments,
+            &partials,
+            &group_public_key(&coefficient_commitments)?,
+        )
+    }
+    
+    /// Deal a session: every share committed, with its opening proof
+    /// 
+    /// Never sent as is; `deal_share_deliveries` splits it per recipient.
+    pub(crate) fn deal_shares(
+        &self,
+        session_id: SessionId,
+    ) -> Result<(Vec<CommittedShare>, Vec<ZeroKnowledgeProof>, CoefficientCommitments)> {
+        let laurent = self.laurent_series.get(&session_id)
+            .ok_or_else(|| Error::InvalidInput(format!("Session {} not initialized", session_id)))?;
+        let pedersen = &self.pedersen[&session_id];
         
         let shares = laurent.generate_shares()?;
-        let pedersen = self.pedersen.as_ref().unwrap();
-        let committed_shares = crate::pedersen::utils::commit_all_shares(&shares, laurent.curve_type)?;
+        let coefficient_commitments = laurent.coefficient_commitments()?;
+        let curve = crate::curve::curve_backend(laurent.curve_type)?;
         
-        // Generate proofs for all shares
+        // Commit to every share under the session parameters and prove each opening
+        let mut rng = rand::thread_rng();
+        let mut committed_shares = Vec::new();
         let mut proofs = Vec::new();
-        for (share, committed_share) in shares.iter().zip(committed_shares.iter()) {
-            let proof = crate::zkp::utils::prove_committed_share(
-                pedersen,
-                share,
-                &committed_share.randomness,
-                laurent.curve_type,
+        for share in &shares {
+            let randomness = pedersen.generate_randomness();
+            let context = format!("{}/{}", session_id, share.id);
+            let (commitment, proof) = pedersen.commit_and_prove(
+                &curve.scalar_from_bytes(&share.value)?,
+                &randomness,
+                context.as_bytes(),
+                &mut rng,
             )?;
+            committed_shares.push(CommittedShare::new(share.clone(), commitment, randomness));
             proofs.push(proof);
         }
+        crate::pedersen::utils::ensure_distinct_randomness(&committed_shares)?;
         
-        let message = NetworkMessage::ShareDistribution {
-            sender_id: self.node.id,
-            shares,
-            commitments: committed_shares,
-            proofs,
-            curve_type: laurent.curve_type,
-        };
-        
-        self.node.broadcast(message).await?;
-        Ok(())
+        Ok((committed_shares, proofs, coefficient_commitments))
     }
     
     /// Run network node
     pub async fn run(&mut self) -> Result<()> {
         tracing::info!("Starting network node on {}", self.node.address);
         
-        while let Some(message) = self.node.message_receiver.recv().await {
-            self.node.process_message(message).await?;
+        // Queued messages come from this process, not from peers
+        while let Some(message) = self.node.next_message().await {
+            self.node.handle_message(message).await?;
         }
         
         Ok(())
@@ -382,30 +1793,86 @@ impl NetworkCoordinator {
     
     /// Start HTTP server for network communication
     pub async fn start_http_server(&self) -> Result<()> {
+        let listener = self.bind().await?;
+        self.serve(listener).await
+    }
+    
+    /// Bind a listener on the node's advertised address
+    pub async fn bind(&self) -> Result<tokio::net::TcpListener> {
+        tokio::net::TcpListener::bind(self.node.address).await
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::AddrInUse {
+                    Error::PortConflict(format!(
+                        "{} is already in use; bind to port 0 for an ephemeral port", self.node.address
+                    ))
+                } else {
+                    Error::Internal(format!("Failed to bind to {}: {}", self.node.address, e))
+                }
+            })
+    }
+    
+    /// Serve HTTP on an already bound listener
+    /// 
+    /// The listener's address should match the node's advertised address.
+    pub async fn serve(&self, listener: tokio::net::TcpListener) -> Result<()> {
+        self.serve_until(listener, std::future::pending()).await
+    }
+    
+    /// Serve HTTP until `shutdown` completes, then finish in-flight requests
+    pub async fn serve_until(
+        &self,
+        listener: tokio::net::TcpListener,
+        shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+    ) -> Result<()> {
+        // Refuse to serve traffic on a misbehaving backend
+        crate::selftest::known_answer_tests()?;
+        
+        let app = self.router();
+        let local_addr = listener.local_addr()
+            .map_err(|e| Error::Internal(format!("Failed to read listener address: {}", e)))?;
+        tracing::info!("Starting HTTP server on {}", local_addr);
+        
+        axum::serve(listener, app)
+            .with_graceful_shutdown(shutdown)
+            .await
+            .map_err(|e| Error::Internal(format!("HTTP server error: {}", e)))?;
+        
+        Ok(())
+    }
+    
+    /// HTTP routes of this node
+    /// 
+    /// Deployments that terminate mutual TLS themselves serve this router and
+    /// attach each connection's `PeerCertificate` to its requests.
+    pub fn router(&self) -> Router {
         let app_state = Arc::new(AppState {
             node_id: self.node.id,
             participants: self.node.participants.clone(),
-            curve_type: self.node.curve_type,
+            sessions: self.node.sessions.clone(),
+            received_shares: self.node.received_shares.clone(),
+            dkg: self.node.dkg.clone(),
+            signer: self.node.signer.clone(),
+            auth: self.node.auth.clone(),
+            insecure_no_auth: self.node.insecure_no_auth,
+            replay_guard: self.node.replay_guard.clone(),
+            clock: self.node.clock.clone(),
+            traffic: self.node.traffic.clone(),
         });
         
-        let app = Router::new()
+        Router::new()
             .route("/health", get(health_check))
-            .route("/shares", post(receive_shares))
+            .route("/shares", post(receive_shares).get(held_shares))
             .route("/commitment", post(verify_commitment))
             .route("/proof", post(verify_proof))
             .route("/reconstruct", post(reconstruct_secret))
+            .route("/contribute", post(receive_contribution))
+            .route("/share-request", post(send_share))
+            .route("/dkg", post(receive_dkg))
+            .route("/sign", post(sign_request))
             .route("/heartbeat", post(receive_heartbeat))
-            .with_state(app_state);
-        
-        tracing::info!("Starting HTTP server on {}", self.node.address);
-        
-        let listener = tokio::net::TcpListener::bind(self.node.address).await
-            .map_err(|e| Error::Internal(format!("Failed to bind to {}: {}", self.node.address, e)))?;
-        
-        axum::serve(listener, app).await
-            .map_err(|e| Error::Internal(format!("HTTP server error: {}", e)))?;
-        
-        Ok(())
+            .route("/state", get(admin_state))
+            .route_layer(middleware::from_fn_with_state(app_state.clone(), authenticate))
+            .with_state(app_state)
     }
 }
 
@@ -413,20 +1880,80 @@ impl NetworkCoordinator {
 #[derive(Clone)]
 struct AppState {
     node_id: ShareId,
-    participants: Arc<RwLock<HashMap<ShareId, Participant>>>,
-    curve_type: CurveType,
+    participants: Arc<RwLock<ParticipantRegistry>>,
+    sessions: SessionMap,
+    received_shares: ReceivedShares,
+    dkg: DkgRounds,
+    signer: ThresholdSigner,
+    auth: Option<Arc<dyn AuthLayer>>,
+    insecure_no_auth: bool,
+    replay_guard: Arc<Mutex<ReplayGuard>>,
+    clock: Arc<dyn Clock>,
+    traffic: Arc<RwLock<TrafficLog>>,
+}
+
+/// Largest envelope the authentication middleware reads
+const MAX_ENVELOPE_BYTES: usize = 2 * 1024 * 1024;
+
+/// Require a valid `SignedEnvelope` on POST requests unless the node is insecure
+/// 
+/// The unwrapped message is passed on, so handlers stay unaware of envelopes.
+/// Rejects with 401 for a missing or bad credential and 409 for a replay.
+async fn authenticate(State(state): State<Arc<AppState>>, request: Request, next: Next) -> Response {
+    if request.method() != Method::POST || state.insecure_no_auth {
+        return next.run(request).await;
+    }
+    
+    let (mut parts, body) = request.into_parts();
+    parts.headers.remove(axum::http::header::CONTENT_LENGTH);
+    let opened = match (&state.auth, axum::body::to_bytes(body, MAX_ENVELOPE_BYTES).await) {
+        (None, _) => Err(Error::AuthError("No authentication layer configured".to_string())),
+        (Some(auth), Ok(bytes)) => match serde_json::from_slice::<SignedEnvelope>(&bytes) {
+            Ok(envelope) => {
+                let peer_certificate = parts.extensions.get::<PeerCertificate>();
+                open_envelope(auth.as_ref(), &state.replay_guard, &envelope, peer_certificate).await
+            }
+            Err(_) => Err(Error::AuthError("Request is not a signed envelope".to_string())),
+        },
+        (Some(_), Err(e)) => Err(Error::AuthError(format!("Unreadable request body: {}", e))),
+    };
+    let body = match opened.and_then(|message| serde_json::to_vec(&message).map_err(Error::from)) {
+        Ok(body) => body,
+        Err(e) => {
+            let status = match e {
+                Error::ReplayedMessage(_) => StatusCode::CONFLICT,
+                _ => StatusCode::UNAUTHORIZED,
+            };
+            tracing::warn!("Rejected request to {}: {}", parts.uri.path(), e);
+            let response = serde_json::json!({
+                "status": "error",
+                "message": e.to_string()
+            });
+            return (status, Json(response)).into_response();
+        }
+    };
+    
+    next.run(Request::from_parts(parts, axum::body::Body::from(body))).await
 }
 
 /// HTTP endpoint handlers
 async fn health_check(State(state): State<Arc<AppState>>) -> (StatusCode, Json<serde_json::Value>) {
-    let timestamp = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs();
+    let timestamp = state.clock.now_unix();
+    
+    // List sessions per curve
+    let mut sessions_by_curve: HashMap<String, Vec<String>> = HashMap::new();
+    for session in state.sessions.read().await.values() {
+        sessions_by_curve.entry(session.curve_type().to_string())
+            .or_default()
+            .push(session.id.to_string());
+    }
+    for session_ids in sessions_by_curve.values_mut() {
+        session_ids.sort();
+    }
     
     let response = serde_json::json!({
         "node_id": state.node_id,
-        "curve_type": state.curve_type.to_string(),
+        "sessions": sessions_by_curve,
         "timestamp": timestamp,
         "status": "healthy"
     });
@@ -434,22 +1961,40 @@ async fn health_check(State(state): State<Arc<AppState>>) -> (StatusCode, Json<s
     (StatusCode::OK, Json(response))
 }
 
+async fn admin_state(State(state): State<Arc<AppState>>) -> (StatusCode, Json<serde_json::Value>) {
+    let participants: Vec<Participant> = state.participants.read().await.values().cloned().collect();
+    let traffic = state.traffic.read().await.sessions().clone();
+    
+    let response = serde_json::json!({
+        "node_id": state.node_id,
+        "participants": participants,
+        "traffic": traffic,
+        "timestamp": state.clock.now_unix(),
+    });
+    
+    (StatusCode::OK, Json(response))
+}
+
 async fn receive_shares(
     State(state): State<Arc<AppState>>,
     Json(message): Json<NetworkMessage>,
 ) -> (StatusCode, Json<serde_json::Value>) {
     match message {
-        NetworkMessage::ShareDistribution { sender_id, shares, commitments, proofs, curve_type } => {
-            tracing::info!("Received shares from participant {}", sender_id);
+        message @ NetworkMessage::ShareDelivery { .. } => {
+            let sender_id = message.sender_id();
+            tracing::info!(
+                "Received shares from participant {} in session {}",
+                sender_id, message.session_id().map(|session_id| session_id.to_string()).unwrap_or_default()
+            );
             
-            // Verify commitments and proofs
-            match verify_share_distribution(&commitments, &proofs, curve_type).await {
-                Ok(_) => {
+            // Verify shares, commitments and proofs on the session's curve
+            match accept_dealing(state.node_id, &state.sessions, &state.received_shares, message).await {
+                Ok(shares_count) => {
                     let response = serde_json::json!({
                         "status": "success",
                         "message": "Shares received and verified",
                         "sender_id": sender_id,
-                        "shares_count": shares.len()
+                        "shares_count": shares_count
                     });
                     (StatusCode::OK, Json(response))
                 }
@@ -459,7 +2004,7 @@ async fn receive_shares(
                         "message": e.to_string(),
                         "sender_id": sender_id
                     });
-                    (StatusCode::BAD_REQUEST, Json(response))
+                    (error_status(&e), Json(response))
                 }
             }
         }
@@ -473,19 +2018,53 @@ async fn receive_shares(
     }
 }
 
+/// Shares this node holds, by session and dealer
+/// 
+/// Lists share ids and commitments only; share values and commitment
+/// randomness never leave the node.
+async fn held_shares(State(state): State<Arc<AppState>>) -> (StatusCode, Json<serde_json::Value>) {
+    let received_shares = state.received_shares.read().await;
+    let mut keys: Vec<&(SessionId, ShareId)> = received_shares.keys().collect();
+    keys.sort();
+    
+    let shares: Vec<serde_json::Value> = keys.into_iter().map(|key| {
+        let (session_id, dealer_id) = key;
+        let committed_share = &received_shares[key];
+        serde_json::json!({
+            "session_id": session_id,
+            "dealer_id": dealer_id,
+            "share_id": committed_share.share.id,
+            "dealing_id": committed_share.share.dealing_id.as_ref().map(hex::encode),
+            "commitment": hex::encode(&committed_share.commitment),
+        })
+    }).collect();
+    
+    let response = serde_json::json!({
+        "node_id": state.node_id,
+        "shares": shares,
+    });
+    (StatusCode::OK, Json(response))
+}
+
 async fn verify_commitment(
     State(state): State<Arc<AppState>>,
     Json(message): Json<NetworkMessage>,
 ) -> (StatusCode, Json<serde_json::Value>) {
     match message {
-        NetworkMessage::CommitmentVerification { sender_id, commitment, value, randomness, curve_type } => {
-            tracing::info!("Verifying commitment from participant {}", sender_id);
+        NetworkMessage::CommitmentVerification { sender_id, session_id, commitment, value, opening } => {
+            tracing::info!("Verifying commitment from participant {} in session {}", sender_id, session_id);
             
-            match verify_commitment_internal(&commitment, &value, &randomness, curve_type).await {
+            let result = match session_curve(&state.sessions, session_id).await {
+                Ok(curve_type) => verify_commitment_internal(&commitment, &value, &opening, curve_type).await,
+                Err(e) => Err(e),
+            };
+            
+            match result {
                 Ok(is_valid) => {
                     let response = serde_json::json!({
                         "status": "success",
                         "sender_id": sender_id,
+                        "session_id": session_id,
                         "commitment": hex::encode(&commitment),
                         "is_valid": is_valid
                     });
@@ -497,7 +2076,7 @@ async fn verify_commitment(
                         "message": e.to_string(),
                         "sender_id": sender_id
                     });
-                    (StatusCode::BAD_REQUEST, Json(response))
+                    (error_status(&e), Json(response))
                 }
             }
         }
@@ -516,14 +2095,20 @@ async fn verify_proof(
     Json(message): Json<NetworkMessage>,
 ) -> (StatusCode, Json<serde_json::Value>) {
     match message {
-        NetworkMessage::ProofVerification { sender_id, proof } => {
-            tracing::info!("Verifying proof from participant {}", sender_id);
+        NetworkMessage::ProofVerification { sender_id, session_id, proof } => {
+            tracing::info!("Verifying proof from participant {} in session {}", sender_id, session_id);
+            
+            let result = match session_curve(&state.sessions, session_id).await {
+                Ok(curve_type) => verify_proof_internal(&proof, curve_type).await,
+                Err(e) => Err(e),
+            };
             
-            match verify_proof_internal(&proof).await {
+            match result {
                 Ok(is_valid) => {
                     let response = serde_json::json!({
                         "status": "success",
                         "sender_id": sender_id,
+                        "session_id": session_id,
                         "proof_commitment": hex::encode(&proof.commitment),
                         "is_valid": is_valid
                     });
@@ -535,7 +2120,7 @@ async fn verify_proof(
                         "message": e.to_string(),
                         "sender_id": sender_id
                     });
-                    (StatusCode::BAD_REQUEST, Json(response))
+                    (error_status(&e), Json(response))
                 }
             }
         }
@@ -554,10 +2139,10 @@ async fn reconstruct_secret(
     Json(message): Json<NetworkMessage>,
 ) -> (StatusCode, Json<serde_json::Value>) {
     match message {
-        NetworkMessage::SecretReconstruction { sender_id, shares, curve_type } => {
-            tracing::info!("Reconstructing secret from participant {}", sender_id);
+        NetworkMessage::SecretReconstruction { sender_id, session_id, shares } => {
+            tracing::info!("Reconstructing secret from participant {} in session {}", sender_id, session_id);
             
-            match reconstruct_secret_internal(&shares, curve_type).await {
+            match reconstruct_in_session(&state.sessions, session_id, &shares, |_| {}).await {
                 Ok(result) => {
                     let response = serde_json::json!({
                         "status": "success",
@@ -574,7 +2159,77 @@ async fn reconstruct_secret(
                         "message": e.to_string(),
                         "sender_id": sender_id
                     });
-                    (StatusCode::BAD_REQUEST, Json(response))
+                    (error_status(&e), Json(response))
+                }
+            }
+        }
+        _ => {
+            let response = serde_json::json!({
+                "status": "error",
+                "message": "Invalid message type"
+            });
+            (StatusCode::BAD_REQUEST, Json(response))
+        }
+    }
+}
+
+async fn receive_contribution(
+    State(state): State<Arc<AppState>>,
+    Json(message): Json<NetworkMessage>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    match message {
+        NetworkMessage::ShareContribution { sender_id, session_id, share } => {
+            tracing::info!("Received share contribution from participant {} in session {}", sender_id, session_id);
+            
+            match accept_contribution(&state.sessions, sender_id, session_id, share).await {
+                Ok(()) => {
+                    let response = serde_json::json!({
+                        "status": "success",
+                        "message": "Share contribution verified",
+                        "sender_id": sender_id
+                    });
+                    (StatusCode::OK, Json(response))
+                }
+                Err(e) => {
+                    let response = serde_json::json!({
+                        "status": "error",
+                        "message": e.to_string(),
+                        "sender_id": sender_id
+                    });
+                    (error_status(&e), Json(response))
+                }
+            }
+        }
+        _ => {
+            let response = serde_json::json!({
+                "status": "error",
+                "message": "Invalid message type"
+            });
+            (StatusCode::BAD_REQUEST, Json(response))
+        }
+    }
+}
+
+/// Answer a share request with this node's share in the response body
+async fn send_share(
+    State(state): State<Arc<AppState>>,
+    Json(message): Json<NetworkMessage>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    match message {
+        NetworkMessage::ShareRequest { sender_id, session_id } => {
+            tracing::info!("Participant {} requested the share of session {}", sender_id, session_id);
+            
+            let response = share_response(state.node_id, &state.participants, &state.sessions, sender_id, session_id).await
+                .and_then(|response| serde_json::to_value(response).map_err(Error::from));
+            match response {
+                Ok(response) => (StatusCode::OK, Json(response)),
+                Err(e) => {
+                    let response = serde_json::json!({
+                        "status": "error",
+                        "message": e.to_string(),
+                        "sender_id": sender_id
+                    });
+                    (error_status(&e), Json(response))
                 }
             }
         }
@@ -588,6 +2243,57 @@ async fn reconstruct_secret(
     }
 }
 
+async fn receive_dkg(
+    State(state): State<Arc<AppState>>,
+    Json(message): Json<NetworkMessage>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let sender_id = message.sender_id();
+    tracing::info!("Received {} from participant {}", message.kind(), sender_id);
+    
+    match accept_dkg_message(state.node_id, &state.participants, &state.dkg, message).await {
+        Ok(()) => {
+            let response = serde_json::json!({
+                "status": "success",
+                "message": "Key generation message recorded",
+                "sender_id": sender_id
+            });
+            (StatusCode::OK, Json(response))
+        }
+        Err(e) => {
+            let response = serde_json::json!({
+                "status": "error",
+                "message": e.to_string(),
+                "sender_id": sender_id
+            });
+            (error_status(&e), Json(response))
+        }
+    }
+}
+
+/// Answer a threshold-signing request with a commitment or partial signature
+async fn sign_request(
+    State(state): State<Arc<AppState>>,
+    Json(message): Json<NetworkMessage>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let sender_id = message.sender_id();
+    tracing::info!("Participant {} sent a {}", sender_id, message.kind());
+    
+    let response = signing_response(
+        state.node_id, &state.participants, &state.sessions, &state.signer, state.clock.now_unix(), message,
+    ).await.and_then(|response| serde_json::to_value(response).map_err(Error::from));
+    match response {
+        Ok(response) => (StatusCode::OK, Json(response)),
+        Err(e) => {
+            let response = serde_json::json!({
+                "status": "error",
+                "message": e.to_string(),
+                "sender_id": sender_id
+            });
+            (error_status(&e), Json(response))
+        }
+    }
+}
+
 async fn receive_heartbeat(
     State(state): State<Arc<AppState>>,
     Json(message): Json<NetworkMessage>,
@@ -596,12 +2302,13 @@ async fn receive_heartbeat(
         NetworkMessage::Heartbeat { sender_id, timestamp } => {
             tracing::debug!("Received heartbeat from participant {}", sender_id);
             
-            // Update participant status
+            // Update participant status on this node's clock
+            let now = state.clock.now_unix();
             let mut participants = state.participants.write().await;
-            if let Some(participant) = participants.get_mut(&sender_id) {
-                participant.last_heartbeat = timestamp;
+            let _ = participants.update(sender_id, |participant| {
+                participant.last_heartbeat = now;
                 participant.is_online = true;
-            }
+            });
             
             let response = serde_json::json!({
                 "status": "success",
@@ -620,14 +2327,278 @@ async fn receive_heartbeat(
     }
 }
 
+/// HTTP status for a rejected message
+fn error_status(error: &Error) -> StatusCode {
+    match error {
+        Error::UnknownSession(_) => StatusCode::NOT_FOUND,
+        Error::ProtocolState { .. } => StatusCode::CONFLICT,
+        Error::PolicyDenied(_) => StatusCode::FORBIDDEN,
+        _ => StatusCode::BAD_REQUEST,
+    }
+}
+
+/// Authenticate an envelope, check its nonce and unwrap its message
+/// 
+/// `peer_certificate` is the client certificate of the connection the
+/// envelope arrived on, if any.
+async fn open_envelope(
+    auth: &dyn AuthLayer,
+    replay_guard: &Mutex<ReplayGuard>,
+    envelope: &SignedEnvelope,
+    peer_certificate: Option<&PeerCertificate>,
+) -> Result<NetworkMessage> {
+    let sender_id = auth.verify_on_connection(envelope, peer_certificate)?;
+    let message = envelope.message()?;
+    
+    // The authenticated peer may only speak for itself
+    if message.sender_id() != sender_id {
+        return Err(Error::AuthError(format!(
+            "Peer {} sent a message claiming to be from {}", sender_id, message.sender_id()
+        )));
+    }
+    
+    replay_guard.lock().await.check(sender_id, envelope.nonce)?;
+    Ok(message)
+}
+
+/// Resolve the curve of a session
+async fn session_curve(sessions: &SessionMap, session_id: SessionId) -> Result<CurveType> {
+    let sessions = sessions.read().await;
+    sessions.get(&session_id)
+        .map(Session::curve_type)
+        .ok_or_else(|| Error::UnknownSession(session_id.to_string()))
+}
+
+/// Resolve the curve of a session, rejecting senders other than a pinned dealer
+/// 
+/// Dealings are refused once the session holds a verified dealing.
+async fn session_dealer_curve(sessions: &SessionMap, session_id: SessionId, sender_id: ShareId) -> Result<CurveType> {
+    let sessions = sessions.read().await;
+    let session = sessions.get(&session_id)
+        .ok_or_else(|| Error::UnknownSession(session_id.to_string()))?;
+    session.require_phase(DEALING_PHASES)?;
+    
+    match session.dealer {
+        Some(dealer) if dealer != sender_id => Err(Error::AuthError(format!(
+            "Participant {} is not the dealer of session {}", sender_id, session_id
+        ))),
+        _ => Ok(session.curve_type()),
+    }
+}
+
+/// Keep the share of a verified distribution addressed to `node_id`
+/// 
+/// The dealing's coefficient commitments are kept to check contributions,
+/// and the session moves to `Verified`.
+async fn keep_own_share(
+    sessions: &SessionMap,
+    node_id: ShareId,
+    session_id: SessionId,
+    shares: Vec<Share>,
+    coefficient_commitments: CoefficientCommitments,
+) {
+    let mut sessions = sessions.write().await;
+    if let Some(session) = sessions.get_mut(&session_id) {
+        session.coefficient_commitments = Some(coefficient_commitments);
+        session.phase = ProtocolPhase::Verified;
+        if let Some(share) = shares.into_iter().find(|share| share.id == node_id) {
+            session.shares.retain(|existing| existing.id != share.id);
+            session.shares.push(share);
+        }
+    }
+}
+
+/// Verify a delivery from its dealer and keep the share addressed to `node_id`
+/// 
+/// The delivery must carry this node's own share; a peer never sees the
+/// others. Returns the number of shares verified.
+async fn accept_dealing(
+    node_id: ShareId,
+    sessions: &SessionMap,
+    received_shares: &ReceivedShares,
+    message: NetworkMessage,
+) -> Result<usize> {
+    let NetworkMessage::ShareDelivery { sender_id, session_id, committed_share, proof, coefficient_commitments } = message else {
+        return Err(Error::InvalidInput(format!("{} carries no share delivery", message.kind())));
+    };
+    if committed_share.share.id != node_id {
+        return Err(Error::InvalidInput(format!(
+            "Share of participant {} delivered to participant {}", committed_share.share.id, node_id
+        )));
+    }
+    let (shares, commitments, proofs) = (vec![committed_share.share.clone()], vec![*committed_share], vec![*proof]);
+    
+    let curve_type = session_dealer_curve(sessions, session_id, sender_id).await?;
+    verify_share_distribution(&shares, &commitments, &proofs, &coefficient_commitments, curve_type).await?;
+    
+    let shares_count = commitments.len();
+    if let Some(own) = commitments.into_iter().find(|committed_share| committed_share.share.id == node_id) {
+        received_shares.write().await.insert((session_id, sender_id), own);
+    }
+    keep_own_share(sessions, node_id, session_id, shares, coefficient_commitments).await;
+    
+    Ok(shares_count)
+}
+
+/// This node's own share of a verified session
+async fn own_share(sessions: &SessionMap, node_id: ShareId, session_id: SessionId) -> Result<Share> {
+    let sessions = sessions.read().await;
+    let session = sessions.get(&session_id)
+        .ok_or_else(|| Error::UnknownSession(session_id.to_string()))?;
+    session.require_phase(VERIFIED_PHASES)?;
+    session.shares.iter()
+        .find(|share| share.id == node_id)
+        .cloned()
+        .ok_or_else(|| Error::InvalidInput(format!("No share of session {} held", session_id)))
+}
+
+/// Answer a registered participant's share request with this node's share
+async fn share_response(
+    node_id: ShareId,
+    participants: &RwLock<ParticipantRegistry>,
+    sessions: &SessionMap,
+    sender_id: ShareId,
+    session_id: SessionId,
+) -> Result<NetworkMessage> {
+    if participants.read().await.get(sender_id).is_none() {
+        return Err(Error::AuthError(format!("Participant {} is not registered", sender_id)));
+    }
+    
+    let share = own_share(sessions, node_id, session_id).await?;
+    Ok(NetworkMessage::ShareResponse { sender_id: node_id, session_id, share })
+}
+
+/// Answer a registered participant's signing request of either round
+async fn signing_response(
+    node_id: ShareId,
+    participants: &RwLock<ParticipantRegistry>,
+    sessions: &SessionMap,
+    signer: &ThresholdSigner,
+    timestamp: u64,
+    message: NetworkMessage,
+) -> Result<NetworkMessage> {
+    let sender_id = message.sender_id();
+    if participants.read().await.get(sender_id).is_none() {
+        return Err(Error::AuthError(format!("Participant {} is not registered", sender_id)));
+    }
+    
+    match message {
+        NetworkMessage::SigningRequest { sender_id, session_id, message, approvals } => {
+            let curve_type = session_curve(sessions, session_id).await?;
+            let context = SigningContext::new(curve_type, timestamp).with_approvals(approvals);
+            let commitment = signer.commit(sessions, node_id, sender_id, session_id, &message, &context).await?;
+            Ok(NetworkMessage::SigningCommitmentResponse { sender_id: node_id, session_id, commitment })
+        }
+        NetworkMessage::PartialSignatureRequest { session_id, message, commitments, .. } => {
+            let partial = signer.sign(sessions, node_id, session_id, &message, &commitments).await?;
+            Ok(NetworkMessage::PartialSignatureResponse { sender_id: node_id, session_id, partial })
+        }
+        other => Err(Error::InvalidInput(format!("{} is not a signing request", other.kind()))),
+    }
+}
+
+/// Sharing parameters and verified coefficient commitments of a session
+async fn verified_dealing(sessions: &SessionMap, session_id: SessionId) -> Result<(SharingParams, CoefficientCommitments)> {
+    let sessions = sessions.read().await;
+    let session = sessions.get(&session_id)
+        .ok_or_else(|| Error::UnknownSession(session_id.to_string()))?;
+    session.require_phase(RECONSTRUCTION_PHASES)?;
+    let coefficient_commitments = session.coefficient_commitments.clone()
+        .ok_or_else(|| Error::InvalidInput(format!("Session {} has no verified dealing", session_id)))?;
+    Ok((session.params.clone(), coefficient_commitments))
+}
+
+/// G·b_{-1}, the public key of the secret the commitments share
+fn group_public_key(coefficient_commitments: &CoefficientCommitments) -> Result<crate::curve::Point> {
+    coefficient_commitments.b_commitments.first()
+        .cloned()
+        .ok_or_else(|| Error::InvalidInput("Coefficient commitments have no residue commitment".to_string()))
+}
+
+/// Keep a peer's own share once it matches the session's dealing
+async fn accept_contribution(sessions: &SessionMap, sender_id: ShareId, session_id: SessionId, share: Share) -> Result<()> {
+    if share.id != sender_id {
+        return Err(Error::AuthError(format!(
+            "Participant {} contributed the share of participant {}", sender_id, share.id
+        )));
+    }
+    
+    let mut sessions = sessions.write().await;
+    let session = sessions.get_mut(&session_id)
+        .ok_or_else(|| Error::UnknownSession(session_id.to_string()))?;
+    session.require_phase(VERIFIED_PHASES)?;
+    let commitments = session.coefficient_commitments.as_ref()
+        .ok_or_else(|| Error::InvalidInput(format!("Session {} has no verified dealing", session_id)))?;
+    if !crate::laurent::verify_share_against_commitments(&share, commitments, session.curve_type())? {
+        return Err(Error::CommitmentError(format!(
+            "Share of participant {} does not match the dealing of session {}", sender_id, session_id
+        )));
+    }
+    
+    session.shares.retain(|existing| existing.id != share.id);
+    session.shares.push(share);
+    Ok(())
+}
+
+/// Record a registered participant's key generation message
+/// 
+/// A sub-share is only accepted if it is addressed to `node_id`.
+async fn accept_dkg_message(
+    node_id: ShareId,
+    participants: &RwLock<ParticipantRegistry>,
+    dkg: &DkgRounds,
+    message: NetworkMessage,
+) -> Result<()> {
+    let sender_id = message.sender_id();
+    if participants.read().await.get(sender_id).is_none() {
+        return Err(Error::AuthError(format!("Participant {} is not registered", sender_id)));
+    }
+    
+    match message {
+        NetworkMessage::DkgCommitment { sender_id, session_id, coefficient_commitments } => {
+            dkg.record_commitments(session_id, sender_id, coefficient_commitments).await
+        }
+        NetworkMessage::DkgShare { sender_id, session_id, share } => {
+            if share.id != node_id {
+                return Err(Error::InvalidInput(format!(
+                    "Sub-share of participant {} sent to participant {}", share.id, node_id
+                )));
+            }
+            dkg.record_share(session_id, sender_id, share).await
+        }
+        NetworkMessage::DkgComplaint { sender_id, session_id, accused, reason } => {
+            tracing::warn!("Participant {} complains about participant {}: {}", sender_id, accused, reason);
+            dkg.record_complaint(session_id, sender_id, accused).await
+        }
+        NetworkMessage::DkgEcho { sender_id, session_id, digest } => {
+            dkg.record_echo(session_id, sender_id, digest).await
+        }
+        other => Err(Error::InvalidInput(format!("{} is not a key generation message", other.kind()))),
+    }
+}
+
 /// Internal verification functions
 async fn verify_share_distribution(
+    shares: &[Share],
     commitments: &[CommittedShare],
     proofs: &[ZeroKnowledgeProof],
+    coefficient_commitments: &CoefficientCommitments,
     curve_type: CurveType,
 ) -> Result<()> {
     let pedersen = PedersenCommitment::new(curve_type)?;
     
+    // Every share must lie on the committed series
+    for share in shares.iter().chain(commitments.iter().map(|committed_share| &committed_share.share)) {
+        if !crate::laurent::verify_share_against_commitments(share, coefficient_commitments, curve_type)? {
+            return Err(Error::CommitmentError(format!(
+                "Share {} does not match the coefficient commitments", share.id
+            )));
+        }
+    }
+    
+    // Reused randomness leaks the difference of the committed values
+    crate::pedersen::utils::ensure_distinct_randomness(commitments)?;
+    
     // Verify commitments
     for committed_share in commitments {
         if !committed_share.verify(curve_type)? {
@@ -635,9 +2606,14 @@ async fn verify_share_distribution(
         }
     }
     
-    // Verify proofs
-    for proof in proofs {
-        if !proof.verify(&pedersen)? {
+    // Verify proofs against the published commitments
+    if proofs.len() != commitments.len() {
+        return Err(Error::ZKProofError(format!(
+            "Expected {} proofs, got {}", commitments.len(), proofs.len()
+        )));
+    }
+    for (committed_share, proof) in commitments.iter().zip(proofs) {
+        if !proof.verify_against(&pedersen, &committed_share.commitment)? {
             return Err(Error::ZKProofError("Invalid proof".to_string()));
         }
     }
@@ -648,78 +2624,204 @@ async fn verify_share_distribution(
 async fn verify_commitment_internal(
     commitment: &[u8],
     value: &[u8],
-    randomness: &[u8],
+    opening: &CommitmentOpening,
     curve_type: CurveType,
 ) -> Result<bool> {
-    let pedersen = PedersenCommitment::new(curve_type)?;
-    let curve = crate::curve::create_curve(curve_type);
+    if opening.curve_type != curve_type {
+        return Err(Error::InvalidCurve(format!("Opening is on {}, session is on {}", opening.curve_type, curve_type)));
+    }
+    let pedersen = PedersenCommitment::new(opening.curve_type)?;
+    let curve = crate::curve::curve_backend(opening.curve_type)?;
     let value_scalar = curve.scalar_from_bytes(value)?;
     
-    pedersen.verify(commitment, &value_scalar, randomness)
+    pedersen.open(commitment, opening, &value_scalar)
 }
 
-async fn verify_proof_internal(proof: &ZeroKnowledgeProof) -> Result<bool> {
+async fn verify_proof_internal(proof: &ZeroKnowledgeProof, curve_type: CurveType) -> Result<bool> {
+    if proof.curve_type != curve_type {
+        return Err(Error::InvalidCurve(format!("Proof is on {}, session is on {}", proof.curve_type, curve_type)));
+    }
     let pedersen = PedersenCommitment::new(proof.curve_type)?;
     proof.verify(&pedersen)
 }
 
-async fn reconstruct_secret_internal(
+/// Reconstruct a session's secret from `shares`
+/// 
+/// Only sessions with a verified dealing reconstruct, so shares cannot be
+/// pushed into a session before it was dealt. Shares that do not match the
+/// session's coefficient commitments are skipped and listed in
+/// `rejected_participants`; the session's `threshold` must still be met.
+/// The session's phase is left to the coordinator's `request_reconstruction`.
+async fn reconstruct_in_session(
+    sessions: &SessionMap,
+    session_id: SessionId,
     shares: &[Share],
-    curve_type: CurveType,
+    on_progress: impl FnMut(ReconstructionProgress) + Send,
 ) -> Result<crate::types::ReconstructionResult> {
-    let params = crate::types::SharingParams {
-        curve_type,
-        threshold: shares.len(),
-        participants: shares.len(),
-    };
+    let (params, coefficient_commitments) = verified_dealing(sessions, session_id).await?;
+    reconstruct_with_progress(shares, &params, coefficient_commitments, on_progress)
+}
+
+/// Move a session to `phase`, if the node takes part in it
+async fn set_phase(sessions: &SessionMap, session_id: SessionId, phase: ProtocolPhase) {
+    if let Some(session) = sessions.write().await.get_mut(&session_id) {
+        session.phase = phase;
+    }
+}
+
+fn reconstruct_with_progress(
+    shares: &[Share],
+    params: &SharingParams,
+    coefficient_commitments: CoefficientCommitments,
+    on_progress: impl FnMut(ReconstructionProgress) + Send,
+) -> Result<crate::types::ReconstructionResult> {
+    let mut reconstructor = Reconstructor::new(params)
+        .with_commitments(coefficient_commitments)
+        .with_progress(on_progress);
+    let mut rejected_participants = Vec::new();
+    for share in shares {
+        if let Err(e) = reconstructor.add_share(share.clone()) {
+            tracing::warn!("Skipping share {}: {}", share.id, e);
+            rejected_participants.push(share.id);
+        }
+    }
     
-    let laurent = LaurentSeries::new(&params)?;
-    laurent.reconstruct_secret(shares)
+    let mut result = reconstructor.finish()?;
+    result.rejected_participants = rejected_participants;
+    Ok(result)
 }
 
 /// Utility functions for network operations
 pub mod utils {
     use super::*;
     
-    /// Create test network with multiple nodes
+    /// Create test network with multiple nodes able to run ceremonies on `curve_types`
+    /// 
+    /// Nodes advertise the fixed ports `8000 + id`; use
+    /// `create_ephemeral_test_network` when several networks may run at once.
     pub async fn create_test_network(
         num_participants: usize,
-        curve_type: CurveType,
+        curve_types: &[CurveType],
     ) -> Result<Vec<NetworkCoordinator>> {
-        let mut coordinators = Vec::new();
+        create_test_network_on(8000, num_participants, curve_types).await
+    }
+    
+    /// Create test network whose nodes advertise the ports `port_base + id`
+    pub async fn create_test_network_on(
+        port_base: u16,
+        num_participants: usize,
+        curve_types: &[CurveType],
+    ) -> Result<Vec<NetworkCoordinator>> {
+        let addresses = (1..=num_participants)
+            .map(|i| format!("127.0.0.1:{}", port_base as usize + i).parse())
+            .collect::<std::result::Result<Vec<SocketAddr>, _>>()
+            .map_err(|e| Error::InvalidInput(format!("Invalid test address: {}", e)))?;
         
-        for i in 1..=num_participants {
-            let address: SocketAddr = format!("127.0.0.1:{}", 8000 + i).parse()?;
-            let node = NetworkNode::new(i as ShareId, address, curve_type);
-            let mut coordinator = NetworkCoordinator::new(node);
-            
-            // Add other participants
-            for j in 1..=num_participants {
-                if i != j {
-                    let other_address: SocketAddr = format!("127.0.0.1:{}", 8000 + j).parse()?;
-                    let participant = Participant {
-                        id: j as ShareId,
-                        address: other_address,
-                        curve_type,
-                        is_online: true,
-                        last_heartbeat: 0,
-                    };
-                    coordinator.node.add_participant(participant).await?;
+        build_test_network(&addresses, curve_types).await
+    }
+    
+    /// Create and start a test network on ephemeral ports
+    /// 
+    /// Every node binds `127.0.0.1:0` before the registries are filled, so
+    /// nodes and their peers record the ports the OS actually assigned.
+    /// Returns the coordinators with the handles of their running servers.
+    pub async fn create_ephemeral_test_network(
+        num_participants: usize,
+        curve_types: &[CurveType],
+    ) -> Result<(Vec<NetworkCoordinator>, Vec<tokio::task::JoinHandle<Result<()>>>)> {
+        create_configured_test_network(num_participants, curve_types, &NodeConfig::default()).await
+    }
+    
+    /// Create and start an ephemeral-port test network whose nodes apply `config`
+    pub async fn create_configured_test_network(
+        num_participants: usize,
+        curve_types: &[CurveType],
+        config: &NodeConfig,
+    ) -> Result<(Vec<NetworkCoordinator>, Vec<tokio::task::JoinHandle<Result<()>>>)> {
+        // Step 1: Bind listeners to reserve the ports
+        let mut listeners = Vec::new();
+        let mut addresses = Vec::new();
+        for _ in 1..=num_participants {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await
+                .map_err(|e| Error::Internal(format!("Failed to bind ephemeral port: {}", e)))?;
+            addresses.push(listener.local_addr()
+                .map_err(|e| Error::Internal(format!("Failed to read listener address: {}", e)))?);
+            listeners.push(listener);
+        }
+        
+        // Step 2: Register the assigned addresses
+        let mut coordinators = build_test_network(&addresses, curve_types).await?;
+        for coordinator in coordinators.iter_mut() {
+            coordinator.node = coordinator.node.clone().with_config(config)?;
+        }
+        
+        // Step 3: Serve on the bound listeners
+        let handles = coordinators.iter()
+            .zip(listeners)
+            .map(|(coordinator, listener)| {
+                let coordinator_clone = coordinator.clone();
+                tokio::spawn(async move {
+                    coordinator_clone.serve(listener).await
+                })
+            })
+            .collect();
+        
+        Ok((coordinators, handles))
+    }
+    
+    /// Build coordinators for nodes `1..=addresses.len()` that know each other
+    async fn build_test_network(
+        addresses: &[SocketAddr],
+        curve_types: &[CurveType],
+    ) -> Result<Vec<NetworkCoordinator>> {
+        let nodes: Vec<NetworkNode> = addresses.iter()
+            .enumerate()
+            .map(|(i, &address)| NetworkNode::new((i + 1) as ShareId, address))
+            .collect();
+        
+        // Published entries, with per-curve keys on the curves the identity does not cover
+        let mut entries = Vec::new();
+        for node in &nodes {
+            let mut entry = node.participant();
+            for &curve_type in curve_types {
+                if let std::collections::hash_map::Entry::Vacant(slot) = entry.public_keys.entry(curve_type) {
+                    let curve = crate::curve::curve_backend(curve_type)?;
+                    let secret = curve.random_scalar()?;
+                    slot.insert(curve.generator().mul(&secret)?.to_compressed_bytes()?);
                 }
             }
-            
-            coordinators.push(coordinator);
+            entries.push(entry);
+        }
+        
+        let mut coordinators = Vec::new();
+        for node in nodes {
+            // Add other participants
+            for entry in entries.iter().filter(|entry| entry.id != node.id) {
+                node.add_participant(Participant { last_heartbeat: 0, ..entry.clone() }).await?;
+            }
+            coordinators.push(NetworkCoordinator::new(node));
         }
         
         Ok(coordinators)
     }
     
-    /// Run distributed z-MPC protocol
+    /// Initialize a session on the first coordinator and join the others to it
+    pub async fn initialize_session(coordinators: &mut [NetworkCoordinator], params: SharingParams) -> Result<SessionId> {
+        let (dealer, others) = coordinators.split_first_mut()
+            .ok_or_else(|| Error::InvalidInput("No coordinators to run the session".to_string()))?;
+        let session_id = dealer.initialize_protocol(params.clone()).await?;
+        for coordinator in others {
+            coordinator.join_protocol(session_id, params.clone()).await?;
+        }
+        Ok(session_id)
+    }
+    
+    /// Run distributed z-MPC protocol in a new session and return its id
     pub async fn run_distributed_protocol(
         coordinators: &mut [NetworkCoordinator],
+        curve_type: CurveType,
         threshold: usize,
-    ) -> Result<()> {
-        let curve_type = coordinators[0].node.curve_type;
+    ) -> Result<SessionId> {
         let participants = coordinators.len();
         
         let params = crate::types::SharingParams {
@@ -729,15 +2831,79 @@ pub mod utils {
         };
         
         // Initialize all coordinators
-        for coordinator in coordinators.iter_mut() {
-            coordinator.initialize_protocol(params.clone()).await?;
-        }
+        let session_id = initialize_session(coordinators, params).await?;
         
         // Dealer distributes shares
-        coordinators[0].distribute_shares().await?;
+        let report = coordinators[0].distribute_shares(session_id).await?;
+        if !report.is_complete() {
+            tracing::warn!("Shares of session {} did not reach {} participant(s)", session_id, report.failed.len());
+        }
         
-        tracing::info!("Distributed z-MPC protocol completed");
-        Ok(())
+        tracing::info!("Distributed z-MPC protocol completed for session {}", session_id);
+        Ok(session_id)
+    }
+    
+    /// Reconstruct a session's secret on a quorum of nodes over HTTP
+    /// 
+    /// The first `threshold` coordinators form the quorum. Each posts its own
+    /// share to the others, which check it against the dealing, and then
+    /// reconstructs from the shares it holds. Returns one result per member.
+    pub async fn reconstruct_over_network(
+        coordinators: &[NetworkCoordinator],
+        session_id: SessionId,
+    ) -> Result<Vec<crate::types::ReconstructionResult>> {
+        let first = coordinators.first()
+            .ok_or_else(|| Error::InvalidInput("No coordinators to reconstruct with".to_string()))?;
+        let threshold = first.node.sessions.read().await.get(&session_id)
+            .map(|session| session.params.threshold)
+            .ok_or_else(|| Error::UnknownSession(session_id.to_string()))?;
+        if coordinators.len() < threshold {
+            return Err(Error::InsufficientShares { required: threshold, got: coordinators.len() });
+        }
+        let quorum = &coordinators[..threshold];
+        let quorum_ids: Vec<ShareId> = quorum.iter().map(|coordinator| coordinator.node.id).collect();
+        
+        // Step 1: Exchange shares within the quorum
+        for coordinator in quorum {
+            coordinator.contribute_share(session_id, &quorum_ids).await?;
+        }
+        
+        // Step 2: Every member reconstructs on its own
+        let mut results = Vec::new();
+        for coordinator in quorum {
+            results.push(coordinator.node.reconstruct_session(session_id).await?);
+        }
+        Ok(results)
+    }
+    
+    /// Bind and serve every coordinator until `shutdown` flips to true
+    /// 
+    /// All listeners are bound before this returns, so peers can be reached
+    /// right away and port conflicts surface as errors.
+    pub async fn serve_all_until(
+        coordinators: &[NetworkCoordinator],
+        shutdown: tokio::sync::watch::Receiver<bool>,
+    ) -> Result<Vec<tokio::task::JoinHandle<Result<()>>>> {
+        let mut listeners = Vec::new();
+        for coordinator in coordinators {
+            listeners.push(coordinator.bind().await?);
+        }
+        
+        let handles = coordinators.iter()
+            .zip(listeners)
+            .map(|(coordinator, listener)| {
+                let coordinator_clone = coordinator.clone();
+                let mut shutdown = shutdown.clone();
+                tokio::spawn(async move {
+                    let stopped = async move {
+                        let _ = shutdown.wait_for(|stop| *stop).await;
+                    };
+                    coordinator_clone.serve_until(listener, stopped).await
+                })
+            })
+            .collect();
+        
+        Ok(handles)
     }
     
     /// Start all HTTP servers for network coordinators
@@ -754,4 +2920,4 @@ pub mod utils {
         
         Ok(handles)
     }
-} 
\ No newline at end of file
+} 
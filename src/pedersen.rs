@@ -3,90 +3,283 @@
 //! This module implements Pedersen commitments for the b_{-1,i} shares:
 //! C_i = g^{b_{-1,i}} * h^{r_i}
 //! 
+//! Group elements P (public keys, nonce points) are committed as C = P * h^r.
+//! 
+//! h is derived by hashing a fixed domain tag onto the curve, so its discrete
+//! log relative to g is unknown and every party derives the same parameters.
+//! 
 //! Provides commitment generation and verification for trustless reconstruction.
 
-use crate::{Error, Result, CurveType, Randomness, Commitment};
-use crate::curve::{Curve, Scalar, Point, create_curve};
+use crate::{Error, Result, CurveType, Randomness, Commitment, ShareId};
+use crate::curve::{Curve, Scalar, Point, PrecomputedPoint, curve_backend};
 use crate::laurent::Share;
+use crate::zkp::ZeroKnowledgeProof;
+use rand::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
-use rand::Rng;
+use sha2::{Sha256, Digest};
+use std::sync::OnceLock;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Pedersen Commitment parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PedersenCommitment {
     pub curve_type: CurveType,
     pub g: Point,  // Generator point
-    pub h: Point,  // Random generator point
+    pub h: Point,  // Nothing-up-my-sleeve generator point
+    /// Fixed-base tables for g and h, built lazily after deserialization
+    #[serde(skip)]
+    tables: OnceLock<(PrecomputedPoint, PrecomputedPoint)>,
 }
 
+/// Portable form of the commitment parameters
+/// 
+/// Generators are stored as hex compressed points, so a committing session
+/// can be pinned and reloaded elsewhere for verification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PedersenParameters {
+    pub curve_type: CurveType,
+    #[serde(with = "crate::types::hex_bytes")]
+    pub g: Vec<u8>,
+    #[serde(with = "crate::types::hex_bytes")]
+    pub h: Vec<u8>,
+}
+
+/// Domain separation message hashed onto the curve to derive h
+const PEDERSEN_DST: &[u8] = b"z-mpc-pedersen-h";
+
 impl PedersenCommitment {
     /// Create new Pedersen commitment scheme
+    /// 
+    /// The parameters are deterministic: independently constructed instances
+    /// for the same curve verify each other's commitments.
     pub fn new(curve_type: CurveType) -> Result<Self> {
-        let curve = create_curve(curve_type);
+        let curve = curve_backend(curve_type)?;
         let g = curve.generator();
+        let h = derive_h(curve)?;
         
-        // Generate random generator h
-        let h_scalar = curve.random_scalar()?;
-        let h = g.mul(&h_scalar)?;
+        let tables = OnceLock::from((PrecomputedPoint::new(&g)?, PrecomputedPoint::new(&h)?));
         
         Ok(Self {
             curve_type,
             g,
             h,
+            tables,
         })
     }
     
+    /// Create a commitment scheme over custom generators
+    /// 
+    /// Binding relies on nobody knowing log_g(h); callers are responsible
+    /// for how `h` was generated.
+    pub fn from_parameters(curve_type: CurveType, g: Point, h: Point) -> Result<Self> {
+        for point in [&g, &h] {
+            if point.curve_type != curve_type {
+                return Err(Error::InvalidCurve(format!(
+                    "Generator on {} does not match commitment scheme on {}", point.curve_type, curve_type
+                )));
+            }
+        }
+        if g.is_identity() || h.is_identity() || g == h {
+            return Err(Error::InvalidInput("Generators must be distinct non-identity points".to_string()));
+        }
+        
+        let tables = OnceLock::from((PrecomputedPoint::new(&g)?, PrecomputedPoint::new(&h)?));
+        
+        Ok(Self {
+            curve_type,
+            g,
+            h,
+            tables,
+        })
+    }
+    
+    /// Export the generators for storage or transfer
+    pub fn to_parameters(&self) -> Result<PedersenParameters> {
+        Ok(PedersenParameters {
+            curve_type: self.curve_type,
+            g: self.g.to_compressed_bytes()?,
+            h: self.h.to_compressed_bytes()?,
+        })
+    }
+    
+    /// Reload generators exported by `to_parameters`
+    pub fn from_exported(parameters: &PedersenParameters) -> Result<Self> {
+        let g = Point::from_compressed_bytes(parameters.curve_type, &parameters.g)?;
+        let h = Point::from_compressed_bytes(parameters.curve_type, &parameters.h)?;
+        
+        Self::from_parameters(parameters.curve_type, g, h)
+    }
+    
     /// Create commitment for a share value
     pub fn commit(&self, value: &Scalar, randomness: &Randomness) -> Result<Commitment> {
-        let curve = create_curve(self.curve_type);
+        let curve = curve_backend(self.curve_type)?;
         let r = curve.scalar_from_bytes(randomness)?;
         
         // C = g^value * h^r
-        let g_value = self.g.mul(value)?;
-        let h_r = self.h.mul(&r)?;
-        let commitment_point = g_value.add(&h_r)?;
+        let commitment_point = self.mul_gh(value, &r)?;
         
-        Ok(commitment_point.to_compressed_bytes()?)
+        commitment_point.to_compressed_bytes()
+    }
+    
+    /// Create commitment for a share with fresh randomness
+    /// 
+    /// Returns the randomness with the commitment; it is needed to open or
+    /// verify the commitment later.
+    pub fn commit_share(&self, share: &Share) -> Result<(Commitment, Randomness)> {
+        self.commit_share_with_rng(share, &mut rand::thread_rng())
     }
     
-    /// Create commitment for a share
-    pub fn commit_share(&self, share: &Share) -> Result<Commitment> {
-        let curve = create_curve(self.curve_type);
+    /// Create commitment for a share with randomness drawn from `rng`
+    pub fn commit_share_with_rng<R: RngCore + CryptoRng>(&self, share: &Share, rng: &mut R) -> Result<(Commitment, Randomness)> {
+        let curve = curve_backend(self.curve_type)?;
         let value = curve.scalar_from_bytes(&share.value)?;
         
-        // Generate random randomness
-        let mut rng = rand::thread_rng();
-        let randomness: Vec<u8> = (0..32).map(|_| rng.gen()).collect();
+        let randomness = self.generate_randomness_with_rng(rng);
+        let commitment = self.commit(&value, &randomness)?;
         
-        self.commit(&value, &randomness)
+        Ok((commitment, randomness))
     }
     
     /// Verify commitment
+    /// 
+    /// An identity commitment is rejected with `Error::ZKProofError`.
     pub fn verify(&self, commitment: &Commitment, value: &Scalar, randomness: &Randomness) -> Result<bool> {
-        let curve = create_curve(self.curve_type);
+        if Point::from_compressed_bytes(self.curve_type, commitment).is_ok_and(|point| point.is_identity()) {
+            return Err(Error::ZKProofError("Commitment is the identity point".to_string()));
+        }
+        
+        let curve = curve_backend(self.curve_type)?;
         let r = curve.scalar_from_bytes(randomness)?;
         
         // Recompute commitment
-        let g_value = self.g.mul(value)?;
-        let h_r = self.h.mul(&r)?;
-        let computed_commitment = g_value.add(&h_r)?;
+        let computed_commitment = self.mul_gh(value, &r)?;
         let computed_bytes = computed_commitment.to_compressed_bytes()?;
         
-        Ok(commitment == &computed_bytes)
+        Ok(bool::from(computed_bytes.as_slice().ct_eq(commitment)))
+    }
+    
+    /// Create commitment to a group element: C = P + h^r
+    /// 
+    /// For committing to public keys or nonce points whose discrete log is
+    /// unknown. A point commitment to P = g^x equals the scalar commitment to x.
+    pub fn commit_point(&self, point: &Point, randomness: &Randomness) -> Result<Commitment> {
+        if point.curve_type != self.curve_type {
+            return Err(Error::InvalidCurve(format!(
+                "Point on {} does not match commitment scheme on {}", point.curve_type, self.curve_type
+            )));
+        }
+        
+        let curve = curve_backend(self.curve_type)?;
+        let r = curve.scalar_from_bytes(randomness)?;
+        let commitment_point = point.add(&self.mul_h(&r)?)?;
+        
+        commitment_point.to_compressed_bytes()
+    }
+    
+    /// Verify commitment to a group element
+    pub fn verify_point(&self, commitment: &Commitment, point: &Point, randomness: &Randomness) -> Result<bool> {
+        let computed_bytes = self.commit_point(point, randomness)?;
+        
+        Ok(bool::from(computed_bytes.as_slice().ct_eq(commitment)))
+    }
+    
+    /// Check an authorized opening of `commitment` against the expected value
+    /// 
+    /// Unlike a `ZeroKnowledgeProof`, an opening reveals the randomness and so
+    /// gives up hiding; only hand it to parties entitled to learn the value.
+    pub fn open(&self, commitment: &[u8], opening: &CommitmentOpening, expected_value: &Scalar) -> Result<bool> {
+        if opening.curve_type != self.curve_type || expected_value.curve_type != self.curve_type {
+            return Err(Error::InvalidCurve(format!(
+                "Opening on {} does not match commitment scheme on {}", opening.curve_type, self.curve_type
+            )));
+        }
+        
+        let value_matches = CommitmentOpening::hash_value(expected_value).as_slice().ct_eq(&opening.value_hash);
+        let recomputed = self.commit(expected_value, &opening.randomness)?;
+        let commitment_matches = recomputed.as_slice().ct_eq(commitment);
+        
+        Ok(bool::from(value_matches & commitment_matches))
     }
     
     /// Verify share commitment
     pub fn verify_share_commitment(&self, share: &Share, commitment: &Commitment, randomness: &Randomness) -> Result<bool> {
-        let curve = create_curve(self.curve_type);
+        let curve = curve_backend(self.curve_type)?;
         let value = curve.scalar_from_bytes(&share.value)?;
         
         self.verify(commitment, &value, randomness)
     }
     
     /// Generate random randomness for commitment
+    /// 
+    /// Always a canonical scalar encoding, so `commit` accepts it.
     pub fn generate_randomness(&self) -> Randomness {
-        let mut rng = rand::thread_rng();
-        (0..32).map(|_| rng.gen()).collect()
+        self.generate_randomness_with_rng(&mut rand::thread_rng())
+    }
+    
+    /// Generate commitment randomness from `rng`
+    pub fn generate_randomness_with_rng<R: RngCore + CryptoRng>(&self, rng: &mut R) -> Randomness {
+        match curve_backend(self.curve_type) {
+            Ok(curve) => sample_scalar(curve, rng).into_bytes(),
+            // A disabled curve has no scalar width and is rejected by `commit` anyway
+            Err(_) => Randomness::new(),
+        }
+    }
+    
+    /// Commit to a value and prove knowledge of its opening in one pass
+    /// 
+    /// The commitment is computed once and reused as the proof's statement,
+    /// instead of being recomputed inside `ZeroKnowledgeProof::prove`.
+    /// `context` is bound into the proof's challenge.
+    pub fn commit_and_prove<R: RngCore + CryptoRng>(
+        &self,
+        value: &Scalar,
+        randomness: &Randomness,
+        context: &[u8],
+        rng: &mut R,
+    ) -> Result<(Commitment, ZeroKnowledgeProof)> {
+        let curve = curve_backend(self.curve_type)?;
+        let commitment = self.commit(value, randomness)?;
+        
+        let alpha = sample_scalar(curve, rng);
+        let beta = sample_scalar(curve, rng);
+        let mut proof = ZeroKnowledgeProof::new(self.curve_type).with_context(context);
+        proof.prove_opening(self, commitment.clone(), value, randomness, &alpha, &beta)?;
+        
+        Ok((commitment, proof))
+    }
+    
+    /// g^a * h^b through the precomputed tables
+    pub(crate) fn mul_gh(&self, a: &Scalar, b: &Scalar) -> Result<Point> {
+        match self.generator_tables()? {
+            Some((g, h)) => g.mul_add(a, h, b),
+            None => curve_backend(self.curve_type)?.msm(&[self.g.clone(), self.h.clone()], &[a.clone(), b.clone()]),
+        }
+    }
+    
+    /// g^a through the precomputed table
+    pub(crate) fn mul_g(&self, a: &Scalar) -> Result<Point> {
+        match self.generator_tables()? {
+            Some((g, _)) => g.mul(a),
+            None => self.g.mul(a),
+        }
+    }
+    
+    /// h^b through the precomputed table
+    pub(crate) fn mul_h(&self, b: &Scalar) -> Result<Point> {
+        match self.generator_tables()? {
+            Some((_, h)) => h.mul(b),
+            None => self.h.mul(b),
+        }
+    }
+    
+    /// Tables for g and h, or None if the generators were replaced after construction
+    fn generator_tables(&self) -> Result<Option<&(PrecomputedPoint, PrecomputedPoint)>> {
+        if self.tables.get().is_none() {
+            let _ = self.tables.set((PrecomputedPoint::new(&self.g)?, PrecomputedPoint::new(&self.h)?));
+        }
+        
+        Ok(self.tables.get().filter(|(g, h)| g.point() == &self.g && h.point() == &self.h))
     }
     
     /// Get commitment parameters
@@ -119,7 +312,7 @@ impl PedersenCommitment {
             return Err(Error::InvalidInput("All arrays must have same length".to_string()));
         }
         
-        for (commitment, value, rand) in commitments.iter().zip(values.iter()).zip(randomness.iter()) {
+        for ((commitment, value), rand) in commitments.iter().zip(values.iter()).zip(randomness.iter()) {
             if !self.verify(commitment, value, rand)? {
                 return Ok(false);
             }
@@ -129,38 +322,69 @@ impl PedersenCommitment {
     }
 }
 
-/// Commitment proof for zero-knowledge verification
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CommitmentProof {
-    pub commitment: Commitment,
+/// Derive h by hashing `PEDERSEN_DST` onto the curve
+/// 
+/// Uses the curve's RFC 9380 `hash_to_point`, which runs in constant time and
+/// lands in the prime-order subgroup, so nobody knows the discrete log of h.
+fn derive_h(curve: &dyn Curve) -> Result<Point> {
+    let h = curve.hash_to_point(PEDERSEN_DST)?;
+    if h.is_identity() {
+        return Err(Error::CurveError("Failed to derive Pedersen generator h".to_string()));
+    }
+    Ok(h)
+}
+
+/// Sample a uniformly random non-zero scalar from `rng` by rejection
+pub(crate) fn sample_scalar<R: RngCore + CryptoRng>(curve: &dyn Curve, rng: &mut R) -> Scalar {
+    let mut bytes = vec![0u8; curve.scalar_byte_len()];
+    loop {
+        rng.fill_bytes(&mut bytes);
+        if let Ok(scalar) = curve.scalar_from_bytes(&bytes) {
+            if scalar.value.iter().any(|&byte| byte != 0) {
+                return scalar;
+            }
+        }
+    }
+}
+
+/// Domain separation tag for opening value hashes
+const OPENING_VALUE_DOMAIN: &[u8] = b"z-mpc-opening-value";
+
+/// Authorized opening of a Pedersen commitment
+/// 
+/// Carries the commitment randomness and a hash binding the opening to the
+/// committed value. This is not a zero-knowledge proof: whoever holds it can
+/// check a guessed value, so use `ZeroKnowledgeProof` when the value must
+/// stay hidden.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitmentOpening {
+    pub value_hash: Vec<u8>,
     pub randomness: Randomness,
+    #[serde(alias = "curve")]
     pub curve_type: CurveType,
 }
 
-impl CommitmentProof {
-    /// Create new commitment proof
-    pub fn new(commitment: Commitment, randomness: Randomness, curve_type: CurveType) -> Self {
+/// Former name of `CommitmentOpening`
+#[deprecated(note = "an opening reveals the randomness, use CommitmentOpening")]
+pub type CommitmentProof = CommitmentOpening;
+
+impl CommitmentOpening {
+    /// Create opening of a commitment to `value` under `randomness`
+    pub fn new(value: &Scalar, randomness: Randomness) -> Self {
         Self {
-            commitment,
+            value_hash: Self::hash_value(value),
             randomness,
-            curve_type,
+            curve_type: value.curve_type,
         }
     }
     
-    /// Verify the proof
-    pub fn verify(&self, value: &Scalar) -> Result<bool> {
-        let pedersen = PedersenCommitment::new(self.curve_type)?;
-        pedersen.verify(&self.commitment, value, &self.randomness)
-    }
-    
-    /// Get commitment bytes
-    pub fn commitment_bytes(&self) -> &[u8] {
-        &self.commitment
-    }
-    
-    /// Get randomness bytes
-    pub fn randomness_bytes(&self) -> &[u8] {
-        &self.randomness
+    /// Hash binding an opening to a value
+    pub fn hash_value(value: &Scalar) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(OPENING_VALUE_DOMAIN);
+        hasher.update(value.curve_type.to_string().as_bytes());
+        hasher.update(value.canonical_bytes());
+        hasher.finalize().to_vec()
     }
 }
 
@@ -170,7 +394,8 @@ pub struct CommittedShare {
     pub share: Share,
     pub commitment: Commitment,
     pub randomness: Randomness,
-    pub proof: Option<CommitmentProof>,
+    #[serde(alias = "proof")]
+    pub opening: Option<CommitmentOpening>,
 }
 
 impl CommittedShare {
@@ -180,13 +405,13 @@ impl CommittedShare {
             share,
             commitment,
             randomness,
-            proof: None,
+            opening: None,
         }
     }
     
-    /// Add zero-knowledge proof
-    pub fn with_proof(mut self, proof: CommitmentProof) -> Self {
-        self.proof = Some(proof);
+    /// Attach an authorized opening
+    pub fn with_opening(mut self, opening: CommitmentOpening) -> Self {
+        self.opening = Some(opening);
         self
     }
     
@@ -207,9 +432,26 @@ impl CommittedShare {
     }
 }
 
+impl Zeroize for CommittedShare {
+    fn zeroize(&mut self) {
+        self.share.zeroize();
+        self.randomness.zeroize();
+    }
+}
+
+impl Drop for CommittedShare {
+    fn drop(&mut self) {
+        self.randomness.zeroize();
+    }
+}
+
+// The share wipes itself; `Drop` covers the randomness
+impl ZeroizeOnDrop for CommittedShare {}
+
 /// Utility functions for Pedersen commitments
 pub mod utils {
     use super::*;
+    use std::collections::HashMap;
     
     /// Create commitments for all shares
     pub fn commit_all_shares(shares: &[Share], curve_type: CurveType) -> Result<Vec<CommittedShare>> {
@@ -217,8 +459,7 @@ pub mod utils {
         let mut committed_shares = Vec::new();
         
         for share in shares {
-            let randomness = pedersen.generate_randomness();
-            let commitment = pedersen.commit_share(share)?;
+            let (commitment, randomness) = pedersen.commit_share(share)?;
             
             committed_shares.push(CommittedShare::new(
                 share.clone(),
@@ -227,9 +468,36 @@ pub mod utils {
             ));
         }
         
+        // Fresh randomness must never repeat, otherwise hiding is lost
+        ensure_distinct_randomness(&committed_shares)?;
+        
         Ok(committed_shares)
     }
     
+    /// Find two committed shares that reuse the same randomness
+    pub fn find_reused_randomness(committed_shares: &[CommittedShare]) -> Option<(ShareId, ShareId)> {
+        let mut seen: HashMap<&[u8], ShareId> = HashMap::new();
+        
+        for committed_share in committed_shares {
+            if let Some(&first) = seen.get(committed_share.randomness.as_slice()) {
+                return Some((first, committed_share.share.id));
+            }
+            seen.insert(&committed_share.randomness, committed_share.share.id);
+        }
+        
+        None
+    }
+    
+    /// Reject a set of committed shares that reuses randomness
+    pub fn ensure_distinct_randomness(committed_shares: &[CommittedShare]) -> Result<()> {
+        match find_reused_randomness(committed_shares) {
+            Some((first, second)) => Err(Error::CommitmentError(format!(
+                "Shares {} and {} are committed with the same randomness", first, second
+            ))),
+            None => Ok(()),
+        }
+    }
+    
     /// Verify all committed shares
     pub fn verify_all_committed_shares(committed_shares: &[CommittedShare], curve_type: CurveType) -> Result<bool> {
         for committed_share in committed_shares {
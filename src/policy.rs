@@ -0,0 +1,350 @@
+//! Threshold signing policy
+//! 
+//! Decides which messages a signing committee is willing to sign:
+//! - Allow-list of message prefixes
+//! - Rate limit on signatures per hour
+//! - k-of-m admin approvals collected as signed `Approval` messages
+//! 
+//! Policies are evaluated before any nonce is generated for a signing request.
+
+use crate::{Error, Result, CurveType, ShareId};
+use crate::curve::Scalar;
+use crate::zkp::SchnorrSignature;
+use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Digest};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+
+/// Length of the rate limit window in seconds
+const RATE_LIMIT_WINDOW_SECS: u64 = 3600;
+
+/// Outcome of a policy check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Decision {
+    /// The message may be signed
+    Allow,
+    /// The message must not be signed
+    Deny(String),
+}
+
+impl Decision {
+    /// Check if the decision allows signing
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, Decision::Allow)
+    }
+}
+
+/// Request context passed to signing policies
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SigningContext {
+    pub curve_type: CurveType,
+    pub timestamp: u64,
+    pub approvals: Vec<Approval>,
+}
+
+impl SigningContext {
+    /// Create new signing context
+    pub fn new(curve_type: CurveType, timestamp: u64) -> Self {
+        Self {
+            curve_type,
+            timestamp,
+            approvals: Vec::new(),
+        }
+    }
+    
+    /// Attach admin approvals
+    pub fn with_approvals(mut self, approvals: Vec<Approval>) -> Self {
+        self.approvals = approvals;
+        self
+    }
+}
+
+/// Policy deciding whether the committee signs a message
+/// 
+/// `authorize` only inspects the request; state such as a rate limit's
+/// history changes in `record`, which `enforce` calls once every policy
+/// allowed the request.
+pub trait SigningPolicy: Send + Sync {
+    /// Authorize a signing request
+    fn authorize(&self, message: &[u8], requester: ShareId, context: &SigningContext) -> Decision;
+    
+    /// Account for a request that was allowed
+    fn record(&self, _message: &[u8], _requester: ShareId, _context: &SigningContext) {}
+}
+
+/// Only sign messages starting with one of the allowed prefixes
+#[derive(Debug, Clone)]
+pub struct PrefixAllowList {
+    prefixes: Vec<Vec<u8>>,
+}
+
+impl PrefixAllowList {
+    /// Create new prefix allow-list
+    pub fn new(prefixes: Vec<Vec<u8>>) -> Self {
+        Self { prefixes }
+    }
+}
+
+impl SigningPolicy for PrefixAllowList {
+    fn authorize(&self, message: &[u8], _requester: ShareId, _context: &SigningContext) -> Decision {
+        if self.prefixes.iter().any(|prefix| message.starts_with(prefix)) {
+            Decision::Allow
+        } else {
+            Decision::Deny("Message does not match any allowed prefix".to_string())
+        }
+    }
+}
+
+/// Limit the number of signatures within a sliding one-hour window
+#[derive(Debug)]
+pub struct RateLimit {
+    max_per_hour: usize,
+    history: Mutex<VecDeque<u64>>,
+}
+
+impl RateLimit {
+    /// Create new rate limit
+    pub fn new(max_per_hour: usize) -> Self {
+        Self {
+            max_per_hour,
+            history: Mutex::new(VecDeque::new()),
+        }
+    }
+}
+
+impl SigningPolicy for RateLimit {
+    fn authorize(&self, _message: &[u8], _requester: ShareId, context: &SigningContext) -> Decision {
+        let mut history = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        
+        // Drop signatures that fell out of the window
+        while let Some(&oldest) = history.front() {
+            if context.timestamp.saturating_sub(oldest) >= RATE_LIMIT_WINDOW_SECS {
+                history.pop_front();
+            } else {
+                break;
+            }
+        }
+        
+        if history.len() >= self.max_per_hour {
+            return Decision::Deny(format!("Rate limit of {} signatures per hour exceeded", self.max_per_hour));
+        }
+        
+        Decision::Allow
+    }
+    
+    fn record(&self, _message: &[u8], _requester: ShareId, context: &SigningContext) {
+        let mut history = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        history.push_back(context.timestamp);
+    }
+}
+
+/// Admin approval of a specific message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Approval {
+    pub admin_id: ShareId,
+    pub message_hash: Vec<u8>,
+    pub signature: SchnorrSignature,
+}
+
+impl Approval {
+    /// Approve a message with an admin key
+    pub fn new(admin_id: ShareId, message: &[u8], admin_key: &Scalar, curve_type: CurveType) -> Result<Self> {
+        let message_hash = Self::hash_message(message);
+        let mut signature = SchnorrSignature::new(curve_type);
+        signature.sign(&Self::signing_payload(&message_hash), admin_key)?;
+        
+        Ok(Self {
+            admin_id,
+            message_hash,
+            signature,
+        })
+    }
+    
+    /// Check that the approval covers `message` and is signed by `admin_public_key`
+    pub fn verify(&self, message: &[u8], admin_public_key: &[u8]) -> Result<bool> {
+        if self.message_hash != Self::hash_message(message) {
+            return Ok(false);
+        }
+        
+        if self.signature.public_key != admin_public_key {
+            return Ok(false);
+        }
+        
+        self.signature.verify(&Self::signing_payload(&self.message_hash))
+    }
+    
+    /// Hash message for approval
+    fn hash_message(message: &[u8]) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(message);
+        hasher.finalize().to_vec()
+    }
+    
+    /// Domain separated payload signed by admins
+    fn signing_payload(message_hash: &[u8]) -> Vec<u8> {
+        let mut payload = Vec::new();
+        payload.extend_from_slice(b"z-mpc-approval");
+        payload.extend_from_slice(message_hash);
+        payload
+    }
+}
+
+/// Require approvals from at least `required` distinct registered admins
+#[derive(Debug, Clone)]
+pub struct ApprovalQuorum {
+    admins: HashMap<ShareId, Vec<u8>>,
+    required: usize,
+}
+
+impl ApprovalQuorum {
+    /// Create new approval quorum from admin ids and compressed public keys
+    pub fn new(admins: HashMap<ShareId, Vec<u8>>, required: usize) -> Result<Self> {
+        if required == 0 || required > admins.len() {
+            return Err(Error::InvalidInput(format!(
+                "Approval quorum of {} is invalid for {} admins", required, admins.len()
+            )));
+        }
+        
+        Ok(Self { admins, required })
+    }
+}
+
+impl SigningPolicy for ApprovalQuorum {
+    fn authorize(&self, message: &[u8], _requester: ShareId, context: &SigningContext) -> Decision {
+        let mut approved_by = HashSet::new();
+        
+        for approval in &context.approvals {
+            let Some(public_key) = self.admins.get(&approval.admin_id) else {
+                continue;
+            };
+            
+            if let Ok(true) = approval.verify(message, public_key) {
+                approved_by.insert(approval.admin_id);
+            }
+        }
+        
+        if approved_by.len() >= self.required {
+            Decision::Allow
+        } else {
+            Decision::Deny(format!(
+                "Only {} of {} required approvals present", approved_by.len(), self.required
+            ))
+        }
+    }
+}
+
+/// Combination of policies that must all allow the request
+pub struct AllOf {
+    policies: Vec<Box<dyn SigningPolicy>>,
+}
+
+impl AllOf {
+    /// Create new policy combination
+    pub fn new(policies: Vec<Box<dyn SigningPolicy>>) -> Self {
+        Self { policies }
+    }
+}
+
+impl SigningPolicy for AllOf {
+    fn authorize(&self, message: &[u8], requester: ShareId, context: &SigningContext) -> Decision {
+        for policy in &self.policies {
+            let decision = policy.authorize(message, requester, context);
+            if !decision.is_allowed() {
+                return decision;
+            }
+        }
+        Decision::Allow
+    }
+    
+    fn record(&self, message: &[u8], requester: ShareId, context: &SigningContext) {
+        for policy in &self.policies {
+            policy.record(message, requester, context);
+        }
+    }
+}
+
+/// Policy configuration as loaded from a config file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PolicyConfig {
+    /// Allowed message prefixes (hex)
+    PrefixAllowList { prefixes: Vec<String> },
+    /// Maximum signatures per hour
+    RateLimit { max_per_hour: usize },
+    /// Admin public keys (hex, compressed) and required approvals
+    ApprovalQuorum { admins: HashMap<ShareId, String>, required: usize },
+    /// All nested policies must allow
+    AllOf { policies: Vec<PolicyConfig> },
+}
+
+impl PolicyConfig {
+    /// Build the configured policy
+    pub fn build(&self) -> Result<Box<dyn SigningPolicy>> {
+        match self {
+            PolicyConfig::PrefixAllowList { prefixes } => {
+                let prefixes = prefixes.iter()
+                    .map(|p| hex::decode(p).map_err(|_| Error::InvalidInput(format!("Invalid hex prefix: {}", p))))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Box::new(PrefixAllowList::new(prefixes)))
+            }
+            PolicyConfig::RateLimit { max_per_hour } => Ok(Box::new(RateLimit::new(*max_per_hour))),
+            PolicyConfig::ApprovalQuorum { admins, required } => {
+                let admins = admins.iter()
+                    .map(|(id, key)| {
+                        hex::decode(key)
+                            .map(|key| (*id, key))
+                            .map_err(|_| Error::InvalidInput(format!("Invalid hex key for admin {}", id)))
+                    })
+                    .collect::<Result<HashMap<_, _>>>()?;
+                Ok(Box::new(ApprovalQuorum::new(admins, *required)?))
+            }
+            PolicyConfig::AllOf { policies } => {
+                let policies = policies.iter()
+                    .map(PolicyConfig::build)
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Box::new(AllOf::new(policies)))
+            }
+        }
+    }
+    
+    /// Load policy configuration from a JSON file
+    pub fn from_file(path: &str) -> Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+}
+
+/// Evaluate a policy and record the decision, failing on denial
+/// 
+/// An allowed request is also recorded with the policy, so it counts
+/// against rate limits; a denied one leaves every policy unchanged.
+pub fn enforce(
+    policy: &dyn SigningPolicy,
+    message: &[u8],
+    requester: ShareId,
+    context: &SigningContext,
+) -> Result<()> {
+    let decision = policy.authorize(message, requester, context);
+    
+    match decision {
+        Decision::Allow => {
+            policy.record(message, requester, context);
+            tracing::info!(
+                target: "z_mpc::audit",
+                "Signing request from {} allowed (message hash {})",
+                requester,
+                hex::encode(Approval::hash_message(message))
+            );
+            Ok(())
+        }
+        Decision::Deny(reason) => {
+            tracing::warn!(
+                target: "z_mpc::audit",
+                "Signing request from {} denied: {}",
+                requester,
+                reason
+            );
+            Err(Error::PolicyDenied(reason))
+        }
+    }
+}
@@ -0,0 +1,302 @@
+//! QR-code export and import of committed shares
+//! 
+//! Lets share holders keep their share on paper:
+//! - Compact binary encoding of a `CommittedShare`
+//! - Split into numbered parts that each fit a scannable QR code
+//! - Base45 text payloads (QR alphanumeric mode), base64 accepted on import
+//! 
+//! Every part carries a sequence header and a digest of the full encoding,
+//! so parts of different shares cannot be mixed up on import.
+
+use crate::{Error, Result, CurveType};
+use crate::laurent::Share;
+use crate::pedersen::{CommittedShare, CommitmentOpening};
+use base64::Engine;
+use sha2::{Sha256, Digest};
+
+/// Prefix of base45 part payloads
+pub const PAYLOAD_PREFIX: &str = "ZMPC:";
+
+/// Binary encoding format version
+/// 
+/// Version 2 adds the dealing id; version 1 encodings are still decoded.
+const FORMAT_VERSION: u8 = 2;
+
+/// Maximum encoded share bytes carried by one QR code
+const MAX_PART_BYTES: usize = 256;
+
+/// Part header length: sequence, total and digest
+const PART_HEADER_LEN: usize = 6;
+
+/// Encode a committed share into the compact binary format
+pub fn encode_share(committed_share: &CommittedShare, curve_type: CurveType) -> Result<Vec<u8>> {
+    let share = &committed_share.share;
+    let mut out = vec![FORMAT_VERSION, curve_to_byte(curve_type)];
+    
+    out.extend_from_slice(&share.id.to_be_bytes());
+    put_bytes(&mut out, &share.value)?;
+    put_optional(&mut out, share.commitment.as_deref())?;
+    put_optional(&mut out, share.proof.as_deref())?;
+    put_optional(&mut out, share.dealing_id.as_deref())?;
+    put_bytes(&mut out, &committed_share.commitment)?;
+    put_bytes(&mut out, &committed_share.randomness)?;
+    
+    match &committed_share.opening {
+        Some(opening) => {
+            out.push(1);
+            out.push(curve_to_byte(opening.curve_type));
+            put_bytes(&mut out, &opening.value_hash)?;
+            put_bytes(&mut out, &opening.randomness)?;
+        }
+        None => out.push(0),
+    }
+    
+    Ok(out)
+}
+
+/// Decode a committed share from the compact binary format
+pub fn decode_share(data: &[u8]) -> Result<(CurveType, CommittedShare)> {
+    let mut reader = Reader::new(data);
+    
+    let version = reader.u8()?;
+    if version != 1 && version != FORMAT_VERSION {
+        return Err(Error::InvalidInput(format!("Unsupported share encoding version {}", version)));
+    }
+    
+    let curve_type = curve_from_byte(reader.u8()?)?;
+    let share = Share {
+        id: reader.u32()?,
+        value: reader.bytes()?,
+        commitment: reader.optional()?,
+        proof: reader.optional()?,
+        dealing_id: if version >= 2 { reader.optional()? } else { None },
+    };
+    let commitment = reader.bytes()?;
+    let randomness = reader.bytes()?;
+    
+    let opening = match reader.u8()? {
+        0 => None,
+        1 => Some(CommitmentOpening {
+            curve_type: curve_from_byte(reader.u8()?)?,
+            value_hash: reader.bytes()?,
+            randomness: reader.bytes()?,
+        }),
+        flag => return Err(Error::InvalidInput(format!("Invalid opening flag {}", flag))),
+    };
+    
+    if !reader.is_empty() {
+        return Err(Error::InvalidInput("Trailing bytes in share encoding".to_string()));
+    }
+    
+    let mut committed_share = CommittedShare::new(share, commitment, randomness);
+    committed_share.opening = opening;
+    Ok((curve_type, committed_share))
+}
+
+/// Split a committed share into QR text payloads, one per code
+pub fn to_qr_payloads(committed_share: &CommittedShare, curve_type: CurveType) -> Result<Vec<String>> {
+    let encoded = encode_share(committed_share, curve_type)?;
+    let digest = digest(&encoded);
+    
+    let chunks: Vec<&[u8]> = encoded.chunks(MAX_PART_BYTES).collect();
+    if chunks.len() > u8::MAX as usize {
+        return Err(Error::InvalidInput("Share too large for QR export".to_string()));
+    }
+    
+    let total = chunks.len() as u8;
+    let payloads = chunks.iter()
+        .enumerate()
+        .map(|(index, chunk)| {
+            let mut part = vec![index as u8 + 1, total];
+            part.extend_from_slice(&digest);
+            part.extend_from_slice(chunk);
+            format!("{}{}", PAYLOAD_PREFIX, base45::encode(&part))
+        })
+        .collect();
+    
+    Ok(payloads)
+}
+
+/// Reassemble a committed share from scanned QR text payloads in any order
+pub fn from_qr_payloads<S: AsRef<str>>(payloads: &[S]) -> Result<(CurveType, CommittedShare)> {
+    if payloads.is_empty() {
+        return Err(Error::InvalidInput("No QR payloads given".to_string()));
+    }
+    
+    // Step 1: Decode part headers
+    let mut parts = payloads.iter()
+        .map(|payload| decode_part(payload.as_ref().trim()))
+        .collect::<Result<Vec<_>>>()?;
+    parts.sort_by_key(|part| part.0);
+    
+    // Step 2: Check that all parts belong together and none is missing
+    let (_, total, digest_expected, _) = &parts[0];
+    if parts.len() != *total as usize {
+        return Err(Error::InvalidInput(format!(
+            "Expected {} QR parts, got {}", total, parts.len()
+        )));
+    }
+    for (index, (sequence, part_total, part_digest, _)) in parts.iter().enumerate() {
+        if *sequence as usize != index + 1 || part_total != total || part_digest != digest_expected {
+            return Err(Error::InvalidInput(format!("QR part {} does not belong to this share", sequence)));
+        }
+    }
+    
+    // Step 3: Reassemble and check the digest
+    let encoded: Vec<u8> = parts.iter().flat_map(|part| part.3.iter().copied()).collect();
+    if digest(&encoded) != *digest_expected {
+        return Err(Error::InvalidInput("QR payload digest mismatch".to_string()));
+    }
+    
+    decode_share(&encoded)
+}
+
+/// Render a QR payload as an SVG image
+pub fn render_svg(payload: &str) -> Result<String> {
+    let code = qrcode::QrCode::with_error_correction_level(payload, qrcode::EcLevel::Q)
+        .map_err(|e| Error::InvalidInput(format!("QR encoding failed: {}", e)))?;
+    
+    Ok(code.render::<qrcode::render::svg::Color>()
+        .min_dimensions(256, 256)
+        .build())
+}
+
+/// Decode QR text payloads from an image file
+#[cfg(feature = "qr-image")]
+pub fn scan_image(path: &str) -> Result<Vec<String>> {
+    let image = image::open(path)
+        .map_err(|e| Error::InvalidInput(format!("Failed to open {}: {}", path, e)))?
+        .to_luma8();
+    
+    let mut prepared = rqrr::PreparedImage::prepare(image);
+    let payloads = prepared.detect_grids()
+        .iter()
+        .map(|grid| {
+            grid.decode()
+                .map(|(_, content)| content)
+                .map_err(|e| Error::InvalidInput(format!("Failed to decode QR code: {}", e)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    
+    if payloads.is_empty() {
+        return Err(Error::InvalidInput(format!("No QR code found in {}", path)));
+    }
+    
+    Ok(payloads)
+}
+
+/// Decode one part payload into (sequence, total, digest, chunk)
+fn decode_part(payload: &str) -> Result<(u8, u8, [u8; 4], Vec<u8>)> {
+    let bytes = match payload.strip_prefix(PAYLOAD_PREFIX) {
+        Some(encoded) => base45::decode(encoded)
+            .map_err(|_| Error::InvalidInput("Invalid base45 QR payload".to_string()))?,
+        None => base64::engine::general_purpose::STANDARD.decode(payload)
+            .map_err(|_| Error::InvalidInput("QR payload is neither base45 nor base64".to_string()))?,
+    };
+    
+    if bytes.len() <= PART_HEADER_LEN {
+        return Err(Error::InvalidInput("QR payload too short".to_string()));
+    }
+    
+    let mut part_digest = [0u8; 4];
+    part_digest.copy_from_slice(&bytes[2..PART_HEADER_LEN]);
+    Ok((bytes[0], bytes[1], part_digest, bytes[PART_HEADER_LEN..].to_vec()))
+}
+
+/// Truncated SHA-256 digest identifying an encoded share
+fn digest(data: &[u8]) -> [u8; 4] {
+    let hash = Sha256::digest(data);
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+/// Stable byte tag for a curve
+fn curve_to_byte(curve_type: CurveType) -> u8 {
+    match curve_type {
+        CurveType::Secp256k1 => 1,
+        CurveType::P256 => 2,
+        CurveType::Edwards25519 => 3,
+        CurveType::Ristretto255 => 4,
+    }
+}
+
+/// Curve for a byte tag
+fn curve_from_byte(tag: u8) -> Result<CurveType> {
+    match tag {
+        1 => Ok(CurveType::Secp256k1),
+        2 => Ok(CurveType::P256),
+        3 => Ok(CurveType::Edwards25519),
+        4 => Ok(CurveType::Ristretto255),
+        _ => Err(Error::InvalidCurve(format!("Unknown curve tag {}", tag))),
+    }
+}
+
+/// Append length-prefixed bytes
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) -> Result<()> {
+    let len = u16::try_from(bytes.len())
+        .map_err(|_| Error::InvalidInput("Field too large for share encoding".to_string()))?;
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(bytes);
+    Ok(())
+}
+
+/// Append optional length-prefixed bytes
+fn put_optional(out: &mut Vec<u8>, bytes: Option<&[u8]>) -> Result<()> {
+    match bytes {
+        Some(bytes) => {
+            out.push(1);
+            put_bytes(out, bytes)
+        }
+        None => {
+            out.push(0);
+            Ok(())
+        }
+    }
+}
+
+/// Cursor over an encoded share
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+    
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.data.len() - self.pos < len {
+            return Err(Error::InvalidInput("Truncated share encoding".to_string()));
+        }
+        let slice = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(slice)
+    }
+    
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+    
+    fn u32(&mut self) -> Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+    
+    fn bytes(&mut self) -> Result<Vec<u8>> {
+        let len = self.take(2)?;
+        let len = u16::from_be_bytes([len[0], len[1]]) as usize;
+        Ok(self.take(len)?.to_vec())
+    }
+    
+    fn optional(&mut self) -> Result<Option<Vec<u8>>> {
+        match self.u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(self.bytes()?)),
+            flag => Err(Error::InvalidInput(format!("Invalid optional flag {}", flag))),
+        }
+    }
+    
+    fn is_empty(&self) -> bool {
+        self.pos == self.data.len()
+    }
+}
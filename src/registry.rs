@@ -0,0 +1,177 @@
+//! Indexed participant registry
+//! 
+//! Participants are stored by `ShareId`, with secondary indexes kept in
+//! step by every mutation:
+//! - By advertised endpoint, for requests that only know the remote address
+//! - By public-key fingerprint, for requests authenticated by key
+//! 
+//! Indexed fields can only change through `insert` and `update`, so the
+//! indexes never point at stale registrations.
+
+use crate::{Error, Result, ShareId};
+use crate::network::Participant;
+use sha2::{Sha256, Digest};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+
+/// SHA-256 fingerprint of a compressed public key
+pub type KeyFingerprint = [u8; 32];
+
+/// Fingerprint of a compressed public key
+pub fn key_fingerprint(public_key: &[u8]) -> KeyFingerprint {
+    Sha256::digest(public_key).into()
+}
+
+/// Participants of a node with lookups by id, endpoint and key
+#[derive(Debug, Clone, Default)]
+pub struct ParticipantRegistry {
+    by_id: HashMap<ShareId, Participant>,
+    by_addr: HashMap<SocketAddr, ShareId>,
+    by_key: HashMap<KeyFingerprint, ShareId>,
+}
+
+impl ParticipantRegistry {
+    /// Create empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Register a participant, replacing any previous registration of its id
+    /// 
+    /// Fails if another participant already advertises the same endpoint or
+    /// public key. Returns the replaced registration.
+    pub fn insert(&mut self, participant: Participant) -> Result<Option<Participant>> {
+        if let Some(&owner) = self.by_addr.get(&participant.address) {
+            if owner != participant.id {
+                return Err(Error::InvalidInput(format!(
+                    "Endpoint {} is registered to participant {}", participant.address, owner
+                )));
+            }
+        }
+        for public_key in participant.public_keys.values() {
+            if let Some(&owner) = self.by_key.get(&key_fingerprint(public_key)) {
+                if owner != participant.id {
+                    return Err(Error::InvalidInput(format!(
+                        "Public key of participant {} is registered to participant {}", participant.id, owner
+                    )));
+                }
+            }
+        }
+        
+        let previous = self.remove(participant.id);
+        self.index(&participant);
+        self.by_id.insert(participant.id, participant);
+        Ok(previous)
+    }
+    
+    /// Unregister a participant
+    pub fn remove(&mut self, id: ShareId) -> Option<Participant> {
+        let participant = self.by_id.remove(&id)?;
+        self.by_addr.remove(&participant.address);
+        for public_key in participant.public_keys.values() {
+            self.by_key.remove(&key_fingerprint(public_key));
+        }
+        Some(participant)
+    }
+    
+    /// Modify a registered participant, re-indexing it afterwards
+    /// 
+    /// If the modified endpoint or keys collide with another participant the
+    /// change is rolled back and an error returned.
+    pub fn update<R>(&mut self, id: ShareId, f: impl FnOnce(&mut Participant) -> R) -> Result<Option<R>> {
+        let Some(mut participant) = self.by_id.get(&id).cloned() else {
+            return Ok(None);
+        };
+        
+        let result = f(&mut participant);
+        participant.id = id;
+        self.insert(participant)?;
+        Ok(Some(result))
+    }
+    
+    /// Participant by id
+    pub fn get(&self, id: ShareId) -> Option<&Participant> {
+        self.by_id.get(&id)
+    }
+    
+    /// Participant advertising an endpoint
+    pub fn participant_by_addr(&self, endpoint: &SocketAddr) -> Option<&Participant> {
+        self.by_addr.get(endpoint).and_then(|id| self.by_id.get(id))
+    }
+    
+    /// Participant owning a public key fingerprint
+    pub fn participant_by_key(&self, fingerprint: &KeyFingerprint) -> Option<&Participant> {
+        self.by_key.get(fingerprint).and_then(|id| self.by_id.get(id))
+    }
+    
+    /// Whether a participant is registered
+    pub fn contains(&self, id: ShareId) -> bool {
+        self.by_id.contains_key(&id)
+    }
+    
+    /// Number of registered participants
+    pub fn len(&self) -> usize {
+        self.by_id.len()
+    }
+    
+    /// Whether no participant is registered
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty()
+    }
+    
+    /// All registered participants, in no particular order
+    pub fn values(&self) -> impl Iterator<Item = &Participant> {
+        self.by_id.values()
+    }
+    
+    /// Ids of all registered participants, in no particular order
+    pub fn ids(&self) -> impl Iterator<Item = ShareId> + '_ {
+        self.by_id.keys().copied()
+    }
+    
+    /// Check that the secondary indexes match the registrations exactly
+    pub fn check_consistency(&self) -> Result<()> {
+        let inconsistent = |what: String| Err(Error::Internal(format!("Participant registry inconsistent: {}", what)));
+        
+        let mut expected_keys = 0;
+        for (id, participant) in &self.by_id {
+            if participant.id != *id {
+                return inconsistent(format!("participant {} stored under id {}", participant.id, id));
+            }
+            if self.by_addr.get(&participant.address) != Some(id) {
+                return inconsistent(format!("endpoint {} not indexed to {}", participant.address, id));
+            }
+            for public_key in participant.public_keys.values() {
+                if self.by_key.get(&key_fingerprint(public_key)) != Some(id) {
+                    return inconsistent(format!("public key of {} not indexed", id));
+                }
+                expected_keys += 1;
+            }
+        }
+        
+        if self.by_addr.len() != self.by_id.len() {
+            return inconsistent(format!("{} endpoints for {} participants", self.by_addr.len(), self.by_id.len()));
+        }
+        if self.by_key.len() != expected_keys {
+            return inconsistent(format!("{} key fingerprints for {} keys", self.by_key.len(), expected_keys));
+        }
+        
+        Ok(())
+    }
+    
+    /// Add a participant's endpoint and keys to the secondary indexes
+    fn index(&mut self, participant: &Participant) {
+        self.by_addr.insert(participant.address, participant.id);
+        for public_key in participant.public_keys.values() {
+            self.by_key.insert(key_fingerprint(public_key), participant.id);
+        }
+    }
+}
+
+impl std::ops::Index<&ShareId> for ParticipantRegistry {
+    type Output = Participant;
+    
+    fn index(&self, id: &ShareId) -> &Participant {
+        self.by_id.get(id).expect("participant registered")
+    }
+}
@@ -0,0 +1,146 @@
+//! Startup self-test of the curve backends
+//! 
+//! Runs fixed known-answer vectors per curve before a node accepts protocol
+//! traffic, so a miscompiled backend or a broken dependency bump fails hard
+//! instead of silently producing bad shares:
+//! - Generator multiplication against published encodings
+//! - Scalar inversion identities
+//! - Pedersen commitment with fixed generators and randomness
+//! - Signature verification under the known-answer key
+
+use crate::{Error, Result, CurveType};
+use crate::curve::{curve_backend, is_curve_enabled, Curve};
+use crate::pedersen::PedersenCommitment;
+use crate::zkp::SchnorrSignature;
+
+/// Message signed by the signature vector
+const SIGNATURE_MESSAGE: &[u8] = b"z-mpc self-test";
+
+/// Known-answer vector for one curve
+/// 
+/// Scalars are small integers so the vectors do not depend on the backend's
+/// scalar byte order; points are compressed standard encodings.
+#[derive(Debug, Clone, Copy)]
+pub struct KnownAnswerVector {
+    pub name: &'static str,
+    pub curve_type: CurveType,
+    /// Scalar k
+    pub scalar: u64,
+    /// Compressed k·G (hex)
+    pub scalar_mul: &'static str,
+    /// Discrete log of the Pedersen generator h = h_scalar·G
+    pub h_scalar: u64,
+    /// Committed value v
+    pub value: u64,
+    /// Commitment randomness r
+    pub randomness: u64,
+    /// Compressed v·G + r·h (hex)
+    pub commitment: &'static str,
+}
+
+/// Vectors for all supported curves
+pub const VECTORS: &[KnownAnswerVector] = &[
+    KnownAnswerVector {
+        name: "secp256k1",
+        curve_type: CurveType::Secp256k1,
+        scalar: 0x0123456789abcdef,
+        scalar_mul: "031a1fd15fce078234aa292fc024178056bf006433c9b4bd208f59eb4c9efec95b",
+        h_scalar: 0x5a5a5a5a,
+        value: 42,
+        randomness: 7,
+        commitment: "02b5693e17c5fe34c297053e4f1ceaace16f02987ff13f006b55040c6816013a29",
+    },
+    KnownAnswerVector {
+        name: "p256",
+        curve_type: CurveType::P256,
+        scalar: 0x0123456789abcdef,
+        scalar_mul: "023988322ab9f52c7f11d5d1aa92a2ac0b00275bcad8e934682257323fda672482",
+        h_scalar: 0x5a5a5a5a,
+        value: 42,
+        randomness: 7,
+        commitment: "02693676b3cd0b5cfaf5d54fb64caf927b903fec9ac33c6ce572de767cc4e24754",
+    },
+    KnownAnswerVector {
+        name: "ed25519",
+        curve_type: CurveType::Edwards25519,
+        scalar: 0x0123456789abcdef,
+        scalar_mul: "4007da55933fc4e347bc451b387cfef6b0dfec7b14da5aab1738f47c52f77b70",
+        h_scalar: 0x5a5a5a5a,
+        value: 42,
+        randomness: 7,
+        commitment: "4496bfe6821edfd39c22578d7007cea7817e610add1d4e6a8d905d9c1a985009",
+    },
+    KnownAnswerVector {
+        name: "ristretto255",
+        curve_type: CurveType::Ristretto255,
+        scalar: 0x0123456789abcdef,
+        scalar_mul: "a825fb7ea163a490fc5116d03b3b58d641dddc050b0e7e41697496029842fa69",
+        h_scalar: 0x5a5a5a5a,
+        value: 42,
+        randomness: 7,
+        commitment: "7cbee831ac358f3316718cd2d0edfa5baa984169645c7e40a2d3b450f800e768",
+    },
+];
+
+/// Run all known-answer vectors, failing on the first mismatch
+/// 
+/// Curves whose feature is disabled are skipped; they reject all artifacts.
+pub fn known_answer_tests() -> Result<()> {
+    let mut passed = 0;
+    for vector in VECTORS.iter().filter(|vector| is_curve_enabled(vector.curve_type)) {
+        run_vector(vector)?;
+        passed += 1;
+    }
+    
+    tracing::info!("Curve backend self-test passed for {} curves", passed);
+    Ok(())
+}
+
+/// Run a single known-answer vector
+pub fn run_vector(vector: &KnownAnswerVector) -> Result<()> {
+    let curve = curve_backend(vector.curve_type)?;
+    let fail = |check: &str| Error::SelfTestFailed(format!("{}: {}", vector.name, check));
+    
+    // Step 1: Generator multiplication
+    let k = curve.scalar_from_u64(vector.scalar)?;
+    let public_key = curve.generator().mul(&k)?.to_compressed_bytes()?;
+    if hex::encode(&public_key) != vector.scalar_mul {
+        return Err(fail("generator multiplication"));
+    }
+    
+    // Step 2: Inversion identities k·k⁻¹ = 1 and (k⁻¹)⁻¹ = k
+    let one = curve.scalar_from_u64(1)?;
+    let k_inv = k.invert()?;
+    if k.mul(&k_inv)? != one || k_inv.invert()? != k {
+        return Err(fail("scalar inversion"));
+    }
+    
+    // Step 3: Pedersen commitment with fixed h and randomness
+    let pedersen = fixed_pedersen(curve, vector.h_scalar)?;
+    let value = curve.scalar_from_u64(vector.value)?;
+    let randomness = curve.scalar_from_u64(vector.randomness)?.into_bytes();
+    let commitment = pedersen.commit(&value, &randomness)?;
+    if hex::encode(&commitment) != vector.commitment {
+        return Err(fail("pedersen commitment"));
+    }
+    
+    // Step 4: Signature under the known-answer key
+    let mut signature = SchnorrSignature::new(vector.curve_type);
+    signature.sign(SIGNATURE_MESSAGE, &k)?;
+    if signature.public_key != public_key
+        || !signature.verify(SIGNATURE_MESSAGE)?
+        || signature.verify(b"z-mpc self-test tampered")?
+    {
+        return Err(fail("signature verification"));
+    }
+    
+    Ok(())
+}
+
+/// Pedersen parameters with h = h_scalar·G
+fn fixed_pedersen(curve: &dyn Curve, h_scalar: u64) -> Result<PedersenCommitment> {
+    let g = curve.generator();
+    let h = g.mul(&curve.scalar_from_u64(h_scalar)?)?;
+    
+    PedersenCommitment::from_parameters(curve.curve_type(), g, h)
+}
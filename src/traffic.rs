@@ -0,0 +1,167 @@
+//! Per-session traffic accounting
+//! 
+//! Records what each node sent to and received from every peer, so operators
+//! can debug asymmetric failures ("I never got the shares"):
+//! - Message counts per variant and byte counts, per peer and direction
+//! - Last error and last successful exchange per peer
+//! - A bounded ring of recent message summaries (ids and digests only)
+
+use crate::{SessionId, ShareId};
+use crate::network::NetworkMessage;
+use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Digest};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// Default number of message summaries kept per session
+pub const DEFAULT_RECENT_CAPACITY: usize = 64;
+
+/// Direction of a message relative to this node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Direction {
+    Sent,
+    Received,
+}
+
+/// Counters for one peer within a session
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeerTraffic {
+    /// Messages sent to the peer, by variant
+    pub sent: BTreeMap<String, u64>,
+    /// Messages received from the peer, by variant
+    pub received: BTreeMap<String, u64>,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub last_error: Option<String>,
+    /// Unix time of the last message from the peer handled without error
+    pub last_success: Option<u64>,
+}
+
+impl PeerTraffic {
+    /// Total messages sent to the peer
+    pub fn messages_sent(&self) -> u64 {
+        self.sent.values().sum()
+    }
+    
+    /// Total messages received from the peer
+    pub fn messages_received(&self) -> u64 {
+        self.received.values().sum()
+    }
+}
+
+/// Payload-free summary of a message
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MessageSummary {
+    pub direction: Direction,
+    pub peer: ShareId,
+    pub kind: String,
+    /// SHA-256 of the serialized message (hex)
+    pub digest: String,
+    pub timestamp: u64,
+}
+
+/// Traffic of one session
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionTraffic {
+    pub peers: BTreeMap<ShareId, PeerTraffic>,
+    pub recent: VecDeque<MessageSummary>,
+}
+
+impl SessionTraffic {
+    /// Counters for a peer, empty if nothing was exchanged
+    pub fn peer(&self, peer: ShareId) -> PeerTraffic {
+        self.peers.get(&peer).cloned().unwrap_or_default()
+    }
+}
+
+/// Traffic log of a node, keyed by session id
+#[derive(Debug, Clone)]
+pub struct TrafficLog {
+    capacity: usize,
+    sessions: HashMap<SessionId, SessionTraffic>,
+}
+
+impl Default for TrafficLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_RECENT_CAPACITY)
+    }
+}
+
+impl TrafficLog {
+    /// Create new traffic log keeping `capacity` summaries per session
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            sessions: HashMap::new(),
+        }
+    }
+    
+    /// Record a message sent to a peer
+    pub fn record_sent(&mut self, peer: ShareId, message: &NetworkMessage, now: u64) {
+        self.record(Direction::Sent, peer, message, now);
+    }
+    
+    /// Record a message received from a peer
+    pub fn record_received(&mut self, peer: ShareId, message: &NetworkMessage, now: u64) {
+        self.record(Direction::Received, peer, message, now);
+    }
+    
+    /// Record the outcome of handling a message from a peer
+    pub fn record_outcome(&mut self, session_id: SessionId, peer: ShareId, outcome: Result<(), String>, now: u64) {
+        let traffic = self.sessions.entry(session_id)
+            .or_default()
+            .peers
+            .entry(peer)
+            .or_default();
+        
+        match outcome {
+            Ok(()) => traffic.last_success = Some(now),
+            Err(error) => traffic.last_error = Some(error),
+        }
+    }
+    
+    /// Traffic of a session
+    pub fn session(&self, session_id: SessionId) -> Option<&SessionTraffic> {
+        self.sessions.get(&session_id)
+    }
+    
+    /// Traffic of all sessions
+    pub fn sessions(&self) -> &HashMap<SessionId, SessionTraffic> {
+        &self.sessions
+    }
+    
+    fn record(&mut self, direction: Direction, peer: ShareId, message: &NetworkMessage, now: u64) {
+        let Some(session_id) = message.session_id() else {
+            return;
+        };
+        
+        let payload = serde_json::to_vec(message).unwrap_or_default();
+        let kind = message.kind().to_string();
+        let session = self.sessions.entry(session_id).or_default();
+        let traffic = session.peers.entry(peer).or_default();
+        
+        match direction {
+            Direction::Sent => {
+                *traffic.sent.entry(kind.clone()).or_default() += 1;
+                traffic.bytes_sent += payload.len() as u64;
+            }
+            Direction::Received => {
+                *traffic.received.entry(kind.clone()).or_default() += 1;
+                traffic.bytes_received += payload.len() as u64;
+            }
+        }
+        
+        if self.capacity == 0 {
+            return;
+        }
+        if session.recent.len() == self.capacity {
+            session.recent.pop_front();
+        }
+        session.recent.push_back(MessageSummary {
+            direction,
+            peer,
+            kind,
+            digest: hex::encode(Sha256::digest(&payload)),
+            timestamp: now,
+        });
+    }
+}
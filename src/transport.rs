@@ -0,0 +1,162 @@
+//! Outbound transport configuration
+//! 
+//! Settings for the HTTP client nodes use to reach their peers in
+//! restricted networks:
+//! - HTTP(S) proxy, from `HTTPS_PROXY` or explicit, with a no-proxy list
+//! - Additional trusted root certificates for private CAs
+//! - Connection pooling (idle connections per host, keep-alive)
+//! - Per-request timeout
+
+use crate::{Error, Result};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Time allowed to establish a connection to a peer
+pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default time allowed for a whole request to a peer
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Outbound HTTP client settings of a node
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransportConfig {
+    /// Proxy URL for peer traffic
+    pub proxy: Option<String>,
+    /// Peers reached directly: `*`, an IP or an `ip:port`
+    pub no_proxy: Vec<String>,
+    /// PEM files with additional trusted root certificates
+    pub root_certificates: Vec<PathBuf>,
+    /// Maximum idle pooled connections per peer
+    pub pool_max_idle_per_host: usize,
+    /// TCP keep-alive interval, disabled if `None`
+    pub keep_alive: Option<Duration>,
+    /// Time allowed for a whole request to a peer
+    #[serde(default = "default_request_timeout")]
+    pub request_timeout: Duration,
+}
+
+fn default_request_timeout() -> Duration {
+    REQUEST_TIMEOUT
+}
+
+impl Default for TransportConfig {
+    fn default() -> Self {
+        Self {
+            proxy: None,
+            no_proxy: Vec::new(),
+            root_certificates: Vec::new(),
+            pool_max_idle_per_host: 8,
+            keep_alive: Some(Duration::from_secs(60)),
+            request_timeout: REQUEST_TIMEOUT,
+        }
+    }
+}
+
+impl TransportConfig {
+    /// Read proxy settings from `HTTPS_PROXY` and `NO_PROXY`
+    pub fn from_env() -> Self {
+        let var = |name: &str| {
+            std::env::var(name)
+                .or_else(|_| std::env::var(name.to_lowercase()))
+                .ok()
+                .filter(|value| !value.is_empty())
+        };
+        
+        Self {
+            proxy: var("HTTPS_PROXY"),
+            no_proxy: var("NO_PROXY")
+                .map(|list| {
+                    list.split(',')
+                        .map(|entry| entry.trim().to_string())
+                        .filter(|entry| !entry.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            ..Self::default()
+        }
+    }
+    
+    /// Proxy to use for a peer, if any
+    pub fn proxy_for(&self, peer: &SocketAddr) -> Option<&str> {
+        let bypass = self.no_proxy.iter().any(|entry| {
+            entry == "*" || *entry == peer.ip().to_string() || *entry == peer.to_string()
+        });
+        
+        if bypass {
+            None
+        } else {
+            self.proxy.as_deref()
+        }
+    }
+    
+    /// Build the HTTP client for peer traffic
+    /// 
+    /// Peers are routed through the proxy unless `proxy_for` exempts them.
+    pub fn build_client(&self) -> Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder()
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .tcp_keepalive(self.keep_alive)
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(self.request_timeout);
+        
+        if let Some(proxy) = &self.proxy {
+            let proxy_url = reqwest::Url::parse(proxy)
+                .map_err(|e| Error::InvalidInput(format!("Invalid proxy URL {}: {}", proxy, e)))?;
+            let config = self.clone();
+            builder = builder.proxy(reqwest::Proxy::custom(move |url| {
+                let peer = url.socket_addrs(|| None).ok()?.into_iter().next()?;
+                config.proxy_for(&peer).map(|_| proxy_url.clone())
+            }));
+        }
+        
+        for der in self.load_root_certificates()? {
+            let certificate = reqwest::Certificate::from_der(&der)
+                .map_err(|e| Error::InvalidInput(format!("Invalid root certificate: {}", e)))?;
+            builder = builder.add_root_certificate(certificate);
+        }
+        
+        builder.build()
+            .map_err(|e| Error::Internal(format!("Failed to build HTTP client: {}", e)))
+    }
+    
+    /// Load the configured root certificates as DER
+    pub fn load_root_certificates(&self) -> Result<Vec<Vec<u8>>> {
+        let mut certificates = Vec::new();
+        
+        for path in &self.root_certificates {
+            let pem = std::fs::read_to_string(path)?;
+            let parsed = parse_pem_certificates(&pem)
+                .map_err(|e| Error::InvalidInput(format!("{}: {}", path.display(), e)))?;
+            if parsed.is_empty() {
+                return Err(Error::InvalidInput(format!("No certificate found in {}", path.display())));
+            }
+            certificates.extend(parsed);
+        }
+        
+        Ok(certificates)
+    }
+}
+
+/// Extract the DER bodies of all PEM certificate blocks
+fn parse_pem_certificates(pem: &str) -> std::result::Result<Vec<Vec<u8>>, String> {
+    const BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+    const END: &str = "-----END CERTIFICATE-----";
+    
+    let mut certificates = Vec::new();
+    let mut rest = pem;
+    
+    while let Some(start) = rest.find(BEGIN) {
+        let body = &rest[start + BEGIN.len()..];
+        let end = body.find(END).ok_or("Unterminated certificate block")?;
+        let encoded: String = body[..end].chars().filter(|c| !c.is_whitespace()).collect();
+        let der = base64::engine::general_purpose::STANDARD.decode(encoded)
+            .map_err(|_| "Invalid base64 in certificate block")?;
+        certificates.push(der);
+        rest = &body[end + END.len()..];
+    }
+    
+    Ok(certificates)
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Supported elliptic curve types
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CurveType {
     /// Bitcoin/Ethereum curve (secp256k1)
     Secp256k1,
@@ -9,6 +9,8 @@ pub enum CurveType {
     P256,
     /// Edwards25519 curve
     Edwards25519,
+    /// Ristretto255 prime-order group over Edwards25519
+    Ristretto255,
 }
 
 impl std::str::FromStr for CurveType {
@@ -19,6 +21,7 @@ impl std::str::FromStr for CurveType {
             "k1" | "secp256k1" => Ok(CurveType::Secp256k1),
             "r1" | "p256" => Ok(CurveType::P256),
             "ed25519" | "edwards25519" => Ok(CurveType::Edwards25519),
+            "ristretto" | "ristretto255" => Ok(CurveType::Ristretto255),
             _ => Err(crate::Error::InvalidCurve(s.to_string())),
         }
     }
@@ -30,6 +33,7 @@ impl std::fmt::Display for CurveType {
             CurveType::Secp256k1 => write!(f, "secp256k1"),
             CurveType::P256 => write!(f, "p256"),
             CurveType::Edwards25519 => write!(f, "ed25519"),
+            CurveType::Ristretto255 => write!(f, "ristretto255"),
         }
     }
 }
@@ -37,6 +41,90 @@ impl std::fmt::Display for CurveType {
 /// Share identifier
 pub type ShareId = u32;
 
+/// Random identifier of a ceremony session
+/// 
+/// Displayed and serialized as 32 hex digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SessionId(pub [u8; 16]);
+
+impl SessionId {
+    /// Generate a fresh session id from 16 random bytes
+    pub fn random() -> Self {
+        let mut bytes = [0u8; 16];
+        rand::RngCore::fill_bytes(&mut rand::thread_rng(), &mut bytes);
+        Self(bytes)
+    }
+}
+
+impl std::str::FromStr for SessionId {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        hex::decode(s).ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(Self)
+            .ok_or_else(|| crate::Error::InvalidInput(format!("Session id {} is not 16 hex-encoded bytes", s)))
+    }
+}
+
+impl std::fmt::Display for SessionId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&hex::encode(self.0))
+    }
+}
+
+impl Serialize for SessionId {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SessionId {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Stage a session has reached on a node
+/// 
+/// Phases are ordered; `Failed` sorts last, so a failed reconstruction can
+/// be retried like a completed one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ProtocolPhase {
+    /// The node does not take part in the session
+    Idle,
+    /// Parameters are set; no dealing seen yet
+    Initialized,
+    /// This node sent out its dealing
+    SharesDistributed,
+    /// A dealing was verified and this node holds its share
+    Verified,
+    /// A reconstruction is running
+    Reconstructing,
+    /// The last reconstruction succeeded
+    Complete,
+    /// The last reconstruction failed
+    Failed,
+}
+
+impl std::fmt::Display for ProtocolPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Mapping from share identifiers to Laurent evaluation points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum IdScheme {
+    /// Evaluate share `id` at z = id
+    #[default]
+    Direct,
+    /// Evaluate share `id` at z = id + offset
+    Offset(u64),
+    /// Evaluate share `id` at z = H(domain || curve || id) mod order
+    Hashed,
+}
+
 /// Threshold for secret sharing
 pub type Threshold = usize;
 
@@ -57,17 +145,52 @@ pub type ZKProof = Vec<u8>;
 pub struct Share {
     pub id: ShareId,
     pub value: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub commitment: Option<Commitment>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub proof: Option<ZKProof>,
 }
 
 /// Laurent series coefficients
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// 
+/// Serialized as lists of hex strings in the curve's scalar byte order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LaurentCoefficients {
+    #[serde(with = "hex_list")]
     pub a_coeffs: Vec<Vec<u8>>,  // A(z) coefficients
+    #[serde(with = "hex_list")]
     pub b_coeffs: Vec<Vec<u8>>,  // B(z) coefficients
 }
 
+/// Serde helpers for lists of byte strings encoded as hex
+mod hex_list {
+    use serde::{Deserialize, Deserializer, Serializer};
+    
+    pub fn serialize<S: Serializer>(values: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(hex::encode))
+    }
+    
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|value| hex::decode(value).map_err(serde::de::Error::custom))
+            .collect()
+    }
+}
+
+/// Serde helpers for byte strings encoded as hex
+pub(crate) mod hex_bytes {
+    use serde::{Deserialize, Deserializer, Serializer};
+    
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(value))
+    }
+    
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        hex::decode(String::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
 /// Secret sharing parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SharingParams {
@@ -82,4 +205,24 @@ pub struct ReconstructionResult {
     pub secret: Vec<u8>,
     pub valid: bool,
     pub participants_used: Vec<ShareId>,
-} 
\ No newline at end of file
+    /// Participants whose shares failed verification and were skipped
+    #[serde(default)]
+    pub rejected_participants: Vec<ShareId>,
+}
+
+/// Progress of a reconstruction collecting shares one at a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReconstructionProgress {
+    /// Shares received so far, including rejected ones
+    pub collected: usize,
+    /// Shares that passed verification
+    pub verified: usize,
+    pub threshold: Threshold,
+}
+
+impl ReconstructionProgress {
+    /// Verified shares still missing to reach the threshold
+    pub fn remaining(&self) -> usize {
+        self.threshold.saturating_sub(self.verified)
+    }
+}
@@ -0,0 +1,174 @@
+//! Deterministic test vectors for cross-implementation compatibility
+//! 
+//! Derives every random input of a dealing from a seed, so ports of z-MPC
+//! can check byte-for-byte that they produce the same:
+//! - Laurent coefficients and share values
+//! - Pedersen generator h, hashed onto the curve, randomness and commitments
+//! - Dealing id (polynomial commitment)
+//! 
+//! Scalars are derived as u64 values so vectors do not depend on a backend's
+//! scalar byte order. Proofs and signatures use fresh nonces and are
+//! therefore checked by verification rather than compared.
+
+use crate::{Error, Result, CurveType, ShareId};
+use crate::curve::{curve_backend, Curve, Scalar};
+use crate::laurent::{coefficient_counts, LaurentSeries};
+use crate::pedersen::PedersenCommitment;
+use crate::types::{LaurentCoefficients, SharingParams};
+use crate::zkp::{SchnorrSignature, ZeroKnowledgeProof};
+use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Digest};
+
+/// Domain separator for seed derivation
+const VECTOR_DOMAIN: &[u8] = b"z-mpc-test-vector";
+
+/// Message signed when checking a vector
+const VECTOR_MESSAGE: &[u8] = b"z-mpc test vector";
+
+/// Seed of the published vectors
+pub const DEFAULT_SEED: &[u8] = b"z-mpc test vectors v1";
+
+/// Threshold of the published vectors
+pub const DEFAULT_THRESHOLD: usize = 3;
+
+/// Participant count of the published vectors
+pub const DEFAULT_PARTICIPANTS: usize = 5;
+
+/// Share of a test vector
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorShare {
+    pub id: ShareId,
+    /// Share value (hex)
+    pub value: String,
+    /// Commitment randomness (hex)
+    pub randomness: String,
+    /// Compressed Pedersen commitment (hex)
+    pub commitment: String,
+}
+
+/// Test vector of one dealing
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVector {
+    pub curve_type: CurveType,
+    /// Seed (hex)
+    pub seed: String,
+    pub threshold: usize,
+    pub participants: usize,
+    pub coefficients: LaurentCoefficients,
+    /// Compressed Pedersen generator h (hex)
+    pub pedersen_h: String,
+    /// Polynomial commitment of the dealing (hex)
+    pub dealing_id: String,
+    pub shares: Vec<VectorShare>,
+}
+
+/// Generate the test vector for a curve from a seed
+pub fn generate(curve_type: CurveType, seed: &[u8], threshold: usize, participants: usize) -> Result<TestVector> {
+    let curve = curve_backend(curve_type)?;
+    let params = SharingParams {
+        curve_type,
+        threshold,
+        participants,
+    };
+    
+    // Step 1: Coefficients
+    let derive_all = |label: &str, count: usize| {
+        (0..count)
+            .map(|k| derive_scalar(curve, seed, label, k as u32))
+            .collect::<Result<Vec<_>>>()
+    };
+    let (a_count, b_count) = coefficient_counts(threshold);
+    let laurent = LaurentSeries::from_coefficients(derive_all("a", a_count)?, derive_all("b", b_count)?, &params)?;
+    let shares = laurent.generate_shares()?;
+    
+    // Step 2: Pedersen generator, randomness and commitments
+    let pedersen = PedersenCommitment::new(curve_type)?;
+    let vector_shares = shares.iter()
+        .map(|share| {
+            let randomness = derive_scalar(curve, seed, "r", share.id)?.into_bytes();
+            let commitment = pedersen.commit(&curve.scalar_from_bytes(&share.value)?, &randomness)?;
+            Ok(VectorShare {
+                id: share.id,
+                value: hex::encode(&share.value),
+                randomness: hex::encode(&randomness),
+                commitment: hex::encode(&commitment),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    
+    Ok(TestVector {
+        curve_type,
+        seed: hex::encode(seed),
+        threshold,
+        participants,
+        coefficients: laurent.get_coefficients(),
+        pedersen_h: hex::encode(pedersen.h.to_compressed_bytes()?),
+        dealing_id: hex::encode(laurent.dealing_id()?),
+        shares: vector_shares,
+    })
+}
+
+/// Check a test vector against this implementation
+/// 
+/// Regenerates the deterministic artifacts from the vector's seed and
+/// compares them, then proves and verifies every commitment and signs with
+/// a seed-derived key.
+pub fn check(vector: &TestVector) -> Result<()> {
+    let mismatch = |field: &str| Error::InvalidInput(format!("{} test vector mismatch: {}", vector.curve_type, field));
+    
+    let seed = hex::decode(&vector.seed).map_err(|_| mismatch("seed"))?;
+    let expected = generate(vector.curve_type, &seed, vector.threshold, vector.participants)?;
+    
+    // Step 1: Deterministic artifacts
+    if expected.coefficients != vector.coefficients {
+        return Err(mismatch("coefficients"));
+    }
+    if expected.pedersen_h != vector.pedersen_h {
+        return Err(mismatch("pedersen_h"));
+    }
+    if expected.dealing_id != vector.dealing_id {
+        return Err(mismatch("dealing_id"));
+    }
+    if expected.shares != vector.shares {
+        return Err(mismatch("shares"));
+    }
+    
+    // Step 2: Proofs over the published commitments
+    let curve = curve_backend(vector.curve_type)?;
+    let pedersen = PedersenCommitment::new(vector.curve_type)?;
+    for share in &vector.shares {
+        let value = curve.scalar_from_bytes(&hex::decode(&share.value).map_err(|_| mismatch("share value"))?)?;
+        let randomness = hex::decode(&share.randomness).map_err(|_| mismatch("share randomness"))?;
+        
+        let mut proof = ZeroKnowledgeProof::new(vector.curve_type);
+        proof.prove(&pedersen, &value, &randomness)?;
+        if hex::encode(&proof.commitment) != share.commitment || !proof.verify(&pedersen)? {
+            return Err(mismatch(&format!("proof for share {}", share.id)));
+        }
+    }
+    
+    // Step 3: Signature under a seed-derived key
+    let signing_key = derive_scalar(curve, &seed, "sk", 0)?;
+    let mut signature = SchnorrSignature::new(vector.curve_type);
+    signature.sign(VECTOR_MESSAGE, &signing_key)?;
+    if !signature.verify_for(VECTOR_MESSAGE, &curve.generator().mul(&signing_key)?)? {
+        return Err(mismatch("signature"));
+    }
+    
+    Ok(())
+}
+
+/// Derive a non-zero scalar from the seed, a label and an index
+fn derive_scalar(curve: &dyn Curve, seed: &[u8], label: &str, index: u32) -> Result<Scalar> {
+    let mut hasher = Sha256::new();
+    hasher.update(VECTOR_DOMAIN);
+    hasher.update(seed);
+    hasher.update(label.as_bytes());
+    hasher.update(index.to_be_bytes());
+    let digest = hasher.finalize();
+    
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    curve.scalar_from_u64(u64::from_be_bytes(bytes).max(1))
+}
+
@@ -0,0 +1,119 @@
+//! WebAssembly bindings
+//!
+//! Exports the core flow through `wasm-bindgen` for browser code:
+//! - `generate_shares` deals a fresh secret
+//! - `commit` / `verify_commitment` for Pedersen commitments to a value
+//! - `reconstruct` recovers the secret from a set of shares
+//!
+//! Scalars and commitments cross the boundary as hex strings, documents as
+//! JSON strings in the same shape the CLI reads and writes. Errors are thrown
+//! as JavaScript strings.
+
+use crate::{Error, Result, CurveType, SharingParams};
+use crate::curve::curve_backend;
+use crate::hexinput::parse_hex;
+use crate::laurent::{utils as laurent_utils, LaurentSeries, Share};
+use crate::pedersen::PedersenCommitment;
+use wasm_bindgen::prelude::*;
+
+/// Deal shares of a fresh random secret
+///
+/// Returns `{curve_type, threshold, participants, dealing_id, shares}` as JSON.
+#[wasm_bindgen]
+pub fn generate_shares(curve: &str, threshold: usize, participants: usize) -> std::result::Result<JsValue, JsValue> {
+    to_js((|| {
+        let params = SharingParams {
+            curve_type: curve.parse()?,
+            threshold,
+            participants,
+        };
+        laurent_utils::validate_params(&params)?;
+
+        let laurent = LaurentSeries::new(&params)?;
+        let shares = laurent.generate_shares()?;
+
+        to_json(&serde_json::json!({
+            "curve_type": params.curve_type.to_string(),
+            "threshold": threshold,
+            "participants": participants,
+            "dealing_id": hex::encode(laurent.dealing_id()?),
+            "shares": shares,
+        }))
+    })())
+}
+
+/// Commit to a hex scalar with fresh randomness
+///
+/// Returns `{commitment, randomness}` as JSON; both are needed to verify.
+#[wasm_bindgen]
+pub fn commit(curve: &str, value_hex: &str) -> std::result::Result<JsValue, JsValue> {
+    to_js((|| {
+        let curve_type: CurveType = curve.parse()?;
+        let value = curve_backend(curve_type)?.scalar_from_bytes(&parse_hex(value_hex, "value")?)?;
+
+        let pedersen = PedersenCommitment::new(curve_type)?;
+        let randomness = pedersen.generate_randomness();
+        let commitment = pedersen.commit(&value, &randomness)?;
+
+        to_json(&serde_json::json!({
+            "commitment": hex::encode(commitment),
+            "randomness": hex::encode(randomness),
+        }))
+    })())
+}
+
+/// Check that `value_hex` and `randomness_hex` open `commitment_hex`
+#[wasm_bindgen]
+pub fn verify_commitment(
+    curve: &str,
+    commitment_hex: &str,
+    value_hex: &str,
+    randomness_hex: &str,
+) -> std::result::Result<bool, JsValue> {
+    to_js((|| {
+        let curve_type: CurveType = curve.parse()?;
+        let commitment = parse_hex(commitment_hex, "commitment")?;
+        let value = curve_backend(curve_type)?.scalar_from_bytes(&parse_hex(value_hex, "value")?)?;
+        let randomness = parse_hex(randomness_hex, "randomness")?;
+
+        PedersenCommitment::new(curve_type)?.verify(&commitment, &value, &randomness)
+    })())
+}
+
+/// Reconstruct the secret from a JSON document with `curve_type` and `shares`
+///
+/// Accepts the output of `generate_shares`, reduced to any threshold-sized
+/// subset of its shares. Returns `{secret, participants_used}` as JSON.
+#[wasm_bindgen]
+pub fn reconstruct(shares_json: &str) -> std::result::Result<JsValue, JsValue> {
+    to_js((|| {
+        let document: serde_json::Value = serde_json::from_str(shares_json)?;
+        let curve_type: CurveType = document["curve_type"].as_str()
+            .ok_or_else(|| Error::InvalidInput("Missing curve_type".to_string()))?
+            .parse()?;
+        let shares: Vec<Share> = serde_json::from_value(document["shares"].clone())?;
+
+        // Reconstruct with every supplied share, like the CLI `combine`
+        let params = SharingParams {
+            curve_type,
+            threshold: shares.len(),
+            participants: shares.len(),
+        };
+        let result = LaurentSeries::new(&params)?.reconstruct_secret(&shares)?;
+
+        to_json(&serde_json::json!({
+            "secret": hex::encode(&result.secret),
+            "participants_used": result.participants_used,
+        }))
+    })())
+}
+
+/// Serialize a JSON document into a JavaScript string
+fn to_json(value: &serde_json::Value) -> Result<JsValue> {
+    Ok(JsValue::from_str(&serde_json::to_string(value)?))
+}
+
+/// Throw library errors as JavaScript strings
+fn to_js<T>(result: Result<T>) -> std::result::Result<T, JsValue> {
+    result.map_err(|e| JsValue::from_str(&e.to_string()))
+}
@@ -4,14 +4,27 @@
 //! Prove ∃x,r: C_i = g^x * h^r
 //! 
 //! Uses Fiat-Shamir heuristic for non-interactive proofs.
+//! 
+//! `DesignatedVerifierProof` opens a commitment to a single verifier without
+//! producing evidence that verifier could pass on.
+//! 
+//! `RangeProof` shows that a commitment opens to a value in [0, 2^bits).
+//! 
+//! `utils::partial_sign` and `utils::aggregate_partial_signatures` produce a
+//! `SchnorrSignature` under the group key from `threshold` shares, without
+//! reconstructing the secret.
 
-use crate::{Error, Result, CurveType, ZKProof};
-use crate::curve::{Curve, Scalar, Point, create_curve};
-use crate::pedersen::{PedersenCommitment, CommitmentProof};
-use crate::laurent::Share;
+use crate::{Error, Result, CurveType, ShareId, Commitment, Randomness};
+use crate::curve::{Curve, Scalar, Point, curve_backend, is_little_endian};
+use crate::pedersen::{sample_scalar, CommitmentOpening, PedersenCommitment};
+use crate::laurent::{Share, residue_coefficient};
+use crate::types::{IdScheme, SharingParams};
 use serde::{Deserialize, Serialize};
+use subtle::ConstantTimeEq;
 use sha2::{Sha256, Digest};
-use rand::Rng;
+use hmac::{Hmac, Mac};
+use rand::{CryptoRng, RngCore};
+use zeroize::Zeroize;
 
 /// Zero-Knowledge Proof for Pedersen commitment
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +34,9 @@ pub struct ZeroKnowledgeProof {
     pub challenge: Vec<u8>,
     pub response: Vec<u8>,
     pub public_point: Vec<u8>,
+    /// Caller context bound into the challenge
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub context: Vec<u8>,
 }
 
 impl ZeroKnowledgeProof {
@@ -32,31 +48,61 @@ impl ZeroKnowledgeProof {
             challenge: Vec::new(),
             response: Vec::new(),
             public_point: Vec::new(),
+            context: Vec::new(),
         }
     }
     
+    /// Bind caller context (e.g. session and share id) into the challenge
+    pub fn with_context(mut self, context: &[u8]) -> Self {
+        self.context = context.to_vec();
+        self
+    }
+    
     /// Generate proof for a commitment
     pub fn prove(&mut self, pedersen: &PedersenCommitment, value: &Scalar, randomness: &[u8]) -> Result<()> {
-        let curve = create_curve(self.curve_type);
+        self.prove_with_rng(pedersen, value, randomness, &mut rand::thread_rng())
+    }
+    
+    /// Generate proof for a commitment with witnesses drawn from `rng`
+    pub fn prove_with_rng<R: RngCore + CryptoRng>(
+        &mut self,
+        pedersen: &PedersenCommitment,
+        value: &Scalar,
+        randomness: &[u8],
+        rng: &mut R,
+    ) -> Result<()> {
+        let curve = curve_backend(self.curve_type)?;
         
         // Generate random witness
-        let mut rng = rand::thread_rng();
-        let alpha = curve.random_scalar()?;
-        let beta = curve.random_scalar()?;
+        let alpha = curve.random_scalar_with_rng(rng)?;
+        let beta = curve.random_scalar_with_rng(rng)?;
         
         // Compute commitment
-        let commitment = pedersen.commit(value, randomness)?;
+        let commitment = pedersen.commit(value, &randomness.to_vec())?;
+        
+        self.prove_opening(pedersen, commitment, value, randomness, &alpha, &beta)
+    }
+    
+    /// Prove knowledge of an opening of an already computed commitment
+    pub(crate) fn prove_opening(
+        &mut self,
+        pedersen: &PedersenCommitment,
+        commitment: Commitment,
+        value: &Scalar,
+        randomness: &[u8],
+        alpha: &Scalar,
+        beta: &Scalar,
+    ) -> Result<()> {
+        let curve = curve_backend(self.curve_type)?;
         self.commitment = commitment.clone();
         
         // Compute public point: A = g^alpha * h^beta
-        let g_alpha = pedersen.g.mul(&alpha)?;
-        let h_beta = pedersen.h.mul(&beta)?;
-        let public_point = g_alpha.add(&h_beta)?;
+        let public_point = pedersen.mul_gh(alpha, beta)?;
         self.public_point = public_point.to_compressed_bytes()?;
         
         // Generate challenge using Fiat-Shamir heuristic
         let challenge_input = self.create_challenge_input(&commitment, &self.public_point)?;
-        let challenge = self.hash_to_scalar(&challenge_input, &curve)?;
+        let challenge = self.hash_to_scalar(&challenge_input, curve)?;
         self.challenge = challenge.as_bytes().to_vec();
         
         // Compute response: s1 = alpha + c*x, s2 = beta + c*r
@@ -70,66 +116,214 @@ impl ZeroKnowledgeProof {
         
         // Combine responses
         let mut response = Vec::new();
-        response.extend_from_slice(&s1.as_bytes());
-        response.extend_from_slice(&s2.as_bytes());
+        response.extend_from_slice(s1.as_bytes());
+        response.extend_from_slice(s2.as_bytes());
         self.response = response;
         
         Ok(())
     }
     
     /// Verify zero-knowledge proof
+    /// 
+    /// The challenge is recomputed from the transcript; a stored challenge
+    /// that does not match it is rejected rather than trusted.
     pub fn verify(&self, pedersen: &PedersenCommitment) -> Result<bool> {
-        let curve = create_curve(self.curve_type);
+        self.verify_against(pedersen, &self.commitment)
+    }
+    
+    /// Verify the proof against an authoritative commitment
+    /// 
+    /// The verifier supplies the commitment from a trusted source, such as the
+    /// published dealing. A proof whose embedded commitment disagrees with it
+    /// is rejected, and the challenge must be bound to the supplied one.
+    pub fn verify_against(&self, pedersen: &PedersenCommitment, commitment: &Commitment) -> Result<bool> {
+        if &self.commitment != commitment {
+            return Ok(false);
+        }
+        self.check_nondegenerate(commitment)?;
+        
+        let curve = curve_backend(self.curve_type)?;
+        let challenge_input = self.create_challenge_input(commitment, &self.public_point)?;
+        let challenge = self.hash_to_scalar(&challenge_input, curve)?;
+        if !bool::from(challenge.as_bytes().ct_eq(&self.challenge)) {
+            return Ok(false);
+        }
+        
+        let (lhs, rhs) = self.verification_sides(pedersen, commitment, &challenge, curve)?;
+        Ok(bool::from(lhs.ct_eq(&rhs)))
+    }
+    
+    /// Compute both sides of g^s1 * h^s2 == A + c*C as compressed points
+    fn verification_sides(
+        &self,
+        pedersen: &PedersenCommitment,
+        commitment: &[u8],
+        challenge: &Scalar,
+        curve: &dyn Curve,
+    ) -> Result<(Vec<u8>, Vec<u8>)> {
+        let (s1, s2) = self.parse_response(curve)?;
+        
+        // Left side: g^s1 * h^s2
+        let lhs = curve.msm_vartime(&[pedersen.g.clone(), pedersen.h.clone()], &[s1, s2])?;
         
-        // Parse response
-        if self.response.len() < 64 {
+        // Right side: A + c*C
+        let announcement = Point::from_compressed_bytes(self.curve_type, &self.public_point)?;
+        let commitment_point = Point::from_compressed_bytes(self.curve_type, commitment)?;
+        let rhs = curve.msm_vartime(&[announcement, commitment_point], &[curve.scalar_from_u64(1)?, challenge.clone()])?;
+        
+        Ok((lhs.to_compressed_bytes()?, rhs.to_compressed_bytes()?))
+    }
+    
+    /// Reject identity points, small-order components and a zero challenge
+    /// 
+    /// Identity points and a zero challenge make the verification equation
+    /// hold without knowledge of an opening. A small-order component on
+    /// Ed25519 makes it depend on the parity of the challenge, and could
+    /// cancel out in a batch. Malformed encodings are left to the equation
+    /// check.
+    fn check_nondegenerate(&self, commitment: &[u8]) -> Result<()> {
+        for (name, bytes) in [("Commitment", commitment), ("Announcement", self.public_point.as_slice())] {
+            let Ok(point) = Point::from_compressed_bytes(self.curve_type, bytes) else {
+                continue;
+            };
+            if point.is_identity() {
+                return Err(Error::ZKProofError(format!("{} is the identity point", name)));
+            }
+            if !point.is_torsion_free() {
+                return Err(Error::ZKProofError(format!("{} has a small-order component", name)));
+            }
+        }
+        if Scalar::new(self.curve_type, self.challenge.clone()).is_zero() {
+            return Err(Error::ZKProofError("Challenge is zero".to_string()));
+        }
+        Ok(())
+    }
+    
+    /// Split the response into s1 and s2
+    fn parse_response(&self, curve: &dyn Curve) -> Result<(Scalar, Scalar)> {
+        let width = curve.scalar_byte_len();
+        if self.response.len() != 2 * width {
             return Err(Error::ZKProofError("Invalid response length".to_string()));
         }
         
-        let s1_bytes = &self.response[..32];
-        let s2_bytes = &self.response[32..64];
+        let s1 = curve.scalar_from_bytes(&self.response[..width])?;
+        let s2 = curve.scalar_from_bytes(&self.response[width..])?;
+        Ok((s1, s2))
+    }
+    
+    /// Explain a verification step by step
+    /// 
+    /// Records every transcript append, the recomputed challenge and both sides
+    /// of the verification equation so a third party can re-check a single
+    /// share and see where verification diverges.
+    pub fn explain(&self, pedersen: &PedersenCommitment) -> Result<ProofTrace> {
+        let curve = curve_backend(self.curve_type)?;
         
-        let s1 = curve.scalar_from_bytes(s1_bytes)?;
-        let s2 = curve.scalar_from_bytes(s2_bytes)?;
-        let c = curve.scalar_from_bytes(&self.challenge)?;
+        let transcript = self.transcript_entries(&self.commitment, &self.public_point)
+            .into_iter()
+            .map(|(label, data)| TranscriptEntry { label: label.to_string(), data: hex::encode(data) })
+            .collect();
+        
+        let challenge_input = self.create_challenge_input(&self.commitment, &self.public_point)?;
+        let recomputed_challenge = self.hash_to_scalar(&challenge_input, curve)?;
+        
+        let mut trace = ProofTrace {
+            curve_type: self.curve_type,
+            g: hex::encode(pedersen.g.to_compressed_bytes()?),
+            h: hex::encode(pedersen.h.to_compressed_bytes()?),
+            commitment: hex::encode(&self.commitment),
+            transcript,
+            stored_challenge: hex::encode(&self.challenge),
+            recomputed_challenge: hex::encode(recomputed_challenge.as_bytes()),
+            lhs: None,
+            rhs: None,
+            failure: None,
+        };
+        
+        if let Err(e) = Point::from_compressed_bytes(self.curve_type, &self.commitment) {
+            trace.failure = Some(ProofFailure::MalformedCommitment(e.to_string()));
+            return Ok(trace);
+        }
+        
+        if let Err(e) = Point::from_compressed_bytes(self.curve_type, &self.public_point) {
+            trace.failure = Some(ProofFailure::MalformedAnnouncement(e.to_string()));
+            return Ok(trace);
+        }
+        
+        let width = curve.scalar_byte_len();
+        if self.response.len() != 2 * width {
+            trace.failure = Some(ProofFailure::MalformedResponse(format!(
+                "expected {} bytes, got {}", 2 * width, self.response.len()
+            )));
+            return Ok(trace);
+        }
+        
+        if let Err(e) = self.check_nondegenerate(&self.commitment) {
+            trace.failure = Some(ProofFailure::DegenerateStatement(e.to_string()));
+            return Ok(trace);
+        }
+        
+        if !bool::from(recomputed_challenge.as_bytes().ct_eq(&self.challenge)) {
+            trace.failure = Some(ProofFailure::ChallengeMismatch);
+            return Ok(trace);
+        }
         
-        // Recompute public point: A' = g^s1 * h^s2 * C^(-c)
-        let g_s1 = pedersen.g.mul(&s1)?;
-        let h_s2 = pedersen.h.mul(&s2)?;
-        let temp = g_s1.add(&h_s2)?;
+        match self.verification_sides(pedersen, &self.commitment, &recomputed_challenge, curve) {
+            Ok((lhs, rhs)) => {
+                if !bool::from(lhs.ct_eq(&rhs)) {
+                    trace.failure = Some(ProofFailure::EquationMismatch);
+                }
+                trace.lhs = Some(hex::encode(lhs));
+                trace.rhs = Some(hex::encode(rhs));
+            }
+            Err(e) => {
+                trace.failure = Some(ProofFailure::MalformedResponse(e.to_string()));
+            }
+        }
         
-        let commitment_point = Point::from_compressed_bytes(self.curve_type, &self.commitment)?;
-        let c_neg = c.invert()?;
-        let commitment_c = commitment_point.mul(&c_neg)?;
-        let computed_public = temp.add(&commitment_c)?;
+        Ok(trace)
+    }
+    
+    /// Labelled transcript appends for Fiat-Shamir
+    fn transcript_entries(&self, commitment: &[u8], public_point: &[u8]) -> Vec<(&'static str, Vec<u8>)> {
+        let mut entries = vec![
+            ("domain", b"z-mpc-zkp".to_vec()),
+            ("curve", self.curve_type.to_string().into_bytes()),
+        ];
         
-        let computed_bytes = computed_public.to_compressed_bytes()?;
+        // Context-free proofs keep the original transcript
+        if !self.context.is_empty() {
+            let mut context = (self.context.len() as u32).to_be_bytes().to_vec();
+            context.extend_from_slice(&self.context);
+            entries.push(("context", context));
+        }
         
-        Ok(computed_bytes == self.public_point)
+        entries.push(("commitment", commitment.to_vec()));
+        entries.push(("public_point", public_point.to_vec()));
+        entries
     }
     
     /// Create challenge input for Fiat-Shamir
     fn create_challenge_input(&self, commitment: &[u8], public_point: &[u8]) -> Result<Vec<u8>> {
         let mut input = Vec::new();
-        input.extend_from_slice(b"z-mpc-zkp");
-        input.extend_from_slice(&self.curve_type.to_string().as_bytes());
-        input.extend_from_slice(commitment);
-        input.extend_from_slice(public_point);
+        for (_, data) in self.transcript_entries(commitment, public_point) {
+            input.extend_from_slice(&data);
+        }
         Ok(input)
     }
     
     /// Hash input to scalar for challenge
-    fn hash_to_scalar(&self, input: &[u8], curve: &Box<dyn Curve>) -> Result<Scalar> {
+    fn hash_to_scalar(&self, input: &[u8], curve: &dyn Curve) -> Result<Scalar> {
         let mut hasher = Sha256::new();
         hasher.update(input);
         let hash = hasher.finalize();
         
-        curve.scalar_from_bytes(&hash.to_vec())
+        curve.scalar_from_bytes_mod_order(&hash)
     }
     
     /// Generate proof for a share
     pub fn prove_share(&mut self, pedersen: &PedersenCommitment, share: &Share, randomness: &[u8]) -> Result<()> {
-        let curve = create_curve(self.curve_type);
+        let curve = curve_backend(self.curve_type)?;
         let value = curve.scalar_from_bytes(&share.value)?;
         self.prove(pedersen, &value, randomness)
     }
@@ -160,6 +354,52 @@ impl ZeroKnowledgeProof {
     }
 }
 
+/// Single transcript append recorded by `ZeroKnowledgeProof::explain`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranscriptEntry {
+    pub label: String,
+    pub data: String,
+}
+
+/// Stage at which proof verification diverged
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProofFailure {
+    /// The commitment is not a valid curve point
+    MalformedCommitment(String),
+    /// The announcement is not a valid curve point
+    MalformedAnnouncement(String),
+    /// The response does not decode to two scalars
+    MalformedResponse(String),
+    /// An identity or small-order point, or a zero challenge
+    DegenerateStatement(String),
+    /// The stored challenge does not match the transcript
+    ChallengeMismatch,
+    /// The verification equation does not hold
+    EquationMismatch,
+}
+
+/// Structured record of a proof verification for independent re-checking
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofTrace {
+    pub curve_type: CurveType,
+    pub g: String,
+    pub h: String,
+    pub commitment: String,
+    pub transcript: Vec<TranscriptEntry>,
+    pub stored_challenge: String,
+    pub recomputed_challenge: String,
+    pub lhs: Option<String>,
+    pub rhs: Option<String>,
+    pub failure: Option<ProofFailure>,
+}
+
+impl ProofTrace {
+    /// Check if the traced verification succeeded
+    pub fn is_valid(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
 /// Schnorr signature using zero-knowledge proof
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SchnorrSignature {
@@ -181,16 +421,28 @@ impl SchnorrSignature {
     }
     
     /// Sign a message using private key
+    /// 
+    /// The nonce is derived deterministically from the key and message
+    /// (RFC 6979 style), so signing the same message twice gives
+    /// byte-identical signatures.
     pub fn sign(&mut self, message: &[u8], private_key: &Scalar) -> Result<()> {
-        let curve = create_curve(self.curve_type);
-        
-        // Generate random k
-        let mut rng = rand::thread_rng();
-        let k = curve.random_scalar()?;
-        
+        let curve = curve_backend(self.curve_type)?;
+        let k = deterministic_nonce(curve, private_key, message)?;
+        self.sign_with_nonce(message, private_key, &k, curve)
+    }
+    
+    /// Sign a message using a nonce drawn from `rng`
+    pub fn sign_with_rng<R: RngCore + CryptoRng>(&mut self, message: &[u8], private_key: &Scalar, rng: &mut R) -> Result<()> {
+        let curve = curve_backend(self.curve_type)?;
+        let k = sample_scalar(curve, rng);
+        self.sign_with_nonce(message, private_key, &k, curve)
+    }
+    
+    /// Sign with the nonce `k`
+    fn sign_with_nonce(&mut self, message: &[u8], private_key: &Scalar, k: &Scalar, curve: &dyn Curve) -> Result<()> {
         // Compute R = k*G
         let g = curve.generator();
-        let r_point = g.mul(&k)?;
+        let r_point = g.mul(k)?;
         let r_bytes = r_point.to_compressed_bytes()?;
         
         // Compute public key P = private_key*G
@@ -199,7 +451,7 @@ impl SchnorrSignature {
         
         // Create challenge
         let challenge_input = self.create_schnorr_challenge_input(&r_bytes, &self.public_key, message)?;
-        let challenge = self.hash_to_scalar(&challenge_input, &curve)?;
+        let challenge = self.hash_to_scalar(&challenge_input, curve)?;
         self.challenge = challenge.as_bytes().to_vec();
         
         // Compute response: s = k + c*private_key
@@ -213,35 +465,59 @@ impl SchnorrSignature {
     
     /// Verify Schnorr signature
     pub fn verify(&self, message: &[u8]) -> Result<bool> {
-        let curve = create_curve(self.curve_type);
+        let curve = curve_backend(self.curve_type)?;
+        
+        if self.response.len() != curve.scalar_byte_len() {
+            return Err(Error::ZKProofError("Invalid response length".to_string()));
+        }
         
         let s = curve.scalar_from_bytes(&self.response)?;
         let c = curve.scalar_from_bytes(&self.challenge)?;
+        if c.is_zero() {
+            return Err(Error::ZKProofError("Challenge is zero".to_string()));
+        }
         
         let g = curve.generator();
         let public_key = Point::from_compressed_bytes(self.curve_type, &self.public_key)?;
+        if public_key.is_identity() {
+            return Err(Error::ZKProofError("Public key is the identity point".to_string()));
+        }
         
         // Compute R' = s*G - c*P
         let s_g = g.mul(&s)?;
         let c_p = public_key.mul(&c)?;
-        let c_neg = c.invert()?;
-        let c_neg_p = public_key.mul(&c_neg)?;
-        let r_prime = s_g.add(&c_neg_p)?;
+        let r_prime = s_g.sub(&c_p)?;
         let r_prime_bytes = r_prime.to_compressed_bytes()?;
         
         // Recompute challenge
         let challenge_input = self.create_schnorr_challenge_input(&r_prime_bytes, &self.public_key, message)?;
-        let computed_challenge = self.hash_to_scalar(&challenge_input, &curve)?;
-        let computed_bytes = computed_challenge.as_bytes().to_vec();
+        let computed_challenge = self.hash_to_scalar(&challenge_input, curve)?;
         
-        Ok(computed_bytes == self.challenge)
+        Ok(bool::from(computed_challenge.as_bytes().ct_eq(&self.challenge)))
+    }
+    
+    /// Verify the signature and check that it was made under `expected_public_key`
+    /// 
+    /// `verify` alone accepts a signature under whatever key it embeds; this
+    /// fails with `Error::KeyMismatch` when the embedded key is not the expected one.
+    pub fn verify_for(&self, message: &[u8], expected_public_key: &Point) -> Result<bool> {
+        let expected = expected_public_key.to_compressed_bytes()?;
+        if expected_public_key.curve_type != self.curve_type || expected != self.public_key {
+            return Err(Error::KeyMismatch(format!(
+                "Signature was made under {}, expected {}",
+                hex::encode(&self.public_key),
+                hex::encode(&expected)
+            )));
+        }
+        
+        self.verify(message)
     }
     
     /// Create challenge input for Schnorr signature
     fn create_schnorr_challenge_input(&self, r: &[u8], public_key: &[u8], message: &[u8]) -> Result<Vec<u8>> {
         let mut input = Vec::new();
         input.extend_from_slice(b"z-mpc-schnorr");
-        input.extend_from_slice(&self.curve_type.to_string().as_bytes());
+        input.extend_from_slice(self.curve_type.to_string().as_bytes());
         input.extend_from_slice(r);
         input.extend_from_slice(public_key);
         input.extend_from_slice(message);
@@ -249,28 +525,1263 @@ impl SchnorrSignature {
     }
     
     /// Hash input to scalar for challenge
-    fn hash_to_scalar(&self, input: &[u8], curve: &Box<dyn Curve>) -> Result<Scalar> {
+    fn hash_to_scalar(&self, input: &[u8], curve: &dyn Curve) -> Result<Scalar> {
         let mut hasher = Sha256::new();
         hasher.update(input);
         let hash = hasher.finalize();
         
-        curve.scalar_from_bytes(&hash.to_vec())
+        curve.scalar_from_bytes_mod_order(&hash)
+    }
+}
+
+/// Domain separation tag for threshold signing binding factors
+const BINDING_FACTOR_DOMAIN: &[u8] = b"z-mpc-threshold-binding";
+
+/// A signer's secret nonces for one threshold signature
+/// 
+/// Consumed by `utils::partial_sign`, so a nonce pair cannot sign twice.
+pub struct SigningNonces {
+    pub id: ShareId,
+    hiding: Scalar,
+    binding: Scalar,
+}
+
+/// Public commitments G·d_i, G·e_i to a signer's nonces
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SigningCommitment {
+    pub id: ShareId,
+    pub hiding: Point,
+    pub binding: Point,
+}
+
+/// A signer's share z_i of the aggregated response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartialSignature {
+    pub id: ShareId,
+    pub response: Vec<u8>,
+}
+
+/// Binding factors ρ_i of the signers, ordered by id
+/// 
+/// Each ρ_i hashes the signer id with the group key, the message and every
+/// commitment, so a signer's nonces only combine into this one signature.
+fn binding_factors(
+    commitments: &[SigningCommitment],
+    group_public_key: &Point,
+    message: &[u8],
+    curve: &dyn Curve,
+) -> Result<Vec<(ShareId, Scalar)>> {
+    let mut ordered: Vec<&SigningCommitment> = commitments.iter().collect();
+    ordered.sort_by_key(|commitment| commitment.id);
+    if ordered.windows(2).any(|pair| pair[0].id == pair[1].id) {
+        return Err(Error::InvalidInput("Duplicate signer commitment".to_string()));
+    }
+    
+    let mut transcript = Vec::new();
+    transcript.extend_from_slice(BINDING_FACTOR_DOMAIN);
+    transcript.extend_from_slice(curve.curve_type().to_string().as_bytes());
+    transcript.extend_from_slice(&group_public_key.to_compressed_bytes()?);
+    transcript.extend_from_slice(&(message.len() as u64).to_be_bytes());
+    transcript.extend_from_slice(message);
+    for commitment in &ordered {
+        transcript.extend_from_slice(&commitment.id.to_be_bytes());
+        transcript.extend_from_slice(&commitment.hiding.to_compressed_bytes()?);
+        transcript.extend_from_slice(&commitment.binding.to_compressed_bytes()?);
+    }
+    
+    ordered.iter().map(|commitment| {
+        let mut hasher = Sha256::new();
+        hasher.update(&transcript);
+        hasher.update(commitment.id.to_be_bytes());
+        Ok((commitment.id, curve.scalar_from_bytes_mod_order(&hasher.finalize())?))
+    }).collect()
+}
+
+/// Group nonce commitment R = Σ D_i + ρ_i·E_i and the challenge c = H(R, P, m)
+fn group_challenge(
+    commitments: &[SigningCommitment],
+    factors: &[(ShareId, Scalar)],
+    group_public_key: &Point,
+    message: &[u8],
+    curve: &dyn Curve,
+) -> Result<Scalar> {
+    let mut r_point = Point::identity(curve.curve_type())?;
+    for (id, factor) in factors {
+        let commitment = commitments.iter().find(|commitment| commitment.id == *id)
+            .ok_or_else(|| Error::InvalidInput(format!("No commitment from signer {}", id)))?;
+        r_point = r_point.add(&commitment.hiding.add(&commitment.binding.mul(factor)?)?)?;
+    }
+    
+    let signature = SchnorrSignature::new(curve.curve_type());
+    let challenge_input = signature.create_schnorr_challenge_input(
+        &r_point.to_compressed_bytes()?,
+        &group_public_key.to_compressed_bytes()?,
+        message,
+    )?;
+    signature.hash_to_scalar(&challenge_input, curve)
+}
+
+/// Domain separation tag for deterministic Schnorr nonces
+const NONCE_DOMAIN: &[u8] = b"z-mpc-schnorr-nonce";
+
+/// Derive a Schnorr nonce from the private key and message
+/// 
+/// Runs the RFC 6979 HMAC-SHA256 DRBG with the canonical private key as
+/// `x` and a domain-separated message hash as `h1`. Each candidate takes
+/// as many DRBG outputs as a scalar is wide, is truncated to the bit length
+/// of the curve order and is rejected unless `0 < k < q`.
+fn deterministic_nonce(curve: &dyn Curve, private_key: &Scalar, message: &[u8]) -> Result<Scalar> {
+    type HmacSha256 = Hmac<Sha256>;
+    let hmac = |key: &[u8], parts: &[&[u8]]| -> Result<Vec<u8>> {
+        let mut mac = HmacSha256::new_from_slice(key)
+            .map_err(|e| Error::Internal(e.to_string()))?;
+        for part in parts {
+            mac.update(part);
+        }
+        Ok(mac.finalize().into_bytes().to_vec())
+    };
+    
+    let width = curve.scalar_byte_len();
+    let order = curve.order().as_bytes().to_vec();
+    let qlen = 8 * width - order.iter().take_while(|&&byte| byte == 0).count() * 8
+        - order.iter().find(|&&byte| byte != 0).map_or(0, |byte| byte.leading_zeros() as usize);
+    
+    let mut x = private_key.canonical_bytes();
+    let mut hasher = Sha256::new();
+    hasher.update(NONCE_DOMAIN);
+    hasher.update(curve.curve_type().to_string().as_bytes());
+    hasher.update(message);
+    let h1 = hasher.finalize();
+    
+    // Step 1: Seed the DRBG state (RFC 6979 section 3.2 steps b-g)
+    let mut v = vec![0x01u8; 32];
+    let mut k = vec![0x00u8; 32];
+    k = hmac(&k, &[&v, &[0x00], &x, &h1])?;
+    v = hmac(&k, &[&v])?;
+    k = hmac(&k, &[&v, &[0x01], &x, &h1])?;
+    v = hmac(&k, &[&v])?;
+    x.zeroize();
+    
+    // Step 2: Draw candidates until one lies in [1, q-1]
+    let nonce = loop {
+        let mut t = Vec::with_capacity(width);
+        while t.len() < width {
+            v = hmac(&k, &[&v])?;
+            t.extend_from_slice(&v);
+        }
+        let mut candidate = bits_to_int(&t[..width], qlen);
+        t.zeroize();
+        if candidate.iter().any(|&byte| byte != 0) && candidate < order {
+            if is_little_endian(curve.curve_type()) {
+                candidate.reverse();
+            }
+            let nonce = curve.scalar_from_bytes(&candidate);
+            candidate.zeroize();
+            break nonce;
+        }
+        candidate.zeroize();
+        k = hmac(&k, &[&v, &[0x00]])?;
+        v = hmac(&k, &[&v])?;
+    };
+    
+    // Step 3: Wipe the DRBG state
+    k.zeroize();
+    v.zeroize();
+    nonce
+}
+
+/// Keep the leftmost `qlen` bits of a big-endian string, right-aligned in as many bytes
+fn bits_to_int(bytes: &[u8], qlen: usize) -> Vec<u8> {
+    let shift = 8 * bytes.len() - qlen;
+    let (byte_shift, bit_shift) = (shift / 8, shift % 8);
+    
+    let mut out = vec![0u8; bytes.len()];
+    for (i, byte) in out.iter_mut().enumerate().skip(byte_shift) {
+        let src = i - byte_shift;
+        *byte = bytes[src] >> bit_shift;
+        if bit_shift > 0 && src > 0 {
+            *byte |= bytes[src - 1] << (8 - bit_shift);
+        }
+    }
+    out
+}
+
+/// Batched proof that two vectors of commitments open to the same values
+/// 
+/// For commitments C1_i = g^{v_i} h^{r1_i} and C2_i = g^{v_i} h^{r2_i}, the
+/// pairs are folded with Fiat-Shamir weights ρ_i into E1 = Σ ρ_i C1_i and
+/// E2 = Σ ρ_i C2_i, and a single equal-openings proof is given for (E1, E2).
+/// A pair with differing values survives the folding with overwhelming
+/// probability, so one proof covers the whole vector.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchLinkProof {
+    pub curve_type: CurveType,
+    pub announcement_1: Vec<u8>,
+    pub announcement_2: Vec<u8>,
+    pub response_value: Vec<u8>,
+    pub response_1: Vec<u8>,
+    pub response_2: Vec<u8>,
+}
+
+impl BatchLinkProof {
+    /// Prove that `commitments_1[i]` and `commitments_2[i]` both commit to `values[i]`
+    pub fn prove(
+        pedersen: &PedersenCommitment,
+        commitments_1: &[Commitment],
+        commitments_2: &[Commitment],
+        values: &[Scalar],
+        randomness_1: &[Randomness],
+        randomness_2: &[Randomness],
+    ) -> Result<Self> {
+        let count = commitments_1.len();
+        if count == 0 {
+            return Err(Error::InvalidInput("Cannot link an empty set of commitments".to_string()));
+        }
+        if commitments_2.len() != count || values.len() != count
+            || randomness_1.len() != count || randomness_2.len() != count {
+            return Err(Error::InvalidInput("All arrays must have same length".to_string()));
+        }
+        
+        let curve_type = pedersen.curve_type;
+        let curve = curve_backend(curve_type)?;
+        let weights = Self::weights(curve_type, commitments_1, commitments_2, curve)?;
+        
+        // Fold the openings: a = Σ ρ_i v_i, b1 = Σ ρ_i r1_i, b2 = Σ ρ_i r2_i
+        let mut a = curve.scalar_from_u64(0)?;
+        let mut b1 = curve.scalar_from_u64(0)?;
+        let mut b2 = curve.scalar_from_u64(0)?;
+        for i in 0..count {
+            let r1 = curve.scalar_from_bytes(&randomness_1[i])?;
+            let r2 = curve.scalar_from_bytes(&randomness_2[i])?;
+            a = a.add(&weights[i].mul(&values[i])?)?;
+            b1 = b1.add(&weights[i].mul(&r1)?)?;
+            b2 = b2.add(&weights[i].mul(&r2)?)?;
+        }
+        
+        // Announcements share the value nonce: A1 = g^ka h^k1, A2 = g^ka h^k2
+        let k_a = curve.random_scalar()?;
+        let k_1 = curve.random_scalar()?;
+        let k_2 = curve.random_scalar()?;
+        let g_ka = pedersen.mul_g(&k_a)?;
+        let announcement_1 = g_ka.add(&pedersen.mul_h(&k_1)?)?.to_compressed_bytes()?;
+        let announcement_2 = g_ka.add(&pedersen.mul_h(&k_2)?)?.to_compressed_bytes()?;
+        
+        let c = Self::challenge(curve_type, commitments_1, commitments_2, &announcement_1, &announcement_2, curve)?;
+        
+        Ok(Self {
+            curve_type,
+            response_value: k_a.add(&c.mul(&a)?)?.as_bytes().to_vec(),
+            response_1: k_1.add(&c.mul(&b1)?)?.as_bytes().to_vec(),
+            response_2: k_2.add(&c.mul(&b2)?)?.as_bytes().to_vec(),
+            announcement_1,
+            announcement_2,
+        })
+    }
+    
+    /// Verify the proof against both commitment vectors
+    pub fn verify(
+        &self,
+        pedersen: &PedersenCommitment,
+        commitments_1: &[Commitment],
+        commitments_2: &[Commitment],
+    ) -> Result<bool> {
+        if self.curve_type != pedersen.curve_type {
+            return Err(Error::ZKProofError("Proof and commitment parameters use different curves".to_string()));
+        }
+        if commitments_1.is_empty() || commitments_1.len() != commitments_2.len() {
+            return Err(Error::InvalidInput("Commitment vectors must be non-empty and of equal length".to_string()));
+        }
+        
+        let curve = curve_backend(self.curve_type)?;
+        let weights = Self::weights(self.curve_type, commitments_1, commitments_2, curve)?;
+        let c = Self::challenge(
+            self.curve_type,
+            commitments_1,
+            commitments_2,
+            &self.announcement_1,
+            &self.announcement_2,
+            curve,
+        )?;
+        
+        // Fold the commitments with the same weights as the prover
+        let e_1 = Self::fold(self.curve_type, commitments_1, &weights)?;
+        let e_2 = Self::fold(self.curve_type, commitments_2, &weights)?;
+        
+        let s_a = curve.scalar_from_bytes(&self.response_value)?;
+        let s_1 = curve.scalar_from_bytes(&self.response_1)?;
+        let s_2 = curve.scalar_from_bytes(&self.response_2)?;
+        let a_1 = Point::from_compressed_bytes(self.curve_type, &self.announcement_1)?;
+        let a_2 = Point::from_compressed_bytes(self.curve_type, &self.announcement_2)?;
+        
+        // Check g^sa h^s1 == A1 + c*E1 and g^sa h^s2 == A2 + c*E2
+        let g_sa = pedersen.mul_g(&s_a)?;
+        let lhs_1 = g_sa.add(&pedersen.mul_h(&s_1)?)?;
+        let rhs_1 = a_1.add(&e_1.mul(&c)?)?;
+        let lhs_2 = g_sa.add(&pedersen.mul_h(&s_2)?)?;
+        let rhs_2 = a_2.add(&e_2.mul(&c)?)?;
+        
+        Ok(lhs_1.ct_eq(&rhs_1) & lhs_2.ct_eq(&rhs_2))
+    }
+    
+    /// Derive folding weights bound to both commitment vectors
+    fn weights(
+        curve_type: CurveType,
+        commitments_1: &[Commitment],
+        commitments_2: &[Commitment],
+        curve: &dyn Curve,
+    ) -> Result<Vec<Scalar>> {
+        let transcript = Self::transcript(b"z-mpc-link-weights", curve_type, commitments_1, commitments_2);
+        
+        (0..commitments_1.len())
+            .map(|i| {
+                let mut input = transcript.clone();
+                input.extend_from_slice(&(i as u64).to_be_bytes());
+                hash_to_scalar(&input, curve)
+            })
+            .collect()
+    }
+    
+    /// Fiat-Shamir challenge over the commitments and announcements
+    fn challenge(
+        curve_type: CurveType,
+        commitments_1: &[Commitment],
+        commitments_2: &[Commitment],
+        announcement_1: &[u8],
+        announcement_2: &[u8],
+        curve: &dyn Curve,
+    ) -> Result<Scalar> {
+        let mut input = Self::transcript(b"z-mpc-link-challenge", curve_type, commitments_1, commitments_2);
+        input.extend_from_slice(announcement_1);
+        input.extend_from_slice(announcement_2);
+        hash_to_scalar(&input, curve)
     }
+    
+    /// Serialize the common transcript prefix
+    fn transcript(
+        domain: &[u8],
+        curve_type: CurveType,
+        commitments_1: &[Commitment],
+        commitments_2: &[Commitment],
+    ) -> Vec<u8> {
+        let mut input = Vec::new();
+        input.extend_from_slice(domain);
+        input.extend_from_slice(curve_type.to_string().as_bytes());
+        input.extend_from_slice(&(commitments_1.len() as u64).to_be_bytes());
+        for commitment in commitments_1.iter().chain(commitments_2.iter()) {
+            input.extend_from_slice(commitment);
+        }
+        input
+    }
+    
+    /// Compute Σ ρ_i C_i
+    fn fold(curve_type: CurveType, commitments: &[Commitment], weights: &[Scalar]) -> Result<Point> {
+        let mut folded: Option<Point> = None;
+        for (commitment, weight) in commitments.iter().zip(weights.iter()) {
+            let term = Point::from_compressed_bytes(curve_type, commitment)?.mul(weight)?;
+            folded = Some(match folded {
+                Some(acc) => acc.add(&term)?,
+                None => term,
+            });
+        }
+        folded.ok_or_else(|| Error::InvalidInput("Cannot fold an empty set of commitments".to_string()))
+    }
+}
+
+/// Designated-verifier proof of a commitment opening
+/// 
+/// Chaum-style OR proof of "I know (x, r) with C = g^x h^r" or "I know the
+/// verifier's secret key y with Y = g^y". The prover knows the opening and
+/// simulates the key branch; the challenge is split as c = c_open + c_key.
+/// Since the verifier can produce an identical-looking proof for any
+/// commitment with `simulate`, a transcript convinces nobody but them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DesignatedVerifierProof {
+    pub curve_type: CurveType,
+    pub commitment: Commitment,
+    /// Compressed public key of the designated verifier
+    pub verifier_key: Vec<u8>,
+    /// Caller context bound into the challenge
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub context: Vec<u8>,
+    pub announcement_opening: Vec<u8>,
+    pub announcement_key: Vec<u8>,
+    pub challenge_opening: Vec<u8>,
+    pub challenge_key: Vec<u8>,
+    pub response_value: Vec<u8>,
+    pub response_randomness: Vec<u8>,
+    pub response_key: Vec<u8>,
+}
+
+impl DesignatedVerifierProof {
+    /// Prove knowledge of an opening of `commitment` to the holder of `verifier_key`
+    pub fn prove(
+        pedersen: &PedersenCommitment,
+        commitment: &Commitment,
+        value: &Scalar,
+        randomness: &[u8],
+        verifier_key: &Point,
+        context: &[u8],
+    ) -> Result<Self> {
+        if !pedersen.verify(commitment, value, &randomness.to_vec())? {
+            return Err(Error::CommitmentError("opening mismatch".to_string()));
+        }
+        
+        let curve_type = pedersen.curve_type;
+        let curve = curve_backend(curve_type)?;
+        let verifier_key = verifier_key.to_compressed_bytes()?;
+        let y = Point::from_compressed_bytes(curve_type, &verifier_key)?;
+        
+        // Step 1: Simulate the key branch: A_key = g^s_key - c_key*Y
+        let c_key = curve.random_scalar()?;
+        let s_key = curve.random_scalar()?;
+        let minus_c_key = c_key.neg()?;
+        let announcement_key = pedersen.mul_g(&s_key)?.add(&y.mul(&minus_c_key)?)?.to_compressed_bytes()?;
+        
+        // Step 2: Commit for the opening branch: A_open = g^alpha h^beta
+        let alpha = curve.random_scalar()?;
+        let beta = curve.random_scalar()?;
+        let announcement_opening = pedersen.mul_gh(&alpha, &beta)?.to_compressed_bytes()?;
+        
+        // Step 3: Split the challenge: c_open = c - c_key
+        let c = Self::challenge(curve_type, commitment, &verifier_key, context, &announcement_opening, &announcement_key, curve)?;
+        let c_open = c.add(&minus_c_key)?;
+        
+        // Step 4: Respond for the opening branch
+        let r = curve.scalar_from_bytes(randomness)?;
+        let s_value = alpha.add(&c_open.mul(value)?)?;
+        let s_randomness = beta.add(&c_open.mul(&r)?)?;
+        
+        Ok(Self {
+            curve_type,
+            commitment: commitment.clone(),
+            verifier_key,
+            context: context.to_vec(),
+            announcement_opening,
+            announcement_key,
+            challenge_opening: c_open.as_bytes().to_vec(),
+            challenge_key: c_key.as_bytes().to_vec(),
+            response_value: s_value.as_bytes().to_vec(),
+            response_randomness: s_randomness.as_bytes().to_vec(),
+            response_key: s_key.as_bytes().to_vec(),
+        })
+    }
+    
+    /// Produce a proof for any commitment using the verifier's secret key
+    /// 
+    /// The opening branch is simulated and the key branch answered honestly,
+    /// yielding proofs distributed exactly like those from `prove`.
+    pub fn simulate(
+        pedersen: &PedersenCommitment,
+        commitment: &Commitment,
+        verifier_secret: &Scalar,
+        context: &[u8],
+    ) -> Result<Self> {
+        let curve_type = pedersen.curve_type;
+        let curve = curve_backend(curve_type)?;
+        let verifier_key = pedersen.mul_g(verifier_secret)?.to_compressed_bytes()?;
+        let commitment_point = Point::from_compressed_bytes(curve_type, commitment)?;
+        
+        // Step 1: Simulate the opening branch: A_open = g^s_x h^s_r - c_open*C
+        let c_open = curve.random_scalar()?;
+        let s_value = curve.random_scalar()?;
+        let s_randomness = curve.random_scalar()?;
+        let minus_c_open = c_open.neg()?;
+        let announcement_opening = pedersen.mul_gh(&s_value, &s_randomness)?
+            .add(&commitment_point.mul(&minus_c_open)?)?
+            .to_compressed_bytes()?;
+        
+        // Step 2: Commit for the key branch: A_key = g^k
+        let k = curve.random_scalar()?;
+        let announcement_key = pedersen.mul_g(&k)?.to_compressed_bytes()?;
+        
+        // Step 3: Split the challenge: c_key = c - c_open
+        let c = Self::challenge(curve_type, commitment, &verifier_key, context, &announcement_opening, &announcement_key, curve)?;
+        let c_key = c.add(&minus_c_open)?;
+        
+        // Step 4: Respond for the key branch
+        let s_key = k.add(&c_key.mul(verifier_secret)?)?;
+        
+        Ok(Self {
+            curve_type,
+            commitment: commitment.clone(),
+            verifier_key,
+            context: context.to_vec(),
+            announcement_opening,
+            announcement_key,
+            challenge_opening: c_open.as_bytes().to_vec(),
+            challenge_key: c_key.as_bytes().to_vec(),
+            response_value: s_value.as_bytes().to_vec(),
+            response_randomness: s_randomness.as_bytes().to_vec(),
+            response_key: s_key.as_bytes().to_vec(),
+        })
+    }
+    
+    /// Verify the proof for `commitment` as the holder of `verifier_key`
+    /// 
+    /// Acceptance only convinces the designated verifier: anyone holding the
+    /// verifier's secret key could have produced the proof.
+    pub fn verify(&self, pedersen: &PedersenCommitment, commitment: &Commitment, verifier_key: &Point) -> Result<bool> {
+        if self.curve_type != pedersen.curve_type {
+            return Err(Error::ZKProofError("Proof and commitment parameters use different curves".to_string()));
+        }
+        if &self.commitment != commitment || self.verifier_key != verifier_key.to_compressed_bytes()? {
+            return Ok(false);
+        }
+        
+        let curve = curve_backend(self.curve_type)?;
+        let c = Self::challenge(
+            self.curve_type,
+            commitment,
+            &self.verifier_key,
+            &self.context,
+            &self.announcement_opening,
+            &self.announcement_key,
+            curve,
+        )?;
+        
+        let c_open = curve.scalar_from_bytes(&self.challenge_opening)?;
+        let c_key = curve.scalar_from_bytes(&self.challenge_key)?;
+        if !c_open.add(&c_key)?.ct_eq(&c) {
+            return Ok(false);
+        }
+        
+        let s_value = curve.scalar_from_bytes(&self.response_value)?;
+        let s_randomness = curve.scalar_from_bytes(&self.response_randomness)?;
+        let s_key = curve.scalar_from_bytes(&self.response_key)?;
+        let commitment_point = Point::from_compressed_bytes(self.curve_type, commitment)?;
+        let y = Point::from_compressed_bytes(self.curve_type, &self.verifier_key)?;
+        let a_open = Point::from_compressed_bytes(self.curve_type, &self.announcement_opening)?;
+        let a_key = Point::from_compressed_bytes(self.curve_type, &self.announcement_key)?;
+        
+        // Check g^s_x h^s_r == A_open + c_open*C and g^s_key == A_key + c_key*Y
+        let lhs_open = pedersen.mul_gh(&s_value, &s_randomness)?;
+        let rhs_open = a_open.add(&commitment_point.mul(&c_open)?)?;
+        let lhs_key = pedersen.mul_g(&s_key)?;
+        let rhs_key = a_key.add(&y.mul(&c_key)?)?;
+        
+        Ok(lhs_open.ct_eq(&rhs_open) & lhs_key.ct_eq(&rhs_key))
+    }
+    
+    /// Fiat-Shamir challenge over the statement and both announcements
+    fn challenge(
+        curve_type: CurveType,
+        commitment: &[u8],
+        verifier_key: &[u8],
+        context: &[u8],
+        announcement_opening: &[u8],
+        announcement_key: &[u8],
+        curve: &dyn Curve,
+    ) -> Result<Scalar> {
+        let mut input = Vec::new();
+        input.extend_from_slice(b"z-mpc-dv-opening");
+        input.extend_from_slice(curve_type.to_string().as_bytes());
+        for part in [commitment, verifier_key, context, announcement_opening, announcement_key] {
+            input.extend_from_slice(&(part.len() as u32).to_be_bytes());
+            input.extend_from_slice(part);
+        }
+        hash_to_scalar(&input, curve)
+    }
+}
+
+/// Largest bit length a `RangeProof` covers
+pub const MAX_RANGE_BITS: u32 = 64;
+
+/// OR-proof that a bit commitment C_j opens to 0 or to 1
+/// 
+/// Proves knowledge of log_h C_j or of log_h (C_j - g); the branch not taken
+/// is simulated, and the challenges of both branches sum to the transcript's.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitProof {
+    pub announcement_zero: Vec<u8>,
+    pub announcement_one: Vec<u8>,
+    pub challenge_zero: Vec<u8>,
+    pub challenge_one: Vec<u8>,
+    pub response_zero: Vec<u8>,
+    pub response_one: Vec<u8>,
+}
+
+/// Proof that a Pedersen commitment opens to a value in [0, 2^bits)
+/// 
+/// Bit decomposition: C_j = g^{v_j} h^{r_j} commits to bit j of the value,
+/// the blindings satisfy Σ 2^j r_j = r so that Σ 2^j·C_j = C, and every
+/// C_j carries a `BitProof`. Proof size grows linearly with `bits`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RangeProof {
+    pub curve_type: CurveType,
+    pub commitment: Commitment,
+    pub bits: u32,
+    pub bit_commitments: Vec<Vec<u8>>,
+    pub bit_proofs: Vec<BitProof>,
+}
+
+impl RangeProof {
+    /// Prove that `commitment`, opened by `(value, randomness)`, holds a value below 2^bits
+    /// 
+    /// Fails with `InvalidInput` if the value is out of range, so no proof
+    /// exists for it.
+    pub fn prove(
+        pedersen: &PedersenCommitment,
+        commitment: &Commitment,
+        value: &Scalar,
+        randomness: &[u8],
+        bits: u32,
+    ) -> Result<Self> {
+        if bits == 0 || bits > MAX_RANGE_BITS {
+            return Err(Error::InvalidInput(format!("Range must cover 1 to {} bits, got {}", MAX_RANGE_BITS, bits)));
+        }
+        if !pedersen.verify(commitment, value, &randomness.to_vec())? {
+            return Err(Error::CommitmentError("opening mismatch".to_string()));
+        }
+        
+        let curve_type = pedersen.curve_type;
+        let curve = curve_backend(curve_type)?;
+        let value = Self::small_value(value, bits)?;
+        
+        // Step 1: Blind bits 1.. at random and let bit 0 absorb the rest of r
+        let mut blindings = vec![curve.scalar_from_u64(0)?];
+        let mut weighted = curve.scalar_from_u64(0)?;
+        for j in 1..bits {
+            let blinding = curve.random_scalar()?;
+            weighted = weighted.add(&Self::power_of_two(j, curve)?.mul(&blinding)?)?;
+            blindings.push(blinding);
+        }
+        blindings[0] = curve.scalar_from_bytes(randomness)?.sub(&weighted)?;
+        
+        // Step 2: Commit to every bit and prove it is 0 or 1
+        let mut bit_commitments = Vec::new();
+        let mut bit_proofs = Vec::new();
+        for (j, blinding) in (0..bits).zip(&blindings) {
+            let bit = (value >> j) & 1;
+            let bit_commitment = pedersen.mul_gh(&curve.scalar_from_u64(bit)?, blinding)?;
+            let proof = Self::prove_bit(pedersen, commitment, bits, j, &bit_commitment, bit == 1, blinding)?;
+            bit_commitments.push(bit_commitment.to_compressed_bytes()?);
+            bit_proofs.push(proof);
+        }
+        
+        Ok(Self {
+            curve_type,
+            commitment: commitment.clone(),
+            bits,
+            bit_commitments,
+            bit_proofs,
+        })
+    }
+    
+    /// Verify that `commitment` holds a value below 2^bits
+    pub fn verify(&self, pedersen: &PedersenCommitment, commitment: &Commitment) -> Result<bool> {
+        if self.curve_type != pedersen.curve_type {
+            return Err(Error::ZKProofError("Proof and commitment parameters use different curves".to_string()));
+        }
+        if &self.commitment != commitment
+            || self.bits == 0
+            || self.bits > MAX_RANGE_BITS
+            || self.bit_commitments.len() != self.bits as usize
+            || self.bit_proofs.len() != self.bits as usize
+        {
+            return Ok(false);
+        }
+        
+        let curve = curve_backend(self.curve_type)?;
+        let g = pedersen.mul_g(&curve.scalar_from_u64(1)?)?;
+        let mut recombined = Point::identity(self.curve_type)?;
+        let mut valid = true;
+        for (j, (bit_commitment, proof)) in (0..self.bits).zip(self.bit_commitments.iter().zip(&self.bit_proofs)) {
+            let c_j = Point::from_compressed_bytes(self.curve_type, bit_commitment)?;
+            recombined = recombined.add(&c_j.mul(&Self::power_of_two(j, curve)?)?)?;
+            
+            // Check c_0 + c_1 == c, h^s_0 == A_0 + c_0*C_j and h^s_1 == A_1 + c_1*(C_j - g)
+            let a_zero = Point::from_compressed_bytes(self.curve_type, &proof.announcement_zero)?;
+            let a_one = Point::from_compressed_bytes(self.curve_type, &proof.announcement_one)?;
+            let c = Self::bit_challenge(commitment, self.bits, j, bit_commitment, &proof.announcement_zero, &proof.announcement_one, curve)?;
+            let c_zero = curve.scalar_from_bytes(&proof.challenge_zero)?;
+            let c_one = curve.scalar_from_bytes(&proof.challenge_one)?;
+            let s_zero = curve.scalar_from_bytes(&proof.response_zero)?;
+            let s_one = curve.scalar_from_bytes(&proof.response_one)?;
+            
+            valid &= c_zero.add(&c_one)?.ct_eq(&c);
+            valid &= pedersen.mul_h(&s_zero)?.ct_eq(&a_zero.add(&c_j.mul(&c_zero)?)?);
+            valid &= pedersen.mul_h(&s_one)?.ct_eq(&a_one.add(&c_j.sub(&g)?.mul(&c_one)?)?);
+        }
+        
+        let commitment_point = Point::from_compressed_bytes(self.curve_type, commitment)?;
+        Ok(valid & recombined.ct_eq(&commitment_point))
+    }
+    
+    /// OR-proof for one bit commitment, answering the branch of `bit` honestly
+    fn prove_bit(
+        pedersen: &PedersenCommitment,
+        commitment: &Commitment,
+        bits: u32,
+        index: u32,
+        bit_commitment: &Point,
+        bit: bool,
+        blinding: &Scalar,
+    ) -> Result<BitProof> {
+        let curve = curve_backend(pedersen.curve_type)?;
+        
+        // Statements Y_0 = C_j and Y_1 = C_j - g, both h^x for the true bit
+        let g = pedersen.mul_g(&curve.scalar_from_u64(1)?)?;
+        let statements = [bit_commitment.clone(), bit_commitment.sub(&g)?];
+        let (real, fake) = if bit { (1, 0) } else { (0, 1) };
+        
+        // Step 1: Simulate the false branch: A_fake = h^s_fake - c_fake*Y_fake
+        let c_fake = curve.random_scalar()?;
+        let s_fake = curve.random_scalar()?;
+        let a_fake = pedersen.mul_h(&s_fake)?.add(&statements[fake].mul(&c_fake.neg()?)?)?;
+        
+        // Step 2: Commit for the true branch: A_real = h^k
+        let k = curve.random_scalar()?;
+        let a_real = pedersen.mul_h(&k)?;
+        
+        // Step 3: Split the challenge and respond for the true branch
+        let mut announcements = [Vec::new(), Vec::new()];
+        announcements[real] = a_real.to_compressed_bytes()?;
+        announcements[fake] = a_fake.to_compressed_bytes()?;
+        let c = Self::bit_challenge(
+            commitment,
+            bits,
+            index,
+            &bit_commitment.to_compressed_bytes()?,
+            &announcements[0],
+            &announcements[1],
+            curve,
+        )?;
+        let c_real = c.sub(&c_fake)?;
+        let s_real = k.add(&c_real.mul(blinding)?)?;
+        
+        let mut challenges = [Vec::new(), Vec::new()];
+        let mut responses = [Vec::new(), Vec::new()];
+        challenges[real] = c_real.as_bytes().to_vec();
+        challenges[fake] = c_fake.as_bytes().to_vec();
+        responses[real] = s_real.as_bytes().to_vec();
+        responses[fake] = s_fake.as_bytes().to_vec();
+        let [announcement_zero, announcement_one] = announcements;
+        let [challenge_zero, challenge_one] = challenges;
+        let [response_zero, response_one] = responses;
+        
+        Ok(BitProof {
+            announcement_zero,
+            announcement_one,
+            challenge_zero,
+            challenge_one,
+            response_zero,
+            response_one,
+        })
+    }
+    
+    /// Fiat-Shamir challenge of one bit, bound to the whole statement
+    fn bit_challenge(
+        commitment: &[u8],
+        bits: u32,
+        index: u32,
+        bit_commitment: &[u8],
+        announcement_zero: &[u8],
+        announcement_one: &[u8],
+        curve: &dyn Curve,
+    ) -> Result<Scalar> {
+        let mut input = Vec::new();
+        input.extend_from_slice(b"z-mpc-range-bit");
+        input.extend_from_slice(curve.curve_type().to_string().as_bytes());
+        input.extend_from_slice(&bits.to_be_bytes());
+        input.extend_from_slice(&index.to_be_bytes());
+        for part in [commitment, bit_commitment, announcement_zero, announcement_one] {
+            input.extend_from_slice(&(part.len() as u32).to_be_bytes());
+            input.extend_from_slice(part);
+        }
+        hash_to_scalar(&input, curve)
+    }
+    
+    /// 2^j as a scalar
+    fn power_of_two(j: u32, curve: &dyn Curve) -> Result<Scalar> {
+        if j < 64 {
+            curve.scalar_from_u64(1u64 << j)
+        } else {
+            Err(Error::InvalidInput(format!("Bit index {} out of range", j)))
+        }
+    }
+    
+    /// The value as an integer, if it is below 2^bits
+    fn small_value(value: &Scalar, bits: u32) -> Result<u64> {
+        let mut bytes = value.canonical_bytes();
+        if is_little_endian(value.curve_type) {
+            bytes.reverse();
+        }
+        
+        let split = bytes.len().saturating_sub(8);
+        let (high, low) = bytes.split_at(split);
+        let mut word = [0u8; 8];
+        word[8 - low.len()..].copy_from_slice(low);
+        let small = u64::from_be_bytes(word);
+        let fits = high.iter().all(|&byte| byte == 0) && (bits == 64 || small >> bits == 0);
+        word.zeroize();
+        bytes.zeroize();
+        
+        if !fits {
+            return Err(Error::InvalidInput(format!("Value does not fit in {} bits", bits)));
+        }
+        Ok(small)
+    }
+}
+
+/// Proof that two Pedersen commitments hide the same value
+/// 
+/// Σ-protocol for C_1 = g^v h_1^{r_1} and C_2 = g^v h_2^{r_2}: one response
+/// covers v in both equations, so the commitments may use different
+/// randomness and, with `prove_across`, different h over the same g.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EqualityProof {
+    pub curve_type: CurveType,
+    pub first_commitment: Commitment,
+    pub second_commitment: Commitment,
+    pub announcement_first: Vec<u8>,
+    pub announcement_second: Vec<u8>,
+    pub response_value: Vec<u8>,
+    pub response_first: Vec<u8>,
+    pub response_second: Vec<u8>,
+}
+
+impl EqualityProof {
+    /// Prove that `c1` and `c2`, both under `pedersen`, open to `value`
+    pub fn prove(
+        pedersen: &PedersenCommitment,
+        c1: &Commitment,
+        r1: &[u8],
+        c2: &Commitment,
+        r2: &[u8],
+        value: &Scalar,
+    ) -> Result<Self> {
+        Self::prove_across([pedersen, pedersen], [c1, c2], [r1, r2], value)
+    }
+    
+    /// Prove that `commitments[i]` under `schemes[i]` opens to `value` with `randomness[i]`
+    /// 
+    /// Both schemes must share curve and g; their h may differ, as when
+    /// moving a value between pinned parameter sets.
+    pub fn prove_across(
+        schemes: [&PedersenCommitment; 2],
+        commitments: [&Commitment; 2],
+        randomness: [&[u8]; 2],
+        value: &Scalar,
+    ) -> Result<Self> {
+        Self::check_schemes(schemes)?;
+        for (scheme, (commitment, r)) in schemes.iter().zip(commitments.iter().zip(randomness)) {
+            if !scheme.verify(commitment, value, &r.to_vec())? {
+                return Err(Error::CommitmentError("opening mismatch".to_string()));
+            }
+        }
+        
+        let curve_type = schemes[0].curve_type;
+        let curve = curve_backend(curve_type)?;
+        
+        // Step 1: Commit with one value nonce shared by both equations
+        let alpha = curve.random_scalar()?;
+        let betas = [curve.random_scalar()?, curve.random_scalar()?];
+        let announcement_first = schemes[0].mul_gh(&alpha, &betas[0])?.to_compressed_bytes()?;
+        let announcement_second = schemes[1].mul_gh(&alpha, &betas[1])?.to_compressed_bytes()?;
+        
+        // Step 2: Challenge over both schemes, commitments and announcements
+        let c = Self::challenge(schemes, commitments, &announcement_first, &announcement_second, curve)?;
+        
+        // Step 3: Respond with s = alpha + c*v and t_i = beta_i + c*r_i
+        let s_value = alpha.add(&c.mul(value)?)?;
+        let s_first = betas[0].add(&c.mul(&curve.scalar_from_bytes(randomness[0])?)?)?;
+        let s_second = betas[1].add(&c.mul(&curve.scalar_from_bytes(randomness[1])?)?)?;
+        
+        Ok(Self {
+            curve_type,
+            first_commitment: commitments[0].clone(),
+            second_commitment: commitments[1].clone(),
+            announcement_first,
+            announcement_second,
+            response_value: s_value.as_bytes().to_vec(),
+            response_first: s_first.as_bytes().to_vec(),
+            response_second: s_second.as_bytes().to_vec(),
+        })
+    }
+    
+    /// Verify that `c1` and `c2`, both under `pedersen`, hide the same value
+    pub fn verify(&self, pedersen: &PedersenCommitment, c1: &Commitment, c2: &Commitment) -> Result<bool> {
+        self.verify_across([pedersen, pedersen], [c1, c2])
+    }
+    
+    /// Verify a proof from `prove_across` against the same pair of schemes
+    pub fn verify_across(&self, schemes: [&PedersenCommitment; 2], commitments: [&Commitment; 2]) -> Result<bool> {
+        Self::check_schemes(schemes)?;
+        if self.curve_type != schemes[0].curve_type {
+            return Err(Error::ZKProofError("Proof and commitment parameters use different curves".to_string()));
+        }
+        if &self.first_commitment != commitments[0] || &self.second_commitment != commitments[1] {
+            return Ok(false);
+        }
+        
+        let curve = curve_backend(self.curve_type)?;
+        let c = Self::challenge(schemes, commitments, &self.announcement_first, &self.announcement_second, curve)?;
+        let s_value = curve.scalar_from_bytes(&self.response_value)?;
+        let responses = [
+            curve.scalar_from_bytes(&self.response_first)?,
+            curve.scalar_from_bytes(&self.response_second)?,
+        ];
+        let announcements = [&self.announcement_first, &self.announcement_second];
+        
+        // Check g^s h_i^t_i == A_i + c*C_i for both commitments
+        let mut valid = true;
+        for ((scheme, commitment), (announcement, response)) in schemes.iter().zip(commitments).zip(announcements.iter().zip(&responses)) {
+            let a = Point::from_compressed_bytes(self.curve_type, announcement)?;
+            let commitment_point = Point::from_compressed_bytes(self.curve_type, commitment)?;
+            let lhs = scheme.mul_gh(&s_value, response)?;
+            let rhs = a.add(&commitment_point.mul(&c)?)?;
+            valid &= lhs.ct_eq(&rhs);
+        }
+        Ok(valid)
+    }
+    
+    /// Both schemes must live on one curve and share g
+    fn check_schemes(schemes: [&PedersenCommitment; 2]) -> Result<()> {
+        if schemes[0].curve_type != schemes[1].curve_type {
+            return Err(Error::InvalidCurve(format!(
+                "Commitments on {} and {} cannot be compared", schemes[0].curve_type, schemes[1].curve_type
+            )));
+        }
+        if schemes[0].g != schemes[1].g {
+            return Err(Error::InvalidInput("Commitment schemes use different g".to_string()));
+        }
+        Ok(())
+    }
+    
+    /// Fiat-Shamir challenge over both statements and announcements
+    fn challenge(
+        schemes: [&PedersenCommitment; 2],
+        commitments: [&Commitment; 2],
+        announcement_first: &[u8],
+        announcement_second: &[u8],
+        curve: &dyn Curve,
+    ) -> Result<Scalar> {
+        let mut input = Vec::new();
+        input.extend_from_slice(b"z-mpc-commitment-equality");
+        input.extend_from_slice(curve.curve_type().to_string().as_bytes());
+        let generators = [
+            schemes[0].g.to_compressed_bytes()?,
+            schemes[0].h.to_compressed_bytes()?,
+            schemes[1].h.to_compressed_bytes()?,
+        ];
+        for part in generators.iter().map(Vec::as_slice)
+            .chain([commitments[0].as_slice(), commitments[1].as_slice(), announcement_first, announcement_second])
+        {
+            input.extend_from_slice(&(part.len() as u32).to_be_bytes());
+            input.extend_from_slice(part);
+        }
+        hash_to_scalar(&input, curve)
+    }
+}
+
+/// Proof that the prover can open one commitment of a set, without saying which
+/// 
+/// The prover publishes a fresh commitment D to its value and proves
+/// knowledge of D's opening. A Groth-Kohlweiss one-of-many proof then shows
+/// that one of the statements S_i = C_i - D is h^ρ, i.e. commits to zero.
+/// The set is padded to 2^m by repeating its last commitment; the proof
+/// holds 4m points and 3m + 1 scalars besides D, so it grows with log n.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MembershipProof {
+    pub curve_type: CurveType,
+    /// Fresh commitment D to the member's value
+    pub recommitment: Vec<u8>,
+    /// Proof of knowledge of D's opening
+    pub recommitment_proof: ZeroKnowledgeProof,
+    /// Commitments to the bits l_j of the index
+    pub bit_commitments: Vec<Vec<u8>>,
+    pub bit_announcements: Vec<Vec<u8>>,
+    /// Commitments to l_j·a_j, showing each l_j is a bit
+    pub product_commitments: Vec<Vec<u8>>,
+    /// Commitments to the low coefficients of the index polynomials
+    pub polynomial_commitments: Vec<Vec<u8>>,
+    pub bit_responses: Vec<Vec<u8>>,
+    pub announcement_responses: Vec<Vec<u8>>,
+    pub product_responses: Vec<Vec<u8>>,
+    pub response: Vec<u8>,
+}
+
+impl MembershipProof {
+    /// Prove that `commitments[index]` opens to `value` under `opening`
+    /// 
+    /// Fails with `CommitmentError` if it does not, so no proof exists for
+    /// a value outside the set.
+    pub fn prove(
+        pedersen: &PedersenCommitment,
+        index: usize,
+        value: &Scalar,
+        opening: &CommitmentOpening,
+        commitments: &[Commitment],
+    ) -> Result<Self> {
+        let member = commitments.get(index)
+            .ok_or_else(|| Error::InvalidInput(format!("Index {} outside a set of {} commitments", index, commitments.len())))?;
+        if opening.value_hash != CommitmentOpening::hash_value(value) || !pedersen.verify(member, value, &opening.randomness)? {
+            return Err(Error::CommitmentError("opening mismatch".to_string()));
+        }
+        
+        let curve_type = pedersen.curve_type;
+        let curve = curve_backend(curve_type)?;
+        let zero = curve.scalar_from_u64(0)?;
+        let one = curve.scalar_from_u64(1)?;
+        
+        // Step 1: Recommit to the value; S_index = C_index - D = h^(r - s)
+        let s = curve.random_scalar()?;
+        let recommitment = pedersen.mul_gh(value, &s)?.to_compressed_bytes()?;
+        let mut recommitment_proof = ZeroKnowledgeProof::new(curve_type).with_context(&Self::context(commitments));
+        recommitment_proof.prove(pedersen, value, s.as_bytes())?;
+        let statements = Self::statements(curve_type, commitments, &recommitment)?;
+        let witness = curve.scalar_from_bytes(&opening.randomness)?.sub(&s)?;
+        let bits = statements.len().trailing_zeros() as usize;
+        
+        // Step 2: Commit to the index bits, their masks a_j and the products l_j·a_j
+        let mut index_bits = Vec::new();
+        let mut bit_blindings = Vec::new();
+        let mut masks = Vec::new();
+        let mut mask_blindings = Vec::new();
+        let mut product_blindings = Vec::new();
+        let mut bit_commitments = Vec::new();
+        let mut bit_announcements = Vec::new();
+        let mut product_commitments = Vec::new();
+        for j in 0..bits {
+            let bit = if (index >> j) & 1 == 1 { one.clone() } else { zero.clone() };
+            let (r_j, a_j, s_j, t_j) = (curve.random_scalar()?, curve.random_scalar()?, curve.random_scalar()?, curve.random_scalar()?);
+            bit_commitments.push(pedersen.mul_gh(&bit, &r_j)?.to_compressed_bytes()?);
+            bit_announcements.push(pedersen.mul_gh(&a_j, &s_j)?.to_compressed_bytes()?);
+            product_commitments.push(pedersen.mul_gh(&bit.mul(&a_j)?, &t_j)?.to_compressed_bytes()?);
+            index_bits.push(bit);
+            bit_blindings.push(r_j);
+            masks.push(a_j);
+            mask_blindings.push(s_j);
+            product_blindings.push(t_j);
+        }
+        
+        // Step 3: Commit to the coefficients below x^m of p_i(x) = Π_j f_{j,i_j}(x)
+        let mut coefficients = Vec::new();
+        for i in 0..statements.len() {
+            let mut polynomial = vec![one.clone()];
+            for j in 0..bits {
+                // f_{j,1} = l_j·x + a_j and f_{j,0} = (1 - l_j)·x - a_j
+                let (constant, linear) = if (i >> j) & 1 == 1 {
+                    (masks[j].clone(), index_bits[j].clone())
+                } else {
+                    (masks[j].neg()?, one.sub(&index_bits[j])?)
+                };
+                let mut product = vec![zero.clone(); polynomial.len() + 1];
+                for (k, coefficient) in polynomial.iter().enumerate() {
+                    product[k] = product[k].add(&coefficient.mul(&constant)?)?;
+                    product[k + 1] = product[k + 1].add(&coefficient.mul(&linear)?)?;
+                }
+                polynomial = product;
+            }
+            coefficients.push(polynomial);
+        }
+        let mut polynomial_blindings = Vec::new();
+        let mut polynomial_commitments = Vec::new();
+        for k in 0..bits {
+            let rho_k = curve.random_scalar()?;
+            let mut sum = pedersen.mul_h(&rho_k)?;
+            for (statement, polynomial) in statements.iter().zip(&coefficients) {
+                sum = sum.add(&statement.mul(&polynomial[k])?)?;
+            }
+            polynomial_commitments.push(sum.to_compressed_bytes()?);
+            polynomial_blindings.push(rho_k);
+        }
+        
+        // Step 4: Respond to the challenge x
+        let x = Self::challenge(
+            pedersen,
+            commitments,
+            &recommitment,
+            [&bit_commitments, &bit_announcements, &product_commitments, &polynomial_commitments],
+            curve,
+        )?;
+        let mut bit_responses = Vec::new();
+        let mut announcement_responses = Vec::new();
+        let mut product_responses = Vec::new();
+        for j in 0..bits {
+            let f_j = index_bits[j].mul(&x)?.add(&masks[j])?;
+            let z_a = bit_blindings[j].mul(&x)?.add(&mask_blindings[j])?;
+            let z_b = bit_blindings[j].mul(&x.sub(&f_j)?)?.add(&product_blindings[j])?;
+            bit_responses.push(f_j.as_bytes().to_vec());
+            announcement_responses.push(z_a.as_bytes().to_vec());
+            product_responses.push(z_b.as_bytes().to_vec());
+        }
+        let mut power = one.clone();
+        let mut blinding_sum = zero.clone();
+        for rho_k in &polynomial_blindings {
+            blinding_sum = blinding_sum.add(&rho_k.mul(&power)?)?;
+            power = power.mul(&x)?;
+        }
+        let response = witness.mul(&power)?.sub(&blinding_sum)?;
+        
+        Ok(Self {
+            curve_type,
+            recommitment,
+            recommitment_proof,
+            bit_commitments,
+            bit_announcements,
+            product_commitments,
+            polynomial_commitments,
+            bit_responses,
+            announcement_responses,
+            product_responses,
+            response: response.as_bytes().to_vec(),
+        })
+    }
+    
+    /// Verify that the prover can open one of `commitments`
+    pub fn verify(&self, pedersen: &PedersenCommitment, commitments: &[Commitment]) -> Result<bool> {
+        if self.curve_type != pedersen.curve_type {
+            return Err(Error::ZKProofError("Proof and commitment parameters use different curves".to_string()));
+        }
+        if commitments.is_empty() {
+            return Ok(false);
+        }
+        let statements = Self::statements(self.curve_type, commitments, &self.recommitment)?;
+        let bits = statements.len().trailing_zeros() as usize;
+        if [
+            &self.bit_commitments,
+            &self.bit_announcements,
+            &self.product_commitments,
+            &self.polynomial_commitments,
+            &self.bit_responses,
+            &self.announcement_responses,
+            &self.product_responses,
+        ].iter().any(|part| part.len() != bits) {
+            return Ok(false);
+        }
+        
+        // D must be opened by its prover, within this set
+        if self.recommitment_proof.context != Self::context(commitments)
+            || !self.recommitment_proof.verify_against(pedersen, &self.recommitment)?
+        {
+            return Ok(false);
+        }
+        
+        let curve = curve_backend(self.curve_type)?;
+        let x = Self::challenge(
+            pedersen,
+            commitments,
+            &self.recommitment,
+            [&self.bit_commitments, &self.bit_announcements, &self.product_commitments, &self.polynomial_commitments],
+            curve,
+        )?;
+        let point = |bytes: &[u8]| Point::from_compressed_bytes(self.curve_type, bytes);
+        
+        // Check x·c_l + c_a == g^f h^z_a and (x - f)·c_l + c_b == h^z_b for every bit
+        let mut valid = true;
+        let mut f = Vec::new();
+        for j in 0..bits {
+            let f_j = curve.scalar_from_bytes(&self.bit_responses[j])?;
+            let z_a = curve.scalar_from_bytes(&self.announcement_responses[j])?;
+            let z_b = curve.scalar_from_bytes(&self.product_responses[j])?;
+            let c_l = point(&self.bit_commitments[j])?;
+            valid &= c_l.mul(&x)?.add(&point(&self.bit_announcements[j])?)?.ct_eq(&pedersen.mul_gh(&f_j, &z_a)?);
+            valid &= c_l.mul(&x.sub(&f_j)?)?.add(&point(&self.product_commitments[j])?)?.ct_eq(&pedersen.mul_h(&z_b)?);
+            f.push((x.sub(&f_j)?, f_j));
+        }
+        
+        // Check Σ_i p_i(x)·S_i - Σ_k x^k·c_d_k == h^z_d
+        let mut sum = Point::identity(self.curve_type)?;
+        for (i, statement) in statements.iter().enumerate() {
+            let mut weight = curve.scalar_from_u64(1)?;
+            for (j, (f_zero, f_one)) in f.iter().enumerate() {
+                weight = weight.mul(if (i >> j) & 1 == 1 { f_one } else { f_zero })?;
+            }
+            sum = sum.add(&statement.mul(&weight)?)?;
+        }
+        let mut power = curve.scalar_from_u64(1)?;
+        for polynomial_commitment in &self.polynomial_commitments {
+            sum = sum.sub(&point(polynomial_commitment)?.mul(&power)?)?;
+            power = power.mul(&x)?;
+        }
+        let z_d = curve.scalar_from_bytes(&self.response)?;
+        
+        Ok(valid & sum.ct_eq(&pedersen.mul_h(&z_d)?))
+    }
+    
+    /// Statements S_i = C_i - D, padded to a power of two of at least 2
+    fn statements(curve_type: CurveType, commitments: &[Commitment], recommitment: &[u8]) -> Result<Vec<Point>> {
+        let last = commitments.last()
+            .ok_or_else(|| Error::InvalidInput("Membership needs at least one commitment".to_string()))?;
+        let d = Point::from_compressed_bytes(curve_type, recommitment)?;
+        let size = commitments.len().max(2).next_power_of_two();
+        
+        commitments.iter()
+            .chain(std::iter::repeat(last))
+            .take(size)
+            .map(|commitment| Point::from_compressed_bytes(curve_type, commitment)?.sub(&d))
+            .collect()
+    }
+    
+    /// Digest of the commitment set, bound into the opening proof of D
+    fn context(commitments: &[Commitment]) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(b"z-mpc-membership-set");
+        for commitment in commitments {
+            hasher.update((commitment.len() as u32).to_be_bytes());
+            hasher.update(commitment);
+        }
+        hasher.finalize().to_vec()
+    }
+    
+    /// Fiat-Shamir challenge over the set, D and every first-move commitment
+    fn challenge(
+        pedersen: &PedersenCommitment,
+        commitments: &[Commitment],
+        recommitment: &[u8],
+        rounds: [&Vec<Vec<u8>>; 4],
+        curve: &dyn Curve,
+    ) -> Result<Scalar> {
+        let mut input = Vec::new();
+        input.extend_from_slice(b"z-mpc-membership");
+        input.extend_from_slice(curve.curve_type().to_string().as_bytes());
+        let generators = [pedersen.g.to_compressed_bytes()?, pedersen.h.to_compressed_bytes()?];
+        for part in generators.iter()
+            .chain(commitments)
+            .chain(std::iter::once(&recommitment.to_vec()))
+            .chain(rounds.into_iter().flatten())
+        {
+            input.extend_from_slice(&(part.len() as u32).to_be_bytes());
+            input.extend_from_slice(part);
+        }
+        hash_to_scalar(&input, curve)
+    }
+}
+
+/// Hash input to scalar for challenges and weights
+fn hash_to_scalar(input: &[u8], curve: &dyn Curve) -> Result<Scalar> {
+    let mut hasher = Sha256::new();
+    hasher.update(input);
+    let hash = hasher.finalize();
+    
+    curve.scalar_from_bytes_mod_order(&hash)
 }
 
 /// Utility functions for zero-knowledge proofs
 pub mod utils {
     use super::*;
     
-    /// Generate proof for a committed share
+    /// Generate proof for a published share commitment
+    /// 
+    /// Fails with `CommitmentError` unless `(share, randomness)` opens
+    /// `commitment`, so the proof always covers the published commitment.
     pub fn prove_committed_share(
         pedersen: &PedersenCommitment,
         share: &Share,
+        commitment: &Commitment,
         randomness: &[u8],
         curve_type: CurveType,
     ) -> Result<ZeroKnowledgeProof> {
+        let curve = curve_backend(curve_type)?;
+        let value = curve.scalar_from_bytes(&share.value)?;
+        if !pedersen.verify(commitment, &value, &randomness.to_vec())? {
+            return Err(Error::CommitmentError("opening mismatch".to_string()));
+        }
+        
         let mut proof = ZeroKnowledgeProof::new(curve_type);
-        proof.prove_share(pedersen, share, randomness)?;
+        proof.prove(pedersen, &value, randomness)?;
+        proof.commitment = commitment.clone();
         Ok(proof)
     }
     
@@ -298,7 +1809,116 @@ pub mod utils {
         signature.verify(message)
     }
     
+    /// Round 1 of threshold signing: draw nonces and commit to them
+    /// 
+    /// The commitment goes to the coordinator; the nonces stay with the
+    /// signer until `partial_sign`.
+    pub fn commit_signing_nonces<R: RngCore + CryptoRng>(
+        share: &Share,
+        curve_type: CurveType,
+        rng: &mut R,
+    ) -> Result<(SigningNonces, SigningCommitment)> {
+        let curve = curve_backend(curve_type)?;
+        let nonces = SigningNonces {
+            id: share.id,
+            hiding: sample_scalar(curve, rng),
+            binding: sample_scalar(curve, rng),
+        };
+        let commitment = SigningCommitment {
+            id: share.id,
+            hiding: curve.generator().mul(&nonces.hiding)?,
+            binding: curve.generator().mul(&nonces.binding)?,
+        };
+        Ok((nonces, commitment))
+    }
+    
+    /// Round 2 of threshold signing: z_i = d_i + ρ_i·e_i + c·w_i·s_i
+    /// 
+    /// `commitments` are those of every signer, this one's included, and
+    /// must hold at least `threshold` of them; w_i is the signer's residue
+    /// coefficient within that set.
+    pub fn partial_sign(
+        share: &Share,
+        message: &[u8],
+        nonces: SigningNonces,
+        commitments: &[SigningCommitment],
+        group_public_key: &Point,
+        params: &SharingParams,
+        id_scheme: IdScheme,
+    ) -> Result<PartialSignature> {
+        let curve = curve_backend(params.curve_type)?;
+        if nonces.id != share.id {
+            return Err(Error::InvalidInput(format!(
+                "Nonces of signer {} used with the share of signer {}", nonces.id, share.id
+            )));
+        }
+        
+        // Step 1: The coordinator must relay this signer's commitment unchanged
+        let own = commitments.iter().find(|commitment| commitment.id == share.id)
+            .ok_or_else(|| Error::InvalidInput(format!("No commitment from signer {}", share.id)))?;
+        let g = curve.generator();
+        if own.hiding != g.mul(&nonces.hiding)? || own.binding != g.mul(&nonces.binding)? {
+            return Err(Error::CommitmentError(format!("Commitment of signer {} was altered", share.id)));
+        }
+        
+        // Step 2: Bind the nonces to this signing set and message
+        let factors = binding_factors(commitments, group_public_key, message, curve)?;
+        let challenge = group_challenge(commitments, &factors, group_public_key, message, curve)?;
+        let signers: Vec<ShareId> = factors.iter().map(|(id, _)| *id).collect();
+        let weight = residue_coefficient(&signers, share.id, params.threshold, id_scheme, params.curve_type)?;
+        let factor = &factors.iter().find(|(id, _)| *id == share.id)
+            .ok_or_else(|| Error::Internal("Signer has no binding factor".to_string()))?.1;
+        
+        // Step 3: z_i = d_i + ρ_i·e_i + c·w_i·s_i
+        let value = curve.scalar_from_bytes(&share.value)?;
+        let response = nonces.hiding
+            .add(&nonces.binding.mul(factor)?)?
+            .add(&challenge.mul(&weight)?.mul(&value)?)?;
+        Ok(PartialSignature { id: share.id, response: response.as_bytes().to_vec() })
+    }
+    
+    /// Combine the partial signatures of every committed signer
+    /// 
+    /// The result is an ordinary `SchnorrSignature` under `group_public_key`.
+    /// Fails with `ZKProofError` if it does not verify, i.e. some partial
+    /// signature was wrong.
+    pub fn aggregate_partial_signatures(
+        message: &[u8],
+        commitments: &[SigningCommitment],
+        partials: &[PartialSignature],
+        group_public_key: &Point,
+    ) -> Result<SchnorrSignature> {
+        let curve_type = group_public_key.curve_type;
+        let curve = curve_backend(curve_type)?;
+        let factors = binding_factors(commitments, group_public_key, message, curve)?;
+        
+        let mut response = curve.scalar_from_u64(0)?;
+        for (id, _) in &factors {
+            let partial = partials.iter().find(|partial| partial.id == *id)
+                .ok_or_else(|| Error::InvalidInput(format!("No partial signature from signer {}", id)))?;
+            response = response.add(&curve.scalar_from_bytes(&partial.response)?)?;
+        }
+        if partials.len() != factors.len() {
+            return Err(Error::InvalidInput("Partial signatures from signers that did not commit".to_string()));
+        }
+        
+        let challenge = group_challenge(commitments, &factors, group_public_key, message, curve)?;
+        let signature = SchnorrSignature {
+            curve_type,
+            challenge: challenge.as_bytes().to_vec(),
+            response: response.as_bytes().to_vec(),
+            public_key: group_public_key.to_compressed_bytes()?,
+        };
+        if !signature.verify(message)? {
+            return Err(Error::ZKProofError("Aggregated threshold signature does not verify".to_string()));
+        }
+        Ok(signature)
+    }
+    
     /// Batch verify multiple proofs
+    /// 
+    /// Every proof is verified on its own; `batch_verify_proofs_fast` checks
+    /// them with one combined equation instead.
     pub fn batch_verify_proofs(
         proofs: &[ZeroKnowledgeProof],
         pedersen: &PedersenCommitment,
@@ -310,4 +1930,96 @@ pub mod utils {
         }
         Ok(true)
     }
+    
+    /// Batch verify multiple proofs with a single combined equation
+    /// 
+    /// Checks Σ ρ_i (g^{s1_i} h^{s2_i} - c_i C_i - A_i) = 0 for random 128-bit
+    /// weights ρ_i as one multi-scalar multiplication. A batch with any invalid
+    /// proof fails except with probability about 2^-128; use
+    /// `batch_verify_proofs_identify` to find out which proof is bad.
+    /// 
+    /// Degenerate proofs, such as ones with a small-order component on
+    /// Ed25519, fail the batch with `Error::ZKProofError` just as they fail
+    /// `batch_verify_proofs`, so it accepts exactly the proofs that verify
+    /// one by one.
+    pub fn batch_verify_proofs_fast(
+        proofs: &[ZeroKnowledgeProof],
+        pedersen: &PedersenCommitment,
+    ) -> Result<bool> {
+        batch_verify_proofs_fast_with_rng(proofs, pedersen, &mut rand::thread_rng())
+    }
+    
+    /// Batch verify multiple proofs, and name the first bad one on failure
+    /// 
+    /// Returns `None` when the combined check passes. Otherwise the proofs
+    /// are verified one by one and the index of the first failing proof is
+    /// returned.
+    pub fn batch_verify_proofs_identify(
+        proofs: &[ZeroKnowledgeProof],
+        pedersen: &PedersenCommitment,
+    ) -> Result<Option<usize>> {
+        if batch_verify_proofs_fast(proofs, pedersen).unwrap_or(false) {
+            return Ok(None);
+        }
+        
+        for (index, proof) in proofs.iter().enumerate() {
+            if !proof.verify(pedersen).unwrap_or(false) {
+                return Ok(Some(index));
+            }
+        }
+        Err(Error::ZKProofError("Batch failed but every proof verifies on its own".to_string()))
+    }
+    
+    /// Batch verify multiple proofs with a combined equation, drawing the weights from `rng`
+    pub fn batch_verify_proofs_fast_with_rng<R: RngCore + CryptoRng>(
+        proofs: &[ZeroKnowledgeProof],
+        pedersen: &PedersenCommitment,
+        rng: &mut R,
+    ) -> Result<bool> {
+        let curve = curve_backend(pedersen.curve_type)?;
+        let mut g_weight = curve.scalar_from_u64(0)?;
+        let mut h_weight = curve.scalar_from_u64(0)?;
+        let mut points = Vec::with_capacity(2 * proofs.len() + 2);
+        let mut weights = Vec::with_capacity(2 * proofs.len() + 2);
+        
+        for proof in proofs {
+            if proof.curve_type != pedersen.curve_type {
+                return Err(Error::InvalidCurve(format!(
+                    "Proof on {} does not match commitment scheme on {}", proof.curve_type, pedersen.curve_type
+                )));
+            }
+            
+            // Step 1: Challenges are still checked per proof
+            proof.check_nondegenerate(&proof.commitment)?;
+            let challenge_input = proof.create_challenge_input(&proof.commitment, &proof.public_point)?;
+            let challenge = proof.hash_to_scalar(&challenge_input, curve)?;
+            if !bool::from(challenge.as_bytes().ct_eq(&proof.challenge)) {
+                return Ok(false);
+            }
+            
+            // Step 2: Fold ρ_i s1_i and ρ_i s2_i into the shared generator weights
+            let (s1, s2) = proof.parse_response(curve)?;
+            let mut rho_bytes = [0u8; 16];
+            rng.fill_bytes(&mut rho_bytes);
+            let rho = curve.scalar_from_bytes(&rho_bytes)?;
+            g_weight = g_weight.add(&rho.mul(&s1)?)?;
+            h_weight = h_weight.add(&rho.mul(&s2)?)?;
+            
+            // Step 3: Subtract ρ_i (c_i C_i + A_i); small-order components
+            // were rejected in step 1, so none can cancel out
+            let commitment = Point::from_compressed_bytes(proof.curve_type, &proof.commitment)?;
+            let announcement = Point::from_compressed_bytes(proof.curve_type, &proof.public_point)?;
+            points.push(commitment);
+            weights.push(rho.mul(&challenge)?.neg()?);
+            points.push(announcement);
+            weights.push(rho.neg()?);
+        }
+        
+        // Step 4: One multi-scalar multiplication for the whole batch
+        points.push(pedersen.g.clone());
+        weights.push(g_weight);
+        points.push(pedersen.h.clone());
+        weights.push(h_weight);
+        Ok(curve.msm_vartime(&points, &weights)?.is_identity())
+    }
 } 
\ No newline at end of file
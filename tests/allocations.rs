@@ -0,0 +1,72 @@
+//! Allocation behaviour of curve backend lookups
+//! 
+//! Runs under a counting global allocator, so it lives in its own test binary.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use z_mpc::{curve, LaurentSeries, SharingParams};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+    
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Allocations made on this thread while running `f`
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let value = f();
+    (value, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn test_curve_backend_lookup_does_not_allocate() {
+    for curve_type in curve::enabled_curves() {
+        let (_, allocations) = count_allocations(|| {
+            for _ in 0..1000 {
+                let backend = curve::curve_backend(curve_type).unwrap();
+                assert_eq!(backend.curve_type(), curve_type);
+                drop(curve::create_curve(curve_type).unwrap());
+            }
+        });
+        assert_eq!(allocations, 0, "{:?} backend lookups allocated", curve_type);
+        
+        // Every lookup hands out the same static instance
+        let first = curve::curve_backend(curve_type).unwrap() as *const dyn curve::Curve as *const ();
+        let second = curve::curve_backend(curve_type).unwrap() as *const dyn curve::Curve as *const ();
+        assert_eq!(first, second);
+    }
+}
+
+#[test]
+fn test_share_generation_for_50_participants_allocates_no_backends() {
+    for curve_type in curve::enabled_curves() {
+        let params = SharingParams {
+            curve_type,
+            threshold: 10,
+            participants: 50,
+        };
+        let laurent = LaurentSeries::new(&params).unwrap();
+        
+        // Backends are shared statics, so repeated generation allocates only
+        // share values and the count stays fixed from one run to the next
+        let (shares, first_run) = count_allocations(|| laurent.generate_shares().unwrap());
+        let (_, second_run) = count_allocations(|| laurent.generate_shares().unwrap());
+        assert_eq!(shares.len(), 50);
+        assert_eq!(first_run, second_run, "{:?}", curve_type);
+    }
+}
@@ -0,0 +1,46 @@
+//! Artifacts of a curve whose feature is disabled
+//! 
+//! Only compiled without the `ed25519` feature:
+//! `cargo test --no-default-features --features std,secp256k1,p256 --test disabled_curve`
+
+#![cfg(not(feature = "ed25519"))]
+
+use z_mpc::{curve, selftest, CurveType, Error, LaurentSeries, PedersenCommitment, Point, Scalar, SharingParams};
+
+fn assert_disabled<T: std::fmt::Debug>(result: Result<T, Error>) {
+    match result {
+        Err(Error::InvalidCurve(message)) => assert!(message.contains("\"ed25519\" feature"), "{}", message),
+        other => panic!("expected InvalidCurve, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_disabled_curve_is_rejected_cleanly() {
+    assert_disabled(curve::create_curve(CurveType::Edwards25519).map(|_| ()));
+    assert_disabled(curve::curve_backend(CurveType::Edwards25519).map(|_| ()));
+    assert!(!curve::is_curve_enabled(CurveType::Edwards25519));
+    assert_eq!(curve::enabled_curves(), vec![CurveType::Secp256k1, CurveType::P256]);
+    
+    // Deserialized artifacts fail on use instead of reaching a backend
+    let scalar: Scalar = serde_json::from_value(serde_json::json!({
+        "curve_type": "Edwards25519",
+        "value": vec![1u8; 32],
+    })).unwrap();
+    assert_disabled(scalar.add(&scalar));
+    assert_disabled(scalar.neg());
+    assert_disabled(scalar.invert());
+    assert_disabled(Point::from_compressed_bytes(CurveType::Edwards25519, &[0u8; 32]));
+    
+    // Public constructors report the missing feature
+    let params = SharingParams {
+        curve_type: CurveType::Edwards25519,
+        threshold: 2,
+        participants: 3,
+    };
+    assert_disabled(LaurentSeries::new(&params).map(|_| ()));
+    assert_disabled(PedersenCommitment::new(CurveType::Edwards25519).map(|_| ()));
+    
+    // Enabled curves keep working
+    assert!(selftest::known_answer_tests().is_ok());
+    assert!(PedersenCommitment::new(CurveType::Secp256k1).is_ok());
+}
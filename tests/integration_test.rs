@@ -4,13 +4,23 @@
 //! including network communication and distributed protocol execution.
 
 use z_mpc::{
-    init, CurveType, SharingParams, LaurentSeries, 
-    PedersenCommitment, ZeroKnowledgeProof, laurent::Share,
+    init, CurveType, SessionId, SharingParams, LaurentSeries, 
+    PedersenCommitment, PedersenParameters, ZeroKnowledgeProof, laurent::Share,
     pedersen::utils as pedersen_utils, zkp::utils as zkp_utils,
     laurent::utils as laurent_utils, network::utils as network_utils,
-    NetworkCoordinator
+    NetworkCoordinator, NetworkMessage, IdScheme, laurent::eval_point_for_id, zkp::BatchLinkProof,
+    zkp::{ProofFailure, ProofTrace, SchnorrSignature}, qr,
+    pedersen::{CommittedShare, CommitmentOpening}, NetworkNode,
+    auth::{AuthLayer, MtlsAuth, PeerCertificate, ReplayGuard, SchnorrAuth}, selftest, Participant,
+    clock::{Clock, MockClock}, invite::{Invitation, RosterEntry}, transport::TransportConfig,
+    network::{NodeConfig, OverflowPolicy},
 };
-use tokio;
+use std::sync::Arc;
+use assert_cmd::Command;
+use z_mpc::policy::{
+    AllOf, Approval, ApprovalQuorum, PolicyConfig, PrefixAllowList, RateLimit, SigningContext, SigningPolicy,
+};
+use proptest::prelude::*;
 
 #[tokio::test]
 async fn test_complete_z_mpc_flow() {
@@ -49,14 +59,18 @@ async fn test_complete_z_mpc_flow() {
     assert!(all_valid);
     println!("✅ All commitments verified");
     
-    // 5. Generate zero-knowledge proofs
+    // 5. Generate zero-knowledge proofs over commitments under the proof parameters
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
     let pedersen = PedersenCommitment::new(curve_type).unwrap();
     let mut proofs = Vec::new();
     
     for (share, committed_share) in shares.iter().zip(committed_shares.iter()) {
+        let value = curve.scalar_from_bytes(&share.value).unwrap();
+        let commitment = pedersen.commit(&value, &committed_share.randomness).unwrap();
         let proof = zkp_utils::prove_committed_share(
             &pedersen,
             share,
+            &commitment,
             &committed_share.randomness,
             curve_type,
         ).unwrap();
@@ -101,18 +115,18 @@ async fn test_distributed_network_protocol() {
     println!("🌐 Testing distributed network protocol with {} participants", num_participants);
     
     // 1. Create test network
-    let mut coordinators = network_utils::create_test_network(num_participants, curve_type).await.unwrap();
+    let mut coordinators = network_utils::create_test_network(num_participants, &[curve_type]).await.unwrap();
     assert_eq!(coordinators.len(), num_participants);
     println!("✅ Test network created with {} nodes", coordinators.len());
     
     // 2. Run distributed protocol
-    network_utils::run_distributed_protocol(&mut coordinators, threshold).await.unwrap();
+    let session_id = network_utils::run_distributed_protocol(&mut coordinators, curve_type, threshold).await.unwrap();
     println!("✅ Distributed protocol executed successfully");
     
     // 3. Verify all coordinators are initialized
     for (i, coordinator) in coordinators.iter().enumerate() {
-        assert!(coordinator.laurent_series.is_some());
-        assert!(coordinator.pedersen.is_some());
+        assert!(coordinator.laurent_series.contains_key(&session_id));
+        assert!(coordinator.pedersen.contains_key(&session_id));
         println!("   Node {}: Protocol initialized", i + 1);
     }
     
@@ -124,7 +138,7 @@ async fn test_multi_curve_support() {
     // Initialize library
     init().unwrap();
     
-    let curves = [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519];
+    let curves = [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519, CurveType::Ristretto255];
     let threshold = 3;
     let participants = 5;
     
@@ -298,4 +312,3521 @@ async fn test_security_properties() {
     println!("   ✅ Commitment binding property verified");
     
     println!("🎉 Security properties test passed!");
-} 
\ No newline at end of file
+} 
+#[test]
+fn test_eval_point_rejects_zero_id() {
+    for scheme in [IdScheme::Direct, IdScheme::Offset(10), IdScheme::Hashed] {
+        assert!(eval_point_for_id(0, scheme, CurveType::Secp256k1).is_err());
+    }
+    
+    // Offsets that would overflow u64 are rejected instead of wrapping
+    assert!(eval_point_for_id(2, IdScheme::Offset(u64::MAX), CurveType::Secp256k1).is_err());
+}
+
+proptest! {
+    #[test]
+    fn prop_distinct_ids_map_to_distinct_nonzero_points(
+        a in 1u32..=u32::MAX,
+        b in 1u32..=u32::MAX,
+        offset in 0u64..1_000_000,
+    ) {
+        prop_assume!(a != b);
+        
+        for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+            let zero = z_mpc::curve::create_curve(curve_type).unwrap().scalar_from_u64(0).unwrap();
+            for scheme in [IdScheme::Direct, IdScheme::Offset(offset), IdScheme::Hashed] {
+                let za = eval_point_for_id(a, scheme, curve_type).unwrap();
+                let zb = eval_point_for_id(b, scheme, curve_type).unwrap();
+                prop_assert_ne!(&za, &zb);
+                prop_assert_ne!(&za, &zero);
+                prop_assert_ne!(&zb, &zero);
+            }
+        }
+    }
+    
+    #[test]
+    fn prop_reconstruction_is_independent_of_share_order(
+        curve_index in 0usize..3,
+        subset_mask in 0u8..32,
+        permutation in Just((0..5usize).collect::<Vec<_>>()).prop_shuffle(),
+    ) {
+        let curve_type = [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519][curve_index];
+        let params = SharingParams {
+            curve_type,
+            threshold: 3,
+            participants: 5,
+        };
+        let laurent = LaurentSeries::new(&params).unwrap();
+        let shares = laurent.generate_shares().unwrap();
+        
+        // Any subset of at least threshold shares, in ascending and in shuffled order
+        let subset: Vec<Share> = shares.iter().enumerate()
+            .filter(|(index, _)| subset_mask & (1 << index) != 0)
+            .map(|(_, share)| share.clone())
+            .collect();
+        prop_assume!(subset.len() >= params.threshold);
+        let shuffled: Vec<Share> = permutation.iter()
+            .filter(|&&index| subset_mask & (1 << index) != 0)
+            .map(|&index| shares[index].clone())
+            .collect();
+        
+        let ordered = laurent.reconstruct_secret(&subset).unwrap();
+        let permuted = laurent.reconstruct_secret_checked(&shuffled).unwrap();
+        prop_assert_eq!(&ordered.secret, &permuted.secret);
+        prop_assert_eq!(&ordered.participants_used, &permuted.participants_used);
+    }
+}
+
+#[test]
+fn test_proof_serialization_uses_curve_scalar_width() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let width = curve.scalar_byte_len();
+        
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let value = curve.random_scalar().unwrap();
+        let randomness = pedersen.generate_randomness();
+        assert_eq!(randomness.len(), width);
+        
+        let mut proof = ZeroKnowledgeProof::new(curve_type);
+        proof.prove(&pedersen, &value, &randomness).unwrap();
+        assert_eq!(proof.response.len(), 2 * width);
+        
+        let signature = zkp_utils::sign_message(b"width", &value, curve_type).unwrap();
+        assert_eq!(signature.response.len(), width);
+        
+        // A response that is not exactly two scalars wide is rejected
+        let mut truncated = proof.clone();
+        truncated.response.pop();
+        assert!(truncated.verify(&pedersen).is_err());
+    }
+}
+
+#[test]
+fn test_signing_policy_prefix_denial() {
+    let policy = PrefixAllowList::new(vec![b"transfer:".to_vec()]);
+    let context = SigningContext::new(CurveType::Secp256k1, 1_000);
+    
+    assert!(policy.authorize(b"transfer:alice:10", 1, &context).is_allowed());
+    assert!(!policy.authorize(b"rotate-keys", 1, &context).is_allowed());
+    
+    let result = z_mpc::policy::enforce(&policy, b"rotate-keys", 1, &context);
+    assert!(matches!(result, Err(z_mpc::Error::PolicyDenied(_))));
+}
+
+#[test]
+fn test_signing_policy_rate_limit_exhaustion() {
+    let policy = RateLimit::new(2);
+    let enforce = |message: &[u8], timestamp| {
+        z_mpc::policy::enforce(&policy, message, 1, &SigningContext::new(CurveType::Secp256k1, timestamp))
+    };
+    
+    // Checking alone does not use up a slot
+    assert!(policy.authorize(b"m0", 1, &SigningContext::new(CurveType::Secp256k1, 0)).is_allowed());
+    
+    assert!(enforce(b"m1", 0).is_ok());
+    assert!(enforce(b"m2", 10).is_ok());
+    assert!(matches!(enforce(b"m3", 20), Err(z_mpc::Error::PolicyDenied(_))));
+    
+    // Once the first signature leaves the one hour window a slot frees up
+    assert!(enforce(b"m4", 3_600).is_ok());
+}
+
+#[test]
+fn test_signing_policy_denial_keeps_rate_limit_slot() {
+    let policy = AllOf::new(vec![
+        Box::new(RateLimit::new(1)),
+        Box::new(PrefixAllowList::new(vec![b"transfer:".to_vec()])),
+    ]);
+    let context = SigningContext::new(CurveType::Secp256k1, 0);
+    
+    // The prefix check denies after the rate limit allowed
+    let result = z_mpc::policy::enforce(&policy, b"rotate-keys", 1, &context);
+    assert!(matches!(result, Err(z_mpc::Error::PolicyDenied(_))));
+    
+    assert!(z_mpc::policy::enforce(&policy, b"transfer:alice:10", 1, &context).is_ok());
+    let result = z_mpc::policy::enforce(&policy, b"transfer:bob:10", 1, &context);
+    assert!(matches!(result, Err(z_mpc::Error::PolicyDenied(reason)) if reason.contains("Rate limit")));
+}
+
+#[test]
+fn test_signing_policy_approval_quorum() {
+    let curve_type = CurveType::Secp256k1;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let message = b"transfer:bob:25";
+    
+    // Three admins, two approvals required
+    let admin_keys: Vec<_> = (0..3).map(|_| curve.random_scalar().unwrap()).collect();
+    let admins = admin_keys.iter().enumerate()
+        .map(|(i, key)| {
+            let public_key = curve.generator().mul(key).unwrap().to_compressed_bytes().unwrap();
+            (i as u32 + 1, public_key)
+        })
+        .collect();
+    let policy = ApprovalQuorum::new(admins, 2).unwrap();
+    
+    let first = Approval::new(1, message, &admin_keys[0], curve_type).unwrap();
+    let context = SigningContext::new(curve_type, 0).with_approvals(vec![first.clone()]);
+    assert!(!policy.authorize(message, 7, &context).is_allowed());
+    
+    // The same admin approving twice does not count twice
+    let context = SigningContext::new(curve_type, 0).with_approvals(vec![first.clone(), first.clone()]);
+    assert!(!policy.authorize(message, 7, &context).is_allowed());
+    
+    // An approval for a different message does not count
+    let other = Approval::new(2, b"transfer:eve:1000", &admin_keys[1], curve_type).unwrap();
+    let context = SigningContext::new(curve_type, 0).with_approvals(vec![first.clone(), other]);
+    assert!(!policy.authorize(message, 7, &context).is_allowed());
+    
+    let second = Approval::new(3, message, &admin_keys[2], curve_type).unwrap();
+    let context = SigningContext::new(curve_type, 0).with_approvals(vec![first, second]);
+    assert!(policy.authorize(message, 7, &context).is_allowed());
+}
+
+#[test]
+fn test_batch_link_proof() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        
+        let values: Vec<_> = (0..5).map(|_| curve.random_scalar().unwrap()).collect();
+        let randomness_1: Vec<_> = values.iter().map(|_| pedersen.generate_randomness()).collect();
+        let randomness_2: Vec<_> = values.iter().map(|_| pedersen.generate_randomness()).collect();
+        let commitments_1 = pedersen.batch_commit(&values, &randomness_1).unwrap();
+        let commitments_2 = pedersen.batch_commit(&values, &randomness_2).unwrap();
+        
+        // All pairs open to the same value
+        let proof = BatchLinkProof::prove(
+            &pedersen, &commitments_1, &commitments_2, &values, &randomness_1, &randomness_2,
+        ).unwrap();
+        assert!(proof.verify(&pedersen, &commitments_1, &commitments_2).unwrap());
+        
+        // One round-2 commitment hides a different value
+        let mut tampered_values = values.clone();
+        tampered_values[3] = curve.random_scalar().unwrap();
+        let tampered_2 = pedersen.batch_commit(&tampered_values, &randomness_2).unwrap();
+        let proof = BatchLinkProof::prove(
+            &pedersen, &commitments_1, &tampered_2, &values, &randomness_1, &randomness_2,
+        ).unwrap();
+        assert!(!proof.verify(&pedersen, &commitments_1, &tampered_2).unwrap());
+        
+        // A valid proof does not transfer to a different commitment vector
+        let proof = BatchLinkProof::prove(
+            &pedersen, &commitments_1, &commitments_2, &values, &randomness_1, &randomness_2,
+        ).unwrap();
+        assert!(!proof.verify(&pedersen, &commitments_1, &tampered_2).unwrap());
+    }
+}
+
+#[test]
+fn test_proof_trace_pinpoints_failure() {
+    let curve_type = CurveType::Secp256k1;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let pedersen = PedersenCommitment::new(curve_type).unwrap();
+    let value = curve.random_scalar().unwrap();
+    let randomness = pedersen.generate_randomness();
+    
+    let mut proof = ZeroKnowledgeProof::new(curve_type);
+    proof.prove(&pedersen, &value, &randomness).unwrap();
+    
+    let trace = proof.explain(&pedersen).unwrap();
+    assert!(trace.is_valid());
+    assert_eq!(trace.stored_challenge, trace.recomputed_challenge);
+    assert_eq!(trace.lhs, trace.rhs);
+    assert_eq!(trace.transcript.len(), 4);
+    
+    // Trace survives a JSON round trip for hand-off to a third party
+    let json = serde_json::to_string(&trace).unwrap();
+    let reloaded: ProofTrace = serde_json::from_str(&json).unwrap();
+    assert_eq!(reloaded.failure, None);
+    
+    // A corrupted response passes the transcript check but breaks the equation
+    let mut bad_response = proof.clone();
+    let last = bad_response.response.len() - 1;
+    bad_response.response[last] ^= 0x01;
+    let trace = bad_response.explain(&pedersen).unwrap();
+    assert_eq!(trace.failure, Some(ProofFailure::EquationMismatch));
+    assert_ne!(trace.lhs, trace.rhs);
+    
+    // A corrupted commitment is caught before the equation is evaluated
+    let mut bad_commitment = proof.clone();
+    let last = bad_commitment.commitment.len() - 1;
+    bad_commitment.commitment[last] ^= 0x01;
+    let trace = bad_commitment.explain(&pedersen).unwrap();
+    assert!(matches!(
+        trace.failure,
+        Some(ProofFailure::ChallengeMismatch) | Some(ProofFailure::MalformedCommitment(_))
+    ));
+    assert!(trace.lhs.is_none());
+}
+
+#[test]
+fn test_cli_combine_sign_keeps_secret_out_of_outputs() {
+    let dir = tempfile::tempdir().unwrap();
+    let shares_path = dir.path().join("shares.json");
+    let signature_path = dir.path().join("sig.json");
+    
+    Command::cargo_bin("z-mpc").unwrap()
+        .args(["share", "--curve", "k1", "--threshold", "3", "--participants", "5", "--output"])
+        .arg(&shares_path)
+        .assert()
+        .success();
+    
+    let combine = Command::cargo_bin("z-mpc").unwrap()
+        .args(["combine", "--curve", "k1", "--sign", "release funds", "--input"])
+        .arg(&shares_path)
+        .arg("--output")
+        .arg(&signature_path)
+        .assert()
+        .success();
+    
+    // Derive the group key independently from the same shares
+    let data: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&shares_path).unwrap()).unwrap();
+    let shares: Vec<Share> = serde_json::from_value(data["shares"].clone()).unwrap();
+    let params = SharingParams {
+        curve_type: CurveType::Secp256k1,
+        threshold: shares.len(),
+        participants: shares.len(),
+    };
+    let secret = LaurentSeries::new(&params).unwrap().reconstruct_secret(&shares).unwrap().secret;
+    let curve = z_mpc::curve::create_curve(CurveType::Secp256k1).unwrap();
+    let group_key = curve.generator()
+        .mul(&z_mpc::Scalar::new(CurveType::Secp256k1, secret.clone()))
+        .unwrap()
+        .to_compressed_bytes()
+        .unwrap();
+    
+    let signature_json = std::fs::read_to_string(&signature_path).unwrap();
+    let output: serde_json::Value = serde_json::from_str(&signature_json).unwrap();
+    let signature: SchnorrSignature = serde_json::from_value(output["signature"].clone()).unwrap();
+    assert_eq!(signature.public_key, group_key);
+    assert!(signature.verify(b"release funds").unwrap());
+    
+    // The secret appears neither in the written file nor on stdout
+    let secret_hex = hex::encode(&secret);
+    assert!(!signature_json.contains(&secret_hex));
+    let stdout = String::from_utf8_lossy(&combine.get_output().stdout);
+    assert!(!stdout.contains(&secret_hex));
+}
+
+#[tokio::test]
+async fn test_node_runs_concurrent_ceremonies_on_different_curves() {
+    init().unwrap();
+    
+    let curves = [CurveType::Edwards25519, CurveType::Secp256k1];
+    let mut coordinators: Vec<_> = network_utils::create_test_network(3, &curves).await.unwrap()
+        .into_iter()
+        .map(NetworkCoordinator::with_insecure_no_auth)
+        .collect();
+    
+    // Every node joins one session per curve
+    let mut session_ids = Vec::new();
+    for curve_type in curves {
+        let params = SharingParams {
+            curve_type,
+            threshold: 2,
+            participants: 3,
+        };
+        session_ids.push(network_utils::initialize_session(&mut coordinators, params).await.unwrap());
+    }
+    
+    let ed_deliveries = coordinators[0].deal_share_deliveries(session_ids[0]).unwrap();
+    let k1_deliveries = coordinators[0].deal_share_deliveries(session_ids[1]).unwrap();
+    
+    // Each node handles both ceremonies at the same time
+    for ((coordinator, (_, ed_delivery)), (_, k1_delivery)) in coordinators.iter().zip(ed_deliveries).zip(k1_deliveries) {
+        let (ed, k1) = tokio::join!(
+            coordinator.node.process_message(ed_delivery),
+            coordinator.node.process_message(k1_delivery),
+        );
+        ed.unwrap();
+        k1.unwrap();
+    }
+    
+    for session_id in session_ids {
+        // Each node kept exactly its own share
+        let mut held = Vec::new();
+        for coordinator in &coordinators {
+            let shares = coordinator.node.session_shares(session_id).await.unwrap();
+            assert_eq!(shares.len(), 1);
+            assert_eq!(shares[0].id, coordinator.node.id);
+            held.extend(shares);
+        }
+        
+        let expected = coordinators[0].laurent_series[&session_id].get_secret_key().unwrap();
+        let result = coordinators[1].node.reconstruct(session_id, &held).await.unwrap();
+        assert_eq!(result.secret, expected.value);
+    }
+    
+    // Unknown sessions are rejected rather than handled on a default curve
+    assert!(matches!(
+        coordinators[1].node.session_curve(SessionId::random()).await,
+        Err(z_mpc::Error::UnknownSession(_))
+    ));
+}
+
+#[test]
+fn test_reused_commitment_randomness_is_detected() {
+    let params = SharingParams {
+        curve_type: CurveType::Secp256k1,
+        threshold: 3,
+        participants: 4,
+    };
+    let shares = LaurentSeries::new(&params).unwrap().generate_shares().unwrap();
+    
+    // Fresh randomness per share passes
+    let mut committed = pedersen_utils::commit_all_shares(&shares, params.curve_type).unwrap();
+    assert_eq!(pedersen_utils::find_reused_randomness(&committed), None);
+    assert!(pedersen_utils::ensure_distinct_randomness(&committed).is_ok());
+    
+    // A dealer reusing randomness for shares 2 and 4 is flagged
+    committed[3].randomness = committed[1].randomness.clone();
+    assert_eq!(
+        pedersen_utils::find_reused_randomness(&committed),
+        Some((committed[1].share.id, committed[3].share.id))
+    );
+    assert!(matches!(
+        pedersen_utils::ensure_distinct_randomness(&committed),
+        Err(z_mpc::Error::CommitmentError(_))
+    ));
+}
+
+#[test]
+fn test_share_qr_round_trip() {
+    let curve_type = CurveType::Secp256k1;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let pedersen = PedersenCommitment::new(curve_type).unwrap();
+    let params = SharingParams {
+        curve_type,
+        threshold: 3,
+        participants: 5,
+    };
+    let shares = LaurentSeries::new(&params).unwrap().generate_shares().unwrap();
+    
+    let commit = |share: &Share| {
+        let randomness = pedersen.generate_randomness();
+        let value = curve.scalar_from_bytes(&share.value).unwrap();
+        let commitment = pedersen.commit(&value, &randomness).unwrap();
+        CommittedShare::new(share.clone(), commitment, randomness)
+    };
+    
+    // A bare share fits in one code
+    let committed = commit(&shares[0]);
+    let payloads = qr::to_qr_payloads(&committed, curve_type).unwrap();
+    assert_eq!(payloads.len(), 1);
+    assert!(payloads[0].starts_with(qr::PAYLOAD_PREFIX));
+    assert!(qr::render_svg(&payloads[0]).unwrap().contains("<svg"));
+    
+    let (imported_curve, imported) = qr::from_qr_payloads(&payloads).unwrap();
+    assert_eq!(imported_curve, curve_type);
+    assert_eq!(imported.share.value, committed.share.value);
+    assert!(pedersen.verify_share_commitment(&imported.share, &imported.commitment, &imported.randomness).unwrap());
+    
+    // Attached proofs split the share across several codes, scanned in any order
+    let mut large = commit(&shares[1]);
+    let proof = zkp_utils::prove_committed_share(&pedersen, &large.share, &large.commitment, &large.randomness, curve_type).unwrap();
+    large.share.proof = Some(serde_json::to_vec(&proof).unwrap());
+    let large_value = curve.scalar_from_bytes(&large.share.value).unwrap();
+    large.opening = Some(CommitmentOpening::new(&large_value, large.randomness.clone()));
+    
+    let mut payloads = qr::to_qr_payloads(&large, curve_type).unwrap();
+    assert!(payloads.len() > 1);
+    payloads.reverse();
+    
+    let (_, imported) = qr::from_qr_payloads(&payloads).unwrap();
+    assert_eq!(imported.share.id, large.share.id);
+    assert_eq!(imported.share.proof, large.share.proof);
+    assert_eq!(imported.opening, large.opening);
+    assert!(pedersen.verify_share_commitment(&imported.share, &imported.commitment, &imported.randomness).unwrap());
+    
+    // A missing part or a part of another share is rejected
+    assert!(qr::from_qr_payloads(&payloads[1..]).is_err());
+    let mut mixed = payloads.clone();
+    mixed[0] = qr::to_qr_payloads(&commit(&shares[2]), curve_type).unwrap().remove(0);
+    assert!(qr::from_qr_payloads(&mixed).is_err());
+}
+
+#[tokio::test]
+async fn test_schnorr_auth_rejects_other_scheme() {
+    let curve_type = CurveType::Secp256k1;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let peer_secret = curve.random_scalar().unwrap();
+    let peer_public = curve.generator().mul(&peer_secret).unwrap().to_compressed_bytes().unwrap();
+    
+    let node = NetworkNode::new(1, "127.0.0.1:9101".parse().unwrap());
+    let coordinator = NetworkCoordinator::new(node)
+        .with_auth(Arc::new(SchnorrAuth::new(1, curve.random_scalar().unwrap()).with_peer(2, peer_public)));
+    
+    let heartbeat = NetworkMessage::Heartbeat { sender_id: 2, timestamp: 1 };
+    
+    // Signed by the registered peer key
+    let envelope = SchnorrAuth::new(2, peer_secret.clone()).sign_outgoing(&heartbeat).unwrap();
+    coordinator.receive_envelope(&envelope).await.unwrap();
+    
+    // Same peer authenticated under the mTLS scheme
+    let envelope = MtlsAuth::new(2, b"peer-2-certificate".to_vec()).sign_outgoing(&heartbeat).unwrap();
+    assert!(matches!(
+        coordinator.receive_envelope(&envelope).await,
+        Err(z_mpc::Error::AuthError(_))
+    ));
+    
+    // Schnorr envelope from a key that is not registered for the peer
+    let envelope = SchnorrAuth::new(2, curve.random_scalar().unwrap()).sign_outgoing(&heartbeat).unwrap();
+    assert!(coordinator.receive_envelope(&envelope).await.is_err());
+    
+    // Authenticated peer speaking for someone else
+    let spoofed = NetworkMessage::Heartbeat { sender_id: 3, timestamp: 1 };
+    let envelope = SchnorrAuth::new(2, peer_secret).sign_outgoing(&spoofed).unwrap();
+    assert!(coordinator.receive_envelope(&envelope).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mtls_auth_trusts_only_the_connection_certificate() {
+    let certificates = [b"peer-2-certificate".to_vec(), b"peer-3-certificate".to_vec()];
+    let auth = MtlsAuth::new(1, b"peer-1-certificate".to_vec())
+        .with_peer(2, &certificates[0])
+        .with_peer(3, &certificates[1]);
+    let coordinator = NetworkCoordinator::new(NetworkNode::new(1, "127.0.0.1:9".parse().unwrap())).with_auth(Arc::new(auth));
+    
+    // Peer 2's certificate is public, so anyone can copy it into an envelope
+    let heartbeat = NetworkMessage::Heartbeat { sender_id: 2, timestamp: 1 };
+    let mut replayed = MtlsAuth::new(2, certificates[0].clone()).sign_outgoing(&heartbeat).unwrap();
+    replayed.credential = certificates[0].clone();
+    
+    // Serve the router as a TLS acceptor would, with the certificate of the connection attached
+    let client = reqwest::Client::new();
+    let mut handles = Vec::new();
+    let mut urls = Vec::new();
+    for certificate in [&certificates[1], &certificates[0]] {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        urls.push(format!("http://{}/heartbeat", listener.local_addr().unwrap()));
+        let app = coordinator.router().layer(axum::Extension(PeerCertificate(certificate.clone())));
+        handles.push(tokio::spawn(async move { axum::serve(listener, app).await }));
+    }
+    
+    // Sent over peer 3's connection, the copied certificate does not count
+    let response = client.post(&urls[0]).json(&replayed).send().await.unwrap();
+    assert_eq!(response.status(), 401);
+    
+    // Nor does it without any connection
+    assert!(matches!(coordinator.receive_envelope(&replayed).await, Err(z_mpc::Error::AuthError(_))));
+    
+    // Peer 2's own connection is accepted
+    let response = client.post(&urls[1]).json(&replayed).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+    
+    for handle in handles {
+        handle.abort();
+    }
+}
+
+#[tokio::test]
+async fn test_http_handlers_require_signed_envelopes() {
+    let curve_type = CurveType::Secp256k1;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let secrets = [curve.random_scalar().unwrap(), curve.random_scalar().unwrap()];
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let participants: Vec<Participant> = secrets.iter().enumerate().map(|(i, secret)| Participant {
+        id: i as u32 + 1,
+        address: if i == 0 { "127.0.0.1:9".parse().unwrap() } else { address },
+        public_keys: [(curve_type, curve.generator().mul(secret).unwrap().to_compressed_bytes().unwrap())].into(),
+        is_online: true,
+        last_heartbeat: 0,
+    }).collect();
+    let params = SharingParams {
+        curve_type,
+        threshold: 2,
+        participants: 2,
+    };
+    
+    // Both nodes sign under their published keys
+    let mut coordinators = Vec::new();
+    let session_id = SessionId::random();
+    for (i, secret) in secrets.iter().enumerate() {
+        let id = i as u32 + 1;
+        let auth = SchnorrAuth::new(id, secret.clone()).with_participants(&participants);
+        let mut coordinator = NetworkCoordinator::new(NetworkNode::new(id, participants[i].address))
+            .with_auth(Arc::new(auth));
+        coordinator.node.add_participant(participants[1 - i].clone()).await.unwrap();
+        coordinator.join_protocol(session_id, params.clone()).await.unwrap();
+        coordinators.push(coordinator);
+    }
+    let server = coordinators[1].clone();
+    let handle = tokio::spawn(async move { server.serve(listener).await });
+    
+    let (_, dealing) = coordinators[0].deal_share_deliveries(session_id).unwrap().remove(1);
+    let client = reqwest::Client::new();
+    let url = format!("http://{}/shares", address);
+    
+    // A correctly signed delivery is accepted once
+    let envelope = coordinators[0].seal(&dealing).unwrap();
+    let response = client.post(&url).json(&envelope).send().await.unwrap();
+    assert_eq!(response.status(), 200);
+    assert_eq!(coordinators[1].node.session_shares(session_id).await.unwrap().len(), 1);
+    let response = client.post(&url).json(&envelope).send().await.unwrap();
+    assert_eq!(response.status(), 409);
+    
+    // Unsigned or signed under another key
+    let response = client.post(&url).json(&dealing).send().await.unwrap();
+    assert_eq!(response.status(), 401);
+    let forged = SchnorrAuth::new(1, curve.random_scalar().unwrap()).sign_outgoing(&dealing).unwrap();
+    let response = client.post(&url).json(&forged).send().await.unwrap();
+    assert_eq!(response.status(), 401);
+    
+    // Nodes sign what they send, so the verified session answers rather
+    // than the authentication layer, and refuse unsigned local input
+    assert!(matches!(
+        coordinators[0].node.send_to(2, dealing.clone()).await,
+        Err(z_mpc::Error::NetworkError { participant_id: 2, status: Some(409), .. })
+    ));
+    assert!(matches!(
+        coordinators[1].node.process_message(dealing).await,
+        Err(z_mpc::Error::AuthError(_))
+    ));
+    handle.abort();
+}
+
+#[tokio::test]
+async fn test_default_node_rejects_unsigned_delivery() {
+    let curve_type = CurveType::Secp256k1;
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let nodes = [
+        NetworkNode::new(1, "127.0.0.1:9".parse().unwrap()),
+        NetworkNode::new(2, listener.local_addr().unwrap()),
+    ];
+    let params = SharingParams {
+        curve_type,
+        threshold: 2,
+        participants: 2,
+    };
+    
+    // Nodes publish their generated identity and learn each other's
+    let mut coordinators = Vec::new();
+    let session_id = SessionId::random();
+    for (i, node) in nodes.iter().enumerate() {
+        assert!(!node.participant().public_keys.is_empty());
+        node.add_participant(nodes[1 - i].participant()).await.unwrap();
+        let mut coordinator = NetworkCoordinator::new(node.clone());
+        coordinator.join_protocol(session_id, params.clone()).await.unwrap();
+        coordinators.push(coordinator);
+    }
+    let server = coordinators[1].clone();
+    let handle = tokio::spawn(async move { server.serve(listener).await });
+    
+    let (_, dealing) = coordinators[0].deal_share_deliveries(session_id).unwrap().remove(1);
+    let client = reqwest::Client::new();
+    let url = format!("http://{}/shares", nodes[1].address);
+    
+    let response = client.post(&url).json(&dealing).send().await.unwrap();
+    assert_eq!(response.status(), 401);
+    assert!(coordinators[1].node.session_shares(session_id).await.unwrap().is_empty());
+    
+    // The same delivery under the dealer's identity is accepted
+    coordinators[0].node.send_to(2, dealing).await.unwrap();
+    assert_eq!(coordinators[1].node.session_shares(session_id).await.unwrap().len(), 1);
+    handle.abort();
+}
+
+#[test]
+fn test_replay_guard_accepts_each_nonce_once() {
+    let mut guard = ReplayGuard::new();
+    guard.check(1, 10).unwrap();
+    assert!(matches!(guard.check(1, 10), Err(z_mpc::Error::ReplayedMessage(_))));
+    
+    // Out-of-order nonces within the window, and other senders, are fine
+    guard.check(1, 12).unwrap();
+    guard.check(1, 11).unwrap();
+    guard.check(2, 10).unwrap();
+    
+    // Nonces that fell out of the window are refused
+    guard.check(1, 11 + z_mpc::auth::REPLAY_WINDOW + 1).unwrap();
+    assert!(guard.check(1, 11).is_err());
+    assert!(guard.check(1, 5).is_err());
+}
+
+#[test]
+fn test_known_answer_vectors() {
+    selftest::known_answer_tests().unwrap();
+    
+    for vector in selftest::VECTORS {
+        selftest::run_vector(vector).unwrap();
+        
+        // A corrupted expected point is reported under the vector's name
+        let mut corrupted = *vector;
+        corrupted.scalar_mul = vector.commitment;
+        match selftest::run_vector(&corrupted) {
+            Err(z_mpc::Error::SelfTestFailed(reason)) => {
+                assert!(reason.contains(vector.name));
+                assert!(reason.contains("generator multiplication"));
+            }
+            other => panic!("Corrupted vector not detected: {:?}", other),
+        }
+        
+        let mut corrupted = *vector;
+        corrupted.randomness += 1;
+        assert!(matches!(selftest::run_vector(&corrupted), Err(z_mpc::Error::SelfTestFailed(_))));
+    }
+}
+
+#[test]
+fn test_reconstruct_public_key_dry_run() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let params = SharingParams {
+            curve_type,
+            threshold: 3,
+            participants: 5,
+        };
+        let laurent = LaurentSeries::new(&params).unwrap();
+        let shares = laurent.generate_shares().unwrap();
+        
+        // Expected group key from a real reconstruction
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let secret = laurent.reconstruct_secret(&shares).unwrap().secret;
+        let expected = curve.generator().mul(&z_mpc::Scalar::new(curve_type, secret.clone())).unwrap();
+        
+        // The dry run only hands back a point, never the secret scalar
+        let public_key: z_mpc::Point = laurent.reconstruct_public_key(&shares).unwrap();
+        assert_eq!(public_key, expected);
+        assert_eq!(public_key.curve_type, curve_type);
+        assert_ne!(public_key.x, secret);
+        
+        // Too few shares fail just like a real reconstruction
+        assert!(laurent.reconstruct_public_key(&shares[..2]).is_err());
+    }
+}
+
+/// `n` random values with their openings and Pedersen commitments
+fn membership_set(
+    pedersen: &PedersenCommitment,
+    n: usize,
+) -> (Vec<(z_mpc::Scalar, CommitmentOpening)>, Vec<z_mpc::Commitment>) {
+    let curve = z_mpc::curve::curve_backend(pedersen.curve_type).unwrap();
+    let openings: Vec<_> = (0..n).map(|_| {
+        let value = curve.random_scalar().unwrap();
+        let randomness = pedersen.generate_randomness();
+        (value.clone(), CommitmentOpening::new(&value, randomness))
+    }).collect();
+    let commitments = openings.iter()
+        .map(|(value, opening)| pedersen.commit(value, &opening.randomness).unwrap())
+        .collect();
+    (openings, commitments)
+}
+
+#[test]
+fn test_membership_proof_verifies_for_a_member() {
+    use z_mpc::zkp::MembershipProof;
+    
+    let pedersen = PedersenCommitment::new(CurveType::Secp256k1).unwrap();
+    for n in [4, 16, 64] {
+        let (openings, commitments) = membership_set(&pedersen, n);
+        
+        // Any member proves membership, also after a serde round trip
+        for index in [0, n / 2 + 1, n - 1] {
+            let (value, opening) = &openings[index];
+            let proof = MembershipProof::prove(&pedersen, index, value, opening, &commitments).unwrap();
+            assert!(proof.verify(&pedersen, &commitments).unwrap(), "member {} of {} should verify", index, n);
+            let encoded = serde_json::to_string(&proof).unwrap();
+            let decoded: MembershipProof = serde_json::from_str(&encoded).unwrap();
+            assert!(decoded.verify(&pedersen, &commitments).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_membership_proof_rejects_non_members() {
+    use z_mpc::zkp::MembershipProof;
+    
+    let curve = z_mpc::curve::curve_backend(CurveType::Secp256k1).unwrap();
+    let pedersen = PedersenCommitment::new(CurveType::Secp256k1).unwrap();
+    let (openings, commitments) = membership_set(&pedersen, 16);
+    let index = 9;
+    let (value, opening) = &openings[index];
+    
+    // A value outside the set has no proof, nor does a member at another index
+    let outsider = curve.random_scalar().unwrap();
+    let outsider_opening = CommitmentOpening::new(&outsider, pedersen.generate_randomness());
+    assert!(matches!(
+        MembershipProof::prove(&pedersen, index, &outsider, &outsider_opening, &commitments),
+        Err(z_mpc::Error::CommitmentError(_))
+    ));
+    assert!(MembershipProof::prove(&pedersen, index + 1, value, opening, &commitments).is_err());
+    
+    // Replacing the member in the set breaks the proof
+    let proof = MembershipProof::prove(&pedersen, index, value, opening, &commitments).unwrap();
+    let mut without_member = commitments.clone();
+    without_member[index] = pedersen.commit(&outsider, &outsider_opening.randomness).unwrap();
+    assert!(!proof.verify(&pedersen, &without_member).unwrap());
+    
+    // As does tampering with the responses
+    let mut tampered = proof.clone();
+    tampered.bit_responses.swap(0, 1);
+    assert!(!tampered.verify(&pedersen, &commitments).unwrap());
+}
+
+#[test]
+fn test_membership_proof_size_is_logarithmic() {
+    use z_mpc::zkp::MembershipProof;
+    
+    let pedersen = PedersenCommitment::new(CurveType::Secp256k1).unwrap();
+    let proof_bytes = |proof: &MembershipProof| {
+        [
+            &proof.bit_commitments,
+            &proof.bit_announcements,
+            &proof.product_commitments,
+            &proof.polynomial_commitments,
+            &proof.bit_responses,
+            &proof.announcement_responses,
+            &proof.product_responses,
+        ].iter().flat_map(|part| part.iter()).map(Vec::len).sum::<usize>() + proof.response.len()
+    };
+    
+    let mut sizes = Vec::new();
+    for n in [4, 16, 64] {
+        let (openings, commitments) = membership_set(&pedersen, n);
+        let (value, opening) = &openings[1];
+        let proof = MembershipProof::prove(&pedersen, 1, value, opening, &commitments).unwrap();
+        assert_eq!(proof.bit_commitments.len(), n.trailing_zeros() as usize);
+        sizes.push(proof_bytes(&proof));
+    }
+    
+    // Each 4x larger set adds the same amount
+    assert_eq!(sizes[1] - sizes[0], sizes[2] - sizes[1]);
+}
+
+#[test]
+fn test_membership_proof_pads_sets_to_a_power_of_two() {
+    use z_mpc::zkp::MembershipProof;
+    
+    let pedersen = PedersenCommitment::new(CurveType::Secp256k1).unwrap();
+    let (openings, commitments) = membership_set(&pedersen, 5);
+    let (value, opening) = &openings[4];
+    
+    let proof = MembershipProof::prove(&pedersen, 4, value, opening, &commitments).unwrap();
+    assert_eq!(proof.bit_commitments.len(), 3);
+    assert!(proof.verify(&pedersen, &commitments).unwrap());
+    assert!(!proof.verify(&pedersen, &commitments[..4]).unwrap());
+}
+
+#[test]
+fn test_prove_membership_cli() {
+    use z_mpc::zkp::MembershipProof;
+    
+    let pedersen = PedersenCommitment::new(CurveType::Secp256k1).unwrap();
+    let (openings, commitments) = membership_set(&pedersen, 5);
+    let (value, opening) = &openings[4];
+    
+    // The CLI proves membership in a public bundle
+    let dir = tempfile::tempdir().unwrap();
+    let bundle = dir.path().join("public.json");
+    let entries: Vec<_> = commitments.iter().map(|commitment| serde_json::json!({ "commitment": hex::encode(commitment) })).collect();
+    std::fs::write(&bundle, serde_json::json!({ "commitments": entries }).to_string()).unwrap();
+    let output = dir.path().join("membership.json");
+    Command::cargo_bin("z-mpc").unwrap()
+        .args(["prove-membership", "--curve", "k1", "--index", "4", "--value", &hex::encode(value.as_bytes())])
+        .args(["--randomness", &hex::encode(&opening.randomness), "--commitments"])
+        .arg(&bundle)
+        .arg("--output")
+        .arg(&output)
+        .assert()
+        .success();
+    let written: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+    let proof: MembershipProof = serde_json::from_value(written["proof"].clone()).unwrap();
+    assert!(proof.verify(&pedersen, &commitments).unwrap());
+}
+
+#[test]
+fn test_share_omits_absent_optional_fields() {
+    let bare = Share::new(1, vec![0xab; 32]);
+    let json = serde_json::to_value(&bare).unwrap();
+    assert!(json.get("commitment").is_none());
+    assert!(json.get("proof").is_none());
+    assert_eq!(serde_json::from_value::<Share>(json).unwrap(), bare);
+    
+    let full = Share::new(2, vec![0xcd; 32])
+        .with_commitment(vec![0x02; 33])
+        .with_proof(vec![0x01, 0x02, 0x03]);
+    let json = serde_json::to_value(&full).unwrap();
+    assert!(json.get("commitment").is_some());
+    assert!(json.get("proof").is_some());
+    assert_eq!(serde_json::from_value::<Share>(json).unwrap(), full);
+    
+    // Older output with explicit nulls still deserializes
+    let legacy = serde_json::json!({ "id": 1, "value": vec![0xab; 32], "commitment": null, "proof": null });
+    assert_eq!(serde_json::from_value::<Share>(legacy).unwrap(), bare);
+}
+
+#[tokio::test]
+async fn test_heartbeat_expiry_with_mock_clock() {
+    let clock = Arc::new(MockClock::new(1_700_000_000));
+    let node = NetworkNode::new(1, "127.0.0.1:9201".parse().unwrap()).with_clock(clock.clone()).with_insecure_no_auth();
+    
+    for id in [2, 3, 4] {
+        node.add_participant(Participant {
+            id,
+            address: format!("127.0.0.1:{}", 9200 + id).parse().unwrap(),
+            public_keys: Default::default(),
+            is_online: true,
+            last_heartbeat: clock.now_unix(),
+        }).await.unwrap();
+    }
+    
+    let timeout = std::time::Duration::from_secs(30);
+    assert!(node.expire_stale_participants(timeout).await.is_empty());
+    
+    // Only participant 3 keeps sending heartbeats, from a clock an hour
+    // behind; participant 4's far-future timestamp buys it nothing
+    node.process_message(NetworkMessage::Heartbeat { sender_id: 4, timestamp: u64::MAX }).await.unwrap();
+    clock.advance(std::time::Duration::from_secs(20));
+    node.process_message(NetworkMessage::Heartbeat { sender_id: 3, timestamp: clock.now_unix() - 3600 }).await.unwrap();
+    clock.advance(std::time::Duration::from_secs(15));
+    
+    assert_eq!(node.expire_stale_participants(timeout).await, vec![2, 4]);
+    let participants = node.participants.read().await;
+    assert!(!participants[&2].is_online);
+    assert!(participants[&3].is_online);
+    assert!(!participants[&4].is_online);
+    assert_eq!(participants[&3].last_heartbeat, clock.now_unix() - 15);
+}
+
+#[tokio::test]
+async fn test_liveness_monitor_marks_silent_peers_offline() {
+    let clock = Arc::new(MockClock::new(1_700_000_000));
+    let node = NetworkNode::new(1, "127.0.0.1:9211".parse().unwrap()).with_clock(clock.clone()).with_insecure_no_auth();
+    node.add_participant(Participant {
+        id: 2,
+        address: "127.0.0.1:9212".parse().unwrap(),
+        public_keys: Default::default(),
+        is_online: true,
+        last_heartbeat: clock.now_unix(),
+    }).await.unwrap();
+    
+    let timeout = std::time::Duration::from_secs(30);
+    let monitor = node.spawn_liveness_monitor(timeout, std::time::Duration::from_millis(10));
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert_eq!(node.online_participants().await.len(), 1);
+    
+    // Silence past the timeout is noticed by the next sweep
+    clock.advance(std::time::Duration::from_secs(31));
+    for _ in 0..100 {
+        if node.online_participants().await.is_empty() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert!(node.online_participants().await.is_empty());
+    assert!(!node.participants.read().await[&2].is_online);
+    
+    // A heartbeat brings the peer back
+    node.process_message(NetworkMessage::Heartbeat { sender_id: 2, timestamp: clock.now_unix() }).await.unwrap();
+    assert_eq!(node.online_participants().await[0].id, 2);
+    monitor.abort();
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_heartbeat_tasks_flip_peer_liveness() {
+    let clock = Arc::new(MockClock::new(1_700_000_000));
+    let node = NetworkNode::new(1, "127.0.0.1:9221".parse().unwrap()).with_clock(clock.clone()).with_insecure_no_auth();
+    node.add_participant(Participant {
+        id: 2,
+        address: "127.0.0.1:9222".parse().unwrap(),
+        public_keys: Default::default(),
+        is_online: true,
+        last_heartbeat: clock.now_unix(),
+    }).await.unwrap();
+    
+    let interval = std::time::Duration::from_secs(1);
+    let timeout = std::time::Duration::from_secs(5);
+    let tasks = node.start_heartbeat(interval, timeout);
+    tokio::time::sleep(interval * 3).await;
+    assert!(node.participants.read().await[&2].is_online);
+    
+    // No heartbeat within the timeout flips the peer offline
+    clock.advance(timeout + interval);
+    tokio::time::sleep(interval * 2).await;
+    assert!(!node.participants.read().await[&2].is_online);
+    let report = node.broadcast(NetworkMessage::Heartbeat { sender_id: 1, timestamp: clock.now_unix() }).await.unwrap();
+    assert!(report.delivered.is_empty());
+    assert_eq!(report.offline, vec![2]);
+    assert_eq!(report.unreachable(), vec![2]);
+    
+    // A heartbeat flips it back until it goes silent again
+    node.process_message(NetworkMessage::Heartbeat { sender_id: 2, timestamp: clock.now_unix() }).await.unwrap();
+    tokio::time::sleep(interval * 2).await;
+    assert!(node.participants.read().await[&2].is_online);
+    tasks.abort();
+}
+
+#[tokio::test]
+async fn test_heartbeats_bring_an_offline_peer_back() {
+    let (coordinators, handles) = network_utils::create_ephemeral_test_network(2, &[CurveType::Secp256k1]).await.unwrap();
+    coordinators[0].node.participants.write().await
+        .update(2, |participant| participant.is_online = false)
+        .unwrap();
+    
+    let tasks = coordinators[1].node.start_heartbeat(std::time::Duration::from_millis(20), std::time::Duration::from_secs(60));
+    for _ in 0..100 {
+        if coordinators[0].node.participants.read().await[&2].is_online {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    assert!(coordinators[0].node.participants.read().await[&2].is_online);
+    
+    tasks.abort();
+    for handle in handles {
+        handle.abort();
+    }
+}
+
+#[test]
+fn test_signature_verify_for_expected_key() {
+    let curve_type = CurveType::Secp256k1;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let expected_key = curve.random_scalar().unwrap();
+    let expected_point = curve.generator().mul(&expected_key).unwrap();
+    
+    let mut signature = SchnorrSignature::new(curve_type);
+    signature.sign(b"transfer", &expected_key).unwrap();
+    assert!(signature.verify_for(b"transfer", &expected_point).unwrap());
+    
+    // Self-consistent signature under another key
+    let mut forged = SchnorrSignature::new(curve_type);
+    forged.sign(b"transfer", &curve.random_scalar().unwrap()).unwrap();
+    assert!(forged.verify(b"transfer").unwrap());
+    assert!(matches!(
+        forged.verify_for(b"transfer", &expected_point),
+        Err(z_mpc::Error::KeyMismatch(_))
+    ));
+}
+
+/// Roster of three participants on `curve_type` with their secret keys
+fn invitation_roster(curve_type: CurveType) -> (Vec<RosterEntry>, Vec<z_mpc::Scalar>) {
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let keys: Vec<_> = (0..3).map(|_| curve.random_scalar().unwrap()).collect();
+    let roster = keys.iter()
+        .enumerate()
+        .map(|(i, key)| RosterEntry {
+            id: i as u32 + 1,
+            address: format!("127.0.0.1:{}", 9301 + i).parse().unwrap(),
+            public_key: curve.generator().mul(key).unwrap().to_compressed_bytes().unwrap(),
+        })
+        .collect();
+    (roster, keys)
+}
+
+#[test]
+fn test_invitation_rejects_expired_tampered_and_wrong_organizer() {
+    let curve_type = CurveType::Secp256k1;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let (roster, keys) = invitation_roster(curve_type);
+    let organizer_point = curve.generator().mul(&keys[0]).unwrap();
+    let params = SharingParams {
+        curve_type,
+        threshold: 2,
+        participants: 3,
+    };
+    
+    let invitation = Invitation::create(
+        params, roster, SessionId::random(), 1,
+        vec!["127.0.0.1:9301".parse().unwrap()], 1_700_000_600, &keys[0],
+    ).unwrap();
+    invitation.verify(&organizer_point, 1_700_000_000).unwrap();
+    
+    // Expired
+    assert!(invitation.verify(&organizer_point, 1_700_000_600).is_err());
+    
+    // Tampered parameters and roster
+    let mut tampered = invitation.clone();
+    tampered.params.threshold = 1;
+    assert!(matches!(tampered.verify(&organizer_point, 1_700_000_000), Err(z_mpc::Error::AuthError(_))));
+    let mut tampered = invitation.clone();
+    tampered.roster[2].public_key = tampered.roster[1].public_key.clone();
+    assert!(matches!(tampered.verify(&organizer_point, 1_700_000_000), Err(z_mpc::Error::AuthError(_))));
+    
+    // Signed by someone other than the pinned organizer
+    let (roster, _) = invitation_roster(curve_type);
+    let forged = Invitation::create(
+        invitation.params.clone(), roster, invitation.session_id, 1,
+        vec![], 1_700_000_600, &keys[1],
+    ).unwrap();
+    assert!(matches!(forged.verify(&organizer_point, 1_700_000_000), Err(z_mpc::Error::KeyMismatch(_))));
+}
+
+#[tokio::test]
+async fn test_invitation_join_then_distribute() {
+    let curve_type = CurveType::Edwards25519;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let (roster, keys) = invitation_roster(curve_type);
+    let organizer_point = curve.generator().mul(&keys[0]).unwrap();
+    let params = SharingParams {
+        curve_type,
+        threshold: 2,
+        participants: 3,
+    };
+    
+    // Organizer runs the ceremony session and invites the others to it
+    let mut organizer = NetworkCoordinator::new(NetworkNode::new(1, roster[0].address)).with_insecure_no_auth();
+    let session_id = organizer.initialize_protocol(params.clone()).await.unwrap();
+    let clock = Arc::new(MockClock::new(1_700_000_000));
+    let invitation = Invitation::create(
+        params, roster.clone(), session_id, 1,
+        vec![roster[0].address], clock.now_unix() + 600, &keys[0],
+    ).unwrap();
+    
+    // Participant 2 joins from the invitation alone
+    let node = NetworkNode::new(2, roster[1].address).with_clock(clock.clone()).with_insecure_no_auth();
+    let mut participant = NetworkCoordinator::new(node);
+    invitation.join(&mut participant, &organizer_point).await.unwrap();
+    assert_eq!(participant.node.session_curve(session_id).await.unwrap(), curve_type);
+    assert_eq!(participant.node.participants.read().await[&3].public_key(curve_type), Some(roster[2].public_key.as_slice()));
+    
+    // Its registration reaches the organizer
+    organizer.node.process_message(NetworkMessage::Join {
+        sender_id: 2,
+        session_id,
+        address: roster[1].address,
+        public_key: roster[1].public_key.clone(),
+    }).await.unwrap();
+    assert!(organizer.node.participants.read().await[&2].is_online);
+    
+    // A share off the committed series is rejected before it is kept
+    let mut deliveries = organizer.deal_share_deliveries(session_id).unwrap();
+    let (_, dealing) = deliveries.remove(1);
+    let (_, NetworkMessage::ShareDelivery { committed_share: other, .. }) = &deliveries[0] else {
+        panic!("Expected a share delivery");
+    };
+    let mut tampered = dealing.clone();
+    if let NetworkMessage::ShareDelivery { committed_share, .. } = &mut tampered {
+        committed_share.share.value = other.share.value.clone();
+    }
+    assert!(matches!(participant.node.process_message(tampered).await, Err(z_mpc::Error::CommitmentError(_))));
+    
+    // Deliveries from anyone but the pinned organizer are rejected
+    let NetworkMessage::ShareDelivery { session_id, committed_share, proof, coefficient_commitments, .. } = dealing.clone() else {
+        panic!("Expected a share delivery");
+    };
+    let rogue = NetworkMessage::ShareDelivery {
+        sender_id: 3, session_id, committed_share, proof, coefficient_commitments,
+    };
+    assert!(matches!(participant.node.process_message(rogue).await, Err(z_mpc::Error::AuthError(_))));
+    
+    // The organizer's delivery is accepted and matched to the session
+    participant.node.process_message(dealing).await.unwrap();
+    let shares = participant.node.session_shares(session_id).await.unwrap();
+    assert_eq!(shares.len(), 1);
+    assert_eq!(shares[0].id, 2);
+    
+    // The same invitation is refused once expired
+    clock.advance(std::time::Duration::from_secs(600));
+    let mut late = NetworkCoordinator::new(NetworkNode::new(3, roster[2].address).with_clock(clock.clone()));
+    assert!(invitation.join(&mut late, &organizer_point).await.is_err());
+}
+
+#[test]
+fn test_n_of_n_requires_every_share() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let params = SharingParams {
+            curve_type,
+            threshold: 4,
+            participants: 4,
+        };
+        laurent_utils::validate_params(&params).unwrap();
+        
+        let laurent = LaurentSeries::new(&params).unwrap();
+        let shares = laurent.generate_shares().unwrap();
+        assert_eq!(shares.len(), 4);
+        
+        let result = laurent.reconstruct_secret(&shares).unwrap();
+        assert_eq!(result.participants_used, vec![1, 2, 3, 4]);
+        
+        for missing in 0..shares.len() {
+            let mut subset = shares.clone();
+            let removed = subset.remove(missing);
+            assert!(matches!(
+                laurent.reconstruct_secret(&subset),
+                Err(z_mpc::Error::InsufficientShares { required: 4, got: 3 })
+            ));
+            
+            // A duplicate cannot stand in for the missing share
+            let padded_with = if missing == 0 { &shares[1] } else { &shares[0] };
+            subset.push(padded_with.clone());
+            assert_ne!(removed.id, padded_with.id);
+            assert!(laurent.reconstruct_secret(&subset).is_err());
+        }
+    }
+}
+
+#[test]
+fn test_imported_coefficients_reproduce_external_shares() {
+    let data: serde_json::Value = serde_json::from_str(include_str!("data/laurent_coefficients.json")).unwrap();
+    
+    for fixture in data["fixtures"].as_array().unwrap() {
+        let curve_type: CurveType = fixture["curve_type"].as_str().unwrap().parse().unwrap();
+        let params = SharingParams {
+            curve_type,
+            threshold: fixture["threshold"].as_u64().unwrap() as usize,
+            participants: fixture["participants"].as_u64().unwrap() as usize,
+        };
+        
+        let coefficients: z_mpc::LaurentCoefficients = serde_json::from_value(fixture["coefficients"].clone()).unwrap();
+        let laurent = LaurentSeries::from_laurent_coefficients(&coefficients, &params).unwrap();
+        
+        let shares = laurent.generate_shares().unwrap();
+        for (share, expected) in shares.iter().zip(fixture["shares"].as_array().unwrap()) {
+            assert_eq!(share.id as u64, expected["id"].as_u64().unwrap());
+            assert_eq!(hex::encode(&share.value), expected["value"].as_str().unwrap(), "{} share {}", curve_type, share.id);
+        }
+        
+        // Coefficients round-trip through the hex serialization
+        assert_eq!(serde_json::to_value(laurent.get_coefficients()).unwrap(), fixture["coefficients"]);
+        
+        // Invalid imports are rejected
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let scalars = |coeffs: &[Vec<u8>]| -> Vec<z_mpc::Scalar> {
+            coeffs.iter().map(|c| z_mpc::Scalar::new(curve_type, c.clone())).collect()
+        };
+        let (a, b) = (scalars(&coefficients.a_coeffs), scalars(&coefficients.b_coeffs));
+        
+        assert!(LaurentSeries::from_coefficients(a.clone(), b[..1].to_vec(), &params).is_err());
+        
+        let mut zero_residue = b.clone();
+        zero_residue[0] = curve.scalar_from_u64(0).unwrap();
+        assert!(LaurentSeries::from_coefficients(a.clone(), zero_residue, &params).is_err());
+        
+        let mut wrong_curve = a.clone();
+        wrong_curve[0] = z_mpc::Scalar::new(CurveType::P256, wrong_curve[0].value.clone());
+        assert!(LaurentSeries::from_coefficients(wrong_curve, b.clone(), &params).is_err());
+        
+        let mut non_canonical = a.clone();
+        non_canonical[0] = z_mpc::Scalar::new(curve_type, vec![0xff; 32]);
+        assert!(LaurentSeries::from_coefficients(non_canonical, b, &params).is_err());
+    }
+}
+
+#[test]
+fn test_cli_share_split_output_writes_one_file_per_participant() {
+    let dir = tempfile::tempdir().unwrap();
+    let split_dir = dir.path().join("ceremony");
+    
+    Command::cargo_bin("z-mpc").unwrap()
+        .args(["share", "--curve", "ed25519", "--threshold", "3", "--participants", "5", "--split-output"])
+        .arg(&split_dir)
+        .assert()
+        .success();
+    
+    for id in 1..=5 {
+        let data: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(split_dir.join(format!("share_{}.json", id))).unwrap()
+        ).unwrap();
+        let committed: CommittedShare = serde_json::from_value(data["committed_share"].clone()).unwrap();
+        assert_eq!(committed.share.id, id);
+        assert!(data.get("shares").is_none());
+    }
+    
+    let public: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(split_dir.join("public.json")).unwrap()
+    ).unwrap();
+    assert_eq!(public["commitments"].as_array().unwrap().len(), 5);
+    assert!(public["group_public_key"].is_string());
+    assert!(public.get("shares").is_none());
+    
+    // Five share files and the public bundle, nothing else
+    assert_eq!(std::fs::read_dir(&split_dir).unwrap().count(), 6);
+}
+
+#[test]
+fn test_transport_proxy_bypass_and_root_certificates() {
+    let transport = TransportConfig {
+        proxy: Some("http://proxy.internal:3128".to_string()),
+        no_proxy: vec!["10.0.0.5".to_string(), "10.0.0.6:8002".to_string()],
+        ..TransportConfig::default()
+    };
+    
+    assert_eq!(transport.proxy_for(&"10.0.0.7:8001".parse().unwrap()), Some("http://proxy.internal:3128"));
+    assert_eq!(transport.proxy_for(&"10.0.0.5:8001".parse().unwrap()), None);
+    assert_eq!(transport.proxy_for(&"10.0.0.6:8002".parse().unwrap()), None);
+    assert!(transport.proxy_for(&"10.0.0.6:8003".parse().unwrap()).is_some());
+    
+    // Root certificates are loaded from PEM bundles
+    let dir = tempfile::tempdir().unwrap();
+    let bundle = dir.path().join("roots.pem");
+    std::fs::write(&bundle, concat!(
+        "-----BEGIN CERTIFICATE-----\nAQID\n-----END CERTIFICATE-----\n",
+        "-----BEGIN CERTIFICATE-----\nBAUG\n-----END CERTIFICATE-----\n",
+    )).unwrap();
+    let empty = dir.path().join("empty.pem");
+    std::fs::write(&empty, "not a certificate").unwrap();
+    
+    let with_roots = TransportConfig { root_certificates: vec![bundle], ..TransportConfig::default() };
+    assert_eq!(with_roots.load_root_certificates().unwrap(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    
+    let without_roots = TransportConfig { root_certificates: vec![empty], ..TransportConfig::default() };
+    assert!(without_roots.load_root_certificates().is_err());
+}
+
+#[test]
+fn test_reconstruction_rejects_shares_from_different_dealings() {
+    let params = SharingParams {
+        curve_type: CurveType::Secp256k1,
+        threshold: 3,
+        participants: 5,
+    };
+    let first = LaurentSeries::new(&params).unwrap();
+    let second = LaurentSeries::new(&params).unwrap();
+    let first_shares = first.generate_shares().unwrap();
+    let second_shares = second.generate_shares().unwrap();
+    
+    assert_eq!(first_shares[0].dealing_id, Some(first.dealing_id().unwrap()));
+    assert_ne!(first.dealing_id().unwrap(), second.dealing_id().unwrap());
+    
+    first.reconstruct_secret(&first_shares[..3]).unwrap();
+    
+    let mixed = vec![first_shares[0].clone(), first_shares[1].clone(), second_shares[2].clone()];
+    assert!(matches!(first.reconstruct_secret(&mixed), Err(z_mpc::Error::DealingMismatch(_))));
+}
+
+#[test]
+fn test_commitment_opening_versus_zero_knowledge_proof() {
+    let curve_type = CurveType::P256;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let pedersen = PedersenCommitment::new(curve_type).unwrap();
+    
+    let value = curve.random_scalar().unwrap();
+    let randomness = pedersen.generate_randomness();
+    let commitment = pedersen.commit(&value, &randomness).unwrap();
+    
+    // An opening checks a claimed value and reveals the randomness
+    let opening = CommitmentOpening::new(&value, randomness.clone());
+    assert!(pedersen.open(&commitment, &opening, &value).unwrap());
+    assert!(!pedersen.open(&commitment, &opening, &curve.random_scalar().unwrap()).unwrap());
+    assert_eq!(opening.randomness, randomness);
+    
+    // The value hash does not depend on how the value is encoded
+    let small = curve.scalar_from_u64(7).unwrap();
+    let padded = z_mpc::Scalar::new(curve_type, small.canonical_bytes());
+    let short = z_mpc::Scalar::new(curve_type, vec![7]);
+    assert_eq!(CommitmentOpening::hash_value(&short), CommitmentOpening::hash_value(&padded));
+    let small_commitment = pedersen.commit(&small, &randomness).unwrap();
+    assert!(pedersen.open(&small_commitment, &CommitmentOpening::new(&padded, randomness.clone()), &short).unwrap());
+    
+    // An opening for another commitment does not verify
+    let other = pedersen.commit(&value, &pedersen.generate_randomness()).unwrap();
+    assert!(!pedersen.open(&other, &opening, &value).unwrap());
+    
+    // Openings on another curve are rejected outright
+    let k1_value = z_mpc::curve::create_curve(CurveType::Secp256k1).unwrap().random_scalar().unwrap();
+    let k1_opening = CommitmentOpening::new(&k1_value, randomness.clone());
+    assert!(pedersen.open(&commitment, &k1_opening, &value).is_err());
+    
+    // The ZK path proves knowledge of the same opening without revealing it
+    let mut proof = ZeroKnowledgeProof::new(curve_type);
+    proof.prove(&pedersen, &value, &randomness).unwrap();
+    assert!(proof.verify(&pedersen).unwrap());
+    let proof_json = serde_json::to_string(&proof).unwrap();
+    assert!(!proof_json.contains(&serde_json::to_string(&randomness).unwrap()));
+    
+    // Openings serialized under the old field name still load
+    let legacy = serde_json::json!({
+        "share": Share::new(1, value.value.clone()),
+        "commitment": commitment,
+        "randomness": randomness,
+        "proof": opening,
+    });
+    let committed: CommittedShare = serde_json::from_value(legacy).unwrap();
+    assert_eq!(committed.opening, Some(opening));
+}
+
+#[test]
+fn test_prove_committed_share_rejects_opening_mismatch() {
+    let curve_type = CurveType::Secp256k1;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let pedersen = PedersenCommitment::new(curve_type).unwrap();
+    let share = Share::new(1, curve.random_scalar().unwrap().into_bytes());
+    let value = curve.scalar_from_bytes(&share.value).unwrap();
+    
+    let randomness = pedersen.generate_randomness();
+    let published = pedersen.commit(&value, &randomness).unwrap();
+    
+    // The proof covers exactly the published commitment
+    let proof = zkp_utils::prove_committed_share(&pedersen, &share, &published, &randomness, curve_type).unwrap();
+    assert_eq!(proof.commitment, published);
+    assert!(proof.verify(&pedersen).unwrap());
+    
+    // Randomness that does not open the published commitment is refused
+    let other_randomness = pedersen.generate_randomness();
+    let result = zkp_utils::prove_committed_share(&pedersen, &share, &published, &other_randomness, curve_type);
+    assert!(matches!(result, Err(z_mpc::Error::CommitmentError(ref message)) if message == "opening mismatch"));
+}
+
+#[test]
+fn test_proof_verifies_against_supplied_commitment() {
+    let curve_type = CurveType::P256;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let pedersen = PedersenCommitment::new(curve_type).unwrap();
+    let value = curve.random_scalar().unwrap();
+    let share = Share::new(1, value.value.clone());
+    
+    // The verifier holds the published commitment independently of the proof
+    let randomness = pedersen.generate_randomness();
+    let published = pedersen.commit(&value, &randomness).unwrap();
+    let proof = zkp_utils::prove_committed_share(&pedersen, &share, &published, &randomness, curve_type).unwrap();
+    assert!(proof.verify_against(&pedersen, &published).unwrap());
+    
+    // An embedded commitment that disagrees with the published one is rejected
+    let other = pedersen.commit(&value, &pedersen.generate_randomness()).unwrap();
+    let mut swapped = proof.clone();
+    swapped.commitment = other.clone();
+    assert!(!swapped.verify_against(&pedersen, &published).unwrap());
+    assert!(!proof.verify_against(&pedersen, &other).unwrap());
+}
+
+#[cfg(feature = "op-counters")]
+#[test]
+fn test_commit_and_prove_saves_scalar_multiplications() {
+    use z_mpc::curve::scalar_mul_count;
+    
+    let curve_type = CurveType::Secp256k1;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let pedersen = PedersenCommitment::new(curve_type).unwrap();
+    let value = curve.random_scalar().unwrap();
+    let randomness = curve.random_scalar().unwrap().into_bytes();
+    
+    // Separate calls commit twice
+    let before = scalar_mul_count();
+    let commitment = pedersen.commit(&value, &randomness).unwrap();
+    let mut separate = ZeroKnowledgeProof::new(curve_type);
+    separate.prove(&pedersen, &value, &randomness).unwrap();
+    let separate_cost = scalar_mul_count() - before;
+    
+    let before = scalar_mul_count();
+    let (combined_commitment, combined) = pedersen
+        .commit_and_prove(&value, &randomness, b"session/1", &mut rand::thread_rng())
+        .unwrap();
+    let combined_cost = scalar_mul_count() - before;
+    assert!(combined_cost < separate_cost, "{} >= {}", combined_cost, separate_cost);
+    
+    // Both paths yield the same commitment and proofs that verify alike
+    assert_eq!(combined_commitment, commitment);
+    assert_eq!(combined.commitment, separate.commitment);
+    assert_eq!(combined.verify(&pedersen).unwrap(), separate.verify(&pedersen).unwrap());
+    assert_eq!(
+        combined.verify_against(&pedersen, &commitment).unwrap(),
+        separate.verify_against(&pedersen, &commitment).unwrap()
+    );
+}
+
+#[test]
+fn test_share_verifies_against_polynomial_commitment() {
+    let params = SharingParams {
+        curve_type: CurveType::Edwards25519,
+        threshold: 3,
+        participants: 5,
+    };
+    let laurent = LaurentSeries::new(&params).unwrap();
+    let shares = laurent.generate_shares().unwrap();
+    
+    let polynomial_commitment = laurent.polynomial_commitment().unwrap();
+    let commitments = laurent.coefficient_commitments().unwrap();
+    let (a_commitments, b_commitments) = (commitments.a_commitments, commitments.b_commitments);
+    let verify = |share: &Share, a: &[z_mpc::Point], b: &[z_mpc::Point]| {
+        LaurentSeries::verify_share_against_polynomial_commitment(
+            share, a, b, &polynomial_commitment, params.curve_type, laurent.id_scheme,
+        ).unwrap()
+    };
+    
+    for share in &shares {
+        assert!(verify(share, &a_commitments, &b_commitments));
+    }
+    
+    // A share that does not lie on the polynomial is caught
+    let mut forged = shares[0].clone();
+    forged.value = shares[1].value.clone();
+    assert!(!verify(&forged, &a_commitments, &b_commitments));
+    
+    // Tampered coefficients change the polynomial commitment
+    let mut tampered = laurent.clone();
+    tampered.b_coeffs[1] = tampered.b_coeffs[1].add(&tampered.a_coeffs[0]).unwrap();
+    assert_ne!(tampered.polynomial_commitment().unwrap(), polynomial_commitment);
+    
+    let tampered_commitments = tampered.coefficient_commitments().unwrap();
+    let (tampered_a, tampered_b) = (tampered_commitments.a_commitments, tampered_commitments.b_commitments);
+    let tampered_share = tampered.generate_shares().unwrap().remove(0);
+    assert!(!verify(&tampered_share, &tampered_a, &tampered_b));
+}
+
+#[tokio::test]
+async fn test_traffic_log_shows_dropped_distribution() {
+    let curve_type = CurveType::Secp256k1;
+    let mut coordinators: Vec<_> = network_utils::create_test_network(3, &[curve_type]).await.unwrap()
+        .into_iter()
+        .map(NetworkCoordinator::with_insecure_no_auth)
+        .collect();
+    let params = SharingParams {
+        curve_type,
+        threshold: 2,
+        participants: 3,
+    };
+    let session_id = SessionId::random();
+    for coordinator in coordinators.iter_mut() {
+        coordinator.join_protocol(session_id, params.clone()).await.unwrap();
+    }
+    
+    // Node 1 deals to everyone, but the message toward node 3 is dropped
+    let mut deliveries = coordinators[0].deal_share_deliveries(session_id).unwrap();
+    deliveries.remove(0);
+    let (_, delivery) = deliveries[0].clone();
+    coordinators[0].node.send_each(deliveries).await.unwrap();
+    coordinators[1].node.process_message(delivery).await.unwrap();
+    
+    let dealer = coordinators[0].session_traffic(session_id).await.unwrap();
+    assert_eq!(dealer.peer(2).sent["ShareDelivery"], 1);
+    assert_eq!(dealer.peer(3).sent["ShareDelivery"], 1);
+    assert!(dealer.peer(3).bytes_sent > 0);
+    
+    let delivered = coordinators[1].session_traffic(session_id).await.unwrap();
+    assert_eq!(delivered.peer(1).messages_received(), 1);
+    assert_eq!(delivered.peer(1).bytes_received, dealer.peer(2).bytes_sent);
+    assert!(delivered.peer(1).last_success.is_some());
+    
+    // Node 3 never saw the dealer's message
+    assert!(coordinators[2].session_traffic(session_id).await.is_none());
+    
+    // Summaries carry digests only, the same on both ends
+    let sent_digest = &dealer.recent.iter().find(|s| s.peer == 2).unwrap().digest;
+    assert_eq!(&delivered.recent[0].digest, sent_digest);
+    assert_eq!(sent_digest.len(), 64);
+}
+
+#[tokio::test]
+async fn test_full_channel_rejects_instead_of_blocking() {
+    let heartbeat = |sender_id| NetworkMessage::Heartbeat { sender_id, timestamp: 0 };
+    
+    let node = NetworkNode::new(1, "127.0.0.1:9401".parse().unwrap())
+        .with_message_channel(2, OverflowPolicy::RejectNew)
+        .unwrap();
+    node.enqueue(heartbeat(2)).await.unwrap();
+    node.enqueue(heartbeat(3)).await.unwrap();
+    
+    let rejected = tokio::time::timeout(std::time::Duration::from_secs(1), node.enqueue(heartbeat(4)))
+        .await
+        .expect("enqueue blocked on a full channel");
+    assert!(matches!(rejected, Err(z_mpc::Error::ChannelFull(_))));
+    assert_eq!(node.dropped_messages(), 1);
+    assert_eq!(node.next_message().await.unwrap().sender_id(), 2);
+    
+    // Drop-oldest keeps the newest messages
+    let node = NetworkNode::new(1, "127.0.0.1:9402".parse().unwrap())
+        .with_message_channel(2, OverflowPolicy::DropOldest)
+        .unwrap();
+    for sender_id in 2..=4 {
+        node.enqueue(heartbeat(sender_id)).await.unwrap();
+    }
+    assert_eq!(node.dropped_messages(), 1);
+    assert_eq!(node.next_message().await.unwrap().sender_id(), 3);
+    assert_eq!(node.next_message().await.unwrap().sender_id(), 4);    
+    // A zero-capacity channel is rejected rather than panicking
+    let result = NetworkNode::new(1, "127.0.0.1:9403".parse().unwrap())
+        .with_message_channel(0, OverflowPolicy::Block);
+    assert!(matches!(result, Err(z_mpc::Error::InvalidInput(_))));
+}
+
+#[test]
+fn test_cli_quickstart_artifacts_verify_with_subcommands() {
+    use chacha20poly1305::{aead::{Aead, KeyInit}, ChaCha20Poly1305, Key, Nonce};
+    
+    let dir = tempfile::tempdir().unwrap();
+    let demo = dir.path().join("demo");
+    let passphrase = "correct horse battery staple";
+    
+    // No passphrase, no ceremony
+    Command::cargo_bin("z-mpc").unwrap()
+        .args(["quickstart", "--curve", "k1", "--dir"])
+        .arg(&demo)
+        .env_remove("Z_MPC_PASSPHRASE")
+        .assert()
+        .failure();
+    
+    let quickstart = Command::cargo_bin("z-mpc").unwrap()
+        .args(["quickstart", "--curve", "k1", "--dir"])
+        .arg(&demo)
+        .env("Z_MPC_PASSPHRASE", passphrase)
+        .assert()
+        .success();
+    let stdout = String::from_utf8(quickstart.get_output().stdout.clone()).unwrap();
+    
+    let public: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(demo.join("public.json")).unwrap()).unwrap();
+    assert_eq!(public["commitments"].as_array().unwrap().len(), 5);
+    assert!(stdout.contains(public["group_public_key"].as_str().unwrap()));
+    
+    for id in 1..=5 {
+        Command::cargo_bin("z-mpc").unwrap()
+            .args(["verify-proof", "--curve", "k1", "--proof"])
+            .arg(demo.join(format!("proof_{}.json", id)))
+            .assert()
+            .success();
+        
+        // Each share file opens with the passphrase and matches the public commitment
+        assert!(!demo.join(format!("share_{}.key", id)).exists());
+        let encrypted: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(demo.join(format!("share_{}.enc.json", id))).unwrap()
+        ).unwrap();
+        assert_eq!(encrypted["kdf"], "argon2id");
+        let salt = hex::decode(encrypted["salt"].as_str().unwrap()).unwrap();
+        let mut key = [0u8; 32];
+        argon2::Argon2::default().hash_password_into(passphrase.as_bytes(), &salt, &mut key).unwrap();
+        let nonce = hex::decode(encrypted["nonce"].as_str().unwrap()).unwrap();
+        let ciphertext = hex::decode(encrypted["ciphertext"].as_str().unwrap()).unwrap();
+        let plaintext = ChaCha20Poly1305::new(Key::from_slice(&key))
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .unwrap();
+        let committed: CommittedShare = serde_json::from_slice(&plaintext).unwrap();
+        assert_eq!(committed.share.id, id);
+        assert_eq!(hex::encode(&committed.commitment), public["commitments"][id as usize - 1]["commitment"]);
+    }
+    
+    Command::cargo_bin("z-mpc").unwrap()
+        .args(["verify-signature", "--message", "z-mpc quickstart", "--signature"])
+        .arg(demo.join("signature.json"))
+        .assert()
+        .success();
+    
+    // The signature is made under the published group key
+    let data: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(demo.join("signature.json")).unwrap()).unwrap();
+    let signature: SchnorrSignature = serde_json::from_value(data["signature"].clone()).unwrap();
+    assert_eq!(hex::encode(&signature.public_key), public["group_public_key"]);
+}
+
+#[test]
+fn test_bench_compare_flags_regressions_only() {
+    use z_mpc::bench::{self, BenchTimings};
+    use std::collections::BTreeMap;
+    
+    let timings = |share_us: f64, prove_us: f64| BenchTimings {
+        curves: BTreeMap::from([(
+            "secp256k1".to_string(),
+            BTreeMap::from([("share".to_string(), share_us), ("prove".to_string(), prove_us)]),
+        )]),
+    };
+    let baseline = timings(100.0, 400.0);
+    
+    // Sharing got 50% slower, proving got twice as fast
+    let current = timings(150.0, 200.0);
+    let regressions = bench::compare(&baseline, &current, bench::DEFAULT_REGRESSION_THRESHOLD);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].curve, "secp256k1");
+    assert_eq!(regressions[0].phase, "share");
+    assert!((regressions[0].change_percent - 50.0).abs() < 1e-9);
+    
+    // Slowdowns within the threshold are noise
+    assert!(bench::compare(&baseline, &timings(110.0, 400.0), bench::DEFAULT_REGRESSION_THRESHOLD).is_empty());
+    
+    // A baseline round-trips through its file
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("baseline.json");
+    baseline.save(&path).unwrap();
+    assert_eq!(BenchTimings::load(&path).unwrap(), baseline);
+}
+
+#[test]
+fn test_participant_registry_indexes_survive_reregistration() {
+    use z_mpc::registry::{key_fingerprint, ParticipantRegistry};
+    use std::collections::HashMap;
+    
+    let participant = |id: z_mpc::ShareId, port: u16, key: u8| Participant {
+        id,
+        address: format!("10.0.0.1:{}", port).parse().unwrap(),
+        public_keys: HashMap::from([(CurveType::Secp256k1, vec![key; 33])]),
+        is_online: true,
+        last_heartbeat: 0,
+    };
+    
+    let mut registry = ParticipantRegistry::new();
+    for id in 1..=3 {
+        registry.insert(participant(id, 7000 + id as u16, id as u8)).unwrap();
+    }
+    
+    // Re-registering with a new endpoint and key drops the old index entries
+    let previous = registry.insert(participant(2, 7100, 20)).unwrap();
+    assert_eq!(previous.unwrap().address, "10.0.0.1:7002".parse().unwrap());
+    assert!(registry.participant_by_addr(&"10.0.0.1:7002".parse().unwrap()).is_none());
+    assert!(registry.participant_by_key(&key_fingerprint(&[2; 33])).is_none());
+    assert_eq!(registry.participant_by_addr(&"10.0.0.1:7100".parse().unwrap()).unwrap().id, 2);
+    assert_eq!(registry.participant_by_key(&key_fingerprint(&[20; 33])).unwrap().id, 2);
+    registry.check_consistency().unwrap();
+    
+    // The freed endpoint can be taken over, a used one cannot
+    registry.update(3, |p| p.address = "10.0.0.1:7002".parse().unwrap()).unwrap();
+    assert_eq!(registry.participant_by_addr(&"10.0.0.1:7002".parse().unwrap()).unwrap().id, 3);
+    assert!(registry.insert(participant(4, 7001, 4)).is_err());
+    assert!(registry.update(1, |p| p.public_keys.insert(CurveType::P256, vec![20; 33])).is_err());
+    assert!(registry.get(1).unwrap().public_key(CurveType::P256).is_none());
+    registry.check_consistency().unwrap();
+    
+    registry.remove(2);
+    assert!(registry.participant_by_key(&key_fingerprint(&[20; 33])).is_none());
+    assert_eq!(registry.len(), 2);
+    registry.check_consistency().unwrap();
+}
+
+#[test]
+fn test_equal_points_and_scalars_collapse_in_hash_set() {
+    use std::collections::HashSet;
+    use z_mpc::curve::{Point, Scalar};
+    
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let generator = curve.generator();
+        
+        // The same point decoded from its compressed form and with padded coordinates
+        let decoded = Point::from_compressed_bytes(curve_type, &generator.to_compressed_bytes().unwrap()).unwrap();
+        let pad = |coordinate: &[u8]| match curve_type {
+            CurveType::Edwards25519 => [coordinate, &[0]].concat(),
+            _ => [&[0], coordinate].concat(),
+        };
+        let padded = Point::new(curve_type, pad(&generator.x), pad(&generator.y));
+        
+        let points: HashSet<Point> = [generator.clone(), decoded, padded].into_iter().collect();
+        assert_eq!(points.len(), 1, "{}", curve_type);
+        
+        let other = generator.mul(&curve.scalar_from_u64(2).unwrap()).unwrap();
+        assert!(!points.contains(&other));
+        
+        // One as a single byte and at full width
+        let one = curve.scalar_from_u64(1).unwrap();
+        let short_one = Scalar::new(curve_type, vec![1]);
+        let scalars: HashSet<Scalar> = [one.clone(), short_one].into_iter().collect();
+        assert_eq!(scalars.len(), 1, "{}", curve_type);
+        assert!(scalars.contains(&one));
+        
+        // Points on different curves never collapse
+        assert_ne!(Point::new(curve_type, generator.x.clone(), generator.y.clone()), Point::new(
+            if curve_type == CurveType::P256 { CurveType::Secp256k1 } else { CurveType::P256 },
+            generator.x.clone(),
+            generator.y.clone(),
+        ));
+    }
+}
+
+#[test]
+fn test_lagrange_coefficients_interpolate_constant_term() {
+    use z_mpc::laurent::{lagrange_coefficient, lagrange_coefficient_at};
+    
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let zero = curve.scalar_from_u64(0).unwrap();
+        
+        // f(x) = c0 + c1·x + c2·x², shared Shamir-style at x = id
+        let coeffs: Vec<_> = (0..3).map(|_| curve.random_scalar().unwrap()).collect();
+        let f = |x: u64| {
+            let x = curve.scalar_from_u64(x).unwrap();
+            coeffs.iter().rev().fold(zero.clone(), |acc, c| acc.mul(&x).unwrap().add(c).unwrap())
+        };
+        
+        let participants = [2, 4, 5];
+        let interpolate = |at: u64, id_scheme: IdScheme, offset: u64| {
+            participants.iter().fold(zero.clone(), |acc, &id| {
+                let lambda = lagrange_coefficient_at(&participants, id, at, id_scheme, curve_type).unwrap();
+                acc.add(&lambda.mul(&f(id as u64 + offset)).unwrap()).unwrap()
+            })
+        };
+        assert_eq!(interpolate(0, IdScheme::Direct, 0), coeffs[0], "{}", curve_type);
+        assert_eq!(interpolate(7, IdScheme::Direct, 0), f(7), "{}", curve_type);
+        
+        // Shares dealt at x = id + 10 interpolate at their own points
+        assert_eq!(interpolate(0, IdScheme::Offset(10), 10), coeffs[0], "{}", curve_type);
+        
+        // Known coefficients for {1, 2, 3} at zero: 3, -3, 1
+        let three = curve.scalar_from_u64(3).unwrap();
+        assert_eq!(lagrange_coefficient(&[1, 2, 3], 1, curve_type).unwrap(), three);
+        assert_eq!(lagrange_coefficient(&[1, 2, 3], 2, curve_type).unwrap().add(&three).unwrap(), zero);
+        assert_eq!(lagrange_coefficient(&[1, 2, 3], 3, curve_type).unwrap(), curve.scalar_from_u64(1).unwrap());
+        
+        assert!(lagrange_coefficient(&[1, 2], 3, curve_type).is_err());
+        assert!(lagrange_coefficient(&[1, 1, 2], 1, curve_type).is_err());
+        assert!(lagrange_coefficient(&[0, 1], 1, curve_type).is_err());
+    }
+}
+
+#[tokio::test]
+async fn test_ephemeral_test_networks_run_side_by_side() {
+    let curve_type = CurveType::Secp256k1;
+    let (first, first_handles) = network_utils::create_ephemeral_test_network(3, &[curve_type]).await.unwrap();
+    let (second, second_handles) = network_utils::create_ephemeral_test_network(3, &[curve_type]).await.unwrap();
+    
+    // Every node got its own assigned port
+    let addresses: std::collections::HashSet<_> = first.iter().chain(&second)
+        .map(|coordinator| coordinator.node.address)
+        .collect();
+    assert_eq!(addresses.len(), 6);
+    assert!(addresses.iter().all(|address| address.port() != 0));
+    
+    // Peers are registered under the assigned addresses
+    for network in [&first, &second] {
+        for coordinator in network {
+            let participants = coordinator.node.participants.read().await;
+            assert_eq!(participants.len(), 2);
+            for peer in network.iter().filter(|peer| peer.node.id != coordinator.node.id) {
+                let registered = participants.participant_by_addr(&peer.node.address).unwrap();
+                assert_eq!(registered.id, peer.node.id);
+            }
+        }
+    }
+    
+    // Both networks are serving
+    for address in &addresses {
+        tokio::net::TcpStream::connect(address).await.unwrap();
+    }
+    for handle in first_handles.iter().chain(&second_handles) {
+        assert!(!handle.is_finished());
+    }
+    
+    for handle in first_handles.into_iter().chain(second_handles) {
+        handle.abort();
+    }
+}
+
+#[test]
+fn test_designated_verifier_proof_is_not_transferable() {
+    use z_mpc::zkp::DesignatedVerifierProof;
+    
+    let curve_type = CurveType::Secp256k1;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let pedersen = PedersenCommitment::new(curve_type).unwrap();
+    let value = curve.random_scalar().unwrap();
+    let randomness = pedersen.generate_randomness();
+    let commitment = pedersen.commit(&value, &randomness).unwrap();
+    
+    let verifier_secret = curve.random_scalar().unwrap();
+    let verifier_key = curve.generator().mul(&verifier_secret).unwrap();
+    let other_key = curve.generator().mul(&curve.random_scalar().unwrap()).unwrap();
+    
+    // The designated verifier accepts; the proof is bound to their key
+    let proof = DesignatedVerifierProof::prove(&pedersen, &commitment, &value, &randomness, &verifier_key, b"dv").unwrap();
+    assert!(proof.verify(&pedersen, &commitment, &verifier_key).unwrap());
+    assert!(!proof.verify(&pedersen, &commitment, &other_key).unwrap());
+    
+    // The verifier can fabricate equally valid proofs, even for commitments
+    // nobody can open, so a transcript proves nothing to third parties
+    let unopened = pedersen.commit(&curve.random_scalar().unwrap(), &pedersen.generate_randomness()).unwrap();
+    for target in [&commitment, &unopened] {
+        let simulated = DesignatedVerifierProof::simulate(&pedersen, target, &verifier_secret, b"dv").unwrap();
+        assert!(simulated.verify(&pedersen, target, &verifier_key).unwrap());
+        
+        let shape = |proof: &DesignatedVerifierProof| {
+            let json = serde_json::to_value(proof).unwrap();
+            json.as_object().unwrap().iter()
+                .map(|(field, value)| (field.clone(), value.as_array().map(|bytes| bytes.len())))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(shape(&simulated), shape(&proof));
+    }
+}
+
+#[test]
+fn test_range_proof_accepts_only_values_in_range() {
+    use z_mpc::zkp::RangeProof;
+    
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let commit = |value: &z_mpc::Scalar| {
+            let randomness = pedersen.generate_randomness();
+            (pedersen.commit(value, &randomness).unwrap(), randomness)
+        };
+        
+        // Values in [0, 2^8) verify, also after a serde round trip
+        for value in [0, 1, 200, 255] {
+            let value = curve.scalar_from_u64(value).unwrap();
+            let (commitment, randomness) = commit(&value);
+            let proof = RangeProof::prove(&pedersen, &commitment, &value, &randomness, 8).unwrap();
+            assert!(proof.verify(&pedersen, &commitment).unwrap(), "{} range proof should verify", curve_type);
+            let decoded: RangeProof = serde_json::from_str(&serde_json::to_string(&proof).unwrap()).unwrap();
+            assert!(decoded.verify(&pedersen, &commitment).unwrap());
+        }
+        
+        // Out-of-range values have no proof
+        for value in [curve.scalar_from_u64(256).unwrap(), curve.random_scalar().unwrap()] {
+            let (commitment, randomness) = commit(&value);
+            assert!(matches!(
+                RangeProof::prove(&pedersen, &commitment, &value, &randomness, 8),
+                Err(z_mpc::Error::InvalidInput(_))
+            ));
+        }
+        
+        // A proof neither moves to another commitment nor shrinks its range
+        let value = curve.scalar_from_u64(200).unwrap();
+        let (commitment, randomness) = commit(&value);
+        let proof = RangeProof::prove(&pedersen, &commitment, &value, &randomness, 8).unwrap();
+        let (other, _) = commit(&curve.scalar_from_u64(300).unwrap());
+        let mut moved = proof.clone();
+        moved.commitment = other.clone();
+        assert!(!moved.verify(&pedersen, &other).unwrap());
+        let mut shrunk = proof.clone();
+        shrunk.bits = 7;
+        shrunk.bit_commitments.pop();
+        shrunk.bit_proofs.pop();
+        assert!(!shrunk.verify(&pedersen, &commitment).unwrap());
+        let mut tampered = proof.clone();
+        tampered.bit_proofs[3] = proof.bit_proofs[4].clone();
+        assert!(!tampered.verify(&pedersen, &commitment).unwrap());
+    }
+}
+
+#[test]
+fn test_equality_proof_links_commitments_to_one_value() {
+    use z_mpc::zkp::EqualityProof;
+    
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let value = curve.random_scalar().unwrap();
+        let r1 = pedersen.generate_randomness();
+        let r2 = pedersen.generate_randomness();
+        let c1 = pedersen.commit(&value, &r1).unwrap();
+        let c2 = pedersen.commit(&value, &r2).unwrap();
+        assert_ne!(c1, c2);
+        
+        // Equal values under fresh randomness verify, also after a serde round trip
+        let proof = EqualityProof::prove(&pedersen, &c1, &r1, &c2, &r2, &value).unwrap();
+        assert!(proof.verify(&pedersen, &c1, &c2).unwrap(), "{} equality proof should verify", curve_type);
+        let decoded: EqualityProof = serde_json::from_str(&serde_json::to_string(&proof).unwrap()).unwrap();
+        assert!(decoded.verify(&pedersen, &c1, &c2).unwrap());
+        
+        // Unequal values have no proof, and a proof does not move to them
+        let other = curve.random_scalar().unwrap();
+        let r3 = pedersen.generate_randomness();
+        let c3 = pedersen.commit(&other, &r3).unwrap();
+        assert!(matches!(
+            EqualityProof::prove(&pedersen, &c1, &r1, &c3, &r3, &value),
+            Err(z_mpc::Error::CommitmentError(_))
+        ));
+        assert!(matches!(
+            EqualityProof::prove(&pedersen, &c1, &r1, &c3, &r3, &other),
+            Err(z_mpc::Error::CommitmentError(_))
+        ));
+        assert!(!proof.verify(&pedersen, &c1, &c3).unwrap());
+        let mut moved = proof.clone();
+        moved.second_commitment = c3.clone();
+        assert!(!moved.verify(&pedersen, &c1, &c3).unwrap());
+        
+        // Across pinned parameters with another h over the same g
+        let h = pedersen.g.mul(&curve.random_scalar().unwrap()).unwrap();
+        let pinned = PedersenCommitment::from_parameters(curve_type, pedersen.g.clone(), h).unwrap();
+        let c4 = pinned.commit(&value, &r2).unwrap();
+        assert_ne!(c2, c4);
+        let proof = EqualityProof::prove_across([&pedersen, &pinned], [&c1, &c4], [&r1, &r2], &value).unwrap();
+        assert!(proof.verify_across([&pedersen, &pinned], [&c1, &c4]).unwrap());
+        assert!(!proof.verify(&pedersen, &c1, &c4).unwrap());
+        let c5 = pinned.commit(&other, &r3).unwrap();
+        assert!(EqualityProof::prove_across([&pedersen, &pinned], [&c1, &c5], [&r1, &r3], &value).is_err());
+        assert!(!proof.verify_across([&pedersen, &pinned], [&c1, &c5]).unwrap());
+        
+        // Schemes over different g cannot be compared
+        let moved_g = PedersenCommitment::from_parameters(curve_type, pinned.h.clone(), pedersen.h.clone()).unwrap();
+        assert!(matches!(
+            proof.verify_across([&pedersen, &moved_g], [&c1, &c4]),
+            Err(z_mpc::Error::InvalidInput(_))
+        ));
+    }
+}
+
+#[test]
+fn test_hex_input_is_canonicalized() {
+    use z_mpc::hexinput::parse_hex;
+    
+    let expected = vec![0xab, 0xcd, 0x01];
+    assert_eq!(parse_hex("abcd01", "value").unwrap(), expected);
+    assert_eq!(parse_hex("0xabcd01", "value").unwrap(), expected);
+    assert_eq!(parse_hex("0XABCD01", "value").unwrap(), expected);
+    assert_eq!(parse_hex("  0xAbCd 01\n", "value").unwrap(), expected);
+    
+    let odd = parse_hex("0xabc", "value").unwrap_err().to_string();
+    assert!(odd.contains("odd number of digits (3)"), "{}", odd);
+    let bad = parse_hex("0xabzd", "commitment").unwrap_err().to_string();
+    assert!(bad.contains("commitment") && bad.contains("'z' at position 4"), "{}", bad);
+    assert!(parse_hex("0x", "value").is_err());
+    
+    // The CLI accepts explorer-style values and reports precise errors
+    Command::cargo_bin("z-mpc").unwrap()
+        .args(["commit", "--curve", "k1", "--value", "0x00000000000000000000000000000000000000000000000000000000000000FF"])
+        .assert()
+        .success();
+    let rejected = Command::cargo_bin("z-mpc").unwrap()
+        .args(["commit", "--curve", "k1", "--value", "0xFFF"])
+        .assert()
+        .failure();
+    assert!(String::from_utf8_lossy(&rejected.get_output().stderr).contains("odd number of digits"));
+}
+
+#[test]
+fn test_off_curve_points_are_rejected() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let generator = curve.generator();
+        let scalar = curve.scalar_from_u64(7).unwrap();
+        
+        // Generator with a tweaked y coordinate is off the curve
+        let mut y = generator.y.clone();
+        *y.last_mut().unwrap() ^= 1;
+        let off_curve = z_mpc::Point::new(curve_type, generator.x.clone(), y);
+        assert!(off_curve.to_compressed_bytes().is_err());
+        assert!(off_curve.mul(&scalar).is_err());
+        assert!(off_curve.add(&generator).is_err());
+        assert!(generator.add(&off_curve).is_err());
+        
+        // x = 7 has no point on either curve
+        let mut encoding = vec![0x02];
+        encoding.extend_from_slice(&[0u8; 31]);
+        encoding.push(7);
+        assert!(z_mpc::Point::from_compressed_bytes(curve_type, &encoding).is_err());
+        assert!(curve.point_from_bytes(&encoding).is_err());
+        
+        // Valid points still go through
+        let valid = generator.mul(&scalar).unwrap();
+        let bytes = valid.to_compressed_bytes().unwrap();
+        assert_eq!(z_mpc::Point::from_compressed_bytes(curve_type, &bytes).unwrap(), valid);
+    }
+}
+
+#[tokio::test]
+async fn test_reconstruction_progress_callback() {
+    use z_mpc::laurent::Reconstructor;
+    
+    let curve_type = CurveType::Secp256k1;
+    let params = SharingParams {
+        curve_type,
+        threshold: 3,
+        participants: 5,
+    };
+    let shares = LaurentSeries::new(&params).unwrap().generate_shares().unwrap();
+    
+    // Streaming: one report per share, duplicates collected but not verified
+    let mut reports = Vec::new();
+    let mut reconstructor = Reconstructor::new(&params).with_progress(|progress| reports.push(progress));
+    reconstructor.add_share(shares[0].clone()).unwrap();
+    assert!(reconstructor.add_share(shares[0].clone()).is_err());
+    reconstructor.add_share(shares[1].clone()).unwrap();
+    assert!(!reconstructor.is_ready());
+    reconstructor.add_share(shares[2].clone()).unwrap();
+    assert!(reconstructor.is_ready());
+    reconstructor.finish().unwrap();
+    
+    let counts: Vec<_> = reports.iter().map(|progress| (progress.collected, progress.verified)).collect();
+    assert_eq!(counts, vec![(1, 1), (2, 1), (3, 2), (4, 3)]);
+    assert_eq!(reports.last().unwrap().remaining(), 0);
+    
+    // Shares dealt under another id scheme reconstruct at their own points
+    let offset = LaurentSeries::new(&params).unwrap().with_id_scheme(IdScheme::Offset(10));
+    let offset_shares = offset.generate_shares().unwrap();
+    let mut reconstructor = Reconstructor::new(&params).with_id_scheme(IdScheme::Offset(10));
+    for share in &offset_shares[1..4] {
+        reconstructor.add_share(share.clone()).unwrap();
+    }
+    assert_eq!(reconstructor.finish().unwrap().secret, offset.reconstruct_secret(&offset_shares).unwrap().secret);
+    
+    // Networked: verified count climbs to the threshold
+    let mut coordinators = network_utils::create_test_network(3, &[curve_type]).await.unwrap();
+    let session_id = coordinators[0].initialize_protocol(params.clone()).await.unwrap();
+    coordinators[0].distribute_shares(session_id).await.unwrap();
+    let shares = coordinators[0].laurent_series[&session_id].generate_shares().unwrap();
+    let mut reports = Vec::new();
+    coordinators[0].node
+        .reconstruct_with_progress(session_id, &shares[..3], |progress| reports.push(progress))
+        .await
+        .unwrap();
+    
+    assert_eq!(reports.len(), 3);
+    assert!(reports.windows(2).all(|pair| pair[0].collected < pair[1].collected));
+    assert_eq!(reports.iter().map(|progress| progress.verified).collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(reports.last().unwrap().remaining(), 0);
+}
+
+#[test]
+fn test_laurent_series_secret_needs_explicit_serialization() {
+    // Inherent consts only exist when the bound holds, else the trait default applies
+    trait NotSerializable {
+        const SERIALIZABLE: bool = false;
+    }
+    impl<T: ?Sized> NotSerializable for T {}
+    struct Probe<T: ?Sized>(std::marker::PhantomData<T>);
+    #[allow(dead_code)]
+    impl<T: ?Sized + serde::Serialize> Probe<T> {
+        const SERIALIZABLE: bool = true;
+    }
+    const _: () = assert!(!Probe::<LaurentSeries>::SERIALIZABLE);
+    const _: () = assert!(Probe::<z_mpc::laurent::PublicLaurentSeries>::SERIALIZABLE);
+    
+    let params = SharingParams {
+        curve_type: CurveType::P256,
+        threshold: 3,
+        participants: 5,
+    };
+    let laurent = LaurentSeries::new(&params).unwrap();
+    
+    // The public form carries commitments only
+    let public = laurent.public_form().unwrap();
+    let public_json = serde_json::to_value(&public).unwrap();
+    assert!(public_json.get("a_coeffs").is_none() && public_json.get("b_coeffs").is_none());
+    assert_eq!(serde_json::from_value::<z_mpc::laurent::PublicLaurentSeries>(public_json).unwrap(), public);
+    
+    // The explicit path round-trips the secret
+    let exposed = laurent.expose_secret_serialize().unwrap();
+    let restored = LaurentSeries::expose_secret_deserialize(&exposed).unwrap();
+    assert_eq!(restored.get_coefficients(), laurent.get_coefficients());
+    assert_eq!(restored.get_secret_key().unwrap(), laurent.get_secret_key().unwrap());
+}
+
+#[test]
+fn test_point_commitment_opens_to_group_element() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let secret = curve.random_scalar().unwrap();
+        let public_key = curve.generator().mul(&secret).unwrap();
+        let randomness = pedersen.generate_randomness();
+        
+        let commitment = pedersen.commit_point(&public_key, &randomness).unwrap();
+        assert!(pedersen.verify_point(&commitment, &public_key, &randomness).unwrap());
+        
+        // Wrong point or randomness does not open it
+        let other_key = public_key.add(&curve.generator()).unwrap();
+        assert!(!pedersen.verify_point(&commitment, &other_key, &randomness).unwrap());
+        assert!(!pedersen.verify_point(&commitment, &public_key, &pedersen.generate_randomness()).unwrap());
+        
+        // Consistent with the scalar path for points of known discrete log
+        assert_eq!(pedersen.commit(&secret, &randomness).unwrap(), commitment);
+        
+        // Points from another curve are rejected
+        let foreign = z_mpc::curve::create_curve(CurveType::P256).unwrap().generator();
+        if curve_type != CurveType::P256 {
+            assert!(pedersen.commit_point(&foreign, &randomness).is_err());
+        }
+    }
+}
+
+#[test]
+fn test_commit_all_shares_stores_commitment_randomness() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let params = SharingParams {
+            curve_type,
+            threshold: 2,
+            participants: 3,
+        };
+        let laurent = LaurentSeries::new(&params).unwrap();
+        let shares = laurent.generate_shares().unwrap();
+        
+        // The stored randomness opens each stored commitment
+        let committed_shares = pedersen_utils::commit_all_shares(&shares, curve_type).unwrap();
+        assert!(pedersen_utils::verify_all_committed_shares(&committed_shares, curve_type).unwrap());
+        
+        // commit_share hands back the randomness it committed with
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let (commitment, randomness) = pedersen.commit_share(&shares[0]).unwrap();
+        assert!(pedersen.verify_share_commitment(&shares[0], &commitment, &randomness).unwrap());
+    }
+}
+
+#[test]
+fn test_identity_point_round_trips() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let g = curve.generator();
+        
+        // G + (-G) lands on the identity, which then behaves as zero
+        let identity = g.add(&g.neg().unwrap()).unwrap();
+        assert!(identity.is_identity());
+        assert_eq!(identity.add(&g).unwrap(), g);
+        
+        let bytes = identity.to_compressed_bytes().unwrap();
+        let decoded = z_mpc::curve::Point::from_compressed_bytes(curve_type, &bytes).unwrap();
+        assert!(decoded.is_identity());
+        assert_eq!(decoded.to_compressed_bytes().unwrap(), bytes);
+    }
+}
+
+#[test]
+fn test_schnorr_sign_verify_round_trip() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let private_key = curve.random_scalar().unwrap();
+        
+        let mut signature = SchnorrSignature::new(curve_type);
+        signature.sign(b"round trip", &private_key).unwrap();
+        assert!(signature.verify(b"round trip").unwrap(), "{} signature should verify", curve_type);
+        
+        // Another message or a tampered response fails
+        assert!(!signature.verify(b"other message").unwrap());
+        let mut tampered = signature.clone();
+        let one = curve.scalar_from_u64(1).unwrap();
+        let response = curve.scalar_from_bytes(&tampered.response).unwrap();
+        tampered.response = response.add(&one).unwrap().as_bytes().to_vec();
+        assert!(!tampered.verify(b"round trip").unwrap());
+    }
+}
+
+#[test]
+fn test_zkp_verification_equation_and_challenge_binding() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let value = curve.random_scalar().unwrap();
+        let randomness = pedersen.generate_randomness();
+        
+        let mut proof = ZeroKnowledgeProof::new(curve_type);
+        proof.prove(&pedersen, &value, &randomness).unwrap();
+        assert!(proof.verify(&pedersen).unwrap(), "{} proof should verify", curve_type);
+        
+        // Flipped response byte
+        let mut flipped = proof.clone();
+        let last = flipped.response.len() - 1;
+        flipped.response[last] ^= 0x01;
+        assert!(!matches!(flipped.verify(&pedersen), Ok(true)));
+        
+        // Proof moved onto a different commitment
+        let other = pedersen.commit(&curve.random_scalar().unwrap(), &randomness).unwrap();
+        let mut moved = proof.clone();
+        moved.commitment = other;
+        assert!(!moved.verify(&pedersen).unwrap());
+        
+        // Freely chosen challenge: A = g^s1 * h^s2 - c*C satisfies the equation
+        // but does not match the transcript
+        let s1 = curve.random_scalar().unwrap();
+        let s2 = curve.random_scalar().unwrap();
+        let c = curve.random_scalar().unwrap();
+        let commitment_point = z_mpc::curve::Point::from_compressed_bytes(curve_type, &proof.commitment).unwrap();
+        let announcement = pedersen.g.mul(&s1).unwrap()
+            .add(&pedersen.h.mul(&s2).unwrap()).unwrap()
+            .sub(&commitment_point.mul(&c).unwrap()).unwrap();
+        let mut forged = proof.clone();
+        forged.public_point = announcement.to_compressed_bytes().unwrap();
+        forged.challenge = c.as_bytes().to_vec();
+        forged.response = [s1.as_bytes(), s2.as_bytes()].concat();
+        assert!(!forged.verify(&pedersen).unwrap());
+        assert_eq!(forged.explain(&pedersen).unwrap().failure, Some(ProofFailure::ChallengeMismatch));
+    }
+}
+
+#[test]
+fn test_committed_share_proofs_verify_on_every_curve() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let share = Share::new(1, curve.random_scalar().unwrap().into_bytes());
+        let (commitment, randomness) = pedersen.commit_share(&share).unwrap();
+        
+        let proof = zkp_utils::prove_committed_share(&pedersen, &share, &commitment, &randomness, curve_type).unwrap();
+        assert!(zkp_utils::verify_committed_share_proof(&proof, &pedersen).unwrap(), "{} proof should verify", curve_type);
+        
+        // Tampered response
+        let mut tampered = proof.clone();
+        tampered.response[0] ^= 0x01;
+        assert!(!matches!(zkp_utils::verify_committed_share_proof(&tampered, &pedersen), Ok(true)));
+    }
+}
+
+#[test]
+fn test_schnorr_signature_is_bound_to_signing_key() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let k1 = curve.random_scalar().unwrap();
+        let k2 = curve.random_scalar().unwrap();
+        
+        let signature = zkp_utils::sign_message(b"m", &k1, curve_type).unwrap();
+        assert!(signature.verify(b"m").unwrap());
+        assert!(!signature.verify(b"m'").unwrap());
+        
+        // Swapping in the public key of k2 breaks the challenge
+        let mut swapped = signature.clone();
+        swapped.public_key = curve.generator().mul(&k2).unwrap().to_compressed_bytes().unwrap();
+        assert!(!swapped.verify(b"m").unwrap());
+    }
+}
+
+#[test]
+fn test_threshold_schnorr_aggregates_three_of_five() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let params = SharingParams {
+            curve_type,
+            threshold: 3,
+            participants: 5,
+        };
+        let laurent = LaurentSeries::new(&params).unwrap();
+        let shares = laurent.generate_shares().unwrap();
+        let group_public_key = curve.generator().mul(&laurent.get_secret_key().unwrap()).unwrap();
+        let message = b"threshold message";
+        let mut rng = rand::thread_rng();
+        
+        for signers in [[1, 3, 5], [2, 3, 4]] {
+            let signing_shares: Vec<&Share> = shares.iter().filter(|share| signers.contains(&share.id)).collect();
+            
+            // Round 1: every signer commits to fresh nonces
+            let (nonces, commitments): (Vec<_>, Vec<_>) = signing_shares.iter()
+                .map(|share| zkp_utils::commit_signing_nonces(share, curve_type, &mut rng).unwrap())
+                .unzip();
+            
+            // Round 2: partial signatures, aggregated by the coordinator
+            let partials: Vec<_> = signing_shares.iter().zip(nonces)
+                .map(|(share, nonces)| {
+                    zkp_utils::partial_sign(share, message, nonces, &commitments, &group_public_key, &params, IdScheme::Direct).unwrap()
+                })
+                .collect();
+            let signature = zkp_utils::aggregate_partial_signatures(message, &commitments, &partials, &group_public_key).unwrap();
+            assert!(signature.verify(message).unwrap(), "{} threshold signature should verify", curve_type);
+            assert!(signature.verify_for(message, &group_public_key).unwrap());
+            assert!(!signature.verify(b"other message").unwrap());
+            
+            // A wrong partial signature spoils the aggregate
+            let mut tampered = partials.clone();
+            let response = curve.scalar_from_bytes(&tampered[0].response).unwrap();
+            tampered[0].response = response.add(&curve.scalar_from_u64(1).unwrap()).unwrap().as_bytes().to_vec();
+            assert!(zkp_utils::aggregate_partial_signatures(message, &commitments, &tampered, &group_public_key).is_err());
+            assert!(zkp_utils::aggregate_partial_signatures(message, &commitments, &partials[..2], &group_public_key).is_err());
+        }
+        
+        // A signer refuses commitments the coordinator altered
+        let (nonces, mut commitment) = zkp_utils::commit_signing_nonces(&shares[0], curve_type, &mut rng).unwrap();
+        let (_, other) = zkp_utils::commit_signing_nonces(&shares[1], curve_type, &mut rng).unwrap();
+        let (_, third) = zkp_utils::commit_signing_nonces(&shares[2], curve_type, &mut rng).unwrap();
+        commitment.hiding = other.hiding.clone();
+        assert!(matches!(
+            zkp_utils::partial_sign(&shares[0], message, nonces, &[commitment, other, third], &group_public_key, &params, IdScheme::Direct),
+            Err(z_mpc::Error::CommitmentError(_))
+        ));
+    }
+}
+
+#[test]
+fn test_pedersen_parameters_are_deterministic() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let dealer = PedersenCommitment::new(curve_type).unwrap();
+        let verifier = PedersenCommitment::new(curve_type).unwrap();
+        assert_eq!(dealer.h, verifier.h);
+        assert_ne!(dealer.h, dealer.g);
+        assert!(!dealer.h.is_identity());
+        
+        // Commitments made by one instance verify under the other
+        let value = curve.random_scalar().unwrap();
+        let randomness = dealer.generate_randomness();
+        let commitment = dealer.commit(&value, &randomness).unwrap();
+        assert!(verifier.verify(&commitment, &value, &randomness).unwrap());
+    }
+    
+    // Custom parameters must be distinct points on one curve
+    let k1 = z_mpc::curve::create_curve(CurveType::Secp256k1).unwrap();
+    let p256 = z_mpc::curve::create_curve(CurveType::P256).unwrap();
+    let g = k1.generator();
+    let h = g.mul(&k1.scalar_from_u64(7).unwrap()).unwrap();
+    assert!(PedersenCommitment::from_parameters(CurveType::Secp256k1, g.clone(), h.clone()).is_ok());
+    assert!(PedersenCommitment::from_parameters(CurveType::Secp256k1, g.clone(), g.clone()).is_err());
+    assert!(PedersenCommitment::from_parameters(CurveType::Secp256k1, g.clone(), p256.generator()).is_err());
+    assert!(PedersenCommitment::from_parameters(CurveType::P256, g, h).is_err());
+}
+
+#[test]
+fn test_pedersen_parameters_round_trip() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let g = curve.generator();
+        let h = g.mul(&curve.random_scalar().unwrap()).unwrap();
+        let original = PedersenCommitment::from_parameters(curve_type, g, h).unwrap();
+        
+        let value = curve.random_scalar().unwrap();
+        let randomness = original.generate_randomness();
+        let commitment = original.commit(&value, &randomness).unwrap();
+        
+        // Reload on the "other machine" from JSON only
+        let json = serde_json::to_string(&original.to_parameters().unwrap()).unwrap();
+        let parameters: PedersenParameters = serde_json::from_str(&json).unwrap();
+        assert_eq!(parameters, original.to_parameters().unwrap());
+        
+        let reloaded = PedersenCommitment::from_exported(&parameters).unwrap();
+        assert_eq!(reloaded.g, original.g);
+        assert_eq!(reloaded.h, original.h);
+        assert!(reloaded.verify(&commitment, &value, &randomness).unwrap());
+        
+        // A different h does not open the same commitment
+        let default = PedersenCommitment::new(curve_type).unwrap();
+        assert!(!default.verify(&commitment, &value, &randomness).unwrap());
+    }
+}
+
+#[test]
+fn test_pedersen_generator_tables_follow_the_generators() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let value = curve.random_scalar().unwrap();
+        let randomness = pedersen.generate_randomness();
+        let r = curve.scalar_from_bytes(&randomness).unwrap();
+        let commitment = pedersen.commit(&value, &randomness).unwrap();
+        
+        // Tables are not serialized; a deserialized instance rebuilds them
+        let reloaded: PedersenCommitment = serde_json::from_str(&serde_json::to_string(&pedersen).unwrap()).unwrap();
+        assert_eq!(reloaded.commit(&value, &randomness).unwrap(), commitment);
+        
+        // Replacing a public generator bypasses the stale table
+        let mut swapped = pedersen.clone();
+        swapped.h = curve.generator().mul(&curve.random_scalar().unwrap()).unwrap();
+        let expected = swapped.g.mul(&value).unwrap().add(&swapped.h.mul(&r).unwrap()).unwrap();
+        assert_eq!(swapped.commit(&value, &randomness).unwrap(), expected.to_compressed_bytes().unwrap());
+        assert!(!swapped.verify(&commitment, &value, &randomness).unwrap());
+    }
+}
+
+#[test]
+fn test_reconstruct_with_ids_uses_exactly_the_quorum() {
+    use rand::seq::SliceRandom;
+    
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let params = SharingParams {
+            curve_type,
+            threshold: 3,
+            participants: 6,
+        };
+        let laurent = LaurentSeries::new(&params).unwrap();
+        let mut shares = laurent.generate_shares().unwrap();
+        let expected = laurent.get_secret_key().unwrap();
+        shares.shuffle(&mut rand::thread_rng());
+        
+        // A non-prefix quorum in any order selects its own shares
+        for ids in [[5, 2, 6], [6, 4, 1], [3, 5, 4]] {
+            let result = laurent.reconstruct_with_ids(&shares, &ids).unwrap();
+            assert_eq!(result.secret, expected.value, "{} quorum {:?}", curve_type, ids);
+            let mut sorted = ids.to_vec();
+            sorted.sort();
+            assert_eq!(result.participants_used, sorted);
+        }
+        
+        assert!(matches!(laurent.reconstruct_with_ids(&shares, &[2, 5, 2]), Err(z_mpc::Error::InvalidInput(_))));
+        assert!(matches!(
+            laurent.reconstruct_with_ids(&shares, &[2, 5]),
+            Err(z_mpc::Error::InsufficientShares { required: 3, got: 2 })
+        ));
+        assert!(matches!(laurent.reconstruct_with_ids(&shares, &[2, 5, 7]), Err(z_mpc::Error::InvalidInput(_))));
+    }
+}
+
+#[test]
+fn test_every_threshold_subset_reconstructs_dealer_secret() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        for threshold in 2..=4 {
+            let params = SharingParams {
+                curve_type,
+                threshold,
+                participants: threshold + 2,
+            };
+            let laurent = LaurentSeries::new(&params).unwrap();
+            let shares = laurent.generate_shares().unwrap();
+            let expected = laurent.get_secret_key().unwrap();
+            
+            // Walk every threshold-sized subset of participant indices
+            for mask in 0u32..(1 << shares.len()) {
+                if mask.count_ones() as usize != threshold {
+                    continue;
+                }
+                let subset: Vec<Share> = shares.iter().enumerate()
+                    .filter(|(index, _)| mask & (1 << index) != 0)
+                    .map(|(_, share)| share.clone())
+                    .collect();
+                
+                let result = laurent.reconstruct_secret(&subset).unwrap();
+                assert_eq!(result.secret, expected.value, "{} {}-of-{} subset {:b}", curve_type, threshold, shares.len(), mask);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_reconstruction_ignores_extra_shares() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let params = SharingParams {
+            curve_type,
+            threshold: 3,
+            participants: 5,
+        };
+        let laurent = LaurentSeries::new(&params).unwrap();
+        let shares = laurent.generate_shares().unwrap();
+        let expected = laurent.get_secret_key().unwrap();
+        
+        // More than threshold shares: only the lowest ids are used
+        let result = laurent.reconstruct_secret(&shares[1..]).unwrap();
+        assert_eq!(result.secret, expected.value, "curve {}", curve_type);
+        assert_eq!(result.participants_used.len(), 3);
+        
+        // A combiner that only knows the share count still recovers b_{-1}
+        let combiner = LaurentSeries::new(&SharingParams {
+            curve_type,
+            threshold: shares.len(),
+            participants: shares.len(),
+        }).unwrap();
+        assert_eq!(combiner.reconstruct_secret(&shares).unwrap().secret, expected.value, "curve {}", curve_type);
+    }
+}
+
+#[test]
+fn test_committed_shares_verify_under_independent_parameters() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let params = SharingParams {
+            curve_type,
+            threshold: 3,
+            participants: 4,
+        };
+        let shares = LaurentSeries::new(&params).unwrap().generate_shares().unwrap();
+        let committed_shares = pedersen_utils::commit_all_shares(&shares, curve_type).unwrap();
+        
+        // A verifier builds its own parameters
+        let verifier = PedersenCommitment::new(curve_type).unwrap();
+        for committed in &committed_shares {
+            assert!(committed.verify(curve_type).unwrap());
+            assert!(verifier.verify_share_commitment(&committed.share, &committed.commitment, &committed.randomness).unwrap());
+        }
+        
+        // Tampering with the share value or the randomness breaks the opening
+        let one = curve.scalar_from_u64(1).unwrap();
+        let mut tampered_value = committed_shares[0].clone();
+        tampered_value.share.value = curve.scalar_from_bytes(&tampered_value.share.value).unwrap().add(&one).unwrap().into_bytes();
+        assert!(!tampered_value.verify(curve_type).unwrap());
+        
+        let mut tampered_randomness = committed_shares[0].clone();
+        tampered_randomness.randomness = curve.scalar_from_bytes(&tampered_randomness.randomness).unwrap().add(&one).unwrap().into_bytes();
+        assert!(!tampered_randomness.verify(curve_type).unwrap());
+    }
+}
+
+#[test]
+fn test_schnorr_signatures_are_deterministic() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let private_key = curve.random_scalar().unwrap();
+        
+        let mut first = SchnorrSignature::new(curve_type);
+        first.sign(b"deterministic", &private_key).unwrap();
+        let mut second = SchnorrSignature::new(curve_type);
+        second.sign(b"deterministic", &private_key).unwrap();
+        assert_eq!(serde_json::to_vec(&first).unwrap(), serde_json::to_vec(&second).unwrap(), "curve {}", curve_type);
+        assert!(first.verify(b"deterministic").unwrap());
+        
+        // A different message gets a different nonce
+        let mut other = SchnorrSignature::new(curve_type);
+        other.sign(b"other message", &private_key).unwrap();
+        assert_ne!(first.response, other.response);
+        
+        // Randomized nonces still verify but differ from the deterministic one
+        let mut randomized = SchnorrSignature::new(curve_type);
+        randomized.sign_with_rng(b"deterministic", &private_key, &mut rand::thread_rng()).unwrap();
+        assert!(randomized.verify(b"deterministic").unwrap());
+        assert_ne!(first.response, randomized.response);
+    }
+}
+
+#[test]
+fn test_from_secret_shares_the_given_secret() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let secret = curve.random_scalar().unwrap();
+        let params = SharingParams {
+            curve_type,
+            threshold: 3,
+            participants: 5,
+        };
+        
+        let laurent = LaurentSeries::from_secret(&secret, &params).unwrap();
+        assert_eq!(laurent.get_secret_key().unwrap(), secret);
+        
+        let shares = laurent.generate_shares().unwrap();
+        for subset in [&shares[..3], &shares[2..]] {
+            let result = laurent.reconstruct_secret(subset).unwrap();
+            assert_eq!(result.secret, secret.value, "curve {}", curve_type);
+        }
+        
+        // Zero and foreign-curve secrets are rejected
+        assert!(LaurentSeries::from_secret(&curve.scalar_from_u64(0).unwrap(), &params).is_err());
+        let foreign = CurveType::Edwards25519;
+        if curve_type != foreign {
+            let other = z_mpc::curve::create_curve(foreign).unwrap().random_scalar().unwrap();
+            assert!(LaurentSeries::from_secret(&other, &params).is_err());
+        }
+    }
+}
+
+#[test]
+fn test_batch_proof_verification_catches_any_flipped_byte() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let proofs: Vec<ZeroKnowledgeProof> = (0..4)
+            .map(|_| {
+                let mut proof = ZeroKnowledgeProof::new(curve_type);
+                proof.prove(&pedersen, &curve.random_scalar().unwrap(), &pedersen.generate_randomness()).unwrap();
+                proof
+            })
+            .collect();
+        
+        assert!(zkp_utils::batch_verify_proofs_fast(&proofs, &pedersen).unwrap());
+        assert!(zkp_utils::batch_verify_proofs_fast(&[], &pedersen).unwrap());
+        
+        // One flipped byte anywhere in any single proof fails the whole batch
+        for index in 0..proofs.len() {
+            for field in 0..4 {
+                for position in [0, 16, 31] {
+                    let mut tampered = proofs.clone();
+                    let bytes = match field {
+                        0 => &mut tampered[index].commitment,
+                        1 => &mut tampered[index].challenge,
+                        2 => &mut tampered[index].response,
+                        _ => &mut tampered[index].public_point,
+                    };
+                    bytes[position] ^= 0x01;
+                    
+                    let result = zkp_utils::batch_verify_proofs_fast(&tampered, &pedersen);
+                    assert!(!matches!(result, Ok(true)), "curve {} proof {} field {} byte {}", curve_type, index, field, position);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_batch_proof_verification_identifies_bad_proof() {
+    use rand_chacha::ChaCha20Rng;
+    use rand_chacha::rand_core::SeedableRng;
+    
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let proofs: Vec<ZeroKnowledgeProof> = (0..20)
+            .map(|_| {
+                let mut proof = ZeroKnowledgeProof::new(curve_type);
+                proof.prove(&pedersen, &curve.random_scalar().unwrap(), &pedersen.generate_randomness()).unwrap();
+                proof
+            })
+            .collect();
+        
+        assert!(zkp_utils::batch_verify_proofs_fast(&proofs, &pedersen).unwrap());
+        assert!(zkp_utils::batch_verify_proofs(&proofs, &pedersen).unwrap());
+        assert_eq!(zkp_utils::batch_verify_proofs_identify(&proofs, &pedersen).unwrap(), None);
+        
+        // A response for a different opening leaves the batch well-formed but invalid
+        let mut corrupted = proofs.clone();
+        corrupted[13].response = proofs[7].response.clone();
+        assert!(!zkp_utils::batch_verify_proofs_fast(&corrupted, &pedersen).unwrap());
+        assert!(!zkp_utils::batch_verify_proofs(&corrupted, &pedersen).unwrap());
+        assert_eq!(zkp_utils::batch_verify_proofs_identify(&corrupted, &pedersen).unwrap(), Some(13), "curve {}", curve_type);
+        
+        // Seeded weights give the same verdicts
+        let mut rng = ChaCha20Rng::seed_from_u64(7);
+        assert!(zkp_utils::batch_verify_proofs_fast_with_rng(&proofs, &pedersen, &mut rng).unwrap());
+        assert!(!zkp_utils::batch_verify_proofs_fast_with_rng(&corrupted, &pedersen, &mut rng).unwrap());
+    }
+}
+
+#[test]
+fn test_batch_proof_verification_rejects_small_order_components() {
+    use sha2::{Digest, Sha256};
+    
+    let curve_type = CurveType::Edwards25519;
+    let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+    let pedersen = PedersenCommitment::new(curve_type).unwrap();
+    let mut proofs: Vec<ZeroKnowledgeProof> = (0..4)
+        .map(|_| {
+            let mut proof = ZeroKnowledgeProof::new(curve_type);
+            proof.prove(&pedersen, &curve.random_scalar().unwrap(), &pedersen.generate_randomness()).unwrap();
+            proof
+        })
+        .collect();
+    
+    // Prove an opening of C + T for the order-2 point T = (0, -1): the
+    // equation is off by c·T, which an even challenge cancels
+    let mut order_two = vec![0xff; 32];
+    order_two[0] = 0xec;
+    order_two[31] = 0x7f;
+    let torsion = z_mpc::Point::from_compressed_bytes(curve_type, &order_two).unwrap();
+    assert!(!torsion.is_torsion_free() && !torsion.is_identity());
+    
+    let value = curve.random_scalar().unwrap();
+    let randomness = curve.random_scalar().unwrap();
+    let commitment = pedersen.commit(&value, &randomness.as_bytes().to_vec()).unwrap();
+    let shifted = z_mpc::Point::from_compressed_bytes(curve_type, &commitment).unwrap().add(&torsion).unwrap();
+    let (alpha, beta) = (curve.random_scalar().unwrap(), curve.random_scalar().unwrap());
+    let announcement = pedersen.g.mul(&alpha).unwrap().add(&pedersen.h.mul(&beta).unwrap()).unwrap();
+    let mut forged = ZeroKnowledgeProof::new(curve_type);
+    forged.commitment = shifted.to_compressed_bytes().unwrap();
+    forged.public_point = announcement.to_compressed_bytes().unwrap();
+    let challenge = curve.scalar_from_bytes_mod_order(&Sha256::new()
+        .chain_update(b"z-mpc-zkp")
+        .chain_update(curve_type.to_string())
+        .chain_update(&forged.commitment)
+        .chain_update(&forged.public_point)
+        .finalize()).unwrap();
+    forged.challenge = challenge.as_bytes().to_vec();
+    forged.response = [
+        alpha.add(&challenge.mul(&value).unwrap()).unwrap().as_bytes(),
+        beta.add(&challenge.mul(&randomness).unwrap()).unwrap().as_bytes(),
+    ].concat();
+    
+    // Whatever the challenge's parity, the proof fails alone and in a batch
+    assert!(matches!(forged.verify(&pedersen), Err(z_mpc::Error::ZKProofError(_))));
+    assert!(matches!(forged.explain(&pedersen).unwrap().failure, Some(ProofFailure::DegenerateStatement(_))));
+    proofs.insert(2, forged);
+    assert!(matches!(zkp_utils::batch_verify_proofs(&proofs, &pedersen), Err(z_mpc::Error::ZKProofError(_))));
+    assert!(matches!(zkp_utils::batch_verify_proofs_fast(&proofs, &pedersen), Err(z_mpc::Error::ZKProofError(_))));
+    assert_eq!(zkp_utils::batch_verify_proofs_identify(&proofs, &pedersen).unwrap(), Some(2));
+}
+
+#[test]
+fn test_verification_rejects_identity_points_and_zero_challenges() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let zero = curve.scalar_from_u64(0).unwrap();
+        assert!(zero.is_zero());
+        assert!(!curve.scalar_from_u64(1).unwrap().is_zero());
+        
+        // Opening (0, 0) commits to the identity, which proves nothing
+        let zero_randomness = zero.as_bytes().to_vec();
+        let mut proof = ZeroKnowledgeProof::new(curve_type);
+        proof.prove(&pedersen, &zero, &zero_randomness).unwrap();
+        assert!(matches!(proof.verify(&pedersen), Err(z_mpc::Error::ZKProofError(_))));
+        assert!(matches!(zkp_utils::batch_verify_proofs_fast(&[proof.clone()], &pedersen), Err(z_mpc::Error::ZKProofError(_))));
+        assert!(matches!(proof.explain(&pedersen).unwrap().failure, Some(ProofFailure::DegenerateStatement(_))));
+        assert!(matches!(
+            pedersen.verify(&proof.commitment, &zero, &zero_randomness),
+            Err(z_mpc::Error::ZKProofError(_))
+        ));
+        
+        // A valid proof with its challenge zeroed out
+        let value = curve.random_scalar().unwrap();
+        let mut proof = ZeroKnowledgeProof::new(curve_type);
+        proof.prove(&pedersen, &value, &pedersen.generate_randomness()).unwrap();
+        assert!(proof.verify(&pedersen).unwrap());
+        proof.challenge = vec![0u8; proof.challenge.len()];
+        assert!(matches!(proof.verify(&pedersen), Err(z_mpc::Error::ZKProofError(_))));
+        
+        // Schnorr: zero challenge, and a signature under the identity key
+        let mut signature = SchnorrSignature::new(curve_type);
+        signature.sign(b"message", &value).unwrap();
+        signature.challenge = vec![0u8; signature.challenge.len()];
+        assert!(matches!(signature.verify(b"message"), Err(z_mpc::Error::ZKProofError(_))));
+        
+        let mut signature = SchnorrSignature::new(curve_type);
+        signature.sign(b"message", &zero).unwrap();
+        assert!(matches!(signature.verify(b"message"), Err(z_mpc::Error::ZKProofError(_))));
+    }
+}
+
+#[test]
+fn test_seeded_rng_reproduces_dealings_and_signatures() {
+    use rand_chacha::ChaCha20Rng;
+    use rand_chacha::rand_core::SeedableRng;
+    
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let params = SharingParams {
+            curve_type,
+            threshold: 3,
+            participants: 5,
+        };
+        let deal = |seed: u64| {
+            let mut rng = ChaCha20Rng::seed_from_u64(seed);
+            LaurentSeries::new_with_rng(&params, &mut rng).unwrap().generate_shares().unwrap()
+        };
+        assert_eq!(deal(7), deal(7), "curve {}", curve_type);
+        assert_ne!(deal(7), deal(8));
+        
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let key = curve.random_scalar_with_rng(&mut ChaCha20Rng::seed_from_u64(1)).unwrap();
+        let sign = |seed: u64| {
+            let mut signature = SchnorrSignature::new(curve_type);
+            signature.sign_with_rng(b"seeded", &key, &mut ChaCha20Rng::seed_from_u64(seed)).unwrap();
+            serde_json::to_vec(&signature).unwrap()
+        };
+        assert_eq!(sign(3), sign(3));
+        assert_ne!(sign(3), sign(4));
+        
+        // Commitments and proofs follow the same seed too
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let share = &deal(7)[0];
+        let commit_and_prove = |seed: u64| {
+            let mut rng = ChaCha20Rng::seed_from_u64(seed);
+            let (commitment, randomness) = pedersen.commit_share_with_rng(share, &mut rng).unwrap();
+            let mut proof = ZeroKnowledgeProof::new(curve_type);
+            proof.prove_with_rng(&pedersen, &share.as_scalar(curve_type).unwrap(), &randomness, &mut rng).unwrap();
+            assert!(proof.verify(&pedersen).unwrap());
+            (commitment, randomness, serde_json::to_vec(&proof).unwrap())
+        };
+        assert_eq!(commit_and_prove(5), commit_and_prove(5));
+    }
+}
+
+#[test]
+fn test_secret_material_zeroizes() {
+    use zeroize::{Zeroize, ZeroizeOnDrop};
+    
+    fn assert_zeroize_on_drop<T: Zeroize + ZeroizeOnDrop>() {}
+    assert_zeroize_on_drop::<z_mpc::Scalar>();
+    assert_zeroize_on_drop::<Share>();
+    assert_zeroize_on_drop::<LaurentSeries>();
+    assert_zeroize_on_drop::<CommittedShare>();
+    
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let params = SharingParams {
+            curve_type,
+            threshold: 2,
+            participants: 3,
+        };
+        let mut laurent = LaurentSeries::new(&params).unwrap();
+        let mut shares = laurent.generate_shares().unwrap();
+        
+        let pedersen = PedersenCommitment::new(curve_type).unwrap();
+        let (commitment, randomness) = pedersen.commit_share(&shares[0]).unwrap();
+        let mut committed = CommittedShare::new(shares[0].clone(), commitment, randomness);
+        
+        let mut secret = laurent.get_secret_key().unwrap();
+        secret.zeroize();
+        assert!(secret.value.iter().all(|&byte| byte == 0));
+        
+        laurent.zeroize();
+        assert!(laurent.a_coeffs.iter().chain(&laurent.b_coeffs).all(|c| c.value.iter().all(|&byte| byte == 0)));
+        
+        shares[1].zeroize();
+        assert!(shares[1].value.iter().all(|&byte| byte == 0));
+        
+        committed.zeroize();
+        assert!(committed.share.value.iter().chain(&committed.randomness).all(|&byte| byte == 0));
+    }
+}
+
+#[test]
+fn test_refreshed_shares_keep_secret_but_not_epochs() {
+    use z_mpc::laurent::verify_share_against_commitments;
+    
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let params = SharingParams {
+            curve_type,
+            threshold: 3,
+            participants: 5,
+        };
+        let laurent = LaurentSeries::new(&params).unwrap();
+        let secret = laurent.get_secret_key().unwrap();
+        let old = laurent.generate_shares().unwrap();
+        
+        let (refreshed, zero_commitments) = laurent.refresh_shares(&old).unwrap();
+        assert_eq!(refreshed.len(), old.len());
+        for (old_share, new_share) in old.iter().zip(&refreshed) {
+            assert_eq!(new_share.id, old_share.id);
+            assert_ne!(new_share.value, old_share.value);
+            assert_ne!(new_share.dealing_id, old_share.dealing_id);
+        }
+        
+        // Refreshed shares verify against the old commitments plus the zero series'
+        let commitments = laurent.coefficient_commitments().unwrap();
+        let refreshed_commitments = commitments.add(&zero_commitments).unwrap();
+        assert!(zero_commitments.b_commitments[0].is_identity());
+        for share in &refreshed {
+            assert!(verify_share_against_commitments(share, &refreshed_commitments, curve_type).unwrap());
+            assert!(!verify_share_against_commitments(share, &commitments, curve_type).unwrap());
+        }
+        
+        // Same-epoch subsets reconstruct the original secret
+        for subset in [&old[..3], &refreshed[..3], &refreshed[2..]] {
+            let result = laurent.reconstruct_secret(subset).unwrap();
+            assert_eq!(result.secret, secret.value, "curve {}", curve_type);
+        }
+        
+        // Mixing epochs is rejected, and yields garbage without dealing ids
+        let mixed = vec![old[0].clone(), refreshed[1].clone(), refreshed[2].clone()];
+        assert!(matches!(laurent.reconstruct_secret(&mixed), Err(z_mpc::Error::DealingMismatch(_))));
+        let untagged: Vec<Share> = mixed.iter().map(|share| Share::new(share.id, share.value.clone())).collect();
+        assert_ne!(laurent.reconstruct_secret(&untagged).unwrap().secret, secret.value);
+        
+        // Refreshing a mixed set or another dealing's shares is rejected too
+        assert!(laurent.refresh_shares(&mixed).is_err());
+        let other = LaurentSeries::new(&params).unwrap().generate_shares().unwrap();
+        assert!(matches!(laurent.refresh_shares(&other), Err(z_mpc::Error::DealingMismatch(_))));
+        assert!(laurent.refresh_shares(&untagged).is_err());
+    }
+}
+
+#[test]
+fn test_cli_share_splits_given_secret() {
+    let dir = tempfile::tempdir().unwrap();
+    let shares_path = dir.path().join("shares.json");
+    let secret = "1f".repeat(32);
+    
+    Command::cargo_bin("z-mpc").unwrap()
+        .args(["share", "--curve", "k1", "--threshold", "3", "--participants", "5", "--secret", &secret, "--output"])
+        .arg(&shares_path)
+        .assert()
+        .success();
+    
+    let data: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&shares_path).unwrap()).unwrap();
+    let shares: Vec<Share> = serde_json::from_value(data["shares"].clone()).unwrap();
+    let params = SharingParams {
+        curve_type: CurveType::Secp256k1,
+        threshold: 3,
+        participants: 5,
+    };
+    let result = LaurentSeries::new(&params).unwrap().reconstruct_secret(&shares[1..4]).unwrap();
+    assert_eq!(hex::encode(result.secret), secret);
+}
+
+#[test]
+fn test_share_generation_matches_naive_evaluation() {
+    for (curve_type, threshold, participants) in [
+        (CurveType::Secp256k1, 3, 5),
+        (CurveType::P256, 4, 6),
+        (CurveType::Edwards25519, 5, 7),
+        (CurveType::Secp256k1, 20, 100),
+    ] {
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let params = SharingParams { curve_type, threshold, participants };
+        let laurent = LaurentSeries::new(&params).unwrap();
+        
+        let started = std::time::Instant::now();
+        let shares = laurent.generate_shares().unwrap();
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+        
+        // Evaluate every term with its own power, one multiplication at a time
+        let power = |base: &z_mpc::Scalar, exponent: usize| {
+            let mut result = curve.scalar_from_u64(1).unwrap();
+            for _ in 0..exponent {
+                result = result.mul(base).unwrap();
+            }
+            result
+        };
+        for share in shares.iter().step_by(participants / 5) {
+            let z = eval_point_for_id(share.id, laurent.id_scheme, curve_type).unwrap();
+            let z_inv = z.invert().unwrap();
+            let mut expected = curve.scalar_from_u64(0).unwrap();
+            for (k, a_k) in laurent.a_coeffs.iter().enumerate() {
+                expected = expected.add(&a_k.mul(&power(&z, k)).unwrap()).unwrap();
+            }
+            for (k, b_neg_k) in laurent.b_coeffs.iter().enumerate() {
+                expected = expected.add(&b_neg_k.mul(&power(&z_inv, k + 1)).unwrap()).unwrap();
+            }
+            assert_eq!(share.value, expected.value, "{} share {}", curve_type, share.id);
+        }
+        
+        // Any threshold subset still reconstructs the secret
+        let result = laurent.reconstruct_secret(&shares[participants - threshold..]).unwrap();
+        assert_eq!(result.secret, laurent.get_secret_key().unwrap().value);
+    }
+}
+
+#[tokio::test]
+async fn test_quorum_reconstructs_over_http() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let (mut coordinators, handles) = network_utils::create_ephemeral_test_network(3, &[curve_type]).await.unwrap();
+        let session_id = network_utils::run_distributed_protocol(&mut coordinators, curve_type, 3).await.unwrap();
+        
+        // All three nodes exchange shares and converge on the dealt secret
+        let results = network_utils::reconstruct_over_network(&coordinators, session_id).await.unwrap();
+        let expected = coordinators[0].laurent_series[&session_id].get_secret_key().unwrap();
+        assert_eq!(results.len(), 3);
+        for result in &results {
+            assert_eq!(result.secret, expected.as_bytes(), "curve {}", curve_type);
+        }
+        
+        // Contributions must be the sender's own share on the dealt series
+        let own = coordinators[1].node.session_shares(session_id).await.unwrap()
+            .into_iter().find(|share| share.id == 2).unwrap();
+        let impersonated = NetworkMessage::ShareContribution { sender_id: 3, session_id, share: own.clone() };
+        let envelope = coordinators[2].seal(&impersonated).unwrap();
+        assert!(matches!(coordinators[0].node.receive_envelope(&envelope).await, Err(z_mpc::Error::AuthError(_))));
+        let forged = NetworkMessage::ShareContribution {
+            sender_id: 2,
+            session_id,
+            share: Share::new(2, results[0].secret.clone()),
+        };
+        let envelope = coordinators[1].seal(&forged).unwrap();
+        assert!(matches!(coordinators[0].node.receive_envelope(&envelope).await, Err(z_mpc::Error::CommitmentError(_))));
+        
+        for handle in handles {
+            handle.abort();
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_broadcast_delivers_shares_over_http() {
+    let curve_type = CurveType::Secp256k1;
+    let (mut coordinators, handles) = network_utils::create_ephemeral_test_network(2, &[curve_type]).await.unwrap();
+    let params = SharingParams {
+        curve_type,
+        threshold: 2,
+        participants: 2,
+    };
+    let session_id = SessionId::random();
+    for coordinator in coordinators.iter_mut() {
+        coordinator.join_protocol(session_id, params.clone()).await.unwrap();
+    }
+    
+    let report = coordinators[0].distribute_shares(session_id).await.unwrap();
+    assert_eq!(report.delivered, vec![2]);
+    assert!(report.is_complete());
+    
+    // The receiving server verified the dealing and kept its own share
+    let shares = coordinators[1].node.session_shares(session_id).await.unwrap();
+    assert_eq!(shares.len(), 1);
+    assert_eq!(shares[0].id, 2);
+    
+    // Once the peer is gone the failure is reported per participant
+    for handle in handles {
+        handle.abort();
+        let _ = handle.await;
+    }
+    let session_id = coordinators[0].initialize_protocol(params).await.unwrap();
+    let report = coordinators[0].distribute_shares(session_id).await.unwrap();
+    assert!(report.delivered.is_empty());
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].0, 2);
+    
+    let heartbeat = NetworkMessage::Heartbeat { sender_id: 1, timestamp: 0 };
+    assert!(matches!(
+        coordinators[0].node.send_to(2, heartbeat).await,
+        Err(z_mpc::Error::NetworkError { participant_id: 2, status: None, .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_each_node_stores_only_its_own_share() {
+    let curve_type = CurveType::P256;
+    let (mut coordinators, handles) = network_utils::create_ephemeral_test_network(3, &[curve_type]).await.unwrap();
+    let params = SharingParams {
+        curve_type,
+        threshold: 2,
+        participants: 3,
+    };
+    let session_id = SessionId::random();
+    for coordinator in coordinators.iter_mut() {
+        coordinator.join_protocol(session_id, params.clone()).await.unwrap();
+    }
+    
+    let report = coordinators[0].distribute_shares(session_id).await.unwrap();
+    assert_eq!(report.delivered, vec![2, 3]);
+    
+    for coordinator in &coordinators {
+        let id = coordinator.node.id;
+        assert_eq!(coordinator.node.received_shares.read().await.len(), 1);
+        let held = coordinator.node.my_share(session_id, 1).await.unwrap();
+        assert_eq!(held.share.id, id);
+        assert!(held.verify(curve_type).unwrap());
+        assert_eq!(coordinator.node.session_shares(session_id).await.unwrap().len(), 1);
+        
+        // The listing shows the share without opening it
+        let listing: serde_json::Value = reqwest::get(format!("http://{}/shares", coordinator.node.address))
+            .await.unwrap()
+            .json().await.unwrap();
+        let shares = listing["shares"].as_array().unwrap();
+        assert_eq!(shares.len(), 1);
+        assert_eq!(shares[0]["session_id"], session_id.to_string());
+        assert_eq!(shares[0]["dealer_id"], 1);
+        assert_eq!(shares[0]["share_id"], id);
+        assert!(shares[0].get("randomness").is_none() && shares[0].get("value").is_none());
+    }
+    
+    // A delivery addressed to another participant is refused
+    let deliveries = coordinators[0].deal_share_deliveries(session_id).unwrap();
+    let (recipient, misdirected) = deliveries.into_iter().find(|(id, _)| *id == 3).unwrap();
+    assert_eq!(recipient, 3);
+    assert!(matches!(
+        coordinators[0].node.send_to(2, misdirected).await,
+        Err(z_mpc::Error::NetworkError { participant_id: 2, status: Some(400), .. })
+    ));
+    
+    for handle in handles {
+        handle.abort();
+    }
+}
+
+#[tokio::test]
+async fn test_concurrent_sessions_keep_their_shares_apart() {
+    let curve_type = CurveType::Secp256k1;
+    let (mut coordinators, handles) = network_utils::create_ephemeral_test_network(2, &[curve_type]).await.unwrap();
+    let params = SharingParams {
+        curve_type,
+        threshold: 2,
+        participants: 2,
+    };
+    
+    // Two sessions dealt by the same node on the same pair
+    let first = coordinators[0].initialize_protocol(params.clone()).await.unwrap();
+    let second = coordinators[0].initialize_protocol(params.clone()).await.unwrap();
+    assert_ne!(first, second);
+    assert_eq!(first.to_string().parse::<SessionId>().unwrap(), first);
+    for session_id in [first, second] {
+        coordinators[1].join_protocol(session_id, params.clone()).await.unwrap();
+    }
+    
+    let (first_report, second_report) = tokio::join!(
+        coordinators[0].distribute_shares(first),
+        coordinators[0].distribute_shares(second),
+    );
+    assert_eq!(first_report.unwrap().delivered, vec![2]);
+    assert_eq!(second_report.unwrap().delivered, vec![2]);
+    
+    // Node 2 holds one share per session from the same dealer
+    let first_share = coordinators[1].node.my_share(first, 1).await.unwrap();
+    let second_share = coordinators[1].node.my_share(second, 1).await.unwrap();
+    assert_ne!(first_share.share.value, second_share.share.value);
+    
+    // Each session reconstructs its own secret
+    let mut secrets = Vec::new();
+    for session_id in [first, second] {
+        let expected = coordinators[0].laurent_series[&session_id].get_secret_key().unwrap();
+        let results = network_utils::reconstruct_over_network(&coordinators, session_id).await.unwrap();
+        for result in results {
+            assert_eq!(result.secret, expected.as_bytes());
+        }
+        secrets.push(expected.as_bytes().to_vec());
+    }
+    assert_ne!(secrets[0], secrets[1]);
+    
+    // A message for a session the peer does not know is refused with 404
+    let stray = NetworkMessage::SecretReconstruction {
+        sender_id: 1,
+        session_id: SessionId::random(),
+        shares: Vec::new(),
+    };
+    assert!(matches!(
+        coordinators[0].node.send_to(2, stray).await,
+        Err(z_mpc::Error::NetworkError { participant_id: 2, status: Some(404), .. })
+    ));
+    
+    for handle in handles {
+        handle.abort();
+    }
+}
+
+#[tokio::test]
+async fn test_session_phases_reject_out_of_order_steps() {
+    use z_mpc::ProtocolPhase;
+    
+    let curve_type = CurveType::P256;
+    let (mut coordinators, handles) = network_utils::create_ephemeral_test_network(3, &[curve_type]).await.unwrap();
+    let params = SharingParams {
+        curve_type,
+        threshold: 2,
+        participants: 3,
+    };
+    let out_of_order = |result: z_mpc::Result<_>, expected, got| {
+        assert!(matches!(
+            result,
+            Err(z_mpc::Error::ProtocolState { expected: e, got: g }) if e == expected && g == got
+        ), "expected {} after {}", expected, got);
+    };
+    
+    // Nothing can be dealt before the session is initialized
+    let session_id = SessionId::random();
+    assert_eq!(coordinators[0].phase(session_id).await, ProtocolPhase::Idle);
+    out_of_order(coordinators[0].distribute_shares(session_id).await.map(|_| ()), ProtocolPhase::Initialized, ProtocolPhase::Idle);
+    for coordinator in coordinators.iter_mut() {
+        coordinator.join_protocol(session_id, params.clone()).await.unwrap();
+        assert_eq!(coordinator.phase(session_id).await, ProtocolPhase::Initialized);
+    }
+    
+    // Nor reconstructed before a dealing was verified, locally or over HTTP
+    let share = LaurentSeries::new(&params).unwrap().generate_shares().unwrap().remove(0);
+    out_of_order(coordinators[1].node.reconstruct(session_id, std::slice::from_ref(&share)).await.map(|_| ()), ProtocolPhase::Verified, ProtocolPhase::Initialized);
+    out_of_order(coordinators[1].request_reconstruction(session_id).await.map(|_| ()), ProtocolPhase::Verified, ProtocolPhase::Initialized);
+    out_of_order(coordinators[1].contribute_share(session_id, &[1, 2]).await, ProtocolPhase::Verified, ProtocolPhase::Initialized);
+    let early = NetworkMessage::SecretReconstruction {
+        sender_id: 1,
+        session_id,
+        shares: vec![share],
+    };
+    assert!(matches!(
+        coordinators[0].node.send_to(2, early).await,
+        Err(z_mpc::Error::NetworkError { participant_id: 2, status: Some(409), .. })
+    ));
+    
+    // Dealing verifies the session everywhere
+    let report = coordinators[0].distribute_shares(session_id).await.unwrap();
+    assert!(report.is_complete());
+    for coordinator in &coordinators {
+        assert_eq!(coordinator.phase(session_id).await, ProtocolPhase::Verified);
+    }
+    
+    // Reconstructing from held shares leaves the phase to the coordinator
+    network_utils::reconstruct_over_network(&coordinators, session_id).await.unwrap();
+    for coordinator in &coordinators {
+        assert_eq!(coordinator.phase(session_id).await, ProtocolPhase::Verified);
+    }
+    
+    // A verified session takes no new dealing
+    out_of_order(coordinators[0].distribute_shares(session_id).await.map(|_| ()), ProtocolPhase::Initialized, ProtocolPhase::Verified);
+    let (_, delivery) = coordinators[0].deal_share_deliveries(session_id).unwrap().remove(1);
+    assert!(matches!(
+        coordinators[0].node.send_to(2, delivery).await,
+        Err(z_mpc::Error::NetworkError { participant_id: 2, status: Some(409), .. })
+    ));
+    
+    // Shares off the dealing are skipped, and the threshold still applies
+    let secret = coordinators[0].laurent_series[&session_id].get_secret_key().unwrap();
+    let mut shares = coordinators[0].node.session_shares(session_id).await.unwrap();
+    let forged = LaurentSeries::new(&params).unwrap().generate_shares().unwrap().remove(2);
+    shares.push(forged.clone());
+    let result = coordinators[0].node.reconstruct(session_id, &shares).await.unwrap();
+    assert_eq!(result.secret, secret.as_bytes());
+    assert_eq!(result.rejected_participants, vec![3]);
+    let own = coordinators[2].node.session_shares(session_id).await.unwrap().remove(0);
+    assert!(matches!(
+        coordinators[2].node.reconstruct(session_id, &[own.clone(), own, forged]).await,
+        Err(z_mpc::Error::InsufficientShares { required: 2, got: 1 })
+    ));
+    assert_eq!(coordinators[2].phase(session_id).await, ProtocolPhase::Verified);
+    
+    // Only the coordinator's reconstruction completes the session
+    let result = coordinators[2].request_reconstruction(session_id).await.unwrap();
+    assert_eq!(result.secret, secret.as_bytes());
+    assert_eq!(coordinators[2].phase(session_id).await, ProtocolPhase::Complete);
+    out_of_order(coordinators[2].distribute_shares(session_id).await.map(|_| ()), ProtocolPhase::Initialized, ProtocolPhase::Complete);
+    
+    for handle in handles {
+        handle.abort();
+    }
+}
+
+#[tokio::test]
+async fn test_request_reconstruction_skips_tampered_shares() {
+    let curve_type = CurveType::Secp256k1;
+    let (mut coordinators, handles) = network_utils::create_ephemeral_test_network(5, &[curve_type]).await.unwrap();
+    let session_id = network_utils::run_distributed_protocol(&mut coordinators, curve_type, 3).await.unwrap();
+    
+    // Node 3 answers with a share that is off the dealt series
+    {
+        let curve = z_mpc::curve::curve_backend(curve_type).unwrap();
+        let mut sessions = coordinators[2].node.sessions.write().await;
+        let share = sessions.get_mut(&session_id).unwrap().shares.iter_mut().find(|share| share.id == 3).unwrap();
+        let tampered = curve.scalar_from_bytes(&share.value).unwrap().add(&curve.scalar_from_u64(1).unwrap()).unwrap();
+        share.value = tampered.into_bytes();
+    }
+    
+    let result = coordinators[0].request_reconstruction(session_id).await.unwrap();
+    let expected = coordinators[0].laurent_series[&session_id].get_secret_key().unwrap();
+    assert_eq!(result.secret, expected.as_bytes());
+    assert_eq!(result.rejected_participants, vec![3]);
+    assert_eq!(result.participants_used.len(), 3);
+    assert!(!result.participants_used.contains(&3));
+    
+    // Without nodes 4 and 5 only two honest shares remain
+    {
+        let mut participants = coordinators[0].node.participants.write().await;
+        for id in [4, 5] {
+            participants.update(id, |participant| participant.is_online = false).unwrap();
+        }
+    }
+    assert!(matches!(
+        coordinators[0].request_reconstruction(session_id).await,
+        Err(z_mpc::Error::InsufficientShares { required: 3, got: 2 })
+    ));
+    
+    for handle in handles {
+        handle.abort();
+    }
+}
+
+#[tokio::test]
+async fn test_threshold_sign_enforces_the_signing_policy() {
+    let curve_type = CurveType::Secp256k1;
+    let config = NodeConfig {
+        signing_policy: Some(PolicyConfig::PrefixAllowList { prefixes: vec![hex::encode("transfer:")] }),
+        ..NodeConfig::default()
+    };
+    let (mut coordinators, handles) = network_utils::create_configured_test_network(4, &[curve_type], &config).await.unwrap();
+    let session_id = network_utils::run_distributed_protocol(&mut coordinators, curve_type, 3).await.unwrap();
+    let secret = coordinators[0].laurent_series[&session_id].get_secret_key().unwrap();
+    let group_public_key = z_mpc::curve::curve_backend(curve_type).unwrap().generator().mul(&secret).unwrap();
+    
+    // An allowed message is signed under the group key by three of four nodes
+    let message = b"transfer:alice:10";
+    let signature = coordinators[0].threshold_sign(session_id, message, &[1, 2, 4], Vec::new()).await.unwrap();
+    assert!(signature.verify_for(message, &group_public_key).unwrap());
+    
+    // A denied message is refused before any signer commits to nonces
+    assert!(matches!(
+        coordinators[0].threshold_sign(session_id, b"rotate-keys", &[1, 2, 4], Vec::new()).await,
+        Err(z_mpc::Error::PolicyDenied(_))
+    ));
+    let request = NetworkMessage::SigningRequest {
+        sender_id: 1,
+        session_id,
+        message: b"rotate-keys".to_vec(),
+        approvals: Vec::new(),
+    };
+    assert!(matches!(
+        coordinators[0].node.request(2, request).await,
+        Err(z_mpc::Error::NetworkError { status: Some(403), .. })
+    ));
+    
+    // Round 2 without an allowed round 1 has no nonces to sign with
+    let request = NetworkMessage::PartialSignatureRequest {
+        sender_id: 1,
+        session_id,
+        message: b"rotate-keys".to_vec(),
+        commitments: Vec::new(),
+    };
+    assert!(coordinators[0].node.request(2, request).await.is_err());
+    
+    for handle in handles {
+        handle.abort();
+    }
+}
+
+#[tokio::test]
+async fn test_threshold_sign_refused_without_a_policy() {
+    let curve_type = CurveType::Secp256k1;
+    let (mut coordinators, handles) = network_utils::create_ephemeral_test_network(3, &[curve_type]).await.unwrap();
+    let session_id = network_utils::run_distributed_protocol(&mut coordinators, curve_type, 2).await.unwrap();
+    
+    assert!(matches!(
+        coordinators[0].threshold_sign(session_id, b"anything", &[1, 2], Vec::new()).await,
+        Err(z_mpc::Error::PolicyDenied(_))
+    ));
+    
+    for handle in handles {
+        handle.abort();
+    }
+}
+
+#[tokio::test]
+async fn test_dkg_shares_reconstruct_to_the_group_key() {
+    let curve_type = CurveType::Secp256k1;
+    let (mut coordinators, handles) = network_utils::create_ephemeral_test_network(4, &[curve_type]).await.unwrap();
+    let params = SharingParams {
+        curve_type,
+        threshold: 3,
+        participants: 4,
+    };
+    
+    let session_id = SessionId::random();
+    let runs = coordinators.iter_mut().map(|coordinator| coordinator.run_dkg(session_id, params.clone()));
+    let outputs: Vec<_> = futures::future::join_all(runs).await
+        .into_iter()
+        .collect::<z_mpc::Result<_>>()
+        .unwrap();
+    
+    // Every node agrees on the group key and holds a share of the joint series
+    let group_public_key = outputs[0].group_public_key.clone();
+    for (i, output) in outputs.iter().enumerate() {
+        assert_eq!(output.share.id, i as u32 + 1);
+        assert_eq!(output.group_public_key, group_public_key);
+        assert!(z_mpc::laurent::verify_share_against_commitments(&output.share, &output.coefficient_commitments, curve_type).unwrap());
+    }
+    
+    // Every 3-of-4 quorum reconstructs the secret behind the group key
+    let curve = z_mpc::curve::curve_backend(curve_type).unwrap();
+    let laurent = LaurentSeries::new(&params).unwrap();
+    let mut secrets = Vec::new();
+    for left_out in 0..outputs.len() {
+        let quorum: Vec<Share> = outputs.iter()
+            .enumerate()
+            .filter(|(i, _)| *i != left_out)
+            .map(|(_, output)| output.share.clone())
+            .collect();
+        let result = laurent.reconstruct_secret(&quorum).unwrap();
+        let secret = curve.scalar_from_bytes(&result.secret).unwrap();
+        assert_eq!(curve.generator().mul(&secret).unwrap(), group_public_key);
+        secrets.push(result.secret);
+    }
+    assert!(secrets.iter().all(|secret| *secret == secrets[0]));
+    
+    // No single contribution is the joint secret
+    for coordinator in &coordinators {
+        let own = coordinator.laurent_series[&session_id].get_secret_key().unwrap();
+        assert_ne!(own.as_bytes(), secrets[0].as_slice());
+    }
+    
+    // The session reconstructs over the network like a dealt one
+    let result = coordinators[0].request_reconstruction(session_id).await.unwrap();
+    assert_eq!(result.secret, secrets[0]);
+    assert!(result.rejected_participants.is_empty());
+    
+    // A sub-share off its commitments draws a complaint and fails the run
+    let tampered = SessionId::random();
+    {
+        let commitments = coordinators[1].laurent_series[&session_id].coefficient_commitments().unwrap();
+        let bogus = Share::new(1, curve.scalar_from_u64(7).unwrap().into_bytes());
+        coordinators[0].node.dkg.record_commitments(tampered, 2, commitments).await.unwrap();
+        coordinators[0].node.dkg.record_share(tampered, 2, bogus).await.unwrap();
+    }
+    let runs = coordinators.iter_mut().map(|coordinator| coordinator.run_dkg(tampered, params.clone()));
+    let results = futures::future::join_all(runs).await;
+    assert!(matches!(results[0], Err(z_mpc::Error::CommitmentError(_))));
+    let round = coordinators[2].node.dkg.round(tampered).await;
+    assert!(round.complaints.contains(&(1, 2)));
+    
+    // A participant that echoes another commitment set fails the run where it disagrees
+    let split = SessionId::random();
+    coordinators[0].node.dkg.record_echo(split, 3, vec![0; 32]).await.unwrap();
+    let runs = coordinators.iter_mut().map(|coordinator| coordinator.run_dkg(split, params.clone()));
+    let results = futures::future::join_all(runs).await;
+    match &results[0] {
+        Err(z_mpc::Error::CommitmentError(message)) => assert!(message.contains("[3]"), "{}", message),
+        other => panic!("expected CommitmentError, got {:?}", other),
+    }
+    assert!(results[1..].iter().all(|result| result.is_ok()));
+    
+    for handle in handles {
+        handle.abort();
+    }
+}
+
+#[tokio::test]
+async fn test_send_to_delivers_and_reports_peer_failures() {
+    let curve_type = CurveType::Secp256k1;
+    let (mut coordinators, handles) = network_utils::create_ephemeral_test_network(2, &[curve_type]).await.unwrap();
+    let params = SharingParams {
+        curve_type,
+        threshold: 2,
+        participants: 2,
+    };
+    let session_id = SessionId::random();
+    for coordinator in coordinators.iter_mut() {
+        coordinator.join_protocol(session_id, params.clone()).await.unwrap();
+    }
+    
+    // A rejected delivery surfaces the peer and its status code
+    let mut deliveries = coordinators[0].deal_share_deliveries(session_id).unwrap();
+    let (_, dealing) = deliveries.remove(1);
+    let NetworkMessage::ShareDelivery { sender_id, session_id, committed_share, coefficient_commitments, .. } = dealing.clone() else {
+        panic!("expected a share delivery");
+    };
+    let (_, NetworkMessage::ShareDelivery { proof, .. }) = deliveries.remove(0) else {
+        panic!("expected a share delivery");
+    };
+    let forged = NetworkMessage::ShareDelivery { sender_id, session_id, committed_share, proof, coefficient_commitments };
+    match coordinators[0].node.send_to(2, forged).await {
+        Err(z_mpc::Error::NetworkError { participant_id, status, .. }) => {
+            assert_eq!(participant_id, 2);
+            assert_eq!(status, Some(400));
+        }
+        other => panic!("expected NetworkError, got {:?}", other),
+    }
+    
+    // Node 2's handler verifies the delivery and stores its share
+    coordinators[0].node.send_to(2, dealing).await.unwrap();
+    let shares = coordinators[1].node.session_shares(session_id).await.unwrap();
+    assert_eq!(shares.len(), 1);
+    assert_eq!(shares[0].id, 2);
+    for handle in handles {
+        handle.abort();
+    }
+    
+    // A peer that accepts but never answers is cut off by the request timeout
+    let silent = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let node = NetworkNode::new(1, "127.0.0.1:0".parse().unwrap()).with_transport(TransportConfig {
+        request_timeout: std::time::Duration::from_millis(200),
+        ..TransportConfig::default()
+    });
+    node.add_participant(Participant {
+        id: 2,
+        address: silent.local_addr().unwrap(),
+        public_keys: std::collections::HashMap::new(),
+        is_online: true,
+        last_heartbeat: 0,
+    }).await.unwrap();
+    
+    let started = std::time::Instant::now();
+    let heartbeat = NetworkMessage::Heartbeat { sender_id: 1, timestamp: 0 };
+    match node.send_to(2, heartbeat).await {
+        Err(z_mpc::Error::NetworkError { participant_id: 2, status: None, message }) => assert!(message.contains("200ms"), "{}", message),
+        other => panic!("expected NetworkError, got {:?}", other),
+    }
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    drop(silent);
+}
+
+#[test]
+fn test_feldman_commitments_verify_shares() {
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let params = SharingParams {
+            curve_type,
+            threshold: 3,
+            participants: 5,
+        };
+        let laurent = LaurentSeries::new(&params).unwrap();
+        let commitments = laurent.commitments().unwrap();
+        assert_eq!(commitments.len(), params.threshold);
+        
+        let shares = laurent.generate_shares().unwrap();
+        for share in &shares {
+            assert!(LaurentSeries::verify_share_against_commitments(share, &commitments).unwrap(), "{} share {}", curve_type, share.id);
+        }
+        
+        // A corrupted value or a share of another dealing fails
+        let curve = z_mpc::curve::create_curve(curve_type).unwrap();
+        let mut corrupted = shares[0].clone();
+        let value = curve.scalar_from_bytes(&corrupted.value).unwrap();
+        corrupted.value = value.add(&curve.scalar_from_u64(1).unwrap()).unwrap().as_bytes().to_vec();
+        assert!(!LaurentSeries::verify_share_against_commitments(&corrupted, &commitments).unwrap());
+        
+        let other = LaurentSeries::new(&params).unwrap().generate_shares().unwrap();
+        assert!(!LaurentSeries::verify_share_against_commitments(&other[0], &commitments).unwrap());
+        assert!(LaurentSeries::verify_share_against_commitments(&shares[0], &[]).is_err());
+    }
+}
+
+#[test]
+fn test_published_coefficient_commitments_verify_shares() {
+    use z_mpc::laurent::verify_share_against_commitments;
+    
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519] {
+        let params = SharingParams {
+            curve_type,
+            threshold: 4,
+            participants: 6,
+        };
+        let laurent = LaurentSeries::new(&params).unwrap();
+        let commitments = laurent.commit_coefficients().unwrap();
+        assert_eq!(commitments, laurent.coefficient_commitments().unwrap());
+        let shares = laurent.generate_shares().unwrap();
+        for share in &shares {
+            assert!(verify_share_against_commitments(share, &commitments, curve_type).unwrap(), "{} share {}", curve_type, share.id);
+        }
+        
+        // Commitments survive the wire
+        let json = serde_json::to_string(&commitments).unwrap();
+        let decoded: z_mpc::CoefficientCommitments = serde_json::from_str(&json).unwrap();
+        assert!(verify_share_against_commitments(&shares[0], &decoded, curve_type).unwrap());
+        
+        // Tampered values, other dealings and shares moved to another id fail
+        let mut tampered = shares[0].clone();
+        tampered.value = shares[1].value.clone();
+        assert!(!verify_share_against_commitments(&tampered, &commitments, curve_type).unwrap());
+        let other = LaurentSeries::new(&params).unwrap().generate_shares().unwrap();
+        assert!(!verify_share_against_commitments(&other[0], &commitments, curve_type).unwrap());
+        let untagged = Share::new(shares[0].id, other[0].value.clone());
+        assert!(!verify_share_against_commitments(&untagged, &commitments, curve_type).unwrap());
+        let moved = Share::new(shares[1].id, shares[0].value.clone());
+        assert!(!verify_share_against_commitments(&moved, &commitments, curve_type).unwrap());
+        
+        // Malformed commitments are errors rather than failures
+        let mut truncated = commitments.clone();
+        truncated.b_commitments.pop();
+        assert!(matches!(verify_share_against_commitments(&shares[0], &truncated, curve_type), Err(z_mpc::Error::InvalidInput(_))));
+        let wrong_curve = if curve_type == CurveType::P256 { CurveType::Secp256k1 } else { CurveType::P256 };
+        assert!(matches!(verify_share_against_commitments(&shares[0], &commitments, wrong_curve), Err(z_mpc::Error::InvalidCurve(_))));
+    }
+}
+
+proptest! {
+    #[test]
+    #[cfg(feature = "p256")]
+    fn prop_p256_scalar_multiplication_matches_p256_crate(bytes in prop::array::uniform32(any::<u8>())) {
+        use p256::elliptic_curve::ff::PrimeField;
+        use p256::elliptic_curve::sec1::ToEncodedPoint;
+        
+        let expected = Option::<p256::Scalar>::from(p256::Scalar::from_repr(bytes.into()));
+        prop_assume!(expected.is_some());
+        let expected = (p256::ProjectivePoint::GENERATOR * expected.unwrap()).to_affine();
+        
+        let curve = z_mpc::curve::create_curve(CurveType::P256).unwrap();
+        let k = curve.scalar_from_bytes(&bytes).unwrap();
+        let point = curve.generator().mul(&k).unwrap();
+        prop_assert_eq!(point.to_compressed_bytes().unwrap(), expected.to_encoded_point(true).as_bytes().to_vec());
+    }
+}
@@ -0,0 +1,61 @@
+//! Cross-implementation test vectors
+//! 
+//! Checks this implementation against the committed vectors in
+//! `tests/vectors`. Regenerate them with the hidden `gen-vectors` command
+//! only when an intentional format change lands.
+
+use z_mpc::{init, CurveType, vectors::{self, TestVector}};
+use assert_cmd::Command;
+
+fn load_vector(curve_type: CurveType) -> TestVector {
+    let path = format!("{}/tests/vectors/{}.json", env!("CARGO_MANIFEST_DIR"), curve_type);
+    let contents = std::fs::read_to_string(&path).expect("vector file exists");
+    serde_json::from_str(&contents).expect("vector file parses")
+}
+
+#[test]
+fn test_vectors_match_committed_files() {
+    init().unwrap();
+    
+    for curve_type in [CurveType::Secp256k1, CurveType::P256, CurveType::Edwards25519, CurveType::Ristretto255] {
+        let expected = load_vector(curve_type);
+        assert_eq!(expected.seed, hex::encode(vectors::DEFAULT_SEED));
+        
+        let generated = vectors::generate(
+            curve_type, vectors::DEFAULT_SEED, vectors::DEFAULT_THRESHOLD, vectors::DEFAULT_PARTICIPANTS,
+        ).unwrap();
+        assert_eq!(generated, expected, "{} vector drifted", curve_type);
+        
+        vectors::check(&expected).unwrap();
+    }
+}
+
+#[test]
+fn test_vector_check_names_the_mismatching_field() {
+    init().unwrap();
+    
+    let mut vector = load_vector(CurveType::Secp256k1);
+    vector.shares[2].commitment = vector.shares[3].commitment.clone();
+    
+    let err = vectors::check(&vector).unwrap_err();
+    assert!(err.to_string().contains("shares"), "unexpected error: {}", err);
+}
+
+#[test]
+fn test_cli_gen_vectors_round_trips() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir_arg = dir.path().to_str().unwrap();
+    
+    Command::cargo_bin("z-mpc").unwrap()
+        .args(["gen-vectors", "--dir", dir_arg])
+        .assert()
+        .success();
+    Command::cargo_bin("z-mpc").unwrap()
+        .args(["gen-vectors", "--dir", dir_arg, "--check"])
+        .assert()
+        .success();
+    
+    let written = std::fs::read_to_string(dir.path().join("ed25519.json")).unwrap();
+    let committed = std::fs::read_to_string(format!("{}/tests/vectors/ed25519.json", env!("CARGO_MANIFEST_DIR"))).unwrap();
+    assert_eq!(written, committed);
+}
@@ -0,0 +1,54 @@
+//! Browser binding tests, run with `wasm-pack test --node --features wasm`
+
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use wasm_bindgen_test::*;
+use z_mpc::wasm;
+
+#[wasm_bindgen_test]
+fn test_generate_and_reconstruct_round_trip() {
+    for curve in ["secp256k1", "p256", "ed25519"] {
+        let dealt: serde_json::Value = serde_json::from_str(
+            &wasm::generate_shares(curve, 3, 5).unwrap().as_string().unwrap(),
+        ).unwrap();
+        assert_eq!(dealt["shares"].as_array().unwrap().len(), 5);
+
+        // Any threshold subset recovers the same secret
+        let first = serde_json::json!({
+            "curve_type": dealt["curve_type"],
+            "shares": dealt["shares"].as_array().unwrap()[..3],
+        });
+        let last = serde_json::json!({
+            "curve_type": dealt["curve_type"],
+            "shares": dealt["shares"].as_array().unwrap()[2..],
+        });
+        let first: serde_json::Value = serde_json::from_str(
+            &wasm::reconstruct(&first.to_string()).unwrap().as_string().unwrap(),
+        ).unwrap();
+        let last: serde_json::Value = serde_json::from_str(
+            &wasm::reconstruct(&last.to_string()).unwrap().as_string().unwrap(),
+        ).unwrap();
+        assert_eq!(first["secret"], last["secret"], "curve {}", curve);
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_commit_and_verify() {
+    for curve in ["secp256k1", "p256", "ed25519"] {
+        let opened: serde_json::Value = serde_json::from_str(
+            &wasm::commit(curve, "2a").unwrap().as_string().unwrap(),
+        ).unwrap();
+        let commitment = opened["commitment"].as_str().unwrap();
+        let randomness = opened["randomness"].as_str().unwrap();
+
+        assert!(wasm::verify_commitment(curve, commitment, "2a", randomness).unwrap());
+        assert!(!wasm::verify_commitment(curve, commitment, "2b", randomness).unwrap());
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_errors_are_thrown_as_strings() {
+    let err = wasm::generate_shares("curve448", 3, 5).unwrap_err();
+    assert!(err.as_string().is_some());
+    assert!(wasm::reconstruct("{}").is_err());
+}